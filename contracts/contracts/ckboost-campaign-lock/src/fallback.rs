@@ -5,11 +5,12 @@ use ckb_deterministic::{
 };
 use ckboost_shared::{
     error::Error,
-    transaction_context::create_transaction_context,
+    transaction_context::create_campaign_transaction_context,
 };
 
 use crate::{
     modules::CKBoostCampaignLock, 
+    recipes::helper::bound_campaign_type_hash,
     ssri::CKBoostCampaign
 };
 
@@ -18,8 +19,14 @@ use crate::{
 pub fn fallback() -> Result<(), Error> {
     debug_trace!("CKBoost Campaign Lock: Starting fallback validation");
     
+    // The lock args start with the type hash of the campaign it is bound to, which leads to
+    // the protocol cell
     debug_trace!("Creating transaction context");
-    let context = match create_transaction_context() {
+    let campaign_type_hash = bound_campaign_type_hash().map_err(|e| {
+        debug_trace!("ERROR: Lock args do not hold a campaign type hash");
+        Error::from(e)
+    })?;
+    let context = match create_campaign_transaction_context(&campaign_type_hash) {
         Ok(ctx) => {
             debug_trace!("Transaction context created successfully");
             ctx
@@ -47,13 +54,75 @@ pub fn fallback() -> Result<(), Error> {
             debug_trace!("verify_approve_completion result: {:?}", verify_result);
             verify_result
         }
+        b"CKBoostCampaign.complete_quest" => {
+            debug_trace!("Executing verify_complete_quest for quest completion");
+            let verify_result = CKBoostCampaignLock::verify_complete_quest(&context);
+            debug_trace!("verify_complete_quest result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.reject_submission" => {
+            debug_trace!("Executing verify_reject_submission for admin unlock");
+            let verify_result = CKBoostCampaignLock::verify_reject_submission(&context);
+            debug_trace!("verify_reject_submission result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.revoke_approval" => {
+            debug_trace!("Executing verify_revoke_approval for admin unlock");
+            let verify_result = CKBoostCampaignLock::verify_revoke_approval(&context);
+            debug_trace!("verify_revoke_approval result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.finalize_approvals" => {
+            debug_trace!("Executing verify_finalize_approvals for admin unlock");
+            let verify_result = CKBoostCampaignLock::verify_finalize_approvals(&context);
+            debug_trace!("verify_finalize_approvals result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.approve_sub_tasks" => {
+            debug_trace!("Executing verify_approve_sub_tasks for admin unlock");
+            let verify_result = CKBoostCampaignLock::verify_approve_sub_tasks(&context);
+            debug_trace!("verify_approve_sub_tasks result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.add_quest" => {
+            debug_trace!("Executing verify_add_quest for quest addition");
+            let verify_result = CKBoostCampaignLock::verify_add_quest(&context);
+            debug_trace!("verify_add_quest result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.edit_quest_metadata" => {
+            debug_trace!("Executing verify_edit_quest_metadata for quest metadata edit");
+            let verify_result = CKBoostCampaignLock::verify_edit_quest_metadata(&context);
+            debug_trace!("verify_edit_quest_metadata result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.update_campaign_metadata" => {
+            debug_trace!("Executing verify_update_campaign_metadata for campaign metadata update");
+            let verify_result = CKBoostCampaignLock::verify_update_campaign_metadata(&context);
+            debug_trace!("verify_update_campaign_metadata result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.set_status" => {
+            debug_trace!("Executing verify_set_status for status change");
+            let verify_result = CKBoostCampaignLock::verify_set_status(&context);
+            debug_trace!("verify_set_status result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostQuest.update_quest" => {
+            debug_trace!("Executing verify_update_quest for quest cell edit");
+            let verify_result = CKBoostCampaignLock::verify_update_quest(&context);
+            debug_trace!("verify_update_quest result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostQuest.approve_completion" => {
+            debug_trace!("Executing verify_approve_quest_completion for quest cell payout");
+            let verify_result = CKBoostCampaignLock::verify_approve_quest_completion(&context);
+            debug_trace!("verify_approve_quest_completion result: {:?}", verify_result);
+            verify_result
+        }
         _ => {
-            // For lock scripts, if no specific method is provided, check both unlock mechanisms
-            debug_trace!("No specific method path, checking unlock mechanisms");
-            
-            // First check if admin is unlocking
-            debug_trace!("User claim detected, validating");
-            CKBoostCampaignLock::verify_approve_completion(&context)
+            debug_trace!("No matching validation rules found for method path: {:?}", core::str::from_utf8(&method_path).unwrap_or("<invalid UTF-8>"));
+            Err(Error::WrongMethodPath)
         }
     };
     
@@ -5,15 +5,14 @@ use ckb_deterministic::{
 };
 use ckboost_shared::transaction_context::TransactionContext;
 use ckb_std::{
-    ckb_constants::Source,
     ckb_types::{
-        packed::{Byte32Vec, Transaction},
+        packed::{Byte32, Byte32Vec, Transaction},
         prelude::*,
     },
-    high_level::{load_cell_type_hash, load_script, load_witness_args}
+    high_level::{load_cell_type_hash, load_script}
 };
 use ckboost_shared::{
-    types::{CampaignData, ConnectedTypeID},
+    types::{CampaignData, CampaignMetadata, ConnectedTypeID, QuestData, QuestMetadata},
     Error,
 };
 
@@ -39,7 +38,10 @@ impl CKBoostCampaign for CKBoostCampaignLock {
         
         // For lock script, we validate that the campaign admin is unlocking
         // This happens when the campaign cell is being updated
-        recipes::approve_completion::validate_approve_completion(context)
+        recipes::approve_completion::validate_approve_completion(context)?;
+        
+        // Withdrawing funds needs a manager holding the manage funds role
+        recipes::fund_management::validate_fund_management(context)
             .map_err(|e| e.into())
     }
     
@@ -48,6 +50,7 @@ impl CKBoostCampaign for CKBoostCampaignLock {
         _campaign_data: CampaignData,
         _quest_id: u32,
         _user_type_ids: Byte32Vec,
+        _approved_at: u64,
     ) -> Result<Transaction, Error> {
         debug_trace!("CKBoostCampaignLock::approve_completion - Not implemented for lock script");
         // Lock scripts don't build approval transactions
@@ -62,48 +65,236 @@ impl CKBoostCampaign for CKBoostCampaignLock {
         
         // For lock script, we validate that an approved user is claiming rewards
         // This checks the approval proof in the transaction
-        recipes::user_claim::validate_user_claim(context)
+        recipes::user_claim::validate_user_claim(context)?;
+        
+        // Participants whose approval can still be revoked are not paid yet
+        recipes::user_claim::validate_no_pending_payouts(context)?;
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
+    }
+    
+    fn complete_quest(
+        _tx: Option<Transaction>,
+        _campaign_data: CampaignData,
+        _quest_id: u32,
+        _user_type_id: Byte32,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignLock::complete_quest - Not implemented for lock script");
+        // Lock scripts don't build quest completion transactions
+        // This is handled by the type script
+        Err(Error::SSRIMethodsNotImplemented)
+    }
+    
+    fn verify_complete_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_complete_quest - Starting validation");
+        
+        // For lock script, we validate that the bound campaign and user cells are spent together
+        recipes::complete_quest::validate_complete_quest(context)?;
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
+    }
+    
+    fn reject_submission(
+        _tx: Option<Transaction>,
+        _campaign_data: CampaignData,
+        _quest_id: u32,
+        _user_type_ids: Byte32Vec,
+        _reason_code: u32,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignLock::reject_submission - Not implemented for lock script");
+        // Lock scripts don't build rejection transactions
+        // This is handled by the type script
+        Err(Error::SSRIMethodsNotImplemented)
+    }
+    
+    fn verify_reject_submission(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_reject_submission - Starting validation");
+        
+        // Rejections never release funds; the campaign admin must be spending the campaign cell
+        recipes::approve_completion::validate_approve_completion(context)?;
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
+    }
+    
+    fn revoke_approval(
+        _tx: Option<Transaction>,
+        _campaign_data: CampaignData,
+        _quest_id: u32,
+        _user_type_ids: Byte32Vec,
+        _recorded_at: u64,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignLock::revoke_approval - Not implemented for lock script");
+        // This is handled by the type script
+        Err(Error::SSRIMethodsNotImplemented)
+    }
+    
+    fn verify_revoke_approval(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_revoke_approval - Starting validation");
+        
+        // Revocations never release funds to participants still under dispute
+        recipes::approve_completion::validate_approve_completion(context)?;
+        recipes::user_claim::validate_no_pending_payouts(context)?;
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
+    }
+    
+    fn finalize_approvals(
+        _tx: Option<Transaction>,
+        _campaign_data: CampaignData,
+        _quest_id: u32,
+        _user_type_ids: Byte32Vec,
+        _recorded_at: u64,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignLock::finalize_approvals - Not implemented for lock script");
+        // This is handled by the type script
+        Err(Error::SSRIMethodsNotImplemented)
+    }
+    
+    fn verify_finalize_approvals(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_finalize_approvals - Starting validation");
+        
+        // Finalized participants may be paid out in the same transaction
+        recipes::approve_completion::validate_approve_completion(context)?;
+        recipes::user_claim::validate_no_pending_payouts(context)?;
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
+    }
+
+    fn approve_sub_tasks(
+        _tx: Option<Transaction>,
+        _campaign_data: CampaignData,
+        _quest_id: u32,
+        _user_type_ids: Byte32Vec,
+        _sub_task_ids: Vec<u8>,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignLock::approve_sub_tasks - Not implemented for lock script");
+        // This is handled by the type script
+        Err(Error::SSRIMethodsNotImplemented)
+    }
+
+    fn verify_approve_sub_tasks(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_approve_sub_tasks - Starting validation");
+
+        // Sub-task approvals never release funds; the campaign admin must be spending the campaign cell
+        recipes::approve_completion::validate_approve_completion(context)?;
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
+    }
+
+    fn add_quest(
+        _tx: Option<Transaction>,
+        _campaign_data: CampaignData,
+        _quest: QuestData,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignLock::add_quest - Not implemented for lock script");
+        // This is handled by the type script
+        Err(Error::SSRIMethodsNotImplemented)
+    }
+
+    fn verify_add_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_add_quest - Starting validation");
+
+        // Campaign patches never release funds
+        recipes::approve_completion::validate_approve_completion(context)?;
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
+    }
+
+    fn edit_quest_metadata(
+        _tx: Option<Transaction>,
+        _campaign_data: CampaignData,
+        _quest_id: u32,
+        _metadata: QuestMetadata,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignLock::edit_quest_metadata - Not implemented for lock script");
+        // This is handled by the type script
+        Err(Error::SSRIMethodsNotImplemented)
+    }
+
+    fn verify_edit_quest_metadata(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_edit_quest_metadata - Starting validation");
+
+        // Campaign patches never release funds
+        recipes::approve_completion::validate_approve_completion(context)?;
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
+    }
+
+    fn update_campaign_metadata(
+        _tx: Option<Transaction>,
+        _campaign_data: CampaignData,
+        _metadata: CampaignMetadata,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignLock::update_campaign_metadata - Not implemented for lock script");
+        // This is handled by the type script
+        Err(Error::SSRIMethodsNotImplemented)
+    }
+
+    fn verify_update_campaign_metadata(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_update_campaign_metadata - Starting validation");
+
+        // Campaign patches never release funds
+        recipes::approve_completion::validate_approve_completion(context)?;
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
+    }
+
+    fn set_status(
+        _tx: Option<Transaction>,
+        _campaign_data: CampaignData,
+        _status: u8,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignLock::set_status - Not implemented for lock script");
+        // This is handled by the type script
+        Err(Error::SSRIMethodsNotImplemented)
+    }
+
+    fn verify_set_status(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_set_status - Starting validation");
+
+        // Campaign patches never release funds
+        recipes::approve_completion::validate_approve_completion(context)?;
+        recipes::reward_distribution::validate_reward_outflow(context)
             .map_err(|e| e.into())
     }
 }
 
 // Helper functions for lock validation
 impl CKBoostCampaignLock {
-    /// Check if an approved user is claiming rewards
-    pub fn is_approved_user_claiming(campaign_type_id: &[u8]) -> Result<bool, Error> {
-        debug_trace!("Checking if approved user is claiming");
-        
-        // Check if there's approval data in any witness
-        let mut index = 0;
-        loop {
-            match load_witness_args(index, Source::Input) {
-                Ok(witness_args) => {
-                    // Check if output_type contains approval proof
-                    match witness_args.output_type().to_opt() {
-                        Some(output_type) => {
-                            // Parse approval proof from output_type field
-                            // Format: [method_name][quest_id][user_type_ids]
-                            let proof_data = output_type.raw_data();
-                            if proof_data.starts_with(b"CKBoostCampaign.approve_completion") {
-                                debug_trace!("Found approval proof in witness");
-                                return Ok(true);
-                            }
-                        }
-                        None => {
-                            // No output_type data in this witness
-                        }
-                    }
-                }
-                Err(ckb_std::error::SysError::IndexOutOfBound) => {
-                    break;
-                }
-                Err(_e) => {
-                    // Continue checking other witnesses
-                }
-            }
-            index += 1;
-        }
-        
-        Ok(false)
+    /// Quest cell edits never release funds
+    pub fn verify_update_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_update_quest - Starting validation");
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
+    }
+    
+    /// Quest cell approvals release exactly the rewards of the newly approved users;
+    /// the quest type script checks the reviewer and the payouts
+    pub fn verify_approve_quest_completion(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("CKBoostCampaignLock::verify_approve_quest_completion - Starting validation");
+        recipes::reward_distribution::validate_reward_outflow(context)
+            .map_err(|e| e.into())
     }
 }
\ No newline at end of file
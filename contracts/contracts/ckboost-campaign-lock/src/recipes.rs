@@ -4,20 +4,20 @@ pub mod helper {
     use ckb_deterministic::debug_trace;
     use ckb_deterministic::errors::Error as DeterministicError;
     use ckb_std::ckb_constants::Source;
-    use ckb_std::high_level::{load_cell_data, load_cell_type_hash};
+    use alloc::vec::Vec;
+    use ckb_std::high_level::{load_cell_type_hash, load_script};
     use ckboost_shared::types::CampaignData;
     use molecule::prelude::Entity;
     
-    /// Find campaign cell in inputs and validate it exists
+    /// Find the campaign cell this lock is bound to in inputs
+    /// Spending it runs the campaign type script, which checks the signer's role for the recipe
     pub fn find_campaign_cell_in_inputs(
-        campaign_type_id: &[u8],
+        campaign_type_hash: &[u8],
     ) -> Result<(), DeterministicError> {
         let mut index = 0;
         loop {
             match load_cell_type_hash(index, Source::Input) {
-                Ok(Some(_type_hash)) => {
-                    // TODO: Parse ConnectedTypeID from cell and check if type_id matches
-                    // For now, we assume any type script is a campaign cell
+                Ok(Some(type_hash)) if type_hash.as_slice() == campaign_type_hash => {
                     debug_trace!("Found campaign cell in inputs at index {}", index);
                     return Ok(());
                 }
@@ -30,6 +30,15 @@ pub mod helper {
         Err(DeterministicError::CellRelationshipRuleViolation)
     }
     
+    /// Type hash of the campaign this lock is bound to, taken from the first 32 bytes of its args
+    pub fn bound_campaign_type_hash() -> Result<Vec<u8>, DeterministicError> {
+        let args = load_script().map_err(|_| DeterministicError::Encoding)?.args().raw_data();
+        if args.len() < 32 {
+            return Err(DeterministicError::Encoding);
+        }
+        Ok(args[0..32].to_vec())
+    }
+    
     /// Validate user is in the approved list for a quest
     pub fn validate_user_in_approved_list(
         campaign_data: &CampaignData,
@@ -62,79 +71,341 @@ pub mod approve_completion {
     use ckb_deterministic::errors::Error as DeterministicError;
     use ckb_deterministic::cell_classifier::RuleBasedClassifier;
     use ckboost_shared::transaction_context::TransactionContext;
+    use super::helper::{bound_campaign_type_hash, find_campaign_cell_in_inputs};
     
-    /// Validate that the campaign admin is unlocking funds
-    /// This is valid when the campaign cell itself is being spent
+    /// Validate that a campaign admin is unlocking funds
+    /// This is valid when the campaign cell this lock is bound to is being spent, as the
+    /// campaign type script then requires an input locked by the endorser or a manager
+    /// holding the role the recipe needs
     pub fn validate_approve_completion(
-        context: &TransactionContext<RuleBasedClassifier>,
+        _context: &TransactionContext<RuleBasedClassifier>,
     ) -> Result<(), DeterministicError> {
         debug_trace!("Validating admin unlock");
         
-        // Check that a campaign cell exists in inputs
-        // This means the campaign admin (who owns the campaign cell) is signing the transaction
-        let input_campaign_cells = context.input_cells.get_custom("campaign");
+        find_campaign_cell_in_inputs(&bound_campaign_type_hash()?).map_err(|err| {
+            debug_trace!("Bound campaign cell not in inputs - admin unlock is invalid");
+            err
+        })
+    }
+}
+
+pub mod complete_quest {
+    use ckb_deterministic::debug_trace;
+    use ckb_deterministic::errors::Error as DeterministicError;
+    use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+    use ckboost_shared::transaction_context::TransactionContext;
+    use super::helper::{bound_campaign_type_hash, find_campaign_cell_in_inputs};
+    
+    /// Validate that campaign funds are only unlocked alongside a quest completion
+    /// The campaign cell this lock is bound to must be spent, so the campaign type script runs its
+    /// complete_quest rules, and the completing user's cell must be spent with it
+    pub fn validate_complete_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        debug_trace!("Validating quest completion unlock");
         
-        if input_campaign_cells.is_some() {
-            debug_trace!("Campaign cell found in inputs - admin unlock is valid");
-            Ok(())
-        } else {
-            debug_trace!("No campaign cell in inputs - admin unlock is invalid");
-            Err(DeterministicError::CellRelationshipRuleViolation)
+        // Any campaign cell would satisfy the classifier; only the bound one vouches for this lock
+        find_campaign_cell_in_inputs(&bound_campaign_type_hash()?).map_err(|err| {
+            debug_trace!("Bound campaign cell not in inputs - quest completion unlock is invalid");
+            err
+        })?;
+        
+        if context.input_cells.get_custom("user").is_none() {
+            debug_trace!("No user cell in inputs - quest completion unlock is invalid");
+            return Err(DeterministicError::CellRelationshipRuleViolation);
         }
+        
+        debug_trace!("Campaign and user cells found in inputs - quest completion unlock is valid");
+        Ok(())
     }
 }
 
 pub mod user_claim {
     use ckb_deterministic::debug_trace;
     use ckb_deterministic::errors::Error as DeterministicError;
-    use ckb_deterministic::transaction_recipe::TransactionRecipeExt;
     use ckb_deterministic::cell_classifier::RuleBasedClassifier;
     use ckboost_shared::transaction_context::TransactionContext;
     use ckb_std::ckb_constants::Source;
-    use ckb_std::high_level::load_witness_args;
+    use ckb_std::high_level::{load_cell_lock_hash, load_cell_type_hash, QueryIter};
+    use super::helper::{bound_campaign_type_hash, find_campaign_cell_in_inputs};
+    use ckboost_shared::approvals::{load_approval_proof, proven_approvals};
+    use ckboost_shared::campaign_data::connected_type_id_from_args;
+    use ckboost_shared::types::CampaignData;
+    use ckboost_shared::CampaignDataExt;
+    use molecule::prelude::Entity;
     
     /// Validate that an approved user is claiming rewards
-    /// This checks for approval proof in the transaction witnesses
+    /// The campaign cell this lock is bound to must be spent, so the campaign type script checks
+    /// the approval and the reviewer's role
     pub fn validate_user_claim(
         context: &TransactionContext<RuleBasedClassifier>,
     ) -> Result<(), DeterministicError> {
         debug_trace!("Validating user claim");
         
-        // Check for approval proof in witnesses
-        let mut index = 0;
-        let mut found_proof = false;
+        let campaign_type_hash = bound_campaign_type_hash()?;
+        find_campaign_cell_in_inputs(&campaign_type_hash).map_err(|err| {
+            debug_trace!("Bound campaign cell not in inputs - user claim is invalid");
+            err
+        })?;
         
-        loop {
-            match load_witness_args(index, Source::Input) {
-                Ok(witness_args) => {
-                    // Check if output_type contains approval proof
-                    match witness_args.output_type().to_opt() {
-                        Some(output_type) => {
-                            let proof_data = output_type.raw_data();
-                            if proof_data.starts_with(b"CKBoostCampaign.approve_completion") {
-                                debug_trace!("Found approval proof in witness at index {}", index);
-                                found_proof = true;
-                                break;
-                            }
-                        }
-                        None => {}
+        // Claims from SMT campaigns also need an approval proof that verifies against the
+        // approvals roots
+        if let (Some(input_cells), Some(output_cells)) = (
+            context.input_cells.get_custom("campaign"),
+            context.output_cells.get_custom("campaign"),
+        ) {
+            let input_data = CampaignData::from_slice(&input_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_data = CampaignData::from_slice(&output_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            if output_data.tracks_approvals_in_smt() {
+                let proven = load_approval_proof(&campaign_type_hash)
+                    .map_err(|_| DeterministicError::Encoding)?
+                    .and_then(|proof| proven_approvals(&input_data, &output_data, &proof));
+                if proven.is_none() {
+                    debug_trace!("SMT campaign claim has no verified approval proof");
+                    return Err(DeterministicError::CellRelationshipRuleViolation);
+                }
+            }
+        }
+        
+        debug_trace!("User claim is valid");
+        Ok(())
+    }
+    
+    /// Refuse payouts to participants whose approval is still inside the dispute window
+    /// A payout is any output locked by a pending participant's lock, other than their user cell
+    pub fn validate_no_pending_payouts(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let campaign_cells = match context
+            .output_cells
+            .get_custom("campaign")
+            .or_else(|| context.input_cells.get_custom("campaign"))
+        {
+            Some(cells) => cells,
+            None => return Ok(()),
+        };
+        let campaign_data = CampaignData::from_slice(&campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+        
+        for quest in campaign_data.quests().into_iter() {
+            for record in quest.pending_approvals().into_iter() {
+                let user_cell = context
+                    .cell_deps
+                    .get_custom("user")
+                    .into_iter()
+                    .chain(context.input_cells.get_custom("user"))
+                    .flatten()
+                    .find(|cell| {
+                        cell.type_script
+                            .as_ref()
+                            .and_then(|script| connected_type_id_from_args(&script.args().raw_data()))
+                            .map(|connected_type_id| {
+                                connected_type_id.type_id().as_slice() == record.user_type_id().as_slice()
+                            })
+                            .unwrap_or(false)
+                    });
+                // Without the user cell the payout target is unknown, so refuse the transaction
+                let user_cell = match user_cell {
+                    Some(cell) => cell,
+                    None => {
+                        debug_trace!(
+                            "User cell for pending approval {:?} is missing from the transaction",
+                            record.user_type_id()
+                        );
+                        return Err(DeterministicError::CellRelationshipRuleViolation);
                     }
+                };
+                
+                let paid_out = QueryIter::new(load_cell_lock_hash, Source::Output)
+                    .enumerate()
+                    .any(|(index, lock_hash)| {
+                        let is_user_cell = load_cell_type_hash(index, Source::Output)
+                            .ok()
+                            .flatten()
+                            .map(|type_hash| {
+                                user_cell.type_hash.as_ref().map(|hash| hash.as_slice())
+                                    == Some(&type_hash[..])
+                            })
+                            .unwrap_or(false);
+                        user_cell.lock_hash.as_slice() == &lock_hash[..] && !is_user_cell
+                    });
+                if paid_out {
+                    debug_trace!(
+                        "Payout to user {:?} refused - approval still inside dispute window",
+                        record.user_type_id()
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
                 }
-                Err(ckb_std::error::SysError::IndexOutOfBound) => break,
-                Err(_) => {}
             }
-            index += 1;
         }
         
-        if found_proof {
-            // TODO: Further validate the proof contains valid quest_id and user_type_id
-            // For now, presence of proof is sufficient
-            debug_trace!("User claim is valid with approval proof");
-            Ok(())
-        } else {
-            debug_trace!("No approval proof found - user claim is invalid");
-            Err(DeterministicError::CellRelationshipRuleViolation)
+        Ok(())
+    }
+}
+
+pub mod reward_distribution {
+    use ckb_deterministic::debug_trace;
+    use ckb_deterministic::errors::Error as DeterministicError;
+    use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+    use ckboost_shared::transaction_context::TransactionContext;
+    use ckb_std::ckb_constants::Source;
+    use alloc::vec::Vec;
+    use ckb_deterministic::cell_classifier::CellInfo;
+    use ckb_std::high_level::load_script_hash;
+    use super::helper::bound_campaign_type_hash;
+    use ckboost_shared::campaign_data::connected_type_id_from_args;
+    use ckboost_shared::approvals::{load_approval_proof, rewarded_by_proof};
+    use ckboost_shared::rewards::{rewarded_in_quest, rewarded_participants, CarriedAssets, RewardEntitlement};
+    use ckboost_shared::types::{CampaignData, QuestData};
+    use ckboost_shared::CampaignDataExt;
+    use molecule::prelude::Entity;
+    
+    /// Cap what leaves this lock at the rewards that became payable in this transaction
+    /// Owed assets are derived from the campaign cell transition and from the transitions
+    /// of quest cells linked to this campaign; everything else must stay
+    pub fn validate_reward_outflow(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let mut owed = RewardEntitlement::default();
+        let campaign_type_hash = bound_campaign_type_hash()?;
+        let input_cells = context.input_cells.get_custom("campaign");
+        let output_cells = context.output_cells.get_custom("campaign");
+        if let (Some(input_cells), Some(output_cells)) = (input_cells, output_cells) {
+            let input_data = CampaignData::from_slice(&input_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_data = CampaignData::from_slice(&output_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            for (_, entitlement) in rewarded_participants(&input_data, &output_data).iter() {
+                owed.merge(entitlement);
+            }
+            // Approvals of SMT campaigns only count once their proof verifies
+            if output_data.tracks_approvals_in_smt() {
+                if let Some(proof) = load_approval_proof(&campaign_type_hash)
+                    .map_err(|_| DeterministicError::Encoding)?
+                {
+                    for (_, entitlement) in rewarded_by_proof(&input_data, &output_data, &proof).iter() {
+                        owed.merge(entitlement);
+                    }
+                }
+            }
+        }
+        
+        for output_cell in linked_quest_cells(context.output_cells.get_custom("quest"), &campaign_type_hash) {
+            let output_quest = QuestData::from_slice(&output_cell.data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let input_quest = match linked_quest_cells(context.input_cells.get_custom("quest"), &campaign_type_hash)
+                .find(|input_cell| {
+                    input_cell.type_hash.as_ref().map(|hash| hash.as_slice())
+                        == output_cell.type_hash.as_ref().map(|hash| hash.as_slice())
+                })
+            {
+                Some(input_cell) => Some(
+                    QuestData::from_slice(&input_cell.data).map_err(|_| DeterministicError::Encoding)?,
+                ),
+                None => None,
+            };
+            for (_, entitlement) in rewarded_in_quest(input_quest.as_ref(), &output_quest).iter() {
+                owed.merge(entitlement);
+            }
+        }
+        
+        let (input_assets, output_assets) = locked_assets(context)?;
+        if !CarriedAssets::releases_within(&input_assets, &output_assets, &owed) {
+            debug_trace!("Campaign lock releases more than the rewards owed: {:?}", owed);
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+        
+        Ok(())
+    }
+    
+    /// Assets under this lock in inputs and outputs, leaving out the campaign cell itself
+    pub fn locked_assets(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(CarriedAssets, CarriedAssets), DeterministicError> {
+        let self_hash = load_script_hash().map_err(|_| DeterministicError::Encoding)?;
+        let mut input_assets = CarriedAssets::locked_by(Source::Input, &self_hash)
+            .map_err(|_| DeterministicError::Encoding)?;
+        let mut output_assets = CarriedAssets::locked_by(Source::Output, &self_hash)
+            .map_err(|_| DeterministicError::Encoding)?;
+        
+        let campaign_cells = context
+            .input_cells
+            .get_custom("campaign")
+            .or_else(|| context.output_cells.get_custom("campaign"));
+        if let Some(campaign_type) = campaign_cells.and_then(|cells| cells[0].type_script.clone()) {
+            input_assets = input_assets.without_type(campaign_type.as_slice());
+            output_assets = output_assets.without_type(campaign_type.as_slice());
+        }
+        // Quest cells kept under this lock are state, not funds
+        let quest_cells = context
+            .input_cells
+            .get_custom("quest")
+            .into_iter()
+            .chain(context.output_cells.get_custom("quest"))
+            .flatten();
+        for quest_type in quest_cells.filter_map(|cell| cell.type_script.clone()) {
+            input_assets = input_assets.without_type(quest_type.as_slice());
+            output_assets = output_assets.without_type(quest_type.as_slice());
+        }
+        Ok((input_assets, output_assets))
+    }
+    
+    /// Quest cells whose ConnectedTypeID links them to the campaign with this type hash
+    fn linked_quest_cells<'a>(
+        cells: Option<&'a Vec<CellInfo>>,
+        campaign_type_hash: &'a [u8],
+    ) -> impl Iterator<Item = &'a CellInfo> + 'a {
+        cells.into_iter().flatten().filter(move |cell| {
+            cell.type_script
+                .as_ref()
+                .and_then(|script| connected_type_id_from_args(&script.args().raw_data()))
+                .map(|connected_type_id| connected_type_id.connected_key().as_slice() == campaign_type_hash)
+                .unwrap_or(false)
+        })
+    }
+}
+
+pub mod fund_management {
+    use ckb_deterministic::debug_trace;
+    use ckb_deterministic::errors::Error as DeterministicError;
+    use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+    use ckboost_shared::transaction_context::TransactionContext;
+    use ckboost_shared::authority::inputs_hold_role;
+    use ckboost_shared::campaign_data::ROLE_MANAGE_FUNDS;
+    use ckboost_shared::rewards::{CarriedAssets, RewardEntitlement};
+    use ckboost_shared::types::CampaignData;
+    use molecule::prelude::Entity;
+    use super::reward_distribution::locked_assets;
+    
+    /// Only managers with the manage funds role may move funds out of this lock
+    /// Adding funds and updates that leave the locked assets in place need no role
+    pub fn validate_fund_management(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let (input_assets, output_assets) = locked_assets(context)?;
+        if CarriedAssets::releases_within(&input_assets, &output_assets, &RewardEntitlement::default()) {
+            return Ok(());
+        }
+        
+        let campaign_cells = context
+            .input_cells
+            .get_custom("campaign")
+            .or_else(|| context.cell_deps.get_custom("campaign"))
+            .ok_or_else(|| {
+                debug_trace!("Moving campaign funds requires the campaign cell in inputs or cell deps");
+                DeterministicError::CellRelationshipRuleViolation
+            })?;
+        let campaign_data = CampaignData::from_slice(&campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+        
+        if !inputs_hold_role(&campaign_data, ROLE_MANAGE_FUNDS) {
+            debug_trace!("No input is locked by a manager allowed to move campaign funds");
+            return Err(DeterministicError::BusinessRuleViolation);
         }
+        
+        Ok(())
     }
 }
 
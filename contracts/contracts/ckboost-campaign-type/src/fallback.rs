@@ -44,6 +44,66 @@ pub fn fallback() -> Result<(), Error> {
             debug_trace!("verify_approve_completion result: {:?}", verify_result);
             verify_result
         }
+        b"CKBoostCampaign.complete_quest" => {
+            debug_trace!("Executing verify_complete_quest");
+            let verify_result = CKBoostCampaignType::verify_complete_quest(&context);
+            debug_trace!("verify_complete_quest result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.reject_submission" => {
+            debug_trace!("Executing verify_reject_submission");
+            let verify_result = CKBoostCampaignType::verify_reject_submission(&context);
+            debug_trace!("verify_reject_submission result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.revoke_approval" => {
+            debug_trace!("Executing verify_revoke_approval");
+            let verify_result = CKBoostCampaignType::verify_revoke_approval(&context);
+            debug_trace!("verify_revoke_approval result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.finalize_approvals" => {
+            debug_trace!("Executing verify_finalize_approvals");
+            let verify_result = CKBoostCampaignType::verify_finalize_approvals(&context);
+            debug_trace!("verify_finalize_approvals result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.approve_sub_tasks" => {
+            debug_trace!("Executing verify_approve_sub_tasks");
+            let verify_result = CKBoostCampaignType::verify_approve_sub_tasks(&context);
+            debug_trace!("verify_approve_sub_tasks result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.add_quest" => {
+            debug_trace!("Executing verify_add_quest");
+            let verify_result = CKBoostCampaignType::verify_add_quest(&context);
+            debug_trace!("verify_add_quest result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.edit_quest_metadata" => {
+            debug_trace!("Executing verify_edit_quest_metadata");
+            let verify_result = CKBoostCampaignType::verify_edit_quest_metadata(&context);
+            debug_trace!("verify_edit_quest_metadata result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.update_campaign_metadata" => {
+            debug_trace!("Executing verify_update_campaign_metadata");
+            let verify_result = CKBoostCampaignType::verify_update_campaign_metadata(&context);
+            debug_trace!("verify_update_campaign_metadata result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostCampaign.set_status" => {
+            debug_trace!("Executing verify_set_status");
+            let verify_result = CKBoostCampaignType::verify_set_status(&context);
+            debug_trace!("verify_set_status result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostQuest.update_quest" => {
+            debug_trace!("Executing verify_update_quest");
+            let verify_result = CKBoostCampaignType::verify_update_quest(&context);
+            debug_trace!("verify_update_quest result: {:?}", verify_result);
+            verify_result
+        }
         _ => {
             debug_trace!("No matching validation rules found for method path: {:?}", core::str::from_utf8(&method_path).unwrap_or("<invalid UTF-8>"));
             Err(Error::SSRIMethodsNotImplemented)
@@ -141,8 +141,334 @@ fn program_entry_wrap() -> Result<(), Error> {
                     Error::InvalidArgument
                 })?;
             
+            // Parse optional approved_at from argv[5] (u64), only needed with a dispute window
+            let approved_at = if argv.len() > 5 && !argv[5].is_empty() {
+                let approved_at_bytes = ckb_std::high_level::decode_hex(argv[5].as_ref())?;
+                if approved_at_bytes.len() != 8 {
+                    return Err(Error::SSRIMethodsArgsInvalid);
+                }
+                let mut approved_at_array = [0u8; 8];
+                approved_at_array.copy_from_slice(&approved_at_bytes);
+                u64::from_le_bytes(approved_at_array)
+            } else {
+                0
+            };
+            
             // Call the approve_completion method and return the transaction
-            let result_tx = crate::modules::CKBoostCampaignType::approve_completion(tx, campaign_data, quest_id, user_type_ids)?;
+            let result_tx = crate::modules::CKBoostCampaignType::approve_completion(tx, campaign_data, quest_id, user_type_ids, approved_at)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+        "CKBoostCampaign.complete_quest" => {
+            debug_trace!("Entered CKBoostCampaign.complete_quest");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse campaign_data from molecule serialized bytes (argv[2])
+            let campaign_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let campaign_data = ckboost_shared::types::CampaignData::from_slice(&campaign_data_bytes)
+                .map_err(|_| Error::InvalidCampaignData)?;
+            
+            // Parse quest_id from argv[3] (u32)
+            let quest_id_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            if quest_id_bytes.len() != 4 {
+                return Err(Error::SSRIMethodsArgsInvalid);
+            }
+            let quest_id = u32::from_le_bytes([quest_id_bytes[0], quest_id_bytes[1], quest_id_bytes[2], quest_id_bytes[3]]);
+            
+            // Parse user_type_id from argv[4] (Byte32)
+            let user_type_id_bytes = ckb_std::high_level::decode_hex(argv[4].as_ref())?;
+            let user_type_id = ckb_std::ckb_types::packed::Byte32::from_slice(&user_type_id_bytes)
+                .map_err(|_| Error::InvalidArgument)?;
+            
+            // Call the complete_quest method and return the transaction
+            let result_tx = crate::modules::CKBoostCampaignType::complete_quest(tx, campaign_data, quest_id, user_type_id)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+        "CKBoostCampaign.reject_submission" => {
+            debug_trace!("Entered CKBoostCampaign.reject_submission");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse campaign_data from molecule serialized bytes (argv[2])
+            let campaign_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let campaign_data = ckboost_shared::types::CampaignData::from_slice(&campaign_data_bytes)
+                .map_err(|_| Error::InvalidCampaignData)?;
+            
+            // Parse quest_id from argv[3] (u32)
+            let quest_id_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            if quest_id_bytes.len() != 4 {
+                return Err(Error::SSRIMethodsArgsInvalid);
+            }
+            let quest_id = u32::from_le_bytes([quest_id_bytes[0], quest_id_bytes[1], quest_id_bytes[2], quest_id_bytes[3]]);
+            
+            // Parse user_type_ids from argv[4]
+            let user_type_ids_bytes = ckb_std::high_level::decode_hex(argv[4].as_ref())?;
+            let user_type_ids = Byte32Vec::from_slice(&user_type_ids_bytes)
+                .map_err(|_| Error::InvalidArgument)?;
+            
+            // Parse reason_code from argv[5] (u32)
+            let reason_code_bytes = ckb_std::high_level::decode_hex(argv[5].as_ref())?;
+            if reason_code_bytes.len() != 4 {
+                return Err(Error::SSRIMethodsArgsInvalid);
+            }
+            let reason_code = u32::from_le_bytes([reason_code_bytes[0], reason_code_bytes[1], reason_code_bytes[2], reason_code_bytes[3]]);
+            
+            // Call the reject_submission method and return the transaction
+            let result_tx = crate::modules::CKBoostCampaignType::reject_submission(tx, campaign_data, quest_id, user_type_ids, reason_code)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+        "CKBoostCampaign.revoke_approval" => {
+            debug_trace!("Entered CKBoostCampaign.revoke_approval");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse campaign_data from molecule serialized bytes (argv[2])
+            let campaign_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let campaign_data = ckboost_shared::types::CampaignData::from_slice(&campaign_data_bytes)
+                .map_err(|_| Error::InvalidCampaignData)?;
+            
+            // Parse quest_id from argv[3] (u32)
+            let quest_id_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            if quest_id_bytes.len() != 4 {
+                return Err(Error::SSRIMethodsArgsInvalid);
+            }
+            let quest_id = u32::from_le_bytes([quest_id_bytes[0], quest_id_bytes[1], quest_id_bytes[2], quest_id_bytes[3]]);
+            
+            // Parse user_type_ids from argv[4]
+            let user_type_ids_bytes = ckb_std::high_level::decode_hex(argv[4].as_ref())?;
+            let user_type_ids = Byte32Vec::from_slice(&user_type_ids_bytes)
+                .map_err(|_| Error::InvalidArgument)?;
+            
+            // Parse recorded_at from argv[5] (u64), the latest header dep timestamp
+            let recorded_at = if argv.len() > 5 && !argv[5].is_empty() {
+                let recorded_at_bytes = ckb_std::high_level::decode_hex(argv[5].as_ref())?;
+                if recorded_at_bytes.len() != 8 {
+                    return Err(Error::SSRIMethodsArgsInvalid);
+                }
+                let mut recorded_at_array = [0u8; 8];
+                recorded_at_array.copy_from_slice(&recorded_at_bytes);
+                u64::from_le_bytes(recorded_at_array)
+            } else {
+                0
+            };
+            
+            // Call the revoke_approval method and return the transaction
+            let result_tx = crate::modules::CKBoostCampaignType::revoke_approval(tx, campaign_data, quest_id, user_type_ids, recorded_at)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+        "CKBoostCampaign.finalize_approvals" => {
+            debug_trace!("Entered CKBoostCampaign.finalize_approvals");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse campaign_data from molecule serialized bytes (argv[2])
+            let campaign_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let campaign_data = ckboost_shared::types::CampaignData::from_slice(&campaign_data_bytes)
+                .map_err(|_| Error::InvalidCampaignData)?;
+            
+            // Parse quest_id from argv[3] (u32)
+            let quest_id_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            if quest_id_bytes.len() != 4 {
+                return Err(Error::SSRIMethodsArgsInvalid);
+            }
+            let quest_id = u32::from_le_bytes([quest_id_bytes[0], quest_id_bytes[1], quest_id_bytes[2], quest_id_bytes[3]]);
+            
+            // Parse user_type_ids from argv[4]
+            let user_type_ids_bytes = ckb_std::high_level::decode_hex(argv[4].as_ref())?;
+            let user_type_ids = Byte32Vec::from_slice(&user_type_ids_bytes)
+                .map_err(|_| Error::InvalidArgument)?;
+            
+            // Parse recorded_at from argv[5] (u64), the latest header dep timestamp
+            let recorded_at = if argv.len() > 5 && !argv[5].is_empty() {
+                let recorded_at_bytes = ckb_std::high_level::decode_hex(argv[5].as_ref())?;
+                if recorded_at_bytes.len() != 8 {
+                    return Err(Error::SSRIMethodsArgsInvalid);
+                }
+                let mut recorded_at_array = [0u8; 8];
+                recorded_at_array.copy_from_slice(&recorded_at_bytes);
+                u64::from_le_bytes(recorded_at_array)
+            } else {
+                0
+            };
+            
+            // Call the finalize_approvals method and return the transaction
+            let result_tx = crate::modules::CKBoostCampaignType::finalize_approvals(tx, campaign_data, quest_id, user_type_ids, recorded_at)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+        "CKBoostCampaign.approve_sub_tasks" => {
+            debug_trace!("Entered CKBoostCampaign.approve_sub_tasks");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse campaign_data from molecule serialized bytes (argv[2])
+            let campaign_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let campaign_data = ckboost_shared::types::CampaignData::from_slice(&campaign_data_bytes)
+                .map_err(|_| Error::InvalidCampaignData)?;
+            
+            // Parse quest_id from argv[3] (u32)
+            let quest_id_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            if quest_id_bytes.len() != 4 {
+                return Err(Error::SSRIMethodsArgsInvalid);
+            }
+            let quest_id = u32::from_le_bytes([quest_id_bytes[0], quest_id_bytes[1], quest_id_bytes[2], quest_id_bytes[3]]);
+            
+            // Parse user_type_ids from argv[4]
+            let user_type_ids_bytes = ckb_std::high_level::decode_hex(argv[4].as_ref())?;
+            let user_type_ids = Byte32Vec::from_slice(&user_type_ids_bytes)
+                .map_err(|_| Error::InvalidArgument)?;
+            
+            // Parse sub_task_ids from argv[5], one byte per sub-task ID
+            let sub_task_ids = ckb_std::high_level::decode_hex(argv[5].as_ref())?;
+            
+            // Call the approve_sub_tasks method and return the transaction
+            let result_tx = crate::modules::CKBoostCampaignType::approve_sub_tasks(tx, campaign_data, quest_id, user_type_ids, sub_task_ids)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+        "CKBoostCampaign.add_quest" => {
+            debug_trace!("Entered CKBoostCampaign.add_quest");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse campaign_data from molecule serialized bytes (argv[2])
+            let campaign_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let campaign_data = ckboost_shared::types::CampaignData::from_slice(&campaign_data_bytes)
+                .map_err(|_| Error::InvalidCampaignData)?;
+            
+            // Parse quest from molecule serialized bytes (argv[3])
+            let quest_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            let quest = ckboost_shared::types::QuestData::from_slice(&quest_bytes)
+                .map_err(|_| Error::InvalidQuestData)?;
+            
+            // Call the add_quest method and return the transaction
+            let result_tx = crate::modules::CKBoostCampaignType::add_quest(tx, campaign_data, quest)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+        "CKBoostCampaign.edit_quest_metadata" => {
+            debug_trace!("Entered CKBoostCampaign.edit_quest_metadata");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse campaign_data from molecule serialized bytes (argv[2])
+            let campaign_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let campaign_data = ckboost_shared::types::CampaignData::from_slice(&campaign_data_bytes)
+                .map_err(|_| Error::InvalidCampaignData)?;
+            
+            // Parse quest_id from argv[3] (u32)
+            let quest_id_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            if quest_id_bytes.len() != 4 {
+                return Err(Error::SSRIMethodsArgsInvalid);
+            }
+            let quest_id = u32::from_le_bytes([quest_id_bytes[0], quest_id_bytes[1], quest_id_bytes[2], quest_id_bytes[3]]);
+            
+            // Parse quest metadata from molecule serialized bytes (argv[4])
+            let metadata_bytes = ckb_std::high_level::decode_hex(argv[4].as_ref())?;
+            let metadata = ckboost_shared::types::QuestMetadata::from_slice(&metadata_bytes)
+                .map_err(|_| Error::InvalidQuestData)?;
+            
+            // Call the edit_quest_metadata method and return the transaction
+            let result_tx = crate::modules::CKBoostCampaignType::edit_quest_metadata(tx, campaign_data, quest_id, metadata)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+        "CKBoostCampaign.update_campaign_metadata" => {
+            debug_trace!("Entered CKBoostCampaign.update_campaign_metadata");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse campaign_data from molecule serialized bytes (argv[2])
+            let campaign_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let campaign_data = ckboost_shared::types::CampaignData::from_slice(&campaign_data_bytes)
+                .map_err(|_| Error::InvalidCampaignData)?;
+            
+            // Parse campaign metadata from molecule serialized bytes (argv[3])
+            let metadata_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            let metadata = ckboost_shared::types::CampaignMetadata::from_slice(&metadata_bytes)
+                .map_err(|_| Error::InvalidCampaignData)?;
+            
+            // Call the update_campaign_metadata method and return the transaction
+            let result_tx = crate::modules::CKBoostCampaignType::update_campaign_metadata(tx, campaign_data, metadata)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+        "CKBoostCampaign.set_status" => {
+            debug_trace!("Entered CKBoostCampaign.set_status");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse campaign_data from molecule serialized bytes (argv[2])
+            let campaign_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let campaign_data = ckboost_shared::types::CampaignData::from_slice(&campaign_data_bytes)
+                .map_err(|_| Error::InvalidCampaignData)?;
+            
+            // Parse status from argv[3] (u8)
+            let status_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            if status_bytes.len() != 1 {
+                return Err(Error::SSRIMethodsArgsInvalid);
+            }
+            
+            // Call the set_status method and return the transaction
+            let result_tx = crate::modules::CKBoostCampaignType::set_status(tx, campaign_data, status_bytes[0])?;
             Ok(Cow::from(result_tx.as_bytes().to_vec()))
         },
     )?;
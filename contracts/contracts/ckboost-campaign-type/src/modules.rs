@@ -4,7 +4,7 @@ use blake2b_ref::Blake2bBuilder;
 use ckb_deterministic::{
     cell_classifier::RuleBasedClassifier, create_inline_argument, create_recipe_with_args, create_recipe_with_reference, debug_info, debug_trace,  serialize_transaction_recipe, transaction_context::TransactionContext, transaction_recipe::TransactionRecipeExt
 };
-use ckb_ssri_std::utils::high_level::{find_cell_by_out_point, find_out_point_by_type};
+use ckb_ssri_std::utils::high_level::{find_cell_by_out_point, find_cell_data_by_out_point, find_out_point_by_type};
 use ckb_std::{
     ckb_constants::Source,
     ckb_types::{
@@ -16,8 +16,13 @@ use ckb_std::{
     high_level::load_script
 };
 use ckboost_shared::{
-    types::{Byte32 as SharedByte32, CampaignData, ConnectedTypeID, QuestData},
-    Error,
+    campaign_data::connected_type_id_from_args,
+    types::{
+        ApprovalRecord, ApprovalRecordVec, Byte32 as SharedByte32, CampaignData, CampaignMetadata,
+        ConnectedTypeID, NftAward, NftAwardVec, QuestData, QuestMetadata, SubmissionRejection,
+        SubmissionRejectionVec, Uint128, Uint32, Uint64, UserData,
+    },
+    CampaignDataExt, Error, QuestDataExt, UserDataExt,
 };
 
 pub struct CKBoostCampaignType;
@@ -140,7 +145,8 @@ impl CKBoostCampaign for CKBoostCampaignType {
                     .build();
                 cell_input_vec_builder = cell_input_vec_builder.push(campaign_input);
 
-                // Get the current campaign cell to preserve lock script
+                // Get the current campaign cell to preserve lock script; campaigns still under
+                // their endorser's lock are moved to the campaign lock by the dapp
                 debug_trace!("Loading campaign cell data");
                 let current_campaign_cell = find_cell_by_out_point(campaign_outpoint)
                     .map_err(|e| {
@@ -214,7 +220,8 @@ impl CKBoostCampaign for CKBoostCampaignType {
                     .args(new_connected_type_id.as_bytes().pack())
             .build();
 
-                // Get first input cell to use its lock for the new campaign cell
+                // Get first input cell to use its lock as a placeholder for the new campaign cell;
+                // the dapp binds the campaign lock once the connected_key fixes the type hash
                 let first_input_outpoint = first_input.previous_output();
                 let first_input_cell = find_cell_by_out_point(first_input_outpoint)?;
 
@@ -423,7 +430,8 @@ impl CKBoostCampaign for CKBoostCampaignType {
         tx: Option<Transaction>,
         campaign_data: CampaignData,
         quest_id: u32,
-        user_type_ids: Byte32Vec
+        user_type_ids: Byte32Vec,
+        approved_at: u64,
     ) -> Result<Transaction, Error> {
         debug_trace!("CKBoostCampaignType::approve_completion - Starting quest completion approval");
         debug_trace!("Quest ID: {}, User Type IDs count: {}", quest_id, user_type_ids.len());
@@ -485,12 +493,17 @@ impl CKBoostCampaign for CKBoostCampaignType {
         //     return Err(Error::CampaignNotActive);
         // }
 
+        // SMT approvals are proven against the off-chain approvals tree, which builders cannot see
+        if campaign_data.tracks_approvals_in_smt() {
+            debug_trace!("Campaign tracks approvals in SMTs; attach an approval proof off-chain");
+            return Err(Error::InvalidCampaignData);
+        }
+
         // Find the quest and update accepted_submission_user_type_ids
         let quests = campaign_data.quests();
         let mut updated_quests = vec![];
         let mut quest_found = false;
         let mut _points_to_mint = 0u64;
-        let mut total_new_approvals = 0u32;  // Track total new approvals for updating campaign total_completions
 
         for i in 0..quests.len() {
             let quest = quests.get(i).unwrap();
@@ -521,6 +534,9 @@ impl CKBoostCampaign for CKBoostCampaignType {
                 }
                 
                 // Add new user type IDs and count new approvals
+                let mut pending_approvals: Vec<ApprovalRecord> = quest.pending_approvals().into_iter().collect();
+                let mut unawarded_nfts = quest.unawarded_nfts().into_iter();
+                let mut nft_awarded_quest = quest.clone();
                 let mut new_approval_count = 0u32;
                 for i in 0..user_type_ids.len() {
                     let user_type_id = user_type_ids.get(i).unwrap();
@@ -535,8 +551,29 @@ impl CKBoostCampaign for CKBoostCampaignType {
                     }
                     
                     if !already_approved {
+                        // Multi-step quests are only approvable once every sub-task is
+                        if !quest.sub_tasks_complete(user_type_id.as_slice()) {
+                            debug_trace!("User {:?} has unapproved sub-tasks in quest {}", user_type_id, quest_id);
+                            return Err(Error::InvalidQuestData);
+                        }
                         // Convert from ckb_std::Byte32 to ckboost_shared::Byte32
-                        let shared_byte32 = SharedByte32::from_slice(user_type_id.as_slice()).unwrap();
+                        let shared_byte32 = SharedByte32::from_slice(user_type_id.as_slice())
+                            .map_err(|_| Error::InvalidArgument)?;
+                        // Approvals stay revocable until the dispute window has passed
+                        if campaign_data.approval_dispute_window_u64() > 0 {
+                            pending_approvals.push(
+                                ApprovalRecord::new_builder()
+                                    .user_type_id(shared_byte32.clone())
+                                    .approved_at(Uint64::from(approved_at.to_le_bytes()))
+                                    .build(),
+                            );
+                        }
+                        // Each newly approved user receives one NFT while the pool lasts
+                        if let Some(nft_script) = unawarded_nfts.next() {
+                            nft_awarded_quest = nft_awarded_quest
+                                .with_nft_awarded(user_type_id.as_slice(), nft_script)
+                                .ok_or(Error::InvalidArgument)?;
+                        }
                         accepted_ids.push(shared_byte32);
                         new_approval_count += 1;
                     }
@@ -558,28 +595,42 @@ impl CKBoostCampaign for CKBoostCampaignType {
                 
                 // Use the actual count of new approvals (not already approved)
                 let updated_count = current_count + new_approval_count;
-                total_new_approvals = new_approval_count;  // Store for campaign total_completions update
                 
+                // Approved users leave the rejected list so the two sets stay disjoint
+                let remaining_rejections: Vec<SubmissionRejection> = quest
+                    .rejected_submissions()
+                    .into_iter()
+                    .filter(|rejection| !accepted_ids
+                        .iter()
+                        .any(|accepted_id| accepted_id.as_slice() == rejection.user_type_id().as_slice()))
+                    .collect();
+
                 // Create updated quest with incremented completion_count
-                let updated_quest = QuestData::new_builder()
-                    .quest_id(quest.quest_id())
-                    .metadata(quest.metadata())
-                    .rewards_on_completion(quest.rewards_on_completion())
+                let updated_quest = quest
+                    .clone()
+                    .as_builder()
                     .accepted_submission_user_type_ids(
                         ckboost_shared::generated::ckboost::Byte32Vec::new_builder()
                             .extend(accepted_ids)
                             .build()
                     )
-                    .completion_deadline(quest.completion_deadline())
-                    .status(quest.status())
-                    .sub_tasks(quest.sub_tasks())
-                    .points(quest.points())
+                    .rejected_submissions(
+                        SubmissionRejectionVec::new_builder()
+                            .extend(remaining_rejections)
+                            .build()
+                    )
+                    .pending_approvals(
+                        ApprovalRecordVec::new_builder()
+                            .extend(pending_approvals)
+                            .build()
+                    )
                     .completion_count(
                         ckboost_shared::generated::ckboost::Uint32::from_slice(
                             &updated_count.to_le_bytes(),
                         )
                         .unwrap()
                     )
+                    .nft_awards(nft_awarded_quest.nft_awards())
                     .build();
                 
                 updated_quests.push(updated_quest);
@@ -592,39 +643,23 @@ impl CKBoostCampaign for CKBoostCampaignType {
             return Err(Error::InvalidQuestData);
         }
 
-        // Update campaign total_completions
-        let current_completions_data = campaign_data.total_completions();
-        let current_completions_bytes = current_completions_data.as_slice();
-        let current_completions = u32::from_le_bytes([
-            current_completions_bytes[0],
-            current_completions_bytes[1],
-            current_completions_bytes[2],
-            current_completions_bytes[3],
-        ]);
-        let new_completions = current_completions + total_new_approvals;
-
-        // Create updated campaign data
-        let updated_campaign_data = CampaignData::new_builder()
-            .endorser(campaign_data.endorser())
-            .created_at(campaign_data.created_at())
-            .starting_time(campaign_data.starting_time())
-            .ending_time(campaign_data.ending_time())
-            .rules(campaign_data.rules())
-            .metadata(campaign_data.metadata())
-            .status(campaign_data.status())
+        // Create updated campaign data, recounting completions and participants from the quests
+        let updated_campaign_data = campaign_data
+            .clone()
+            .as_builder()
             .quests(
                 ckboost_shared::generated::ckboost::QuestDataVec::new_builder()
                     .extend(updated_quests)  
                     .build()
             )
-            .participants_count(campaign_data.participants_count())
-            .total_completions(
-                ckboost_shared::generated::ckboost::Uint32::from_slice(
-                    &new_completions.to_le_bytes(),
-                )
-                .unwrap(),
-            )
-            .build();
+            .build()
+            .with_recounted_stats();
+        // Approvals under a dispute window move the campaign clock to their timestamp
+        let updated_campaign_data = if campaign_data.approval_dispute_window_u64() > 0 {
+            updated_campaign_data.with_recorded_at(approved_at)
+        } else {
+            updated_campaign_data
+        };
 
         // Create output campaign cell with updated data
         let campaign_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
@@ -656,7 +691,8 @@ impl CKBoostCampaign for CKBoostCampaignType {
         let mut user_type_ids_builder = Byte32VecBuilder::default();
         for i in 0..user_type_ids.len() {
             let user_type_id = user_type_ids.get(i).unwrap();
-            user_type_ids_builder = user_type_ids_builder.push(Byte32::from_slice(user_type_id.as_slice()).unwrap());
+            user_type_ids_builder = user_type_ids_builder
+                .push(Byte32::from_slice(user_type_id.as_slice()).map_err(|_| Error::InvalidArgument)?);
         }
         let user_type_ids_vec = user_type_ids_builder.build();
 
@@ -772,5 +808,835 @@ impl CKBoostCampaign for CKBoostCampaignType {
         debug_trace!("verify_approve_completion completed successfully");
         Ok(())
     }
-    
-}
\ No newline at end of file
+    fn complete_quest(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        user_type_id: Byte32,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignType::complete_quest - Starting quest completion");
+        debug_trace!("Quest ID: {}, User Type ID: {:?}", quest_id, user_type_id);
+
+        // The participant's user cell must already be part of the transaction
+        let base_tx = tx.clone().ok_or_else(|| {
+            debug_trace!("No transaction provided. Add the participant's user cell as an input first.");
+            Error::MissingTransactionInput
+        })?;
+
+        // Initialize transaction builders
+        let tx_builder = base_tx.clone().as_builder();
+        let raw_tx_builder = base_tx.raw().as_builder();
+        let mut cell_input_vec_builder = base_tx.raw().inputs().as_builder();
+        let mut cell_output_vec_builder = base_tx.raw().outputs().as_builder();
+        let mut outputs_data_builder = base_tx.raw().outputs_data().as_builder();
+        let cell_dep_vec_builder = base_tx.raw().cell_deps().as_builder();
+
+        // Locate the participant's user cell among the existing inputs
+        let mut user_cell = None;
+        for input in base_tx.raw().inputs().into_iter() {
+            let out_point = input.previous_output();
+            let cell = match find_cell_by_out_point(out_point.clone()) {
+                Ok(cell) => cell,
+                Err(_) => continue,
+            };
+            let type_script = match cell.type_().to_opt() {
+                Some(type_script) => type_script,
+                None => continue,
+            };
+            let is_target = connected_type_id_from_args(&type_script.args().raw_data())
+                .map(|connected_type_id| connected_type_id.type_id().as_slice() == user_type_id.as_slice())
+                .unwrap_or(false);
+            if is_target {
+                user_cell = Some((out_point, cell, type_script));
+                break;
+            }
+        }
+        let (user_outpoint, user_cell_output, user_type_script) = user_cell.ok_or_else(|| {
+            debug_trace!("User cell with the given type ID is not an input of the transaction");
+            Error::UserCellNotFound
+        })?;
+        let user_data_bytes = find_cell_data_by_out_point(user_outpoint)
+            .map_err(|_| Error::UserCellNotFound)?;
+        let user_data = UserData::from_slice(&user_data_bytes)
+            .map_err(|_| Error::InvalidUserData)?;
+
+        // Get context script and parse ConnectedTypeID from args
+        let current_script = load_script()?;
+        let connected_type_id = ConnectedTypeID::from_slice(&current_script.args().raw_data())
+            .map_err(|_| Error::InvalidConnectedTypeId)?;
+        let campaign_type_id = connected_type_id.type_id();
+
+
+        // Find and add existing campaign cell as input
+        let campaign_outpoint = find_out_point_by_type(current_script.clone())?;
+        let campaign_input = CellInput::new_builder()
+            .previous_output(campaign_outpoint.clone())
+            .build();
+        cell_input_vec_builder = cell_input_vec_builder.push(campaign_input);
+
+        // Get the current campaign cell to preserve lock script
+        let current_campaign_cell = find_cell_by_out_point(campaign_outpoint)
+            .map_err(|_| Error::CampaignCellNotFound)?;
+
+        // Completions pay out immediately; SMT and disputable campaigns approve instead
+        if campaign_data.tracks_approvals_in_smt() || campaign_data.approval_dispute_window_u64() > 0 {
+            debug_trace!("Campaign completes quests through approve_completion");
+            return Err(Error::InvalidCampaignData);
+        }
+
+        // Record the completion on the quest
+        let quest = campaign_data.find_quest(quest_id).ok_or(Error::InvalidQuestData)?;
+        if quest.is_accepted(user_type_id.as_slice()) {
+            debug_trace!("User already completed quest {}", quest_id);
+            return Err(Error::InvalidQuestData);
+        }
+        if !quest.sub_tasks_complete(user_type_id.as_slice()) {
+            debug_trace!("User has unapproved sub-tasks in quest {}", quest_id);
+            return Err(Error::InvalidQuestData);
+        }
+
+        // The participant must have a submission for this quest that was not rejected
+        let submission_count = user_data.submission_count(campaign_type_id.as_slice(), quest_id);
+        if submission_count <= quest.rejection_count_of(user_type_id.as_slice()) {
+            debug_trace!("User has no pending submission for quest {}", quest_id);
+            return Err(Error::InvalidUserData);
+        }
+        let accepted_ids = quest
+            .accepted_submission_user_type_ids()
+            .as_builder()
+            .push(SharedByte32::from_slice(user_type_id.as_slice()).map_err(|_| Error::InvalidArgument)?)
+            .build();
+        let mut updated_quest = quest
+            .clear_rejection(user_type_id.as_slice())
+            .as_builder()
+            .accepted_submission_user_type_ids(accepted_ids)
+            .completion_count(Uint32::from((quest.completion_count_u32() + 1).to_le_bytes()))
+            .build();
+        // Award the first NFT of the pool nobody holds yet
+        if let Some(nft_script) = quest.unawarded_nfts().into_iter().next() {
+            updated_quest = updated_quest
+                .with_nft_awarded(user_type_id.as_slice(), nft_script)
+                .ok_or(Error::InvalidArgument)?;
+        }
+        let updated_campaign_data = campaign_data.with_quest(updated_quest).with_recounted_stats();
+
+        // Credit the quest points to the participant
+        let updated_points = user_data.total_points_earned_u128() + quest.points_u128();
+        let updated_user_data = user_data
+            .as_builder()
+            .total_points_earned(Uint128::from(updated_points.to_le_bytes()))
+            .build();
+
+        // Create output campaign cell with updated data
+        let campaign_output_index = base_tx.raw().outputs().len() as u32;
+        let campaign_output = CellOutputBuilder::default()
+            .type_(
+                ScriptOptBuilder::default()
+                    .set(Some(current_script))
+                    .build(),
+            )
+            .lock(current_campaign_cell.lock())
+            .capacity(0u64.pack()) // Placeholder capacity
+            .build();
+        cell_output_vec_builder = cell_output_vec_builder.push(campaign_output);
+        outputs_data_builder = outputs_data_builder.push(updated_campaign_data.as_bytes().pack());
+
+        // Create output user cell with updated data, preserving its lock and type scripts
+        let user_output = CellOutputBuilder::default()
+            .type_(
+                ScriptOptBuilder::default()
+                    .set(Some(user_type_script))
+                    .build(),
+            )
+            .lock(user_cell_output.lock())
+            .capacity(0u64.pack()) // Placeholder capacity
+            .build();
+        cell_output_vec_builder = cell_output_vec_builder.push(user_output);
+        outputs_data_builder = outputs_data_builder.push(updated_user_data.as_bytes().pack());
+
+        let quest_id_bytes = quest_id.to_le_bytes();
+        let recipe = create_recipe_with_args(
+            "CKBoostCampaign.complete_quest",
+            vec![
+                create_inline_argument(&quest_id_bytes[..]),
+                create_inline_argument(user_type_id.as_slice()),
+            ],
+        )?;
+
+        // Serialize the recipe to bytes
+        let recipe_bytes = serialize_transaction_recipe(&recipe);
+
+        // Create WitnessArgs with recipe in output_type field
+        let witness_args = WitnessArgsBuilder::default()
+            .lock(BytesOpt::default())
+            .input_type(BytesOpt::default())
+            .output_type(
+                BytesOpt::new_builder()
+                    .set(Some(recipe_bytes.pack()))
+                    .build(),
+            )
+            .build();
+
+        // Build witnesses vector with recipe witness at campaign output index
+        let mut witnesses_builder = BytesVecBuilder::default();
+        let witnesses = base_tx.witnesses();
+        for i in 0..campaign_output_index as usize {
+            match witnesses.get(i) {
+                Some(witness) => {
+                    witnesses_builder = witnesses_builder.push(witness);
+                }
+                None => {
+                    let empty_witness = WitnessArgsBuilder::default().build();
+                    witnesses_builder = witnesses_builder.push(empty_witness.as_bytes().pack());
+                }
+            }
+        }
+        witnesses_builder = witnesses_builder.push(witness_args.as_bytes().pack());
+        for i in (campaign_output_index + 1) as usize..witnesses.len() {
+            if let Some(witness) = witnesses.get(i) {
+                witnesses_builder = witnesses_builder.push(witness);
+            }
+        }
+
+        // Build the complete transaction
+        Ok(tx_builder
+            .raw(
+                raw_tx_builder
+                    .cell_deps(cell_dep_vec_builder.build())
+                    .inputs(cell_input_vec_builder.build())
+                    .outputs(cell_output_vec_builder.build())
+                    .outputs_data(outputs_data_builder.build())
+                    .build(),
+            )
+            .witnesses(witnesses_builder.build())
+            .build())
+    }
+
+    fn verify_complete_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_complete_quest");
+
+        // Use the predefined rules from recipes module
+        let rules = recipes::complete_quest::get_rules();
+
+        // Execute validation
+        rules.validate(context)?;
+
+        debug_trace!("verify_complete_quest completed successfully");
+        Ok(())
+    }
+
+    fn reject_submission(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        user_type_ids: Byte32Vec,
+        reason_code: u32,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignType::reject_submission - Starting submission rejection");
+        debug_trace!("Quest ID: {}, User Type IDs count: {}, reason code: {}", quest_id, user_type_ids.len(), reason_code);
+
+        // Record the rejections on the quest
+        let quest = campaign_data.find_quest(quest_id).ok_or(Error::InvalidQuestData)?;
+        let mut rejections: Vec<SubmissionRejection> = quest.rejected_submissions().into_iter().collect();
+        for user_type_id in user_type_ids.clone().into_iter() {
+            if quest.is_accepted(user_type_id.as_slice()) {
+                debug_trace!("User {:?} is already approved for quest {}", user_type_id, quest_id);
+                return Err(Error::InvalidQuestData);
+            }
+            let rejection_count = quest.rejection_count_of(user_type_id.as_slice()) + 1;
+            let rejection = SubmissionRejection::new_builder()
+                .user_type_id(SharedByte32::from_slice(user_type_id.as_slice()).map_err(|_| Error::InvalidArgument)?)
+                .reason_code(Uint32::from(reason_code.to_le_bytes()))
+                .rejection_count(Uint32::from(rejection_count.to_le_bytes()))
+                .build();
+            match rejections
+                .iter()
+                .position(|existing| existing.user_type_id().as_slice() == user_type_id.as_slice())
+            {
+                Some(position) => rejections[position] = rejection,
+                None => rejections.push(rejection),
+            }
+        }
+        // A rejection resets sub-task progress; the resubmission is reviewed from scratch
+        let mut updated_quest = quest;
+        for user_type_id in user_type_ids.clone().into_iter() {
+            updated_quest = updated_quest.clear_sub_task_approvals(user_type_id.as_slice());
+        }
+        let updated_quest = updated_quest
+            .as_builder()
+            .rejected_submissions(
+                SubmissionRejectionVec::new_builder()
+                    .extend(rejections)
+                    .build(),
+            )
+            .build();
+        let updated_campaign_data = campaign_data.with_quest(updated_quest);
+
+        let campaign_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let quest_id_bytes = quest_id.to_le_bytes();
+        let reason_code_bytes = reason_code.to_le_bytes();
+        let recipe = create_recipe_with_args(
+            "CKBoostCampaign.reject_submission",
+            vec![
+                create_recipe_with_reference(Source::Output, campaign_output_index),
+                create_inline_argument(&quest_id_bytes[..]),
+                create_inline_argument(&user_type_ids.as_bytes()),
+                create_inline_argument(&reason_code_bytes[..]),
+            ],
+        )?;
+
+        assemble_campaign_transition(tx, &updated_campaign_data, &serialize_transaction_recipe(&recipe))
+    }
+
+    fn verify_reject_submission(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_reject_submission");
+
+        // Use the predefined rules from recipes module
+        let rules = recipes::reject_submission::get_rules();
+
+        // Execute validation
+        rules.validate(context)?;
+
+        debug_trace!("verify_reject_submission completed successfully");
+        Ok(())
+    }
+
+    fn revoke_approval(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        user_type_ids: Byte32Vec,
+        recorded_at: u64,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignType::revoke_approval - Starting approval revocation");
+        debug_trace!("Quest ID: {}, User Type IDs count: {}", quest_id, user_type_ids.len());
+
+        // Revoked users leave both the accepted list and the pending approvals
+        let quest = campaign_data.find_quest(quest_id).ok_or(Error::InvalidQuestData)?;
+        for user_type_id in user_type_ids.clone().into_iter() {
+            if quest.find_pending_approval(user_type_id.as_slice()).is_none() {
+                debug_trace!("User {:?} has no revocable approval for quest {}", user_type_id, quest_id);
+                return Err(Error::InvalidQuestStatus);
+            }
+        }
+        let is_revoked = |user_type_id: &[u8]| {
+            user_type_ids
+                .clone()
+                .into_iter()
+                .any(|revoked_id| revoked_id.as_slice() == user_type_id)
+        };
+        let accepted_ids: Vec<SharedByte32> = quest
+            .accepted_submission_user_type_ids()
+            .into_iter()
+            .filter(|accepted_id| !is_revoked(accepted_id.as_slice()))
+            .collect();
+        let pending_approvals: Vec<ApprovalRecord> = quest
+            .pending_approvals()
+            .into_iter()
+            .filter(|record| !is_revoked(record.user_type_id().as_slice()))
+            .collect();
+        let revoked_count = (quest.accepted_submission_user_type_ids().len() - accepted_ids.len()) as u32;
+        // NFTs awarded to revoked users return to the quest's pool
+        let nft_awards: Vec<NftAward> = quest
+            .nft_awards()
+            .into_iter()
+            .filter(|award| !is_revoked(award.user_type_id().as_slice()))
+            .collect();
+
+        let updated_quest = quest
+            .clone()
+            .as_builder()
+            .nft_awards(NftAwardVec::new_builder().extend(nft_awards).build())
+            .accepted_submission_user_type_ids(
+                ckboost_shared::generated::ckboost::Byte32Vec::new_builder()
+                    .extend(accepted_ids)
+                    .build(),
+            )
+            .pending_approvals(
+                ApprovalRecordVec::new_builder()
+                    .extend(pending_approvals)
+                    .build(),
+            )
+            .completion_count(Uint32::from((quest.completion_count_u32() - revoked_count).to_le_bytes()))
+            .build();
+        let updated_campaign_data = campaign_data
+            .with_quest(updated_quest)
+            .with_recounted_stats()
+            .with_recorded_at(recorded_at);
+
+        let campaign_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let quest_id_bytes = quest_id.to_le_bytes();
+        let recipe = create_recipe_with_args(
+            "CKBoostCampaign.revoke_approval",
+            vec![
+                create_recipe_with_reference(Source::Output, campaign_output_index),
+                create_inline_argument(&quest_id_bytes[..]),
+                create_inline_argument(&user_type_ids.as_bytes()),
+            ],
+        )?;
+
+        assemble_campaign_transition(tx, &updated_campaign_data, &serialize_transaction_recipe(&recipe))
+    }
+
+    fn verify_revoke_approval(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_revoke_approval");
+
+        // Use the predefined rules from recipes module
+        let rules = recipes::revoke_approval::get_rules();
+
+        // Execute validation
+        rules.validate(context)?;
+
+        debug_trace!("verify_revoke_approval completed successfully");
+        Ok(())
+    }
+
+    fn finalize_approvals(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        user_type_ids: Byte32Vec,
+        recorded_at: u64,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignType::finalize_approvals - Starting approval finalization");
+        debug_trace!("Quest ID: {}, User Type IDs count: {}", quest_id, user_type_ids.len());
+
+        // Finalized users stay accepted but leave the pending approvals
+        let quest = campaign_data.find_quest(quest_id).ok_or(Error::InvalidQuestData)?;
+        for user_type_id in user_type_ids.clone().into_iter() {
+            if quest.find_pending_approval(user_type_id.as_slice()).is_none() {
+                debug_trace!("User {:?} has no pending approval for quest {}", user_type_id, quest_id);
+                return Err(Error::InvalidQuestStatus);
+            }
+        }
+        let pending_approvals: Vec<ApprovalRecord> = quest
+            .pending_approvals()
+            .into_iter()
+            .filter(|record| {
+                !user_type_ids
+                    .clone()
+                    .into_iter()
+                    .any(|finalized_id| finalized_id.as_slice() == record.user_type_id().as_slice())
+            })
+            .collect();
+        let updated_quest = quest
+            .as_builder()
+            .pending_approvals(
+                ApprovalRecordVec::new_builder()
+                    .extend(pending_approvals)
+                    .build(),
+            )
+            .build();
+        let updated_campaign_data = campaign_data.with_quest(updated_quest).with_recorded_at(recorded_at);
+
+        let campaign_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let quest_id_bytes = quest_id.to_le_bytes();
+        let recipe = create_recipe_with_args(
+            "CKBoostCampaign.finalize_approvals",
+            vec![
+                create_recipe_with_reference(Source::Output, campaign_output_index),
+                create_inline_argument(&quest_id_bytes[..]),
+                create_inline_argument(&user_type_ids.as_bytes()),
+            ],
+        )?;
+
+        assemble_campaign_transition(tx, &updated_campaign_data, &serialize_transaction_recipe(&recipe))
+    }
+
+    fn verify_finalize_approvals(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_finalize_approvals");
+
+        // Use the predefined rules from recipes module
+        let rules = recipes::finalize_approvals::get_rules();
+
+        // Execute validation
+        rules.validate(context)?;
+
+        debug_trace!("verify_finalize_approvals completed successfully");
+        Ok(())
+    }
+
+    fn approve_sub_tasks(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        user_type_ids: Byte32Vec,
+        sub_task_ids: Vec<u8>,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignType::approve_sub_tasks - Starting sub-task approval");
+        debug_trace!("Quest ID: {}, User Type IDs count: {}, sub-tasks: {:?}", quest_id, user_type_ids.len(), sub_task_ids);
+
+        let quest = campaign_data.find_quest(quest_id).ok_or(Error::InvalidQuestData)?;
+        let quest_sub_task_ids = quest.sub_task_ids();
+        if sub_task_ids.is_empty() || sub_task_ids.iter().any(|id| !quest_sub_task_ids.contains(id)) {
+            debug_trace!("Sub-tasks {:?} are not all part of quest {}", sub_task_ids, quest_id);
+            return Err(Error::InvalidArgument);
+        }
+
+        // Record the approved sub-tasks for each user
+        let mut updated_quest = quest;
+        for user_type_id in user_type_ids.clone().into_iter() {
+            if updated_quest.is_accepted(user_type_id.as_slice()) {
+                debug_trace!("User {:?} is already approved for quest {}", user_type_id, quest_id);
+                return Err(Error::InvalidQuestData);
+            }
+            updated_quest = updated_quest
+                .with_sub_tasks_approved(user_type_id.as_slice(), &sub_task_ids)
+                .ok_or(Error::InvalidArgument)?;
+        }
+        let updated_campaign_data = campaign_data.with_quest(updated_quest);
+
+        let campaign_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let quest_id_bytes = quest_id.to_le_bytes();
+        let recipe = create_recipe_with_args(
+            "CKBoostCampaign.approve_sub_tasks",
+            vec![
+                create_recipe_with_reference(Source::Output, campaign_output_index),
+                create_inline_argument(&quest_id_bytes[..]),
+                create_inline_argument(&user_type_ids.as_bytes()),
+                create_inline_argument(&sub_task_ids[..]),
+            ],
+        )?;
+
+        assemble_campaign_transition(tx, &updated_campaign_data, &serialize_transaction_recipe(&recipe))
+    }
+
+    fn verify_approve_sub_tasks(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_approve_sub_tasks");
+
+        // Use the predefined rules from recipes module
+        let rules = recipes::approve_sub_tasks::get_rules();
+
+        // Execute validation
+        rules.validate(context)?;
+
+        debug_trace!("verify_approve_sub_tasks completed successfully");
+        Ok(())
+    }
+
+    fn add_quest(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest: QuestData,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignType::add_quest - Starting quest addition");
+        debug_trace!("Next quest ID: {}", campaign_data.next_quest_id_u32());
+
+        if campaign_data.uses_quest_cells() {
+            debug_trace!("Campaigns with quest cells add quests through the quest type");
+            return Err(Error::InvalidCampaignData);
+        }
+        if !quest.has_unique_sub_task_ids() {
+            debug_trace!("Quest has duplicate sub-task IDs");
+            return Err(Error::InvalidQuestData);
+        }
+
+        // Allocate the next quest ID; IDs of removed quests are never handed out again
+        let quest_id = campaign_data.next_quest_id_u32();
+        let next_quest_id = quest_id.checked_add(1).ok_or(Error::InvalidCampaignData)?;
+        let quest = quest.as_builder().quest_id(Uint32::from(quest_id.to_le_bytes())).build();
+        let updated_campaign_data = campaign_data
+            .clone()
+            .as_builder()
+            .quests(campaign_data.quests().as_builder().push(quest.clone()).build())
+            .next_quest_id(Uint32::from(next_quest_id.to_le_bytes()))
+            .build();
+
+        let campaign_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let recipe = create_recipe_with_args(
+            "CKBoostCampaign.add_quest",
+            vec![
+                create_recipe_with_reference(Source::Output, campaign_output_index),
+                create_inline_argument(quest.as_slice()),
+            ],
+        )?;
+
+        assemble_campaign_transition(tx, &updated_campaign_data, &serialize_transaction_recipe(&recipe))
+    }
+
+    fn verify_add_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_add_quest");
+
+        // Use the predefined rules from recipes module
+        let rules = recipes::add_quest::get_rules();
+
+        // Execute validation
+        rules.validate(context)?;
+
+        debug_trace!("verify_add_quest completed successfully");
+        Ok(())
+    }
+
+    fn edit_quest_metadata(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        metadata: QuestMetadata,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignType::edit_quest_metadata - Starting quest metadata edit");
+        debug_trace!("Quest ID: {}", quest_id);
+
+        let quest = campaign_data.find_quest(quest_id).ok_or(Error::InvalidQuestData)?;
+        let updated_campaign_data =
+            campaign_data.with_quest(quest.as_builder().metadata(metadata.clone()).build());
+
+        let campaign_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let quest_id_bytes = quest_id.to_le_bytes();
+        let recipe = create_recipe_with_args(
+            "CKBoostCampaign.edit_quest_metadata",
+            vec![
+                create_recipe_with_reference(Source::Output, campaign_output_index),
+                create_inline_argument(&quest_id_bytes[..]),
+                create_inline_argument(metadata.as_slice()),
+            ],
+        )?;
+
+        assemble_campaign_transition(tx, &updated_campaign_data, &serialize_transaction_recipe(&recipe))
+    }
+
+    fn verify_edit_quest_metadata(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_edit_quest_metadata");
+
+        // Use the predefined rules from recipes module
+        let rules = recipes::edit_quest_metadata::get_rules();
+
+        // Execute validation
+        rules.validate(context)?;
+
+        debug_trace!("verify_edit_quest_metadata completed successfully");
+        Ok(())
+    }
+
+    fn update_campaign_metadata(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        metadata: CampaignMetadata,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignType::update_campaign_metadata - Starting metadata update");
+
+        if metadata.title().is_empty()
+            || metadata.short_description().is_empty()
+            || metadata.long_description().is_empty()
+        {
+            debug_trace!("Title and descriptions must not be empty");
+            return Err(Error::InvalidCampaignData);
+        }
+        let updated_campaign_data = campaign_data.as_builder().metadata(metadata.clone()).build();
+
+        let campaign_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let recipe = create_recipe_with_args(
+            "CKBoostCampaign.update_campaign_metadata",
+            vec![
+                create_recipe_with_reference(Source::Output, campaign_output_index),
+                create_inline_argument(metadata.as_slice()),
+            ],
+        )?;
+
+        assemble_campaign_transition(tx, &updated_campaign_data, &serialize_transaction_recipe(&recipe))
+    }
+
+    fn verify_update_campaign_metadata(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_update_campaign_metadata");
+
+        // Use the predefined rules from recipes module
+        let rules = recipes::update_campaign_metadata::get_rules();
+
+        // Execute validation
+        rules.validate(context)?;
+
+        debug_trace!("verify_update_campaign_metadata completed successfully");
+        Ok(())
+    }
+
+    fn set_status(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        status: u8,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostCampaignType::set_status - Starting status change");
+        debug_trace!("Status: {} -> {}", campaign_data.status(), status);
+
+        // Statuses only move forward, up to 5 (completed)
+        if status < campaign_data.status().as_slice()[0] || status > 5 {
+            debug_trace!("Campaign status cannot move to {}", status);
+            return Err(Error::InvalidArgument);
+        }
+        let updated_campaign_data = campaign_data.as_builder().status(status).build();
+
+        let campaign_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let recipe = create_recipe_with_args(
+            "CKBoostCampaign.set_status",
+            vec![
+                create_recipe_with_reference(Source::Output, campaign_output_index),
+                create_inline_argument(&[status][..]),
+            ],
+        )?;
+
+        assemble_campaign_transition(tx, &updated_campaign_data, &serialize_transaction_recipe(&recipe))
+    }
+
+    fn verify_set_status(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_set_status");
+
+        // Use the predefined rules from recipes module
+        let rules = recipes::set_status::get_rules();
+
+        // Execute validation
+        rules.validate(context)?;
+
+        debug_trace!("verify_set_status completed successfully");
+        Ok(())
+    }
+
+    fn verify_update_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_update_quest");
+
+        // Use the predefined rules from recipes module
+        let rules = recipes::update_quest::get_rules();
+
+        // Execute validation
+        rules.validate(context)?;
+
+        debug_trace!("verify_update_quest completed successfully");
+        Ok(())
+    }
+}
+
+/// Spend the current campaign cell and recreate it with `updated_campaign_data`
+///
+/// The campaign output is appended after the existing outputs of `tx`, and the
+/// serialized recipe is placed in the witness at the same index.
+fn assemble_campaign_transition(
+    tx: Option<Transaction>,
+    updated_campaign_data: &CampaignData,
+    recipe_bytes: &[u8],
+) -> Result<Transaction, Error> {
+    // Initialize transaction builders
+    let tx_builder = match tx {
+        Some(ref tx) => tx.clone().as_builder(),
+        None => TransactionBuilder::default(),
+    };
+    let raw_tx_builder = match tx {
+        Some(ref tx) => tx.clone().raw().as_builder(),
+        None => RawTransactionBuilder::default(),
+    };
+    let mut cell_input_vec_builder = match tx {
+        Some(ref tx) => tx.clone().raw().inputs().as_builder(),
+        None => CellInputVecBuilder::default(),
+    };
+    let mut cell_output_vec_builder = match tx {
+        Some(ref tx) => tx.clone().raw().outputs().as_builder(),
+        None => CellOutputVecBuilder::default(),
+    };
+    let mut outputs_data_builder = match tx {
+        Some(ref tx) => tx.clone().raw().outputs_data().as_builder(),
+        None => BytesVecBuilder::default(),
+    };
+    let cell_dep_vec_builder = match tx {
+        Some(ref tx) => tx.clone().raw().cell_deps().as_builder(),
+        None => CellDepVecBuilder::default(),
+    };
+
+    // Find and add existing campaign cell as input
+    let current_script = load_script()?;
+    let campaign_outpoint = find_out_point_by_type(current_script.clone())?;
+    let campaign_input = CellInput::new_builder()
+        .previous_output(campaign_outpoint.clone())
+        .build();
+    cell_input_vec_builder = cell_input_vec_builder.push(campaign_input);
+
+    // Get the current campaign cell to preserve lock script
+    let current_campaign_cell = find_cell_by_out_point(campaign_outpoint)
+        .map_err(|_| Error::CampaignCellNotFound)?;
+
+    // Create output campaign cell with updated data
+    let campaign_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+    let campaign_output = CellOutputBuilder::default()
+        .type_(
+            ScriptOptBuilder::default()
+                .set(Some(current_script))
+                .build(),
+        )
+        .lock(current_campaign_cell.lock())
+        .capacity(0u64.pack()) // Placeholder capacity
+        .build();
+    cell_output_vec_builder = cell_output_vec_builder.push(campaign_output);
+    outputs_data_builder = outputs_data_builder.push(updated_campaign_data.as_bytes().pack());
+
+    // Create WitnessArgs with recipe in output_type field
+    let witness_args = WitnessArgsBuilder::default()
+        .lock(BytesOpt::default())
+        .input_type(BytesOpt::default())
+        .output_type(
+            BytesOpt::new_builder()
+                .set(Some(recipe_bytes.pack()))
+                .build(),
+        )
+        .build();
+
+    // Build witnesses vector with recipe witness at campaign output index
+    let witnesses_builder = match tx {
+        Some(ref tx) => {
+            let mut builder = BytesVecBuilder::default();
+            let witnesses = tx.witnesses();
+            for i in 0..campaign_output_index as usize {
+                match witnesses.get(i) {
+                    Some(witness) => {
+                        builder = builder.push(witness);
+                    }
+                    None => {
+                        let empty_witness = WitnessArgsBuilder::default().build();
+                        builder = builder.push(empty_witness.as_bytes().pack());
+                    }
+                }
+            }
+            builder = builder.push(witness_args.as_bytes().pack());
+            for i in (campaign_output_index + 1) as usize..witnesses.len() {
+                if let Some(witness) = witnesses.get(i) {
+                    builder = builder.push(witness);
+                }
+            }
+            builder
+        }
+        None => BytesVecBuilder::default().push(witness_args.as_bytes().pack()),
+    };
+
+    // Build the complete transaction
+    Ok(tx_builder
+        .raw(
+            raw_tx_builder
+                .version(tx.clone().map(|t| t.raw().version()).unwrap_or_default())
+                .cell_deps(cell_dep_vec_builder.build())
+                .header_deps(
+                    tx.clone()
+                        .map(|t| t.raw().header_deps())
+                        .unwrap_or_else(|| Byte32Vec::default()),
+                )
+                .inputs(cell_input_vec_builder.build())
+                .outputs(cell_output_vec_builder.build())
+                .outputs_data(outputs_data_builder.build())
+                .build(),
+        )
+        .witnesses(witnesses_builder.build())
+        .build())
+}
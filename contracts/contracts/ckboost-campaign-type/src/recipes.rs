@@ -4,9 +4,22 @@ pub mod helper {
     use ckb_deterministic::debug_trace;
     use ckb_deterministic::errors::Error as DeterministicError;
     use ckb_std::ckb_constants::Source;
-    use ckb_std::high_level::{load_cell_data, load_cell_type_hash};
+    use ckb_std::high_level::{load_cell_data, load_cell_lock, load_cell_type_hash, QueryIter};
+    use ckb_deterministic::cell_classifier::CellInfo;
+    use ckb_std::high_level::{load_script, load_script_hash};
+    use ckboost_shared::approvals::load_approval_proof;
+    use ckboost_shared::campaign_data::connected_type_id_from_args;
     use ckboost_shared::types::protocol::ProtocolDataReader;
-    use molecule::prelude::Reader;
+    use ckboost_shared::types::ProtocolData;
+    use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+    use ckb_deterministic::transaction_recipe::TransactionRecipeExt;
+    use ckb_std::ckb_types::packed::Byte32Vec;
+    use ckboost_shared::header::max_header_dep_timestamp;
+    use ckboost_shared::transaction_context::TransactionContext;
+    use ckboost_shared::types::{ApprovalProof, Byte32, CampaignData, QuestData, UserData};
+    use alloc::vec::Vec;
+    use ckboost_shared::{CampaignDataExt, QuestDataExt};
+    use molecule::prelude::{Entity, Reader};
 
     // 1.Validate a protocol cell's data against expected campaign code hash
     // 2. Validate connection to campaign type
@@ -66,14 +79,695 @@ pub mod helper {
         debug_trace!("  Looking for protocol type hash: {:?}", protocol_type_hash);
         Err(DeterministicError::CellRelationshipRuleViolation)
     }
+
+    // The campaign cell must be locked by the protocol's campaign lock bound to this campaign, so
+    // unlocking it defers to the role checks of this type script
+    pub fn ensure_bound_to_campaign_lock(protocol_data: &ProtocolData) -> Result<(), DeterministicError> {
+        let campaign_type_hash = load_script_hash().map_err(|_| DeterministicError::Encoding)?;
+        let index = QueryIter::new(load_cell_type_hash, Source::Output)
+            .position(|type_hash| type_hash == Some(campaign_type_hash))
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let lock = load_cell_lock(index, Source::Output).map_err(|_| DeterministicError::Encoding)?;
+        let campaign_lock_code_hash = protocol_data
+            .protocol_config()
+            .script_code_hashes()
+            .ckb_boost_campaign_lock_code_hash();
+        if lock.code_hash().as_slice() != campaign_lock_code_hash.as_slice()
+            || lock.args().raw_data().as_ref() != campaign_type_hash.as_slice()
+        {
+            debug_trace!("CellRelationshipRuleViolation: Campaign cell is not locked by its campaign lock");
+            return Err(DeterministicError::CellRelationshipRuleViolation);
+        }
+        Ok(())
+    }
+
+    // Type ID of the campaign currently being validated, taken from the ConnectedTypeID args
+    pub fn current_campaign_type_id() -> Result<Byte32, DeterministicError> {
+        let script = load_script().map_err(|_| DeterministicError::Encoding)?;
+        connected_type_id_from_args(&script.args().raw_data())
+            .map(|connected_type_id| connected_type_id.type_id())
+            .ok_or_else(|| {
+                debug_trace!("Campaign type args are not a ConnectedTypeID");
+                DeterministicError::Encoding
+            })
+    }
+
+    // Find the user data of the user cell whose ConnectedTypeID type_id matches
+    pub fn find_user_data_by_type_id(cells: &[CellInfo], user_type_id: &[u8]) -> Option<UserData> {
+        cells.iter().find_map(|cell| {
+            let type_script = cell.type_script.as_ref()?;
+            let connected_type_id = connected_type_id_from_args(&type_script.args().raw_data())?;
+            if connected_type_id.type_id().as_slice() != user_type_id {
+                return None;
+            }
+            UserData::from_slice(&cell.data).ok()
+        })
+    }
+
+    // Find the user cell of a participant, provided either as a cell dep or as an input
+    pub fn find_participant_cell<'a>(
+        context: &'a TransactionContext<RuleBasedClassifier>,
+        user_type_id: &[u8],
+    ) -> Option<&'a CellInfo> {
+        let matches = |cell: &&CellInfo| {
+            cell.type_script
+                .as_ref()
+                .and_then(|script| connected_type_id_from_args(&script.args().raw_data()))
+                .map(|connected_type_id| connected_type_id.type_id().as_slice() == user_type_id)
+                .unwrap_or(false)
+        };
+        context
+            .cell_deps
+            .get_custom("user")
+            .and_then(|cells| cells.iter().find(matches))
+            .or_else(|| {
+                context
+                    .input_cells
+                    .get_custom("user")
+                    .and_then(|cells| cells.iter().find(matches))
+            })
+    }
+
+    // Protocol data of the protocol cell provided as a cell dep
+    pub fn find_protocol_data_in_deps(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Option<ProtocolData> {
+        context
+            .cell_deps
+            .get_custom("protocol")?
+            .iter()
+            .find_map(|cell| ProtocolData::from_slice(&cell.data).ok())
+    }
+
+    // Find the user data of a reviewed user, provided either as a cell dep or as an input
+    pub fn find_reviewed_user_data(
+        context: &TransactionContext<RuleBasedClassifier>,
+        user_type_id: &[u8],
+    ) -> Option<UserData> {
+        context
+            .cell_deps
+            .get_custom("user")
+            .and_then(|cells| find_user_data_by_type_id(cells, user_type_id))
+            .or_else(|| {
+                context
+                    .input_cells
+                    .get_custom("user")
+                    .and_then(|cells| find_user_data_by_type_id(cells, user_type_id))
+            })
+    }
+
+    // Parse the quest_id (argument 1) and user_type_ids (argument 2) shared by the review recipes
+    pub fn parse_review_arguments(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(u32, Byte32Vec), DeterministicError> {
+        let arguments = context.recipe.arguments();
+        let quest_id_arg = arguments.get(1).ok_or_else(|| {
+            debug_trace!("InvalidArgumentCount: Missing quest_id argument");
+            DeterministicError::InvalidArgumentCount
+        })?;
+        let user_ids_arg = arguments.get(2).ok_or_else(|| {
+            debug_trace!("InvalidArgumentCount: Missing user_type_ids argument");
+            DeterministicError::InvalidArgumentCount
+        })?;
+        if quest_id_arg.arg_type().as_slice()[0] != 0 || user_ids_arg.arg_type().as_slice()[0] != 0 {
+            debug_trace!("Review arguments must be inline data");
+            return Err(DeterministicError::InvalidArgumentCount);
+        }
+
+        let quest_id_bytes = quest_id_arg.data().raw_data();
+        let quest_id = u32::from_le_bytes(quest_id_bytes.as_ref().try_into().map_err(|_| {
+            debug_trace!("ERROR: quest_id should be exactly 4 bytes (u32), got {}", quest_id_bytes.len());
+            DeterministicError::InvalidArgumentCount
+        })?);
+        let user_type_ids = Byte32Vec::from_slice(&user_ids_arg.data().raw_data())
+            .map_err(|_| DeterministicError::InvalidArgumentCount)?;
+        Ok((quest_id, user_type_ids))
+    }
+
+    // Data of the inline recipe argument at `index`
+    pub fn inline_argument(
+        context: &TransactionContext<RuleBasedClassifier>,
+        index: usize,
+    ) -> Result<Vec<u8>, DeterministicError> {
+        let argument = context.recipe.arguments().get(index).ok_or_else(|| {
+            debug_trace!("InvalidArgumentCount: Missing argument {}", index);
+            DeterministicError::InvalidArgumentCount
+        })?;
+        if argument.arg_type().as_slice()[0] != 0 {
+            debug_trace!("Argument {} must be inline data", index);
+            return Err(DeterministicError::InvalidArgumentCount);
+        }
+        Ok(argument.data().raw_data().to_vec())
+    }
+
+    // Timestamp (ms) proven by the header deps; required by dispute window rules
+    pub fn header_dep_timestamp() -> Result<u64, DeterministicError> {
+        max_header_dep_timestamp().ok_or_else(|| {
+            debug_trace!("CellRelationshipRuleViolation: Dispute window rules require a header dep");
+            DeterministicError::CellRelationshipRuleViolation
+        })
+    }
+
+    // Timestamp (ms) a dispute window rule relies on: the latest header dep, which must not be
+    // older than the campaign clock. Header deps only bound the commit time from below, so the
+    // outputs record it as the new clock; a stale header can then neither backdate an approval
+    // nor reopen a window that the campaign has already seen pass
+    pub fn fresh_header_timestamp(input_campaign_data: &CampaignData) -> Result<u64, DeterministicError> {
+        let now = header_dep_timestamp()?;
+        if now < input_campaign_data.last_recorded_at_u64() {
+            debug_trace!(
+                "Header dep timestamp {} is older than the campaign clock {}",
+                now,
+                input_campaign_data.last_recorded_at_u64()
+            );
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+        Ok(now)
+    }
+
+    // End (ms) of the dispute window of an approval made at `approved_at` (ms)
+    pub fn dispute_window_end(approved_at: u64, window_seconds: u64) -> u64 {
+        approved_at.saturating_add(window_seconds.saturating_mul(1000))
+    }
+
+    // Approved and rejected user sets of a quest must never overlap
+    pub fn ensure_review_sets_disjoint(quest: &QuestData) -> Result<(), DeterministicError> {
+        for rejection in quest.rejected_submissions().into_iter() {
+            if quest.is_accepted(rejection.user_type_id().as_slice()) {
+                debug_trace!(
+                    "User {:?} is both approved and rejected for quest {}",
+                    rejection.user_type_id(),
+                    quest.quest_id_u32()
+                );
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+        }
+        Ok(())
+    }
+
+    // Approval proof of an SMT campaign, carried in the campaign input's input_type witness
+    pub fn approval_proof(
+        campaign_data: &CampaignData,
+    ) -> Result<Option<ApprovalProof>, DeterministicError> {
+        if !campaign_data.tracks_approvals_in_smt() {
+            return Ok(None);
+        }
+        let campaign_type_hash = load_script_hash().map_err(|_| DeterministicError::Encoding)?;
+        load_approval_proof(&campaign_type_hash).map_err(|_| {
+            debug_trace!("Malformed approval proof in the campaign witness");
+            DeterministicError::Encoding
+        })
+    }
+
+    // Approvals SMT roots change only where `proven_quest_id` allows, new quests start with an
+    // empty tree, and campaigns tracking approvals in SMTs never list accepted users
+    pub fn ensure_approvals_roots_kept(
+        input: Option<&CampaignData>,
+        output: &CampaignData,
+        proven_quest_id: Option<u32>,
+    ) -> Result<(), DeterministicError> {
+        for output_quest in output.quests().into_iter() {
+            let quest_id = output_quest.quest_id_u32();
+            if output.tracks_approvals_in_smt() && !output_quest.accepted_submission_user_type_ids().is_empty() {
+                debug_trace!("Quest {} lists accepted users in an SMT campaign", quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if proven_quest_id == Some(quest_id) {
+                continue;
+            }
+            let kept = match input.and_then(|input| input.find_quest(quest_id)) {
+                Some(input_quest) => input_quest.approvals_root().as_slice() == output_quest.approvals_root().as_slice(),
+                None => output_quest.approvals_root().as_slice() == [0u8; 32],
+            };
+            if !kept {
+                debug_trace!("Approvals root of quest {} changed without a proof", quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+        }
+        Ok(())
+    }
+
+    // Fields written by reviews rather than by campaign edits
+    pub fn review_state(quest: &QuestData) -> [Vec<u8>; 6] {
+        [
+            quest.accepted_submission_user_type_ids().as_slice().to_vec(),
+            quest.completion_count().as_slice().to_vec(),
+            quest.rejected_submissions().as_slice().to_vec(),
+            quest.pending_approvals().as_slice().to_vec(),
+            quest.sub_task_approvals().as_slice().to_vec(),
+            quest.nft_awards().as_slice().to_vec(),
+        ]
+    }
+
+    // Every quest of `output` keeps the review state it has in `input`, and new quests start
+    // without reviews
+    pub fn ensure_review_state_kept(
+        input: Option<&CampaignData>,
+        output: &CampaignData,
+    ) -> Result<(), DeterministicError> {
+        for output_quest in output.quests().into_iter() {
+            let kept = match input.and_then(|input| input.find_quest(output_quest.quest_id_u32())) {
+                Some(input_quest) => review_state(&input_quest) == review_state(&output_quest),
+                None => review_state(&output_quest) == review_state(&QuestData::default()),
+            };
+            if !kept {
+                debug_trace!("Review state of quest {} changed outside a review", output_quest.quest_id_u32());
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+        }
+        Ok(())
+    }
 }
 
 pub mod common {
+    use alloc::vec::Vec;
     use ckb_deterministic::debug_trace;
     use ckb_deterministic::errors::Error as DeterministicError;
     use ckb_deterministic::transaction_recipe::TransactionRecipeExt;
     use ckb_deterministic::{assertions::expect, cell_classifier::RuleBasedClassifier};
+    use ckb_std::ckb_constants::Source;
+    use crate::recipes::helper::{
+        approval_proof, current_campaign_type_id, ensure_approvals_roots_kept, find_participant_cell,
+    };
+    use ckboost_shared::approvals::{proven_approvals, rewarded_by_proof};
+    use ckboost_shared::authority::{inputs_hold_role, inputs_include_lock};
+    use ckboost_shared::badge::{badge_content, parse_badge};
+    use ckboost_shared::generated::ckboost::{CampaignData, NftAward};
+    use ckboost_shared::rewards::{
+        merge_rewarded, rewarded_participants, CarriedAssets, RewardEntitlement,
+    };
     use ckboost_shared::transaction_context::TransactionContext;
+    use ckboost_shared::campaign_data::{unpack_u32, ROLE_APPROVE_COMPLETIONS, ROLE_EDIT_METADATA};
+    use ckboost_shared::{CampaignDataExt, QuestDataExt};
+    use molecule::prelude::*;
+
+    // **Reward distribution**: Every participant whose rewards become payable receives exactly
+    // the quest's UDT rewards and at least its CKB reward at the lock of their user cell
+    pub fn reward_distribution_validation(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let input_campaign_cells = context
+            .input_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let output_campaign_cells = context
+            .output_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+        let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+
+        let mut participants = rewarded_participants(&input_campaign_data, &output_campaign_data);
+        if let Some(proof) = approval_proof(&output_campaign_data)? {
+            merge_rewarded(
+                &mut participants,
+                rewarded_by_proof(&input_campaign_data, &output_campaign_data, &proof),
+            );
+        }
+
+        // Participants sharing a lock are paid together
+        let mut owed_by_lock: Vec<(Vec<u8>, RewardEntitlement)> = Vec::new();
+        for (user_type_id, entitlement) in participants {
+            if entitlement.is_empty() {
+                continue;
+            }
+            let user_cell = find_participant_cell(context, &user_type_id).ok_or_else(|| {
+                debug_trace!("Rewarded user {:?} has no user cell in cell deps or inputs", user_type_id);
+                DeterministicError::CellRelationshipRuleViolation
+            })?;
+            let lock_hash = user_cell.lock_hash.as_slice();
+            match owed_by_lock.iter_mut().find(|(owed_lock, _)| owed_lock.as_slice() == lock_hash) {
+                Some((_, owed)) => owed.merge(&entitlement),
+                None => owed_by_lock.push((lock_hash.to_vec(), entitlement)),
+            }
+        }
+
+        for (lock_hash, owed) in owed_by_lock.iter() {
+            let received = CarriedAssets::locked_by(Source::Output, lock_hash)
+                .map_err(|_| DeterministicError::Encoding)?;
+            if !received.delivers(owed) {
+                debug_trace!("Rewards delivered to lock {:?} do not match {:?}", lock_hash, owed);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+        }
+
+        Ok(())
+    }
+
+    // **NFT awards**: Every awarded NFT belongs to its quest's pool, goes to an accepted user and
+    // is awarded at most once per campaign. Awards are kept while their user stays accepted, and
+    // users accepted in this transaction receive one unawarded NFT each while the pool lasts
+    pub fn nft_award_validation(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let output_campaign_cells = context
+            .output_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+        let input_campaign_data = match context.input_cells.get_custom("campaign") {
+            Some(cells) => Some(
+                CampaignData::from_slice(&cells[0].data).map_err(|_| DeterministicError::Encoding)?,
+            ),
+            None => None,
+        };
+
+        let mut awarded_nfts: Vec<Vec<u8>> = Vec::new();
+        for quest in output_campaign_data.quests().into_iter() {
+            let pool = quest.nft_pool();
+            for award in quest.nft_awards().into_iter() {
+                let nft_script = award.nft_script();
+                if awarded_nfts.iter().any(|awarded| awarded.as_slice() == nft_script.as_slice())
+                    || !pool.iter().any(|pooled| pooled.as_slice() == nft_script.as_slice())
+                    || !quest.is_accepted(award.user_type_id().as_slice())
+                {
+                    debug_trace!("Invalid NFT award in quest {}: {:?}", quest.quest_id_u32(), award);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                awarded_nfts.push(nft_script.as_slice().to_vec());
+            }
+        }
+
+        for output_quest in output_campaign_data.quests().into_iter() {
+            let input_quest = input_campaign_data
+                .as_ref()
+                .and_then(|data| data.find_quest(output_quest.quest_id_u32()));
+            let input_awards: Vec<NftAward> = input_quest
+                .as_ref()
+                .map(|quest| quest.nft_awards().into_iter().collect())
+                .unwrap_or_default();
+            let is_kept = |award: &NftAward| {
+                output_quest
+                    .nft_awards()
+                    .into_iter()
+                    .any(|output_award| output_award.as_slice() == award.as_slice())
+            };
+
+            // Awards only disappear together with their user's approval
+            let mut kept_count = 0usize;
+            for award in input_awards.iter() {
+                if is_kept(award) {
+                    kept_count += 1;
+                } else if output_quest.is_accepted(award.user_type_id().as_slice()) {
+                    debug_trace!("NFT award dropped for a user still accepted: {:?}", award);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            let newly_accepted: Vec<Vec<u8>> = output_quest
+                .accepted_submission_user_type_ids()
+                .into_iter()
+                .map(|user_type_id| user_type_id.as_slice().to_vec())
+                .filter(|user_type_id| {
+                    !input_quest
+                        .as_ref()
+                        .map(|quest| quest.is_accepted(user_type_id))
+                        .unwrap_or(false)
+                })
+                .collect();
+            let mut added_count = 0usize;
+            for award in output_quest.nft_awards().into_iter() {
+                let is_new = !input_awards
+                    .iter()
+                    .any(|input_award| input_award.as_slice() == award.as_slice());
+                if !is_new {
+                    continue;
+                }
+                if !newly_accepted
+                    .iter()
+                    .any(|user_type_id| user_type_id.as_slice() == award.user_type_id().as_slice())
+                {
+                    debug_trace!("NFT awarded to a user not approved in this transaction: {:?}", award);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                added_count += 1;
+            }
+            if newly_accepted
+                .iter()
+                .any(|user_type_id| output_quest.awarded_nfts_of(user_type_id).len() > 1)
+            {
+                debug_trace!("A user was awarded more than one NFT in quest {}", output_quest.quest_id_u32());
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let available = output_quest.nft_pool().len().saturating_sub(kept_count);
+            if added_count != newly_accepted.len().min(available) {
+                debug_trace!(
+                    "Quest {} awarded {} NFTs, expected {}",
+                    output_quest.quest_id_u32(),
+                    added_count,
+                    newly_accepted.len().min(available)
+                );
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+        }
+
+        Ok(())
+    }
+
+    // **Completion badges**: When the campaign has a badge cluster, every user approved in this
+    // transaction receives exactly one badge DOB for the quest at the lock of their user cell
+    pub fn badge_mint_validation(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let input_campaign_cells = context
+            .input_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let output_campaign_cells = context
+            .output_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+        let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+        let cluster_id = match output_campaign_data.badge_cluster_id().to_opt() {
+            Some(cluster_id) => cluster_id,
+            None => return Ok(()),
+        };
+        let campaign_type_id = current_campaign_type_id()?;
+
+        let mut newly_approved: Vec<(u32, Vec<u8>)> = Vec::new();
+        for output_quest in output_campaign_data.quests().into_iter() {
+            let quest_id = output_quest.quest_id_u32();
+            let input_quest = input_campaign_data.find_quest(quest_id);
+            for user_type_id in output_quest.accepted_submission_user_type_ids().into_iter() {
+                let already_accepted = input_quest
+                    .as_ref()
+                    .map(|quest| quest.is_accepted(user_type_id.as_slice()))
+                    .unwrap_or(false);
+                if !already_accepted {
+                    newly_approved.push((quest_id, user_type_id.as_slice().to_vec()));
+                }
+            }
+        }
+        if let Some(proof) = approval_proof(&output_campaign_data)? {
+            let quest_id = unpack_u32(proof.quest_id().as_slice());
+            for user_type_id in proven_approvals(&input_campaign_data, &output_campaign_data, &proof)
+                .into_iter()
+                .flatten()
+            {
+                newly_approved.push((quest_id, user_type_id));
+            }
+        }
+
+        let mut expected_badges: Vec<(Vec<u8>, Vec<u8>)> = Vec::new();
+        for (quest_id, user_type_id) in newly_approved.iter() {
+            let user_cell = find_participant_cell(context, user_type_id).ok_or_else(|| {
+                debug_trace!("Badge recipient {:?} has no user cell in cell deps or inputs", user_type_id);
+                DeterministicError::CellRelationshipRuleViolation
+            })?;
+            expected_badges.push((
+                user_cell.lock_hash.as_slice().to_vec(),
+                badge_content(campaign_type_id.as_slice(), *quest_id),
+            ));
+        }
+
+        // Badges are the cluster's spore cells created by this transaction
+        let input_spore_cells = context.input_cells.get_custom("spore");
+        let mut minted_badges: Vec<(Vec<u8>, Vec<u8>)> = Vec::new();
+        for cell in context.output_cells.get_custom("spore").into_iter().flatten() {
+            let type_script = match cell.type_script.as_ref() {
+                Some(type_script) => type_script,
+                None => continue,
+            };
+            let is_transfer = input_spore_cells.into_iter().flatten().any(|input_cell| {
+                input_cell
+                    .type_script
+                    .as_ref()
+                    .map(|input_script| input_script.as_slice() == type_script.as_slice())
+                    .unwrap_or(false)
+            });
+            if is_transfer {
+                continue;
+            }
+            if let Some(content) = parse_badge(&cell.data, cluster_id.as_slice()) {
+                minted_badges.push((cell.lock_hash.as_slice().to_vec(), content));
+            }
+        }
+
+        expected_badges.sort();
+        minted_badges.sort();
+        if expected_badges != minted_badges {
+            debug_trace!(
+                "Minted {} badges, expected one for each of {} newly approved users",
+                minted_badges.len(),
+                expected_badges.len()
+            );
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+
+        Ok(())
+    }
+
+    // **Campaign stats**: total_completions equals the sum of the quests' completion counts and
+    // participants_count the number of distinct users accepted for any quest, so it grows exactly
+    // when a user is accepted for their first quest of the campaign. Campaigns tracking approvals
+    // in SMTs cannot prove a user is new across their per-quest roots, so they keep no
+    // participants count: it stays 0 while their completions are counted
+    pub fn campaign_stats_validation(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let output_campaign_cells = context
+            .output_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+
+        if output_campaign_data.total_completions_u32() != output_campaign_data.quest_completions_sum()
+        {
+            debug_trace!(
+                "Total completions {} do not match the quest completion counts {}",
+                output_campaign_data.total_completions_u32(),
+                output_campaign_data.quest_completions_sum()
+            );
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+        if output_campaign_data.tracks_approvals_in_smt()
+            && output_campaign_data.participants_count_u32() != 0
+        {
+            debug_trace!("SMT campaigns do not count participants");
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+        if output_campaign_data.participants_count_u32() != output_campaign_data.distinct_participants()
+        {
+            debug_trace!(
+                "Participants count {} does not match the {} distinct accepted users",
+                output_campaign_data.participants_count_u32(),
+                output_campaign_data.distinct_participants()
+            );
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+
+        Ok(())
+    }
+
+    // **Approvals root immutability**: Outside of approvals, every quest keeps its approvals SMT
+    // root, and campaigns tracking approvals in SMTs never list accepted users
+    pub fn approvals_root_immutability(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let output_campaign_cells = context
+            .output_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+        let input_campaign_data = match context.input_cells.get_custom("campaign") {
+            Some(cells) => Some(
+                CampaignData::from_slice(&cells[0].data).map_err(|_| DeterministicError::Encoding)?,
+            ),
+            None => None,
+        };
+
+        ensure_approvals_roots_kept(input_campaign_data.as_ref(), &output_campaign_data, None)
+    }
+
+    // **Manager change authorization**: Only the endorser can appoint or remove campaign managers
+    pub fn manager_change_authorization(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let input_campaign_data = match context.input_cells.get_custom("campaign") {
+            Some(cells) => {
+                CampaignData::from_slice(&cells[0].data).map_err(|_| DeterministicError::Encoding)?
+            }
+            // Managers set on creation are signed for by the endorser creating the campaign
+            None => return Ok(()),
+        };
+        let output_campaign_cells = context
+            .output_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+
+        if input_campaign_data.managers().as_slice() != output_campaign_data.managers().as_slice() {
+            let endorser_lock_hash = input_campaign_data.endorser().endorser_lock_hash();
+            if !inputs_include_lock(endorser_lock_hash.as_slice()) {
+                debug_trace!("Only the endorser can change campaign managers");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+        }
+
+        Ok(())
+    }
+
+    // **Reviewer authorization**: Reviewing submissions requires an input locked by a manager with
+    // the approve completions role, and leaves the managers untouched
+    pub fn reviewer_authorization(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let input_campaign_cells = context
+            .input_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+
+        if !inputs_hold_role(&input_campaign_data, ROLE_APPROVE_COMPLETIONS) {
+            debug_trace!("No input is locked by a manager allowed to review completions");
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+
+        // Reviews never hand out roles
+        let output_campaign_cells = context
+            .output_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+        if input_campaign_data.managers().as_slice() != output_campaign_data.managers().as_slice() {
+            debug_trace!("Campaign managers changed during review");
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+
+        Ok(())
+    }
+
+    // **Editor authorization**: Patching the campaign requires an input locked by the endorser or
+    // a manager with the edit metadata role
+    pub fn editor_authorization(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let input_campaign_cells = context
+            .input_cells
+            .get_custom("campaign")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+            .map_err(|_| DeterministicError::Encoding)?;
+
+        if !inputs_hold_role(&input_campaign_data, ROLE_EDIT_METADATA) {
+            debug_trace!("No input is locked by the endorser or a manager allowed to edit the campaign");
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+
+        Ok(())
+    }
 
     // **Script immutability**: Lock hash and type hash for campaign cells must remain unchanged
     pub fn script_immutability(
@@ -194,6 +888,30 @@ pub mod update_campaign {
                     "Validate campaign update data and permissions".to_string(),
                     vec!["campaign".to_string(), "protocol".to_string()],
                     business_logic::campaign_update_validation,
+                )
+                .with_business_rule(
+                    "campaign_stats_validation".to_string(),
+                    "Campaign completion and participant counts must match the quests".to_string(),
+                    vec!["campaign".to_string()],
+                    super::common::campaign_stats_validation,
+                )
+                .with_business_rule(
+                    "approvals_root_immutability".to_string(),
+                    "Approvals SMT roots only change through proven approvals".to_string(),
+                    vec!["campaign".to_string()],
+                    super::common::approvals_root_immutability,
+                )
+                .with_business_rule(
+                    "nft_award_validation".to_string(),
+                    "NFT awards cannot be edited outside of approvals".to_string(),
+                    vec!["campaign".to_string()],
+                    super::common::nft_award_validation,
+                )
+                .with_business_rule(
+                    "manager_change_authorization".to_string(),
+                    "Only the endorser can change campaign managers".to_string(),
+                    vec!["campaign".to_string()],
+                    super::common::manager_change_authorization,
                 );
         for udt_identifier in udt_identifiers {
             rules = rules.with_custom_cell(
@@ -202,7 +920,12 @@ pub mod update_campaign {
                 CellCountConstraint::at_least(0),
             );
         }
-        rules
+        // Spore/DOB cells: escrowed as NFT rewards and transferred to approved users
+        rules.with_custom_cell(
+            "spore",
+            CellCountConstraint::at_least(0),
+            CellCountConstraint::at_least(0),
+        )
     }
     pub mod cell_relationship {
         use ckb_deterministic::cell_classifier::RuleBasedClassifier;
@@ -283,8 +1006,17 @@ pub mod update_campaign {
         use ckb_deterministic::cell_classifier::RuleBasedClassifier;
         use ckb_deterministic::debug_trace;
         use ckb_deterministic::errors::Error as DeterministicError;
+        use alloc::vec::Vec;
+        use crate::recipes::helper::{
+            ensure_bound_to_campaign_lock, ensure_review_state_kept, find_protocol_data_in_deps,
+        };
+        use ckboost_shared::authority::{inputs_hold_role, inputs_include_lock};
+        use ckboost_shared::campaign_data::{
+            APPROVAL_TRACKING_LIST, QUEST_LAYOUT_INLINE, ROLE_EDIT_METADATA,
+        };
         use ckboost_shared::generated::ckboost::CampaignData;
         use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::{CampaignDataExt, ProtocolDataExt, QuestDataExt};
         use molecule::prelude::*;
 
         // **Campaign update validation**: Ensure campaign data is valid and creator has permission
@@ -336,6 +1068,58 @@ pub mod update_campaign {
                         return Err(DeterministicError::BusinessRuleViolation);
                     }
 
+                    // The endorser must sign the update, directly or through a manager they
+                    // appointed to edit the campaign
+                    if !inputs_hold_role(&input_campaign_data, ROLE_EDIT_METADATA) {
+                        debug_trace!("Campaign update is not signed by the endorser or an editor");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+
+                    // Quests cannot move between inline storage and quest cells
+                    if input_campaign_data.quest_layout().as_slice()
+                        != output_campaign_data.quest_layout().as_slice()
+                    {
+                        debug_trace!("Campaign quest layout changed during update");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+
+                    // Approvals cannot move between accepted user lists and SMT roots
+                    if input_campaign_data.approval_tracking().as_slice()
+                        != output_campaign_data.approval_tracking().as_slice()
+                    {
+                        debug_trace!("Campaign approval tracking changed during update");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+
+                    // The campaign clock only moves through the dispute window rules
+                    if output_campaign_data.last_recorded_at().as_slice()
+                        != input_campaign_data.last_recorded_at().as_slice()
+                    {
+                        debug_trace!("Campaign clock changed during update");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+
+                    // Quest IDs below the input's next_quest_id were allocated before and are never
+                    // handed out again, even after their quest is removed
+                    if output_campaign_data.next_quest_id_u32() < input_campaign_data.next_quest_id_u32() {
+                        debug_trace!("Campaign next_quest_id decreased during update");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                    for quest in output_campaign_data.quests().into_iter() {
+                        let quest_id = quest.quest_id_u32();
+                        if input_campaign_data.find_quest(quest_id).is_none()
+                            && quest_id < input_campaign_data.next_quest_id_u32()
+                        {
+                            debug_trace!("Quest ID {} was allocated before and cannot be reused", quest_id);
+                            return Err(DeterministicError::BusinessRuleViolation);
+                        }
+                    }
+
+                    // Accepted users, completions, rejections, pending approvals, sub-task
+                    // approvals and NFT awards only change through the review recipes, which
+                    // check the reviewer role and the users' submissions
+                    ensure_review_state_kept(Some(&input_campaign_data), &output_campaign_data)?;
+
                     // Validate status transitions
                     let input_status = input_campaign_data.status();
                     let output_status = output_campaign_data.status();
@@ -351,6 +1135,21 @@ pub mod update_campaign {
                 None => {
                     debug_trace!(" This is a new campaign creation");
 
+                    // The endorser creating the campaign must sign it and be whitelisted
+                    let endorser_lock_hash = output_campaign_data.endorser().endorser_lock_hash();
+                    if !inputs_include_lock(endorser_lock_hash.as_slice()) {
+                        debug_trace!(" ERROR: New campaign is not signed by its endorser");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                    let protocol_data = find_protocol_data_in_deps(context).ok_or_else(|| {
+                        debug_trace!(" ERROR: Campaign creation needs the protocol cell in cell deps");
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                    if !protocol_data.is_endorser_whitelisted(endorser_lock_hash.as_slice()) {
+                        debug_trace!(" ERROR: Endorser is not in the protocol whitelist");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+
                     // This is a new campaign creation
                     // Verify status is 0 (created)
                     let status = output_campaign_data.status();
@@ -372,34 +1171,130 @@ pub mod update_campaign {
 
                     if participants.as_slice() != zero_u32.as_slice()
                         || completions.as_slice() != zero_u32.as_slice()
+                        || output_campaign_data.last_recorded_at_u64() != 0
                     {
                         debug_trace!(
-                            " ERROR: New campaign must have 0 participants and completions"
+                            " ERROR: New campaign must have 0 participants, completions and clock"
                         );
                         debug_trace!(" Participants count: {:?}", participants.as_slice());
                         debug_trace!(" Total completions: {:?}", completions.as_slice());
                         return Err(DeterministicError::BusinessRuleViolation);
                     }
+
+                    // Its quests start without reviews
+                    ensure_review_state_kept(None, &output_campaign_data)?;
                 }
             }
 
             // Common validations for both create and update
-            // 1. Campaign must have at least one quest
+            // 0. The campaign cell is locked by the campaign lock bound to it, which defers
+            // unlocking to the role checks of this type script. Campaigns still under their
+            // endorser's lock move to it on their next update
+            let protocol_data = find_protocol_data_in_deps(context).ok_or_else(|| {
+                debug_trace!(" ERROR: Campaign updates need the protocol cell in cell deps");
+                DeterministicError::CellRelationshipRuleViolation
+            })?;
+            ensure_bound_to_campaign_lock(&protocol_data)?;
+
+            // 1. Campaign must have at least one quest, unless its quests live in quest cells
             let quest_count = output_campaign_data.quests().len();
             debug_trace!(" Quest count: {}", quest_count);
-            if quest_count == 0 {
+            if output_campaign_data.uses_quest_cells() {
+                if quest_count != 0 {
+                    debug_trace!(" ERROR: Campaign with quest cells cannot have inline quests");
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            } else if quest_count == 0 {
                 debug_trace!(" ERROR: Campaign must have at least one quest");
                 debug_trace!(" Quest count: {}", quest_count);
                 return Err(DeterministicError::BusinessRuleViolation);
+            } else if output_campaign_data.quest_layout().as_slice()[0] != QUEST_LAYOUT_INLINE {
+                debug_trace!(" ERROR: Unknown quest layout");
+                return Err(DeterministicError::BusinessRuleViolation);
             }
 
-            // 2. Title and descriptions must not be empty
-            let title_empty = output_campaign_data.metadata().title().is_empty();
-            let short_desc_empty = output_campaign_data
-                .metadata()
-                .short_description()
-                .is_empty();
-            let long_desc_empty = output_campaign_data
+            // 2. Quest IDs are unique and allocated in ascending order below next_quest_id, and
+            // sub-task IDs are unique within their quest
+            if !output_campaign_data.has_allocated_quest_ids() {
+                debug_trace!(" ERROR: Quest IDs must be ascending and below next_quest_id");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if let Some(quest) = output_campaign_data
+                .quests()
+                .into_iter()
+                .find(|quest| !quest.has_unique_sub_task_ids())
+            {
+                debug_trace!(" ERROR: Quest {} has duplicate sub-task IDs", quest.quest_id_u32());
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            // 3. SMT approvals cover inline quests whose approvals pay out at once; prerequisites,
+            // dispute windows and NFT pools need the accepted user lists
+            if output_campaign_data.tracks_approvals_in_smt() {
+                let needs_lists = output_campaign_data.uses_quest_cells()
+                    || output_campaign_data.approval_dispute_window_u64() != 0
+                    || output_campaign_data.quests().into_iter().any(|quest| {
+                        !quest.prerequisite_quest_ids_u32().is_empty() || !quest.nft_pool().is_empty()
+                    });
+                if needs_lists {
+                    debug_trace!(" ERROR: Campaign features need accepted user lists, not SMT approvals");
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            } else if output_campaign_data.approval_tracking().as_slice()[0] != APPROVAL_TRACKING_LIST {
+                debug_trace!(" ERROR: Unknown approval tracking");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            // 4. Prerequisites must reference other quests of this campaign, each at most once
+            for quest in output_campaign_data.quests().into_iter() {
+                let prerequisite_ids = quest.prerequisite_quest_ids_u32();
+                for (index, prerequisite_id) in prerequisite_ids.iter().enumerate() {
+                    if *prerequisite_id == quest.quest_id_u32()
+                        || prerequisite_ids[..index].contains(prerequisite_id)
+                        || output_campaign_data.find_quest(*prerequisite_id).is_none()
+                    {
+                        debug_trace!(
+                            " ERROR: Quest {} has invalid prerequisite {}",
+                            quest.quest_id_u32(),
+                            prerequisite_id
+                        );
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                }
+            }
+
+            // 5. NFT rewards must be spore/DOB cells of a type accepted by the protocol
+            let nft_pools: Vec<_> = output_campaign_data
+                .quests()
+                .into_iter()
+                .flat_map(|quest| quest.nft_pool())
+                .collect();
+            if !nft_pools.is_empty() {
+                let accepted_dob_scripts = find_protocol_data_in_deps(context)
+                    .map(|protocol_data| protocol_data.accepted_dob_type_scripts())
+                    .ok_or_else(|| {
+                        debug_trace!(" ERROR: NFT rewards need the protocol cell in cell deps");
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                for nft_script in nft_pools.iter() {
+                    let accepted = accepted_dob_scripts.iter().any(|dob_script| {
+                        dob_script.code_hash().as_slice() == nft_script.code_hash().as_slice()
+                            && dob_script.hash_type().as_slice() == nft_script.hash_type().as_slice()
+                    });
+                    if !accepted {
+                        debug_trace!(" ERROR: NFT reward {:?} is not an accepted DOB type", nft_script);
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                }
+            }
+
+            // 6. Title and descriptions must not be empty
+            let title_empty = output_campaign_data.metadata().title().is_empty();
+            let short_desc_empty = output_campaign_data
+                .metadata()
+                .short_description()
+                .is_empty();
+            let long_desc_empty = output_campaign_data
                 .metadata()
                 .long_description()
                 .is_empty();
@@ -475,6 +1370,60 @@ pub mod approve_completion {
                     "Validate quest approval and update accepted submissions".to_string(),
                     vec!["campaign".to_string()],
                     business_logic::approval_validation,
+                )
+                .with_business_rule(
+                    "campaign_stats_validation".to_string(),
+                    "Campaign completion and participant counts must match the quests".to_string(),
+                    vec!["campaign".to_string()],
+                    common::campaign_stats_validation,
+                )
+                .with_business_rule(
+                    "smt_approval_validation".to_string(),
+                    "Approvals of SMT campaigns must be proven against the quest's approvals root".to_string(),
+                    vec!["campaign".to_string()],
+                    business_logic::smt_approval_validation,
+                )
+                .with_business_rule(
+                    "reviewer_authorization".to_string(),
+                    "A manager with the approve completions role must authorize reviews".to_string(),
+                    vec!["campaign".to_string()],
+                    common::reviewer_authorization,
+                )
+                .with_business_rule(
+                    "submission_cross_check".to_string(),
+                    "Every approved user must have submitted for the quest".to_string(),
+                    vec!["campaign".to_string(), "user".to_string()],
+                    business_logic::submission_cross_check,
+                )
+                .with_business_rule(
+                    "prerequisite_validation".to_string(),
+                    "Approved users must already be approved for every prerequisite quest".to_string(),
+                    vec!["campaign".to_string()],
+                    business_logic::prerequisite_validation,
+                )
+                .with_business_rule(
+                    "dispute_window_validation".to_string(),
+                    "New approvals are recorded as pending while the campaign has a dispute window".to_string(),
+                    vec!["campaign".to_string()],
+                    business_logic::dispute_window_validation,
+                )
+                .with_business_rule(
+                    "nft_award_validation".to_string(),
+                    "Each NFT reward goes to exactly one newly approved user".to_string(),
+                    vec!["campaign".to_string()],
+                    common::nft_award_validation,
+                )
+                .with_business_rule(
+                    "badge_mint_validation".to_string(),
+                    "Badge campaigns mint exactly one badge per newly approved user".to_string(),
+                    vec!["campaign".to_string(), "user".to_string()],
+                    common::badge_mint_validation,
+                )
+                .with_business_rule(
+                    "reward_distribution_validation".to_string(),
+                    "Payable participants must receive exactly the quest rewards".to_string(),
+                    vec!["campaign".to_string(), "user".to_string()],
+                    common::reward_distribution_validation,
                 );
         for udt_identifier in udt_identifiers {
             rules = rules.with_custom_cell(
@@ -483,21 +1432,285 @@ pub mod approve_completion {
                 CellCountConstraint::at_least(0),
             );
         }
-        rules
+        // Spore/DOB cells: escrowed as NFT rewards and transferred to approved users
+        rules.with_custom_cell(
+            "spore",
+            CellCountConstraint::at_least(0),
+            CellCountConstraint::at_least(0),
+        )
     }
 
     pub mod business_logic {
-        use alloc::string::String;
         use alloc::vec::Vec;
         use ckb_deterministic::assertions::expect;
         use ckb_deterministic::cell_classifier::RuleBasedClassifier;
         use ckb_deterministic::errors::Error as DeterministicError;
         use ckb_deterministic::{debug_info, debug_trace};
-        use ckb_std::ckb_types::packed::Byte32Vec;
-        use ckboost_shared::generated::ckboost::CampaignData;
+        use crate::recipes::helper::{
+            approval_proof, current_campaign_type_id, ensure_approvals_roots_kept,
+            find_reviewed_user_data, fresh_header_timestamp, parse_review_arguments,
+        };
+        use ckboost_shared::approvals::proven_approvals;
+        use ckboost_shared::campaign_data::unpack_u32;
+        use ckboost_shared::generated::ckboost::{
+            ApprovalRecord, ApprovalRecordVec, Byte32 as SharedByte32, CampaignData, Uint32,
+            Uint64,
+        };
         use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::{CampaignDataExt, QuestDataExt, UserDataExt};
         use molecule::prelude::*;
 
+        // **Submission cross-check**: Every approved user type ID must belong to a user cell
+        // (in cell deps or inputs) that carries a pending submission for this campaign and quest
+        pub fn submission_cross_check(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let (quest_id, approved_user_type_ids) = parse_review_arguments(context)?;
+
+            let campaign_type_id = current_campaign_type_id()?;
+
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let quest = input_campaign_data.find_quest(quest_id).ok_or_else(|| {
+                debug_trace!("Quest {} not found in campaign", quest_id);
+                DeterministicError::BusinessRuleViolation
+            })?;
+
+            for user_type_id in approved_user_type_ids.into_iter() {
+                let user_data = find_reviewed_user_data(context, user_type_id.as_slice())
+                    .ok_or_else(|| {
+                        debug_trace!(
+                            "Approved user {:?} has no user cell in cell deps or inputs",
+                            user_type_id
+                        );
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+
+                // A previously rejected user can only be approved on a newer resubmission
+                let submission_count =
+                    user_data.submission_count(campaign_type_id.as_slice(), quest_id);
+                if submission_count <= quest.rejection_count_of(user_type_id.as_slice()) {
+                    debug_trace!(
+                        "Approved user {:?} has no pending submission for quest {}",
+                        user_type_id,
+                        quest_id
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                if !user_data.latest_submission_revealed(
+                    campaign_type_id.as_slice(),
+                    quest_id,
+                    user_type_id.as_slice(),
+                ) {
+                    debug_trace!(
+                        "Approved user {:?} has not revealed the submission for quest {}",
+                        user_type_id,
+                        quest_id
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+
+                // Multi-step quests complete only once every sub-task is approved
+                if !quest.is_accepted(user_type_id.as_slice())
+                    && !quest.sub_tasks_complete(user_type_id.as_slice())
+                {
+                    debug_trace!(
+                        "Approved user {:?} has unapproved sub-tasks in quest {}",
+                        user_type_id,
+                        quest_id
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            debug_trace!("All approved users have matching submission records");
+            Ok(())
+        }
+
+        // **Prerequisite validation**: Every approved user must already be approved for
+        // all prerequisite quests of the quest being approved
+        pub fn prerequisite_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let (quest_id, approved_user_type_ids) = parse_review_arguments(context)?;
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let quest = input_campaign_data.find_quest(quest_id).ok_or_else(|| {
+                debug_trace!("Quest {} not found in campaign", quest_id);
+                DeterministicError::BusinessRuleViolation
+            })?;
+
+            for user_type_id in approved_user_type_ids.into_iter() {
+                if let Some(prerequisite_id) =
+                    input_campaign_data.missing_prerequisite(&quest, user_type_id.as_slice())
+                {
+                    debug_trace!(
+                        "Approved user {:?} has not been approved for prerequisite quest {}",
+                        user_type_id,
+                        prerequisite_id
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
+
+        // **Dispute window validation**: With a dispute window, every newly approved user
+        // gets a pending approval stamped with the header dep timestamp, which becomes the
+        // campaign clock; without one, pending approvals and the clock are untouched
+        pub fn dispute_window_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            let window = input_campaign_data.approval_dispute_window_u64();
+            if output_campaign_data.approval_dispute_window_u64() != window {
+                debug_trace!("Approval dispute window changed during approval");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let (quest_id, _) = parse_review_arguments(context)?;
+            let input_quest = input_campaign_data
+                .find_quest(quest_id)
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+            let output_quest = output_campaign_data
+                .find_quest(quest_id)
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+
+            if window == 0 {
+                if input_quest.pending_approvals().as_slice()
+                    != output_quest.pending_approvals().as_slice()
+                {
+                    debug_trace!("Pending approvals changed without a dispute window");
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                if output_campaign_data.last_recorded_at_u64() != input_campaign_data.last_recorded_at_u64() {
+                    debug_trace!("Campaign clock moved without a dispute window");
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                return Ok(());
+            }
+
+            let approved_at = fresh_header_timestamp(&input_campaign_data)?;
+            if output_campaign_data.last_recorded_at_u64() != approved_at {
+                debug_trace!("Campaign clock is not moved to the approval timestamp");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            let mut expected_pending: Vec<ApprovalRecord> =
+                input_quest.pending_approvals().into_iter().collect();
+            for accepted_id in output_quest.accepted_submission_user_type_ids().into_iter() {
+                if !input_quest.is_accepted(accepted_id.as_slice()) {
+                    expected_pending.push(
+                        ApprovalRecord::new_builder()
+                            .user_type_id(accepted_id)
+                            .approved_at(Uint64::from(approved_at.to_le_bytes()))
+                            .build(),
+                    );
+                }
+            }
+            let expected_pending = ApprovalRecordVec::new_builder()
+                .extend(expected_pending)
+                .build();
+            if output_quest.pending_approvals().as_slice() != expected_pending.as_slice() {
+                debug_trace!("New approvals are not recorded as pending at the header dep timestamp");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+
+        // **SMT approvals**: Campaigns tracking approvals in SMTs carry a proof that the approved
+        // users were absent from the quest's approvals root and are approved in the new root, and
+        // count each of them as one more completion. Other campaigns keep their roots
+        pub fn smt_approval_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            let proof = match approval_proof(&output_campaign_data)? {
+                Some(proof) => proof,
+                None if output_campaign_data.tracks_approvals_in_smt() => {
+                    debug_trace!("SMT campaign approval carries no approval proof");
+                    return Err(DeterministicError::CellRelationshipRuleViolation);
+                }
+                None => {
+                    return ensure_approvals_roots_kept(
+                        Some(&input_campaign_data),
+                        &output_campaign_data,
+                        None,
+                    )
+                }
+            };
+
+            // The proof must cover exactly the reviewed quest and users
+            let (quest_id, user_type_ids) = parse_review_arguments(context)?;
+            if unpack_u32(proof.quest_id().as_slice()) != quest_id
+                || proof.user_type_ids().as_slice() != user_type_ids.as_slice()
+            {
+                debug_trace!("Approval proof does not match the approved quest and users");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            let proven = proven_approvals(&input_campaign_data, &output_campaign_data, &proof)
+                .ok_or_else(|| {
+                    debug_trace!("Approval proof does not verify against the approvals roots");
+                    DeterministicError::BusinessRuleViolation
+                })?;
+            ensure_approvals_roots_kept(Some(&input_campaign_data), &output_campaign_data, Some(quest_id))?;
+
+            let input_quest = input_campaign_data
+                .find_quest(quest_id)
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+            let output_quest = output_campaign_data
+                .find_quest(quest_id)
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+            let expected_count = input_quest
+                .completion_count_u32()
+                .checked_add(proven.len() as u32)
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+            if output_quest.completion_count_u32() != expected_count {
+                debug_trace!(
+                    "Quest {} completion count {} should be {}",
+                    quest_id,
+                    output_quest.completion_count_u32(),
+                    expected_count
+                );
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+
         // **Approval validation**: Ensure valid quest approval by admin
         pub fn approval_validation(
             context: &TransactionContext<RuleBasedClassifier>,
@@ -544,132 +1757,100 @@ pub mod approve_completion {
             //     return Err(DeterministicError::BusinessRuleViolation);
             // }
 
-            // Get campaign_data from transaction arguments (should be first argument)
-            let _campaign_data_arg = context.recipe.arguments().get(0).ok_or_else(|| {
-                debug_trace!(
-                    "InvalidArgumentCount: Missing campaign_data argument (approval_validation)"
-                );
-                DeterministicError::InvalidArgumentCount
-            })?;
-
-            // Get quest_id from transaction arguments (should be second argument)
-            let quest_id_arg = context.recipe.arguments().get(1).ok_or_else(|| {
-                debug_trace!(
-                    "InvalidArgumentCount: Missing quest_id argument (approval_validation)"
-                );
-                DeterministicError::InvalidArgumentCount
-            })?;
-
-            // Get user_type_ids from transaction arguments (should be third argument)
-            let user_ids_arg = context.recipe.arguments().get(2).ok_or_else(|| {
-                debug_trace!(
-                    "InvalidArgumentCount: Missing user_type_ids argument (approval_validation)"
-                );
-                DeterministicError::InvalidArgumentCount
-            })?;
-
-            // Extract inline data quest_id
-            let quest_id_bytes = quest_id_arg.data().raw_data();
-            let arg_type = quest_id_arg.arg_type();
-
-            if arg_type.as_slice()[0] != 0 {
-                debug_trace!("Quest ID argument is not inline data");
-                return Err(DeterministicError::InvalidArgumentCount);
-            }
-
-            let quest_id_slice = quest_id_bytes.iter().as_slice();
-
-            // Parse quest_id as u32 (should be exactly 4 bytes)
+            let (quest_id, approved_user_type_ids) = parse_review_arguments(context)?;
             debug_trace!(
-                "Quest ID bytes length: {}, hex: 0x{}",
-                quest_id_bytes.len(),
-                quest_id_slice
-                    .iter()
-                    .map(|b| format!("{:02x}", b))
-                    .collect::<String>()
+                "Processing approval of {} users for quest ID: {}",
+                approved_user_type_ids.len(),
+                quest_id
             );
 
-            if quest_id_slice.len() != 4 {
-                debug_trace!(
-                    "ERROR: quest_id should be exactly 4 bytes (u32), got {}",
-                    quest_id_slice.len()
-                );
-                return Err(DeterministicError::InvalidArgumentCount);
-            }
-
-            let quest_id = u32::from_le_bytes(quest_id_slice.try_into().map_err(|e| {
-                debug_trace!("Failed to convert quest_id_slice to [u8; 4]: {:?}", e);
-                DeterministicError::InvalidArgumentCount
-            })?);
-
-            debug_trace!("Processing approval for quest ID: {}", quest_id);
-
-            // Extract user_type_ids from argument as inline data
-            let user_ids_bytes = user_ids_arg.data();
-            let user_ids_arg_type = user_ids_arg.arg_type();
-
-            if user_ids_arg_type.as_slice()[0] != 0 {
-                debug_trace!("User IDs argument is not inline data");
-                return Err(DeterministicError::InvalidArgumentCount);
+            // The campaign output must record exactly the approval of the argument users on the
+            // quest: nothing else in the campaign changes
+            if approved_user_type_ids.is_empty() {
+                debug_trace!("Approval names no users");
+                return Err(DeterministicError::BusinessRuleViolation);
             }
+            let quest = input_campaign_data.find_quest(quest_id).ok_or_else(|| {
+                debug_trace!("Quest {} not found in campaign", quest_id);
+                DeterministicError::BusinessRuleViolation
+            })?;
+            let output_quest = output_campaign_data
+                .find_quest(quest_id)
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+            let tracks_in_smt = input_campaign_data.tracks_approvals_in_smt();
+            let window = input_campaign_data.approval_dispute_window_u64();
+            let approved_at = if window > 0 {
+                fresh_header_timestamp(&input_campaign_data)?
+            } else {
+                input_campaign_data.last_recorded_at_u64()
+            };
 
-            // Parse the user_type_ids list - should be a Byte32Vec
-            let approved_user_type_ids = Byte32Vec::from_slice(&user_ids_bytes.raw_data())
-                .map_err(|e| {
-                    debug_trace!("Failed to parse user_type_ids as Byte32Vec: {:?}", e);
-                    debug_trace!("User IDs bytes length: {}", user_ids_bytes.as_slice().len());
-                    DeterministicError::InvalidArgumentCount
-                })?;
-            debug_trace!(
-                "Approved user type IDs count: {}",
-                approved_user_type_ids.len()
-            );
-
-            // Verify that total_completions increased
-            let input_completions_data = input_campaign_data.total_completions();
-            let output_completions_data = output_campaign_data.total_completions();
-
-            let input_completions_bytes = input_completions_data.as_slice();
-            let output_completions_bytes = output_completions_data.as_slice();
-
-            // Convert to u32 for comparison
-            let mut input_comp_array = [0u8; 4];
-            let mut output_comp_array = [0u8; 4];
-            input_comp_array.copy_from_slice(&input_completions_bytes[0..4]);
-            output_comp_array.copy_from_slice(&output_completions_bytes[0..4]);
-            let input_completions = u32::from_le_bytes(input_comp_array);
-            let output_completions = u32::from_le_bytes(output_comp_array);
+            let mut expected_quest = quest.clone();
+            for user_type_id in approved_user_type_ids.clone().into_iter() {
+                let user_type_id = SharedByte32::from_slice(user_type_id.as_slice())
+                    .map_err(|_| DeterministicError::Encoding)?;
+                if expected_quest.is_accepted(user_type_id.as_slice()) {
+                    debug_trace!("User {:?} is already approved for quest {}", user_type_id, quest_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                expected_quest = expected_quest.clear_rejection(user_type_id.as_slice());
+                // SMT campaigns record the user in the approvals root, proven by
+                // smt_approval_validation, instead of the accepted list
+                if tracks_in_smt {
+                    continue;
+                }
 
-            // Completions should increase (by the number of approved users)
-            if output_completions <= input_completions {
-                debug_trace!(
-                    "Total completions did not increase: {} -> {}",
-                    input_completions,
-                    output_completions
-                );
-                return Err(DeterministicError::BusinessRuleViolation);
+                let accepted_ids = expected_quest
+                    .accepted_submission_user_type_ids()
+                    .as_builder()
+                    .push(user_type_id.clone())
+                    .build();
+                let mut quest_builder = expected_quest
+                    .clone()
+                    .as_builder()
+                    .accepted_submission_user_type_ids(accepted_ids);
+                if window > 0 {
+                    let pending_approvals = expected_quest
+                        .pending_approvals()
+                        .as_builder()
+                        .push(
+                            ApprovalRecord::new_builder()
+                                .user_type_id(user_type_id.clone())
+                                .approved_at(Uint64::from(approved_at.to_le_bytes()))
+                                .build(),
+                        )
+                        .build();
+                    quest_builder = quest_builder.pending_approvals(pending_approvals);
+                }
+                expected_quest = quest_builder.build();
+                // Each approved user receives the first NFT of the pool nobody holds yet
+                if let Some(nft_script) = expected_quest.unawarded_nfts().into_iter().next() {
+                    expected_quest = expected_quest
+                        .with_nft_awarded(user_type_id.as_slice(), nft_script)
+                        .ok_or(DeterministicError::Encoding)?;
+                }
             }
 
-            // Verify endorser remains the same (campaigns cannot change ownership)
-            if input_campaign_data.endorser().as_slice()
-                != output_campaign_data.endorser().as_slice()
-            {
-                debug_trace!("Campaign endorser changed during approval");
-                return Err(DeterministicError::BusinessRuleViolation);
+            let completion_count = quest
+                .completion_count_u32()
+                .checked_add(approved_user_type_ids.len() as u32)
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+            let mut quest_builder = expected_quest
+                .as_builder()
+                .completion_count(Uint32::from(completion_count.to_le_bytes()));
+            if tracks_in_smt {
+                quest_builder = quest_builder.approvals_root(output_quest.approvals_root());
             }
-
-            // Verify that at least one quest exists
-            let quest_count = output_campaign_data.quests().len();
-            if quest_count == 0 {
-                debug_trace!("Campaign has no quests");
+            let expected_campaign_data = input_campaign_data
+                .with_quest(quest_builder.build())
+                .with_recounted_stats()
+                .with_recorded_at(approved_at);
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected approval update");
                 return Err(DeterministicError::BusinessRuleViolation);
             }
 
-            // Verify that points and udt rewards are properly distributed
-
-            // Get quest data for reward validation
-            // Note: quest_id is in the arguments but we need to parse it properly
-            // For now, we'll validate that rewards go to approved users
+            // Reward cells must go to approved users, or back to the funding lock as change
 
             // 1. Get user cells from CellDeps to validate approved users
             let dep_user_cells = context.cell_deps.get_custom("user");
@@ -703,6 +1884,13 @@ pub mod approve_completion {
                 }
             }
 
+            // Add NFT (spore/DOB) cells to rewards
+            if let Some(spore_cells) = context.output_cells.get_custom("spore") {
+                for cell in spore_cells {
+                    reward_cells.push((cell, "spore"));
+                }
+            }
+
             // If rewards are distributed, validate they go to approved users
             if !reward_cells.is_empty() {
                 debug_trace!("Validating {} reward cells", reward_cells.len());
@@ -715,6 +1903,8 @@ pub mod approve_completion {
 
                 // Get input UDT cells to validate change cell locks match input locks
                 let input_udt_cells_for_type: Vec<_> = udt_identifiers.iter()
+                    .map(|udt_id| udt_id.as_str())
+                    .chain(core::iter::once("spore"))
                     .filter_map(|udt_id| context.input_cells.get_custom(udt_id))
                     .flatten()
                     .collect();
@@ -815,99 +2005,11 @@ pub mod approve_completion {
                     }
                 }
 
-                // Validate reward amounts match quest data
-                // Get the quest from campaign data
-                let quests = output_campaign_data.quests();
-
-                // Turn quests into iter and search for matching quest
-                let quest = quests
-                    .into_iter()
-                    .find(|q| q.quest_id().raw_data().to_vec() == quest_id.to_le_bytes())
-                    .ok_or_else(|| {
-                        debug_trace!("Quest not found in campaign data");
-                        DeterministicError::BusinessRuleViolation
-                    })?;
-                // Get rewards from the quest's reward structure
-                let quest_points = quest.points();
-                let quest_rewards_on_completion = quest.rewards_on_completion();
-
-                // For each reward type, validate amounts
-                // Points rewards - check if non-zero
-                let zero_points_u32 = [0u8; 4];
-                if quest_points.as_slice() != &zero_points_u32 {
-                    // Verify points cells exist and have correct amounts
-                    let points_rewards: Vec<_> = reward_cells
-                        .iter()
-                        .filter(|(_, reward_type)| *reward_type == "points")
-                        .collect();
-
-                    if points_rewards.is_empty() {
-                        debug_trace!("Quest expects points but no points rewards found");
-                        // This is OK - rewards might be distributed later
-                    } else {
-                        // Validate each points reward has the correct amount
-                        // Note: Points validation would be done by the Points UDT type script
-                        debug_trace!("Found {} points rewards", points_rewards.len());
-                    }
-                }
-
-                // Asset rewards on completion (AssetListVec)
-                // rewards_on_completion is an AssetListVec, which contains AssetList items
-                for i in 0..quest_rewards_on_completion.len() {
-                    let asset_list = quest_rewards_on_completion.get(i).unwrap();
-
-                    // Check UDT assets in the AssetList
-                    let udt_assets = asset_list.udt_assets();
-                    for j in 0..udt_assets.len() {
-                        let udt_asset = udt_assets.get(j).unwrap();
-                        let udt_type_script = udt_asset.udt_script();
-                        let udt_amount = udt_asset.amount();
-
-                        // Check if UDT amount is non-zero (compare as slices)
-                        let zero_amount = [0u8; 16];
-                        if udt_amount.as_slice() != &zero_amount {
-                            // Find the UDT identifier for this type script
-                            let udt_identifier = ckboost_shared::cell_collector::get_udt_identifier(
-                                &udt_type_script,
-                            );
-
-                            // Check if rewards of this type exist
-                            let udt_specific_rewards: Vec<_> = reward_cells
-                                .iter()
-                                .filter(|(_, reward_type)| *reward_type == udt_identifier.as_str())
-                                .collect();
-
-                            if udt_specific_rewards.is_empty() {
-                                debug_trace!(
-                                    "Quest expects UDT {} but no such rewards found",
-                                    udt_identifier
-                                );
-                                // This is OK - rewards might be distributed later
-                            } else {
-                                // Validate amounts - actual validation done by UDT type script
-                                debug_trace!(
-                                    "Found {} rewards of UDT {}",
-                                    udt_specific_rewards.len(),
-                                    udt_identifier
-                                );
-                            }
-                        }
-                    }
-
-                    // Also check points amount in the AssetList if needed
-                    let asset_points = asset_list.points_amount();
-                    // Check if points amount is non-zero
-                    let zero_points = [0u8; 8];
-                    if asset_points.as_slice() != &zero_points {
-                        debug_trace!("AssetList also contains points");
-                    }
-                }
-                debug_trace!("All rewards validated successfully");
+                // Reward amounts are enforced by reward_distribution_validation
+                debug_trace!("All reward recipients validated successfully");
             } else {
-                debug_trace!("No rewards distributed - deferred distribution");
-                // This is acceptable - admin might approve without immediate reward distribution
+                debug_trace!("No reward cells in outputs");
             }
-            // Distribution amount validation is done in campaign-lock
 
             Ok(())
         }
@@ -925,7 +2027,7 @@ pub mod complete_quest {
     pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
         let udt_identifiers = ckboost_shared::cell_collector::get_all_udt_identifiers().unwrap();
         let mut rules = TransactionValidationRules::new(b"CKBoostCampaign.complete_quest".to_vec())
-            .with_arguments(2) // quest_id and user proof
+            .with_arguments(2) // quest_id and user_type_id
             // Protocol cells not allowed
             .with_custom_cell(
                 "protocol",
@@ -952,27 +2054,66 @@ pub mod complete_quest {
             )
             .with_business_rule(
                 "quest_completion_validation".to_string(),
-                "Validate quest completion proof and update campaign state".to_string(),
+                "Validate quest completion and update campaign and user state".to_string(),
                 vec!["campaign".to_string(), "user".to_string()],
                 business_logic::quest_completion_validation,
-            );
-        for udt_identifier in udt_identifiers {
-            rules = rules.with_custom_cell(
-                udt_identifier,
-                CellCountConstraint::at_least(0),
-                CellCountConstraint::at_least(0),
-            );
-        }
-        rules
-    }
-
-    pub mod business_logic {
-        use ckb_deterministic::assertions::expect;
+            )
+            .with_business_rule(
+                "reviewer_authorization".to_string(),
+                "A manager with the approve completions role must authorize completions".to_string(),
+                vec!["campaign".to_string()],
+                common::reviewer_authorization,
+            )
+            .with_business_rule(
+                "campaign_stats_validation".to_string(),
+                "Campaign completion and participant counts must match the quests".to_string(),
+                vec!["campaign".to_string()],
+                common::campaign_stats_validation,
+            )
+            .with_business_rule(
+                "approvals_root_immutability".to_string(),
+                "Approvals SMT roots only change through proven approvals".to_string(),
+                vec!["campaign".to_string()],
+                common::approvals_root_immutability,
+            )
+            .with_business_rule(
+                "badge_mint_validation".to_string(),
+                "Badge campaigns mint a badge for the completing participant".to_string(),
+                vec!["campaign".to_string(), "user".to_string()],
+                common::badge_mint_validation,
+            )
+            .with_business_rule(
+                "reward_distribution_validation".to_string(),
+                "The completing participant must receive exactly the quest rewards".to_string(),
+                vec!["campaign".to_string(), "user".to_string()],
+                common::reward_distribution_validation,
+            );
+        for udt_identifier in udt_identifiers {
+            rules = rules.with_custom_cell(
+                udt_identifier,
+                CellCountConstraint::at_least(0),
+                CellCountConstraint::at_least(0),
+            );
+        }
+        // Spore/DOB cells: escrowed as NFT rewards and transferred to approved users
+        rules.with_custom_cell(
+            "spore",
+            CellCountConstraint::at_least(0),
+            CellCountConstraint::at_least(0),
+        )
+    }
+
+    pub mod business_logic {
+        use ckb_deterministic::assertions::expect;
         use ckb_deterministic::cell_classifier::RuleBasedClassifier;
         use ckb_deterministic::debug_trace;
         use ckb_deterministic::errors::Error as DeterministicError;
-        use ckboost_shared::generated::ckboost::CampaignData;
+        use crate::recipes::helper::{current_campaign_type_id, find_user_data_by_type_id};
+        use ckboost_shared::generated::ckboost::{
+            Byte32 as SharedByte32, CampaignData, Uint128, Uint32, UserData,
+        };
         use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::{CampaignDataExt, QuestDataExt, UserDataExt};
         use molecule::prelude::*;
 
         // **Quest completion validation**: Ensure valid quest completion
@@ -984,24 +2125,24 @@ pub mod complete_quest {
                 .input_cells
                 .get_custom("campaign")
                 .ok_or_else(|| {
-                    ("CellCountViolation: Missing campaign cell in input (quest_completion_validation)");
+                    debug_trace!("CellCountViolation: Missing campaign cell in input (quest_completion_validation)");
                     DeterministicError::CellCountViolation
                 })?;
             let output_campaign_cells = context
                 .output_cells
                 .get_custom("campaign")
                 .ok_or_else(|| {
-                    ("CellCountViolation: Missing campaign cell in output (quest_completion_validation)");
+                    debug_trace!("CellCountViolation: Missing campaign cell in output (quest_completion_validation)");
                     DeterministicError::CellCountViolation
                 })?;
 
             // Get user cells
             let input_user_cells = context.input_cells.get_custom("user").ok_or_else(|| {
-                ("CellCountViolation: Missing user cell in input (quest_completion_validation)");
+                debug_trace!("CellCountViolation: Missing user cell in input (quest_completion_validation)");
                 DeterministicError::CellCountViolation
             })?;
             let output_user_cells = context.output_cells.get_custom("user").ok_or_else(|| {
-                ("CellCountViolation: Missing user cell in output (quest_completion_validation)");
+                debug_trace!("CellCountViolation: Missing user cell in output (quest_completion_validation)");
                 DeterministicError::CellCountViolation
             })?;
 
@@ -1009,19 +2150,9 @@ pub mod complete_quest {
             expect(input_campaign_cells.len()).to_equal(1)?;
             expect(output_campaign_cells.len()).to_equal(1)?;
 
-            // Ensure we have at least one user cell
-            if input_user_cells.is_empty() || output_user_cells.is_empty() {
-                debug_trace!("No user cells found");
-                return Err(DeterministicError::CellCountViolation);
-            }
-
-            let input_campaign_cell = &input_campaign_cells[0];
-            let output_campaign_cell = &output_campaign_cells[0];
-
-            // Parse campaign data
-            let input_campaign_data = CampaignData::from_slice(&input_campaign_cell.data)
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
                 .map_err(|_| DeterministicError::Encoding)?;
-            let output_campaign_data = CampaignData::from_slice(&output_campaign_cell.data)
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
                 .map_err(|_| DeterministicError::Encoding)?;
 
             // Get quest_id from transaction arguments (should be first argument)
@@ -1031,105 +2162,1545 @@ pub mod complete_quest {
                 );
                 DeterministicError::InvalidArgumentCount
             })?;
+            // Get user_type_id from transaction arguments (should be second argument)
+            let user_type_id_arg = context.recipe.arguments().get(1).ok_or_else(|| {
+                debug_trace!(
+                    "InvalidArgumentCount: Missing user_type_id argument (quest_completion_validation)"
+                );
+                DeterministicError::InvalidArgumentCount
+            })?;
 
-            // Extract the data from the RecipeArgument
-            let quest_id_bytes = quest_id_arg.data();
-
-            // Check if it's a reference (arg_type should be 2 for output reference)
-            let arg_type = quest_id_arg.arg_type();
-            if arg_type.as_slice()[0] == 2 {
-                // It's an output reference, we need to get the actual index
-                let index_bytes = quest_id_bytes.as_slice();
-                if index_bytes.len() != 4 {
-                    debug_trace!("InvalidArgumentCount: Quest ID index length is not 4 (quest_completion_validation)");
-                    return Err(DeterministicError::InvalidArgumentCount);
-                }
-                let _index = u32::from_le_bytes([
-                    index_bytes[0],
-                    index_bytes[1],
-                    index_bytes[2],
-                    index_bytes[3],
-                ]);
+            if quest_id_arg.arg_type().as_slice()[0] != 0
+                || user_type_id_arg.arg_type().as_slice()[0] != 0
+            {
+                debug_trace!("Quest completion arguments must be inline data");
+                return Err(DeterministicError::InvalidArgumentCount);
+            }
 
-                // For validation purposes, we'll assume the quest ID is 32 bytes
-                // In a real implementation, we would load the data from outputs_data[index]
-                // For now, let's validate with a placeholder
+            let quest_id_bytes = quest_id_arg.data().raw_data();
+            if quest_id_bytes.len() != 4 {
+                debug_trace!("ERROR: quest_id should be exactly 4 bytes (u32), got {}", quest_id_bytes.len());
+                return Err(DeterministicError::InvalidArgumentCount);
+            }
+            let quest_id = u32::from_le_bytes([
+                quest_id_bytes[0],
+                quest_id_bytes[1],
+                quest_id_bytes[2],
+                quest_id_bytes[3],
+            ]);
+
+            let user_type_id_bytes = user_type_id_arg.data().raw_data();
+            if user_type_id_bytes.len() != 32 {
+                debug_trace!("ERROR: user_type_id should be exactly 32 bytes, got {}", user_type_id_bytes.len());
+                return Err(DeterministicError::InvalidArgumentCount);
             }
+            let user_type_id = SharedByte32::from_slice(&user_type_id_bytes)
+                .map_err(|_| DeterministicError::Encoding)?;
 
-            // let quest_exists = input_campaign_data
-            //     .quests()
-            //     .into_iter()
-            //     .any(|quest| quest.id().as_slice() == quest_id_byte32.as_slice());
+            debug_trace!("Processing completion of quest {} for user {:?}", quest_id, user_type_id);
 
-            // if !quest_exists {
-            //     return Err(DeterministicError::BusinessRuleViolation);
-            // }
+            // 1. Completions pay out immediately, so campaigns holding approvals in an SMT or behind
+            // a dispute window must go through approve_completion instead
+            if input_campaign_data.tracks_approvals_in_smt() {
+                debug_trace!("SMT campaigns only record completions through proven approvals");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if input_campaign_data.approval_dispute_window_u64() > 0 {
+                debug_trace!("Campaigns with a dispute window hold completions as pending approvals");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
 
-            // 2. Check user hasn't already completed quest
-            // // Parse user progress data to check completion history
-            // let output_user_cell = &output_user_cells[0];
+            // 2. The quest must exist and the user must not have completed it yet
+            let quest = input_campaign_data.find_quest(quest_id).ok_or_else(|| {
+                debug_trace!("Quest {} not found in campaign", quest_id);
+                DeterministicError::BusinessRuleViolation
+            })?;
+            if quest.is_accepted(user_type_id.as_slice()) {
+                debug_trace!("User already completed quest {}", quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if let Some(prerequisite_id) =
+                input_campaign_data.missing_prerequisite(&quest, user_type_id.as_slice())
+            {
+                debug_trace!("User has not been approved for prerequisite quest {}", prerequisite_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if !quest.sub_tasks_complete(user_type_id.as_slice()) {
+                debug_trace!("User has unapproved sub-tasks in quest {}", quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
 
-            // // Check if the user progress is for the same campaign
-            // if user_progress.campaign_id().as_slice() != campaign_id.as_slice() {
-            //     return Err(DeterministicError::BusinessRuleViolation);
-            // }
+            // 3. The campaign output must record exactly this completion
+            let accepted_ids = quest
+                .accepted_submission_user_type_ids()
+                .as_builder()
+                .push(user_type_id.clone())
+                .build();
+            let mut expected_quest = quest
+                .clear_rejection(user_type_id.as_slice())
+                .as_builder()
+                .accepted_submission_user_type_ids(accepted_ids)
+                .completion_count(Uint32::from((quest.completion_count_u32() + 1).to_le_bytes()))
+                .build();
+            // The completing user receives the first NFT of the pool nobody holds yet
+            if let Some(nft_script) = quest.unawarded_nfts().into_iter().next() {
+                expected_quest = expected_quest
+                    .with_nft_awarded(user_type_id.as_slice(), nft_script)
+                    .ok_or(DeterministicError::Encoding)?;
+            }
+            let expected_campaign_data =
+                input_campaign_data.with_quest(expected_quest).with_recounted_stats();
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected completion update");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
 
-            // // Get completed quest IDs from user progress data
-            // let completed_quest_ids = user_progress.completed_quest_ids();
+            // 4. The user cell must be consumed and recreated with the quest points credited
+            let input_user_data = find_user_data_by_type_id(input_user_cells, user_type_id.as_slice())
+                .ok_or_else(|| {
+                    debug_trace!("User cell for completion not found in inputs");
+                    DeterministicError::CellRelationshipRuleViolation
+                })?;
+            let output_user_data = find_user_data_by_type_id(output_user_cells, user_type_id.as_slice())
+                .ok_or_else(|| {
+                    debug_trace!("User cell for completion not found in outputs");
+                    DeterministicError::CellRelationshipRuleViolation
+                })?;
 
-            // Check if this quest has already been completed
-            // let already_completed = completed_quest_ids
-            //     .into_iter()
-            //     .any(|completed_id| completed_id.as_slice() == quest_id_byte32.as_slice());
+            // The user must have a submission for this quest that was not rejected
+            let campaign_type_id = current_campaign_type_id()?;
+            let submission_count =
+                input_user_data.submission_count(campaign_type_id.as_slice(), quest_id);
+            if submission_count <= quest.rejection_count_of(user_type_id.as_slice()) {
+                debug_trace!("User has no pending submission for quest {}", quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if !input_user_data.latest_submission_revealed(
+                campaign_type_id.as_slice(),
+                quest_id,
+                user_type_id.as_slice(),
+            ) {
+                debug_trace!("User has not revealed the submission for quest {}", quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
 
-            // if already_completed {
-            //     return Err(DeterministicError::BusinessRuleViolation);
-            // }
+            let expected_points = input_user_data
+                .total_points_earned_u128()
+                .checked_add(quest.points_u128())
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+            let expected_user_data = input_user_data
+                .as_builder()
+                .total_points_earned(Uint128::from(expected_points.to_le_bytes()))
+                .build();
+            if output_user_data.as_slice() != expected_user_data.as_slice() {
+                debug_trace!("Output user data does not credit exactly the quest points");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod reject_submission {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.reject_submission".to_vec())
+            .with_arguments(4) // campaign_data, quest_id, user_type_ids and reason_code
+            // Protocol cells not allowed
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: exactly 1 in, 1 out (to record rejections)
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells: may be spent as submission proof, recreated unchanged
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::at_least(0),
+                CellCountConstraint::at_least(0),
+            )
+            // Points UDT cells: rejections never mint points
+            .with_custom_cell(
+                "points",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during rejection".to_string(),
+                vec!["campaign".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "rejection_validation".to_string(),
+                "Validate rejected submissions are recorded without touching approvals".to_string(),
+                vec!["campaign".to_string(), "user".to_string()],
+                business_logic::rejection_validation,
+            )
+            .with_business_rule(
+                "campaign_stats_validation".to_string(),
+                "Campaign completion and participant counts must match the quests".to_string(),
+                vec!["campaign".to_string()],
+                common::campaign_stats_validation,
+            )
+            .with_business_rule(
+                "approvals_root_immutability".to_string(),
+                "Approvals SMT roots only change through proven approvals".to_string(),
+                vec!["campaign".to_string()],
+                common::approvals_root_immutability,
+            )
+            .with_business_rule(
+                "reviewer_authorization".to_string(),
+                "A manager with the approve completions role must authorize reviews".to_string(),
+                vec!["campaign".to_string()],
+                common::reviewer_authorization,
+            )
+    }
 
-            // 3. Update campaign quest completion count
-            // Verify that total_completions increased by 1
-            let input_completions_data = input_campaign_data.total_completions();
-            let output_completions_data = output_campaign_data.total_completions();
+    pub mod business_logic {
+        use alloc::vec::Vec;
+        use ckb_deterministic::assertions::expect;
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use ckb_std::ckb_types::packed::Byte32Vec;
+        use crate::recipes::helper::{
+            current_campaign_type_id, ensure_review_sets_disjoint, find_reviewed_user_data,
+        };
+        use ckboost_shared::generated::ckboost::{
+            Byte32 as SharedByte32, CampaignData, SubmissionRejection, SubmissionRejectionVec,
+            Uint32,
+        };
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::{CampaignDataExt, QuestDataExt, UserDataExt};
+        use molecule::prelude::*;
 
-            let input_completions_bytes = input_completions_data.as_slice();
-            let output_completions_bytes = output_completions_data.as_slice();
+        // **Rejection validation**: Each rejected user has a pending submission, is not approved,
+        // and the campaign output differs from the input only by the updated rejection entries
+        pub fn rejection_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            expect(input_campaign_cells.len()).to_equal(1)?;
+            expect(output_campaign_cells.len()).to_equal(1)?;
 
-            // Convert to u32 for comparison
-            let mut input_comp_array = [0u8; 4];
-            let mut output_comp_array = [0u8; 4];
-            input_comp_array.copy_from_slice(&input_completions_bytes[0..4]);
-            output_comp_array.copy_from_slice(&output_completions_bytes[0..4]);
-            let input_completions = u32::from_le_bytes(input_comp_array);
-            let output_completions = u32::from_le_bytes(output_comp_array);
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
 
-            if output_completions != input_completions + 1 {
-                debug_trace!(
-                    "Total completions did not increase by 1: {} -> {}",
-                    input_completions,
-                    output_completions
-                );
+            let arguments = context.recipe.arguments();
+            let quest_id_arg = arguments.get(1).ok_or(DeterministicError::InvalidArgumentCount)?;
+            let user_ids_arg = arguments.get(2).ok_or(DeterministicError::InvalidArgumentCount)?;
+            let reason_code_arg = arguments.get(3).ok_or(DeterministicError::InvalidArgumentCount)?;
+            if quest_id_arg.arg_type().as_slice()[0] != 0
+                || user_ids_arg.arg_type().as_slice()[0] != 0
+                || reason_code_arg.arg_type().as_slice()[0] != 0
+            {
+                debug_trace!("Rejection arguments must be inline data");
+                return Err(DeterministicError::InvalidArgumentCount);
+            }
+
+            let quest_id_bytes = quest_id_arg.data().raw_data();
+            let quest_id = u32::from_le_bytes(
+                quest_id_bytes
+                    .as_ref()
+                    .try_into()
+                    .map_err(|_| DeterministicError::InvalidArgumentCount)?,
+            );
+            let reason_code_bytes = reason_code_arg.data().raw_data();
+            let reason_code: [u8; 4] = reason_code_bytes
+                .as_ref()
+                .try_into()
+                .map_err(|_| DeterministicError::InvalidArgumentCount)?;
+            let rejected_user_type_ids = Byte32Vec::from_slice(&user_ids_arg.data().raw_data())
+                .map_err(|_| DeterministicError::InvalidArgumentCount)?;
+
+            let quest = input_campaign_data.find_quest(quest_id).ok_or_else(|| {
+                debug_trace!("Quest {} not found in campaign", quest_id);
+                DeterministicError::BusinessRuleViolation
+            })?;
+            let campaign_type_id = current_campaign_type_id()?;
+
+            let mut rejections: Vec<SubmissionRejection> =
+                quest.rejected_submissions().into_iter().collect();
+            for user_type_id in rejected_user_type_ids.clone().into_iter() {
+                if quest.is_accepted(user_type_id.as_slice()) {
+                    debug_trace!("Cannot reject approved user {:?}", user_type_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+
+                // Only a submission that has not been reviewed yet can be rejected
+                let user_data = find_reviewed_user_data(context, user_type_id.as_slice())
+                    .ok_or_else(|| {
+                        debug_trace!(
+                            "Rejected user {:?} has no user cell in cell deps or inputs",
+                            user_type_id
+                        );
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                let rejection_count = quest.rejection_count_of(user_type_id.as_slice());
+                if user_data.submission_count(campaign_type_id.as_slice(), quest_id)
+                    <= rejection_count
+                {
+                    debug_trace!(
+                        "Rejected user {:?} has no pending submission for quest {}",
+                        user_type_id,
+                        quest_id
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+
+                let rejection = SubmissionRejection::new_builder()
+                    .user_type_id(
+                        SharedByte32::from_slice(user_type_id.as_slice())
+                            .map_err(|_| DeterministicError::Encoding)?,
+                    )
+                    .reason_code(Uint32::from(reason_code))
+                    .rejection_count(Uint32::from((rejection_count + 1).to_le_bytes()))
+                    .build();
+                match rejections.iter().position(|existing| {
+                    existing.user_type_id().as_slice() == user_type_id.as_slice()
+                }) {
+                    Some(position) => rejections[position] = rejection,
+                    None => rejections.push(rejection),
+                }
+            }
+
+            // A rejection resets the rejected users' sub-task progress
+            let mut expected_quest = quest;
+            for user_type_id in rejected_user_type_ids.into_iter() {
+                expected_quest = expected_quest.clear_sub_task_approvals(user_type_id.as_slice());
+            }
+            let expected_quest = expected_quest
+                .as_builder()
+                .rejected_submissions(
+                    SubmissionRejectionVec::new_builder()
+                        .extend(rejections)
+                        .build(),
+                )
+                .build();
+            ensure_review_sets_disjoint(&expected_quest)?;
+            let expected_campaign_data = input_campaign_data.with_quest(expected_quest);
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected rejection update");
                 return Err(DeterministicError::BusinessRuleViolation);
             }
 
-            // TODO: Not handling this for now
-            // // Verify campaign status is active (4)
-            // if input_campaign_data.status() != 4u8.into() {
-            //     return Err(DeterministicError::BusinessRuleViolation);
-            // }
+            Ok(())
+        }
+    }
+}
 
-            // Verify quest rewards match what's defined in the campaign
-            // let quest = input_campaign_data
-            //     .quests()
-            //     .into_iter()
-            //     .find(|q| q.id().as_slice() == quest_id_byte32.as_slice())
-            //     .ok_or(DeterministicError::BusinessRuleViolation)?;
-
-            // The user should receive the quest rewards
-            // This validation would check that proper reward distribution occurred
-            // For now, we just verify the quest has rewards defined
-            // if quest.rewards_on_completion().is_empty() {
-            //     return Err(DeterministicError::BusinessRuleViolation);
-            // }
+pub mod revoke_approval {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.revoke_approval".to_vec())
+            .with_arguments(3) // campaign_data, quest_id and user_type_ids
+            // Protocol cells not allowed
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: exactly 1 in, 1 out (to remove approvals)
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // Points UDT cells: revocations never mint points
+            .with_custom_cell(
+                "points",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during revocation".to_string(),
+                vec!["campaign".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "revocation_validation".to_string(),
+                "Only pending approvals inside the dispute window can be revoked".to_string(),
+                vec!["campaign".to_string()],
+                business_logic::revocation_validation,
+            )
+            .with_business_rule(
+                "campaign_stats_validation".to_string(),
+                "Campaign completion and participant counts must match the quests".to_string(),
+                vec!["campaign".to_string()],
+                common::campaign_stats_validation,
+            )
+            .with_business_rule(
+                "approvals_root_immutability".to_string(),
+                "Approvals SMT roots only change through proven approvals".to_string(),
+                vec!["campaign".to_string()],
+                common::approvals_root_immutability,
+            )
+            .with_business_rule(
+                "reviewer_authorization".to_string(),
+                "A manager with the approve completions role must authorize reviews".to_string(),
+                vec!["campaign".to_string()],
+                common::reviewer_authorization,
+            )
+    }
+
+    pub mod business_logic {
+        use alloc::vec::Vec;
+        use ckb_deterministic::assertions::expect;
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use crate::recipes::helper::{dispute_window_end, fresh_header_timestamp, parse_review_arguments};
+        use ckboost_shared::generated::ckboost::{
+            ApprovalRecord, ApprovalRecordVec, Byte32, Byte32Vec, CampaignData, NftAward,
+            NftAwardVec, Uint32,
+        };
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::{CampaignDataExt, QuestDataExt};
+        use molecule::prelude::*;
+
+        // **Revocation validation**: Every revoked user has a pending approval whose window has
+        // not passed by the header dep timestamp, which cannot be older than the campaign clock;
+        // the campaign output only drops them and moves the clock to that timestamp
+        pub fn revocation_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            expect(input_campaign_cells.len()).to_equal(1)?;
+            expect(output_campaign_cells.len()).to_equal(1)?;
+
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            let (quest_id, revoked_user_type_ids) = parse_review_arguments(context)?;
+            let quest = input_campaign_data.find_quest(quest_id).ok_or_else(|| {
+                debug_trace!("Quest {} not found in campaign", quest_id);
+                DeterministicError::BusinessRuleViolation
+            })?;
+
+            // Campaign scripts cannot read the commit time, and since only gives lower bounds, so
+            // the window is closed against the campaign clock, which finalizations and later
+            // approvals move forward
+            let now = fresh_header_timestamp(&input_campaign_data)?;
+            let window = input_campaign_data.approval_dispute_window_u64();
+            for user_type_id in revoked_user_type_ids.clone().into_iter() {
+                let record = quest.find_pending_approval(user_type_id.as_slice()).ok_or_else(|| {
+                    debug_trace!("User {:?} has no pending approval for quest {}", user_type_id, quest_id);
+                    DeterministicError::BusinessRuleViolation
+                })?;
+                let approved_at = u64::from_le_bytes(
+                    record.approved_at().as_slice().try_into().map_err(|_| DeterministicError::Encoding)?,
+                );
+                if now >= dispute_window_end(approved_at, window) {
+                    debug_trace!("Dispute window for user {:?} has passed", user_type_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            let is_revoked = |user_type_id: &[u8]| {
+                revoked_user_type_ids
+                    .clone()
+                    .into_iter()
+                    .any(|revoked_id| revoked_id.as_slice() == user_type_id)
+            };
+            let accepted_ids: Vec<Byte32> = quest
+                .accepted_submission_user_type_ids()
+                .into_iter()
+                .filter(|accepted_id| !is_revoked(accepted_id.as_slice()))
+                .collect();
+            let pending_approvals: Vec<ApprovalRecord> = quest
+                .pending_approvals()
+                .into_iter()
+                .filter(|record| !is_revoked(record.user_type_id().as_slice()))
+                .collect();
+            let revoked_count =
+                (quest.accepted_submission_user_type_ids().len() - accepted_ids.len()) as u32;
+            // NFTs awarded to revoked users return to the quest's pool
+            let nft_awards: Vec<NftAward> = quest
+                .nft_awards()
+                .into_iter()
+                .filter(|award| !is_revoked(award.user_type_id().as_slice()))
+                .collect();
+
+            let expected_quest = quest
+                .clone()
+                .as_builder()
+                .nft_awards(NftAwardVec::new_builder().extend(nft_awards).build())
+                .accepted_submission_user_type_ids(
+                    Byte32Vec::new_builder().extend(accepted_ids).build(),
+                )
+                .pending_approvals(ApprovalRecordVec::new_builder().extend(pending_approvals).build())
+                .completion_count(Uint32::from(
+                    quest
+                        .completion_count_u32()
+                        .checked_sub(revoked_count)
+                        .ok_or(DeterministicError::BusinessRuleViolation)?
+                        .to_le_bytes(),
+                ))
+                .build();
+            let expected_campaign_data = input_campaign_data
+                .with_quest(expected_quest)
+                .with_recounted_stats()
+                .with_recorded_at(now);
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected revocation update");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod finalize_approvals {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        let udt_identifiers = ckboost_shared::cell_collector::get_all_udt_identifiers().unwrap();
+        let mut rules = TransactionValidationRules::new(b"CKBoostCampaign.finalize_approvals".to_vec())
+            .with_arguments(3) // campaign_data, quest_id and user_type_ids
+            // Protocol cells not allowed
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: exactly 1 in, 1 out (to clear pending approvals)
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells: finalized participants may be paid out in the same transaction
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::at_least(0),
+                CellCountConstraint::at_least(0),
+            )
+            .with_custom_cell(
+                "points",
+                CellCountConstraint::at_least(0),
+                CellCountConstraint::at_least(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during finalization".to_string(),
+                vec!["campaign".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "finalization_validation".to_string(),
+                "Only pending approvals past the dispute window can be finalized".to_string(),
+                vec!["campaign".to_string()],
+                business_logic::finalization_validation,
+            )
+            .with_business_rule(
+                "campaign_stats_validation".to_string(),
+                "Campaign completion and participant counts must match the quests".to_string(),
+                vec!["campaign".to_string()],
+                common::campaign_stats_validation,
+            )
+            .with_business_rule(
+                "approvals_root_immutability".to_string(),
+                "Approvals SMT roots only change through proven approvals".to_string(),
+                vec!["campaign".to_string()],
+                common::approvals_root_immutability,
+            )
+            .with_business_rule(
+                "reviewer_authorization".to_string(),
+                "A manager with the approve completions role must authorize reviews".to_string(),
+                vec!["campaign".to_string()],
+                common::reviewer_authorization,
+            )
+            .with_business_rule(
+                "reward_distribution_validation".to_string(),
+                "Finalized participants must receive exactly the quest rewards".to_string(),
+                vec!["campaign".to_string(), "user".to_string()],
+                common::reward_distribution_validation,
+            );
+        for udt_identifier in udt_identifiers {
+            rules = rules.with_custom_cell(
+                udt_identifier,
+                CellCountConstraint::at_least(0),
+                CellCountConstraint::at_least(0),
+            );
+        }
+        // Spore/DOB cells: escrowed as NFT rewards and transferred to approved users
+        rules.with_custom_cell(
+            "spore",
+            CellCountConstraint::at_least(0),
+            CellCountConstraint::at_least(0),
+        )
+    }
+
+    pub mod business_logic {
+        use alloc::vec::Vec;
+        use ckb_deterministic::assertions::expect;
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use crate::recipes::helper::{dispute_window_end, fresh_header_timestamp, parse_review_arguments};
+        use ckboost_shared::generated::ckboost::{ApprovalRecord, ApprovalRecordVec, CampaignData};
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::{CampaignDataExt, QuestDataExt};
+        use molecule::prelude::*;
+
+        // **Finalization validation**: Every finalized user has a pending approval whose window,
+        // as proven by the header deps, has passed; the campaign output only clears those entries
+        // and moves the campaign clock to the header dep timestamp
+        pub fn finalization_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            expect(input_campaign_cells.len()).to_equal(1)?;
+            expect(output_campaign_cells.len()).to_equal(1)?;
+
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            let (quest_id, finalized_user_type_ids) = parse_review_arguments(context)?;
+            let quest = input_campaign_data.find_quest(quest_id).ok_or_else(|| {
+                debug_trace!("Quest {} not found in campaign", quest_id);
+                DeterministicError::BusinessRuleViolation
+            })?;
+
+            let now = fresh_header_timestamp(&input_campaign_data)?;
+            let window = input_campaign_data.approval_dispute_window_u64();
+            for user_type_id in finalized_user_type_ids.clone().into_iter() {
+                let record = quest.find_pending_approval(user_type_id.as_slice()).ok_or_else(|| {
+                    debug_trace!("User {:?} has no pending approval for quest {}", user_type_id, quest_id);
+                    DeterministicError::BusinessRuleViolation
+                })?;
+                let approved_at = u64::from_le_bytes(
+                    record.approved_at().as_slice().try_into().map_err(|_| DeterministicError::Encoding)?,
+                );
+                if now < dispute_window_end(approved_at, window) {
+                    debug_trace!("Dispute window for user {:?} has not passed yet", user_type_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            let pending_approvals: Vec<ApprovalRecord> = quest
+                .pending_approvals()
+                .into_iter()
+                .filter(|record| {
+                    !finalized_user_type_ids
+                        .clone()
+                        .into_iter()
+                        .any(|finalized_id| finalized_id.as_slice() == record.user_type_id().as_slice())
+                })
+                .collect();
+            let expected_quest = quest
+                .as_builder()
+                .pending_approvals(ApprovalRecordVec::new_builder().extend(pending_approvals).build())
+                .build();
+            let expected_campaign_data =
+                input_campaign_data.with_quest(expected_quest).with_recorded_at(now);
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected finalization update");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod approve_sub_tasks {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.approve_sub_tasks".to_vec())
+            .with_arguments(4) // campaign_data, quest_id, user_type_ids and sub_task_ids
+            // Protocol cells not allowed
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: exactly 1 in, 1 out (to record sub-task progress)
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells: may be spent as submission proof, recreated unchanged
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::at_least(0),
+                CellCountConstraint::at_least(0),
+            )
+            // Points UDT cells: sub-task approvals never mint points
+            .with_custom_cell(
+                "points",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during sub-task approval".to_string(),
+                vec!["campaign".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "sub_task_approval_validation".to_string(),
+                "Validate approved sub-tasks were submitted and only sub-task progress changes".to_string(),
+                vec!["campaign".to_string(), "user".to_string()],
+                business_logic::sub_task_approval_validation,
+            )
+            .with_business_rule(
+                "campaign_stats_validation".to_string(),
+                "Campaign completion and participant counts must match the quests".to_string(),
+                vec!["campaign".to_string()],
+                common::campaign_stats_validation,
+            )
+            .with_business_rule(
+                "approvals_root_immutability".to_string(),
+                "Approvals SMT roots only change through proven approvals".to_string(),
+                vec!["campaign".to_string()],
+                common::approvals_root_immutability,
+            )
+            .with_business_rule(
+                "reviewer_authorization".to_string(),
+                "A manager with the approve completions role must authorize reviews".to_string(),
+                vec!["campaign".to_string()],
+                common::reviewer_authorization,
+            )
+    }
+
+    pub mod business_logic {
+        use ckb_deterministic::assertions::expect;
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use crate::recipes::helper::{
+            current_campaign_type_id, find_reviewed_user_data, parse_review_arguments,
+        };
+        use ckboost_shared::generated::ckboost::CampaignData;
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::{CampaignDataExt, QuestDataExt, UserDataExt};
+        use molecule::prelude::*;
+
+        // **Sub-task approval validation**: Each approved sub-task belongs to the quest and is
+        // covered by the user's latest submission; the campaign output only records the progress
+        pub fn sub_task_approval_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            expect(input_campaign_cells.len()).to_equal(1)?;
+            expect(output_campaign_cells.len()).to_equal(1)?;
+
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            let (quest_id, approved_user_type_ids) = parse_review_arguments(context)?;
+            let sub_task_ids_arg = context.recipe.arguments().get(3).ok_or_else(|| {
+                debug_trace!("InvalidArgumentCount: Missing sub_task_ids argument");
+                DeterministicError::InvalidArgumentCount
+            })?;
+            if sub_task_ids_arg.arg_type().as_slice()[0] != 0 {
+                debug_trace!("sub_task_ids argument must be inline data");
+                return Err(DeterministicError::InvalidArgumentCount);
+            }
+            let sub_task_ids = sub_task_ids_arg.data().raw_data();
+
+            let quest = input_campaign_data.find_quest(quest_id).ok_or_else(|| {
+                debug_trace!("Quest {} not found in campaign", quest_id);
+                DeterministicError::BusinessRuleViolation
+            })?;
+            let quest_sub_task_ids = quest.sub_task_ids();
+            if sub_task_ids.is_empty()
+                || sub_task_ids.iter().any(|id| !quest_sub_task_ids.contains(id))
+            {
+                debug_trace!("Sub-tasks {:?} are not all part of quest {}", sub_task_ids, quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let campaign_type_id = current_campaign_type_id()?;
+            let mut expected_quest = quest.clone();
+            for user_type_id in approved_user_type_ids.into_iter() {
+                if quest.is_accepted(user_type_id.as_slice()) {
+                    debug_trace!("User {:?} is already approved for quest {}", user_type_id, quest_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+
+                // The latest submission must carry an entry for every approved sub-task
+                let user_data = find_reviewed_user_data(context, user_type_id.as_slice())
+                    .ok_or_else(|| {
+                        debug_trace!(
+                            "Approved user {:?} has no user cell in cell deps or inputs",
+                            user_type_id
+                        );
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                if user_data.submission_count(campaign_type_id.as_slice(), quest_id)
+                    <= quest.rejection_count_of(user_type_id.as_slice())
+                {
+                    debug_trace!(
+                        "Approved user {:?} has no pending submission for quest {}",
+                        user_type_id,
+                        quest_id
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                if !user_data.latest_submission_revealed(
+                    campaign_type_id.as_slice(),
+                    quest_id,
+                    user_type_id.as_slice(),
+                ) {
+                    debug_trace!(
+                        "Approved user {:?} has not revealed the submission for quest {}",
+                        user_type_id,
+                        quest_id
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                let submitted_ids =
+                    user_data.submitted_sub_task_ids(campaign_type_id.as_slice(), quest_id);
+                if let Some(missing_id) = sub_task_ids.iter().find(|id| !submitted_ids.contains(id)) {
+                    debug_trace!(
+                        "User {:?} has not submitted sub-task {} of quest {}",
+                        user_type_id,
+                        missing_id,
+                        quest_id
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+
+                expected_quest = expected_quest
+                    .with_sub_tasks_approved(user_type_id.as_slice(), &sub_task_ids)
+                    .ok_or(DeterministicError::Encoding)?;
+            }
+
+            let expected_campaign_data = input_campaign_data.with_quest(expected_quest);
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected sub-task approval update");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod add_quest {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.add_quest".to_vec())
+            .with_arguments(2) // campaign_data and quest
+            // Protocol cells not allowed
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: exactly 1 in, 1 out (with the quest appended)
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells not allowed
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Points UDT cells: campaign patches never mint points
+            .with_custom_cell(
+                "points",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained when adding a quest".to_string(),
+                vec!["campaign".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "quest_addition_validation".to_string(),
+                "Validate the new quest and that only the quest list grows".to_string(),
+                vec!["campaign".to_string()],
+                business_logic::quest_addition_validation,
+            )
+            .with_business_rule(
+                "campaign_stats_validation".to_string(),
+                "Campaign completion and participant counts must match the quests".to_string(),
+                vec!["campaign".to_string()],
+                common::campaign_stats_validation,
+            )
+            .with_business_rule(
+                "approvals_root_immutability".to_string(),
+                "Approvals SMT roots only change through proven approvals".to_string(),
+                vec!["campaign".to_string()],
+                common::approvals_root_immutability,
+            )
+            .with_business_rule(
+                "editor_authorization".to_string(),
+                "The endorser or a manager with the edit metadata role must authorize the patch".to_string(),
+                vec!["campaign".to_string()],
+                common::editor_authorization,
+            )
+    }
+
+    pub mod business_logic {
+        use ckb_deterministic::assertions::expect;
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use crate::recipes::helper::{find_protocol_data_in_deps, inline_argument};
+        use ckboost_shared::generated::ckboost::{CampaignData, QuestData, Uint32};
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::{CampaignDataExt, ProtocolDataExt, QuestDataExt};
+        use molecule::prelude::*;
+
+        // **Quest addition validation**: The new quest takes a fresh ID and has no review state
+        // and valid prerequisites and NFT rewards; the campaign output only appends it and moves
+        // next_quest_id past it
+        pub fn quest_addition_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            expect(input_campaign_cells.len()).to_equal(1)?;
+            expect(output_campaign_cells.len()).to_equal(1)?;
+
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let quest = QuestData::from_slice(&inline_argument(context, 1)?)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let quest_id = quest.quest_id_u32();
+
+            if input_campaign_data.uses_quest_cells() {
+                debug_trace!("Campaigns with quest cells add quests through the quest type");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if quest_id < input_campaign_data.next_quest_id_u32() {
+                debug_trace!("Quest ID {} was already allocated", quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if !quest.has_unique_sub_task_ids() {
+                debug_trace!("Quest {} has duplicate sub-task IDs", quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            let next_quest_id = quest_id.checked_add(1).ok_or_else(|| {
+                debug_trace!("No quest IDs left to allocate");
+                DeterministicError::BusinessRuleViolation
+            })?;
+
+            // A new quest starts without any review state
+            let unreviewed = quest.accepted_submission_user_type_ids().is_empty()
+                && quest.completion_count_u32() == 0
+                && quest.rejected_submissions().is_empty()
+                && quest.pending_approvals().is_empty()
+                && quest.sub_task_approvals().is_empty()
+                && quest.nft_awards().is_empty()
+                && quest.approvals_root().as_slice() == [0u8; 32];
+            if !unreviewed {
+                debug_trace!("New quest {} carries review state", quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            // Prerequisites must reference existing quests, each at most once
+            let prerequisite_ids = quest.prerequisite_quest_ids_u32();
+            for (index, prerequisite_id) in prerequisite_ids.iter().enumerate() {
+                if *prerequisite_id == quest_id
+                    || prerequisite_ids[..index].contains(prerequisite_id)
+                    || input_campaign_data.find_quest(*prerequisite_id).is_none()
+                {
+                    debug_trace!("Quest {} has invalid prerequisite {}", quest_id, prerequisite_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            // SMT approvals need quests without prerequisites or NFT pools
+            let nft_pool = quest.nft_pool();
+            if input_campaign_data.tracks_approvals_in_smt()
+                && (!prerequisite_ids.is_empty() || !nft_pool.is_empty())
+            {
+                debug_trace!("Quest {} needs accepted user lists, not SMT approvals", quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            // NFT rewards must be spore/DOB cells of a type accepted by the protocol
+            if !nft_pool.is_empty() {
+                let accepted_dob_scripts = find_protocol_data_in_deps(context)
+                    .map(|protocol_data| protocol_data.accepted_dob_type_scripts())
+                    .ok_or_else(|| {
+                        debug_trace!("NFT rewards need the protocol cell in cell deps");
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                for nft_script in nft_pool.iter() {
+                    let accepted = accepted_dob_scripts.iter().any(|dob_script| {
+                        dob_script.code_hash().as_slice() == nft_script.code_hash().as_slice()
+                            && dob_script.hash_type().as_slice() == nft_script.hash_type().as_slice()
+                    });
+                    if !accepted {
+                        debug_trace!("NFT reward {:?} is not an accepted DOB type", nft_script);
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                }
+            }
+
+            let expected_campaign_data = input_campaign_data
+                .clone()
+                .as_builder()
+                .quests(input_campaign_data.quests().as_builder().push(quest).build())
+                .next_quest_id(Uint32::from(next_quest_id.to_le_bytes()))
+                .build();
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected quest addition");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod edit_quest_metadata {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.edit_quest_metadata".to_vec())
+            .with_arguments(3) // campaign_data, quest_id and metadata
+            // Protocol cells not allowed
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: exactly 1 in, 1 out (with the quest metadata replaced)
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells not allowed
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Points UDT cells: campaign patches never mint points
+            .with_custom_cell(
+                "points",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained when editing a quest".to_string(),
+                vec!["campaign".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "quest_metadata_validation".to_string(),
+                "Validate that only the quest metadata changes".to_string(),
+                vec!["campaign".to_string()],
+                business_logic::quest_metadata_validation,
+            )
+            .with_business_rule(
+                "editor_authorization".to_string(),
+                "The endorser or a manager with the edit metadata role must authorize the patch".to_string(),
+                vec!["campaign".to_string()],
+                common::editor_authorization,
+            )
+    }
+
+    pub mod business_logic {
+        use ckb_deterministic::assertions::expect;
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use crate::recipes::helper::inline_argument;
+        use ckboost_shared::generated::ckboost::{CampaignData, QuestMetadata};
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::CampaignDataExt;
+        use molecule::prelude::*;
+
+        // **Quest metadata validation**: The quest exists and the campaign output only replaces
+        // its metadata
+        pub fn quest_metadata_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            expect(input_campaign_cells.len()).to_equal(1)?;
+            expect(output_campaign_cells.len()).to_equal(1)?;
+
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            let quest_id_bytes = inline_argument(context, 1)?;
+            let quest_id = u32::from_le_bytes(quest_id_bytes.as_slice().try_into().map_err(|_| {
+                debug_trace!("ERROR: quest_id should be exactly 4 bytes (u32), got {}", quest_id_bytes.len());
+                DeterministicError::InvalidArgumentCount
+            })?);
+            let metadata = QuestMetadata::from_slice(&inline_argument(context, 2)?)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            let quest = input_campaign_data.find_quest(quest_id).ok_or_else(|| {
+                debug_trace!("Quest {} not found in campaign", quest_id);
+                DeterministicError::BusinessRuleViolation
+            })?;
+            let expected_campaign_data =
+                input_campaign_data.with_quest(quest.as_builder().metadata(metadata).build());
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected quest metadata edit");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod update_campaign_metadata {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.update_campaign_metadata".to_vec())
+            .with_arguments(2) // campaign_data and metadata
+            // Protocol cells not allowed
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: exactly 1 in, 1 out (with the metadata replaced)
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells not allowed
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Points UDT cells: campaign patches never mint points
+            .with_custom_cell(
+                "points",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained when updating campaign metadata".to_string(),
+                vec!["campaign".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "campaign_metadata_validation".to_string(),
+                "Validate the new metadata and that only the metadata changes".to_string(),
+                vec!["campaign".to_string()],
+                business_logic::campaign_metadata_validation,
+            )
+            .with_business_rule(
+                "editor_authorization".to_string(),
+                "The endorser or a manager with the edit metadata role must authorize the patch".to_string(),
+                vec!["campaign".to_string()],
+                common::editor_authorization,
+            )
+    }
+
+    pub mod business_logic {
+        use ckb_deterministic::assertions::expect;
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use crate::recipes::helper::inline_argument;
+        use ckboost_shared::generated::ckboost::{CampaignData, CampaignMetadata};
+        use ckboost_shared::transaction_context::TransactionContext;
+        use molecule::prelude::*;
+
+        // **Campaign metadata validation**: Title and descriptions stay non-empty and the
+        // campaign output only replaces the metadata
+        pub fn campaign_metadata_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            expect(input_campaign_cells.len()).to_equal(1)?;
+            expect(output_campaign_cells.len()).to_equal(1)?;
+
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let metadata = CampaignMetadata::from_slice(&inline_argument(context, 1)?)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            if metadata.title().is_empty()
+                || metadata.short_description().is_empty()
+                || metadata.long_description().is_empty()
+            {
+                debug_trace!("Title and descriptions must not be empty");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let expected_campaign_data = input_campaign_data.as_builder().metadata(metadata).build();
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected metadata update");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod set_status {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.set_status".to_vec())
+            .with_arguments(2) // campaign_data and status
+            // Protocol cells not allowed
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: exactly 1 in, 1 out (with the status changed)
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells not allowed
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Points UDT cells: campaign patches never mint points
+            .with_custom_cell(
+                "points",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained when changing the status".to_string(),
+                vec!["campaign".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "status_transition_validation".to_string(),
+                "Validate the status moves forward and nothing else changes".to_string(),
+                vec!["campaign".to_string()],
+                business_logic::status_transition_validation,
+            )
+            .with_business_rule(
+                "editor_authorization".to_string(),
+                "The endorser or a manager with the edit metadata role must authorize the patch".to_string(),
+                vec!["campaign".to_string()],
+                common::editor_authorization,
+            )
+    }
+
+    pub mod business_logic {
+        use ckb_deterministic::assertions::expect;
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use crate::recipes::helper::inline_argument;
+        use ckboost_shared::generated::ckboost::CampaignData;
+        use ckboost_shared::transaction_context::TransactionContext;
+        use molecule::prelude::*;
+
+        // Highest campaign status, 5 (completed)
+        const STATUS_COMPLETED: u8 = 5;
+
+        // **Status transition validation**: Status transition rules:
+        // 0 (created) -> 1 (funding) -> 2 (reviewing) -> 3 (approved) -> 4 (active) -> 5 (completed)
+        // Backwards transitions are not allowed and the campaign output only changes the status
+        pub fn status_transition_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            expect(input_campaign_cells.len()).to_equal(1)?;
+            expect(output_campaign_cells.len()).to_equal(1)?;
+
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            let status_bytes = inline_argument(context, 1)?;
+            if status_bytes.len() != 1 {
+                debug_trace!("ERROR: status should be exactly 1 byte, got {}", status_bytes.len());
+                return Err(DeterministicError::InvalidArgumentCount);
+            }
+            let status = status_bytes[0];
+            let input_status = input_campaign_data.status().as_slice()[0];
+            if status < input_status || status > STATUS_COMPLETED {
+                debug_trace!("Campaign status cannot move from {} to {}", input_status, status);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let expected_campaign_data = input_campaign_data.as_builder().status(status).build();
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected status change");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod update_quest {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostQuest.update_quest".to_vec())
+            .with_arguments(1) // quest_data
+            // Protocol cells not allowed
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: exactly 1 in, 1 out (to allocate the quest ID)
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // Quest cells: exactly 1 created with the allocated ID
+            .with_custom_cell(
+                "quest",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells not allowed
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_custom_cell(
+                "points",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained when creating a quest cell".to_string(),
+                vec!["campaign".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "quest_allocation_validation".to_string(),
+                "The new quest cell takes the campaign's next quest ID, which moves past it".to_string(),
+                vec!["campaign".to_string(), "quest".to_string()],
+                business_logic::quest_allocation_validation,
+            )
+            .with_business_rule(
+                "editor_authorization".to_string(),
+                "The endorser or a manager with the edit metadata role must authorize the patch".to_string(),
+                vec!["campaign".to_string()],
+                common::editor_authorization,
+            )
+    }
+
+    pub mod business_logic {
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use ckb_std::high_level::load_script_hash;
+        use ckboost_shared::campaign_data::connected_type_id_from_args;
+        use ckboost_shared::generated::ckboost::{CampaignData, QuestData, Uint32};
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::{CampaignDataExt, QuestDataExt};
+        use molecule::prelude::*;
+
+        // **Quest allocation validation**: A campaign keeping its quests in quest cells is only
+        // spent by the quest type to create a quest cell linked to it under next_quest_id; the
+        // campaign output only moves next_quest_id past it
+        pub fn quest_allocation_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_campaign_cells = context
+                .input_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_campaign_cells = context
+                .output_cells
+                .get_custom("campaign")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let quest_cells = context
+                .output_cells
+                .get_custom("quest")
+                .ok_or(DeterministicError::CellCountViolation)?;
+
+            let input_campaign_data = CampaignData::from_slice(&input_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_campaign_data = CampaignData::from_slice(&output_campaign_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            if !input_campaign_data.uses_quest_cells() {
+                debug_trace!("Campaigns with inline quests add quests through add_quest");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            // The quest cell must belong to this campaign
+            let campaign_type_hash = load_script_hash().map_err(|_| DeterministicError::Encoding)?;
+            let linked = quest_cells[0]
+                .type_script
+                .as_ref()
+                .and_then(|script| connected_type_id_from_args(&script.args().raw_data()))
+                .map(|connected_type_id| connected_type_id.connected_key().as_slice() == campaign_type_hash)
+                .unwrap_or(false);
+            if !linked {
+                debug_trace!("Quest cell is not linked to this campaign");
+                return Err(DeterministicError::CellRelationshipRuleViolation);
+            }
+
+            let quest = QuestData::from_slice(&quest_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let quest_id = input_campaign_data.next_quest_id_u32();
+            if quest.quest_id_u32() != quest_id {
+                debug_trace!("Quest cell takes ID {} instead of {}", quest.quest_id_u32(), quest_id);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            let next_quest_id = quest_id.checked_add(1).ok_or_else(|| {
+                debug_trace!("No quest IDs left to allocate");
+                DeterministicError::BusinessRuleViolation
+            })?;
+
+            let expected_campaign_data = input_campaign_data
+                .as_builder()
+                .next_quest_id(Uint32::from(next_quest_id.to_le_bytes()))
+                .build();
+            if output_campaign_data.as_slice() != expected_campaign_data.as_slice() {
+                debug_trace!("Output campaign data does not match the expected quest allocation");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
 
             Ok(())
         }
@@ -3,9 +3,9 @@ use ckb_deterministic::{
     cell_classifier::RuleBasedClassifier, transaction_context::TransactionContext,
 };
 use ckboost_shared::{
-    types::{Byte32, CampaignData, UDTAsset}, Error
+    types::{Byte32, CampaignData, CampaignMetadata, QuestData, QuestMetadata, UDTAsset}, Error
 };
-use ckb_std::ckb_types::packed::{Byte32Vec, Transaction};
+use ckb_std::ckb_types::packed::{Byte32 as PackedByte32, Byte32Vec, Transaction};
 
 /// CKBoost Campaign SSRI trait for campaign management operations
 pub trait CKBoostCampaign {
@@ -32,12 +32,18 @@ pub trait CKBoostCampaign {
     
     /// Approve quest completion and distribute rewards
     /// 
+    /// Every approved user's cell must be present in `tx` as a cell dep or input,
+    /// carrying a submission record for this campaign and quest.
+    /// 
     /// # Arguments
     /// 
     /// * `tx` - Optional existing transaction to build upon
     /// * `campaign_data` - The current campaign data
     /// * `quest_id` - The ID of the quest being approved
     /// * `user_type_ids` - List of user type IDs to approve
+    /// * `approved_at` - Timestamp (ms) of the latest header dep in `tx`; only recorded, on
+    ///   the new approvals and as the campaign clock, when the campaign has an approval
+    ///   dispute window
     /// 
     /// # Returns
     /// 
@@ -47,6 +53,7 @@ pub trait CKBoostCampaign {
         campaign_data: CampaignData,
         quest_id: u32,
         user_type_ids: Byte32Vec,
+        approved_at: u64,
     ) -> Result<Transaction, Error>;
     
     /// Verify quest completion approval transaction in Type Script
@@ -54,4 +61,260 @@ pub trait CKBoostCampaign {
     fn verify_approve_completion(
         context: &TransactionContext<RuleBasedClassifier>,
     ) -> Result<(), Error>;
-}
\ No newline at end of file
+
+    /// Complete a quest for a single participant
+    /// 
+    /// Updates the campaign quest state and the participant's user cell in one transaction.
+    /// The participant's user cell must already be an input of `tx`, and a manager with the
+    /// approve completions role must sign. Campaigns tracking approvals in an SMT or holding
+    /// them behind a dispute window complete quests through `approve_completion` instead.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Existing transaction containing the participant's user cell as input
+    /// * `campaign_data` - The current campaign data
+    /// * `quest_id` - The ID of the quest being completed
+    /// * `user_type_id` - The type ID of the participant's user cell
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the campaign cell and user cell updated
+    fn complete_quest(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        user_type_id: PackedByte32,
+    ) -> Result<Transaction, Error>;
+    
+    /// Verify quest completion transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_complete_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+
+    /// Reject submissions for a quest with an optional reviewer reason code
+    /// 
+    /// Rejected users may resubmit up to the quest's `max_resubmissions`.
+    /// Every rejected user's cell must be present in `tx` as a cell dep or input,
+    /// carrying a submission record for this campaign and quest.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `campaign_data` - The current campaign data
+    /// * `quest_id` - The ID of the quest being reviewed
+    /// * `user_type_ids` - List of user type IDs to reject
+    /// * `reason_code` - Reviewer feedback code, 0 if unspecified
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the rejections recorded
+    fn reject_submission(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        user_type_ids: Byte32Vec,
+        reason_code: u32,
+    ) -> Result<Transaction, Error>;
+
+    /// Verify submission rejection transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_reject_submission(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+
+    /// Revoke approvals that are still inside the campaign's dispute window
+    /// 
+    /// `tx` must carry a header dep proving the window has not yet passed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `campaign_data` - The current campaign data
+    /// * `quest_id` - The ID of the quest the approvals belong to
+    /// * `user_type_ids` - List of user type IDs whose approval is revoked
+    /// * `recorded_at` - Timestamp (ms) of the latest header dep in `tx`, recorded as the
+    ///   campaign clock; it cannot be older than the current clock
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the approvals removed
+    fn revoke_approval(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        user_type_ids: Byte32Vec,
+        recorded_at: u64,
+    ) -> Result<Transaction, Error>;
+
+    /// Verify approval revocation transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_revoke_approval(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+
+    /// Finalize approvals whose dispute window has passed, making their rewards claimable
+    /// 
+    /// `tx` must carry a header dep proving the window has passed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `campaign_data` - The current campaign data
+    /// * `quest_id` - The ID of the quest the approvals belong to
+    /// * `user_type_ids` - List of user type IDs whose approval is finalized
+    /// * `recorded_at` - Timestamp (ms) of the latest header dep in `tx`, recorded as the
+    ///   campaign clock; it cannot be older than the current clock
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the pending approvals cleared
+    fn finalize_approvals(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        user_type_ids: Byte32Vec,
+        recorded_at: u64,
+    ) -> Result<Transaction, Error>;
+
+    /// Verify approval finalization transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_finalize_approvals(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+
+    /// Approve individual sub-tasks of a multi-step quest
+    /// 
+    /// Approved users' cells must be present as cell deps or inputs, with the sub-tasks
+    /// covered by their latest submission. The quest only becomes approvable once every
+    /// sub-task is approved.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `campaign_data` - The current campaign data
+    /// * `quest_id` - The ID of the quest being reviewed
+    /// * `user_type_ids` - List of user type IDs whose sub-tasks are approved
+    /// * `sub_task_ids` - IDs of the sub-tasks to approve
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the sub-task approvals recorded
+    fn approve_sub_tasks(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        user_type_ids: Byte32Vec,
+        sub_task_ids: Vec<u8>,
+    ) -> Result<Transaction, Error>;
+
+    /// Verify sub-task approval transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_approve_sub_tasks(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+
+    /// Add a quest to a campaign without touching anything else
+    /// 
+    /// The quest is given the campaign's next quest ID, whatever ID it carries, and must
+    /// start without any review state.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `campaign_data` - The current campaign data
+    /// * `quest` - The quest to append, its quest ID is allocated by the campaign
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the quest added
+    fn add_quest(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest: QuestData,
+    ) -> Result<Transaction, Error>;
+
+    /// Verify quest addition transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_add_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+
+    /// Replace the metadata of a single quest
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `campaign_data` - The current campaign data
+    /// * `quest_id` - The ID of the quest being edited
+    /// * `metadata` - The new quest metadata
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the quest metadata replaced
+    fn edit_quest_metadata(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        quest_id: u32,
+        metadata: QuestMetadata,
+    ) -> Result<Transaction, Error>;
+
+    /// Verify quest metadata edit transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_edit_quest_metadata(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+
+    /// Replace the campaign metadata
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `campaign_data` - The current campaign data
+    /// * `metadata` - The new campaign metadata
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the campaign metadata replaced
+    fn update_campaign_metadata(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        metadata: CampaignMetadata,
+    ) -> Result<Transaction, Error>;
+
+    /// Verify campaign metadata update transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_update_campaign_metadata(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+
+    /// Move the campaign to a later status
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `campaign_data` - The current campaign data
+    /// * `status` - The new status, never lower than the current one
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the campaign status changed
+    fn set_status(
+        tx: Option<Transaction>,
+        campaign_data: CampaignData,
+        status: u8,
+    ) -> Result<Transaction, Error>;
+
+    /// Verify campaign status change transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_set_status(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+
+    /// Verify quest cell creation transaction in Type Script
+    /// Campaigns keeping quests in quest cells are spent alongside a new quest cell, which takes
+    /// the campaign's next quest ID
+    fn verify_update_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+}
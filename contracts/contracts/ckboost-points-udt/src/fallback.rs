@@ -1,5 +1,5 @@
 use ckboost_shared::Error;
-use crate::utils::{is_minting_operation, validate_protocol_owner_mode, validate_udt_rules};
+use crate::utils::{is_minting_operation, validate_points_mint, validate_udt_rules};
 use ckb_std::{
     ckb_types::{bytes::Bytes, prelude::*},
     debug,
@@ -24,8 +24,8 @@ pub fn fallback() -> Result<(), Error> {
     
     // Check if this is a minting operation
     if is_minting_operation()? {
-        debug!("Minting operation detected, validating protocol owner mode");
-        validate_protocol_owner_mode(protocol_type_hash)?;
+        debug!("Minting operation detected, validating points owed");
+        validate_points_mint(protocol_type_hash)?;
     }
     
     // Standard UDT validation (balance checks)
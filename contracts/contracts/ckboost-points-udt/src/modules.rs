@@ -104,7 +104,7 @@ impl UDT for PointsUDT {
     }
 
     /// Mint new Points tokens
-    /// This requires protocol owner mode validation (campaign cell + admin signature)
+    /// Amounts must equal the quest points owed to newly approved users, each under their user cell lock
     fn mint(
         tx: Option<Transaction>,
         to_lock_vec: Vec<Script>,
@@ -200,8 +200,8 @@ impl UDT for PointsUDT {
         
         let protocol_type_hash = args.as_ref();
         
-        // Validate protocol owner mode for minting
-        crate::utils::validate_protocol_owner_mode(protocol_type_hash)?;
+        // Validate the mint against the quest points owed
+        crate::utils::validate_points_mint(protocol_type_hash)?;
         
         Ok(())
     }
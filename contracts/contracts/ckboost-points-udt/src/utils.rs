@@ -1,12 +1,20 @@
+use alloc::vec::Vec;
 use ckb_std::{
     ckb_constants::Source,
-    ckb_types::{bytes::Bytes, prelude::*},
+    ckb_types::{packed::Script, prelude::*},
     debug,
     high_level::{
-        load_cell_data, load_cell_type_hash, load_script, load_input, QueryIter,
+        load_cell_data, load_cell_lock_hash, load_cell_type, load_cell_type_hash, load_script,
+        load_script_hash, QueryIter,
     },
 };
-use ckboost_shared::{Error, types::ConnectedTypeID};
+use ckboost_shared::{
+    approvals::{load_approval_proof, points_owed_by_proof},
+    campaign_data::{connected_type_id_from_args, unpack_u128},
+    rewards::{is_payout_transaction, merge_points, points_owed, rewarded_in_quest},
+    types::{CampaignData, ProtocolData, QuestData},
+    CampaignDataExt, Error, ProtocolDataExt, QuestDataExt,
+};
 use core::result::Result;
 
 /// Check if this is a minting operation by comparing input and output amounts
@@ -65,152 +73,219 @@ pub fn is_minting_operation() -> Result<bool, Error> {
     Ok(output_amount > input_amount)
 }
 
-/// Validate protocol owner mode for minting operations
+/// Validate a mint against the quest points owed in the transaction
+///
 /// Requires:
 /// 1. Protocol cell in CellDeps
-/// 2. Campaign cell in inputs with valid ConnectedTypeID
-/// 3. User cells in inputs with valid ConnectedTypeID
-/// 4. Campaign admin signature (validated by lock script)
-pub fn validate_protocol_owner_mode(protocol_type_hash: &[u8]) -> Result<(), Error> {
-    debug!("Validating protocol owner mode");
+/// 2. Campaign cells of the protocol in inputs and outputs, or quest cells linked
+///    to a campaign of the protocol, whose transitions newly approve users
+/// 3. Minted amount equal to the quest points owed to those users
+/// 4. Each user's points delivered under the lock of their user cell
+///
+/// Campaign and quest type scripts validate the approvals themselves, so the
+/// points owed follow from their input/output diff, priced at the input quests'
+/// points. Only the diff of a payout recipe counts; under any other recipe
+/// nothing is owed.
+pub fn validate_points_mint(protocol_type_hash: &[u8]) -> Result<(), Error> {
+    debug!("Validating points mint");
     
-    // 1. Verify protocol cell exists in CellDeps
-    if !find_protocol_cell_in_deps(protocol_type_hash)? {
-        debug!("Protocol cell not found in CellDeps");
-        return Err(Error::InvalidProtocolReference);
-    }
+    // 1. Load protocol data from CellDeps
+    let protocol_data = load_protocol_data(protocol_type_hash)?;
     
-    // 2. Find campaign cell in inputs and validate its ConnectedTypeID
-    let campaign_found = find_and_validate_campaign_cell(protocol_type_hash)?;
-    if !campaign_found {
-        debug!("No valid campaign cell found in inputs");
-        return Err(Error::InvalidCampaignCell);
-    }
+    // 2. Collect the points owed by campaign and quest transitions
+    let owed = points_owed_in_transaction(protocol_type_hash, &protocol_data)?;
     
-    // 3. Find user cells in inputs and validate their ConnectedTypeIDs
-    let users_found = find_and_validate_user_cells(protocol_type_hash)?;
-    if !users_found {
-        debug!("No valid user cells found in inputs");
-        return Err(Error::InvalidUserCell);
+    // 3. The minted amount must match the points owed exactly
+    let total_owed = owed
+        .iter()
+        .fold(0u128, |total, (_, points)| total.saturating_add(*points));
+    let minted = points_amount(Source::Output, None)?.saturating_sub(points_amount(Source::Input, None)?);
+    if minted != total_owed {
+        debug!("Minted {} points, but {} are owed", minted, total_owed);
+        return Err(Error::InvalidUDTAmount);
     }
     
-    // 4. Campaign admin signature is validated by the campaign cell's lock script
-    // The fact that the campaign cell is spent means the admin has signed
+    // 4. Every user receives their points under their own lock
+    let mut owed_by_lock: Vec<([u8; 32], u128)> = Vec::new();
+    for (user_type_id, points) in owed.iter() {
+        let lock_hash = find_user_lock_hash(protocol_type_hash, &protocol_data, user_type_id)?;
+        match owed_by_lock.iter_mut().find(|(lock, _)| *lock == lock_hash) {
+            Some((_, total)) => *total = total.saturating_add(*points),
+            None => owed_by_lock.push((lock_hash, *points)),
+        }
+    }
+    for (lock_hash, points) in owed_by_lock.iter() {
+        let received = points_amount(Source::Output, Some(lock_hash))?
+            .saturating_sub(points_amount(Source::Input, Some(lock_hash))?);
+        if received != *points {
+            debug!("User lock receives {} points, but {} are owed", received, points);
+            return Err(Error::InvalidUDTAmount);
+        }
+    }
     
-    debug!("Protocol owner mode validation successful");
+    debug!("Points mint validation successful");
     Ok(())
 }
 
-/// Find protocol cell in CellDeps
-fn find_protocol_cell_in_deps(protocol_type_hash: &[u8]) -> Result<bool, Error> {
-    let mut index = 0;
-    loop {
-        match load_cell_type_hash(index, Source::CellDep) {
-            Ok(Some(type_hash)) if type_hash.as_slice() == protocol_type_hash => {
-                debug!("Found protocol cell in CellDeps at index {}", index);
-                return Ok(true);
+/// Load protocol data from the protocol cell in CellDeps
+fn load_protocol_data(protocol_type_hash: &[u8]) -> Result<ProtocolData, Error> {
+    let index = QueryIter::new(load_cell_type_hash, Source::CellDep)
+        .position(|type_hash| type_hash.map(|hash| &hash[..] == protocol_type_hash).unwrap_or(false))
+        .ok_or_else(|| {
+            debug!("Protocol cell not found in CellDeps");
+            Error::InvalidProtocolReference
+        })?;
+    debug!("Found protocol cell in CellDeps at index {}", index);
+    let data = load_cell_data(index, Source::CellDep).map_err(|_| Error::ItemMissing)?;
+    ProtocolData::from_slice(&data).map_err(|_| Error::InvalidProtocolData)
+}
+
+/// Points owed to each user type ID by the campaign and quest transitions
+fn points_owed_in_transaction(
+    protocol_type_hash: &[u8],
+    protocol_data: &ProtocolData,
+) -> Result<Vec<(Vec<u8>, u128)>, Error> {
+    let mut owed: Vec<(Vec<u8>, u128)> = Vec::new();
+    if !is_payout_transaction() {
+        debug!("Transaction recipe makes no rewards payable");
+        return Ok(owed);
+    }
+    let campaign_code_hash = protocol_data.campaign_type_code_hash();
+    
+    // Campaigns updated in the transaction
+    for (index, type_script) in QueryIter::new(load_cell_type, Source::Input).enumerate() {
+        let type_script = match type_script {
+            Some(script) if is_connected_to(&script, &campaign_code_hash, protocol_type_hash) => script,
+            _ => continue,
+        };
+        let campaign_type_hash = type_script.calc_script_hash();
+        let output_index = find_by_type_hash(Source::Output, campaign_type_hash.as_slice())
+            .ok_or(Error::InvalidCampaignCell)?;
+        let input_data = load_cell_data(index, Source::Input)
+            .ok()
+            .and_then(|data| CampaignData::from_slice(&data).ok())
+            .ok_or(Error::InvalidCampaignCell)?;
+        let output_data = load_cell_data(output_index, Source::Output)
+            .ok()
+            .and_then(|data| CampaignData::from_slice(&data).ok())
+            .ok_or(Error::InvalidCampaignCell)?;
+        
+        merge_points(&mut owed, points_owed(&input_data, &output_data));
+        // Approvals of SMT campaigns only count once their proof verifies
+        if output_data.tracks_approvals_in_smt() {
+            if let Some(proof) = load_approval_proof(campaign_type_hash.as_slice())? {
+                merge_points(&mut owed, points_owed_by_proof(&input_data, &output_data, &proof));
             }
-            Err(ckb_std::error::SysError::IndexOutOfBound) => break,
-            _ => {}
         }
-        index += 1;
     }
     
-    Ok(false)
-}
-
-/// Find and validate campaign cell in inputs
-fn find_and_validate_campaign_cell(protocol_type_hash: &[u8]) -> Result<bool, Error> {
-    let mut index = 0;
-    loop {
-        match load_input(index, Source::Input) {
-            Ok(_input) => {
-                // Try to load the cell's type script
-                if let Ok(Some(_type_hash)) = load_cell_type_hash(index, Source::Input) {
-                    // Load the cell data to check if it looks like campaign data
-                    if let Ok(data) = load_cell_data(index, Source::Input) {
-                        // Campaign cells have substantial data (at least a few hundred bytes)
-                        if data.len() > 100 {
-                            // Check the ConnectedTypeID in the type script args
-                            if validate_connected_type_id(index, Source::Input, protocol_type_hash)? {
-                                debug!("Found valid campaign cell at index {}", index);
-                                return Ok(true);
-                            }
-                        }
-                    }
-                }
+    // Quest cells linked to a campaign of the protocol, in inputs or CellDeps
+    let mut campaign_type_hashes: Vec<[u8; 32]> = Vec::new();
+    for source in [Source::Input, Source::CellDep] {
+        for type_script in QueryIter::new(load_cell_type, source).flatten() {
+            if is_connected_to(&type_script, &campaign_code_hash, protocol_type_hash) {
+                let mut campaign_type_hash = [0u8; 32];
+                campaign_type_hash.copy_from_slice(type_script.calc_script_hash().as_slice());
+                campaign_type_hashes.push(campaign_type_hash);
             }
-            Err(ckb_std::error::SysError::IndexOutOfBound) => break,
-            Err(_) => {}
         }
-        index += 1;
+    }
+    let quest_code_hash = protocol_data.quest_type_code_hash();
+    for (index, type_script) in QueryIter::new(load_cell_type, Source::Output).enumerate() {
+        let type_script = match type_script {
+            Some(script)
+                if campaign_type_hashes
+                    .iter()
+                    .any(|campaign_type_hash| is_connected_to(&script, &quest_code_hash, campaign_type_hash)) =>
+            {
+                script
+            }
+            _ => continue,
+        };
+        let output_quest = load_cell_data(index, Source::Output)
+            .ok()
+            .and_then(|data| QuestData::from_slice(&data).ok())
+            .ok_or(Error::InvalidQuestData)?;
+        let input_quest = match find_by_type_hash(Source::Input, type_script.calc_script_hash().as_slice()) {
+            Some(input_index) => Some(
+                load_cell_data(input_index, Source::Input)
+                    .ok()
+                    .and_then(|data| QuestData::from_slice(&data).ok())
+                    .ok_or(Error::InvalidQuestData)?,
+            ),
+            None => None,
+        };
+        let points = input_quest.as_ref().map(|quest| quest.points_u128()).unwrap_or(0);
+        merge_points(
+            &mut owed,
+            rewarded_in_quest(input_quest.as_ref(), &output_quest)
+                .into_iter()
+                .map(|(user_type_id, _)| (user_type_id, points))
+                .collect(),
+        );
     }
     
-    Ok(false)
+    Ok(owed)
 }
 
-/// Find and validate user cells in inputs
-fn find_and_validate_user_cells(protocol_type_hash: &[u8]) -> Result<bool, Error> {
-    let mut found_any = false;
-    let mut index = 0;
-    
-    loop {
-        match load_input(index, Source::Input) {
-            Ok(_) => {
-                // Check if this is a user cell by validating ConnectedTypeID
-                if let Ok(Some(_type_hash)) = load_cell_type_hash(index, Source::Input) {
-                    // Load cell data to distinguish user cells
-                    if let Ok(data) = load_cell_data(index, Source::Input) {
-                        // User cells have moderate data size (UserData structure)
-                        if data.len() > 50 && data.len() < 10000 {
-                            if validate_connected_type_id(index, Source::Input, protocol_type_hash)? {
-                                debug!("Found valid user cell at index {}", index);
-                                found_any = true;
-                            }
-                        }
-                    }
-                }
-            }
-            Err(ckb_std::error::SysError::IndexOutOfBound) => break,
-            Err(_) => {}
+/// Lock hash of the user cell for `user_type_id`, from inputs or CellDeps
+fn find_user_lock_hash(
+    protocol_type_hash: &[u8],
+    protocol_data: &ProtocolData,
+    user_type_id: &[u8],
+) -> Result<[u8; 32], Error> {
+    let user_code_hash = protocol_data.user_type_code_hash();
+    for source in [Source::Input, Source::CellDep] {
+        let index = QueryIter::new(load_cell_type, source).position(|type_script| {
+            type_script
+                .filter(|script| is_connected_to(script, &user_code_hash, protocol_type_hash))
+                .and_then(|script| connected_type_id_from_args(&script.args().raw_data()))
+                .map(|connected_type_id| connected_type_id.type_id().as_slice() == user_type_id)
+                .unwrap_or(false)
+        });
+        if let Some(index) = index {
+            return load_cell_lock_hash(index, source).map_err(|_| Error::ItemMissing);
         }
-        index += 1;
     }
-    
-    Ok(found_any)
+    debug!("User cell not found for a user owed points");
+    Err(Error::InvalidUserCell)
 }
 
-/// Validate ConnectedTypeID in a cell's type script args
-fn validate_connected_type_id(index: usize, source: Source, protocol_type_hash: &[u8]) -> Result<bool, Error> {
-    // Load the cell's type script
-    let type_script = match ckb_std::high_level::load_cell_type(index, source).map_err(|_| Error::ItemMissing)? {
-        Some(script) => script,
-        None => return Ok(false),
-    };
-    
-    let args: Bytes = type_script.args().unpack();
-    
-    // ConnectedTypeID is 76 bytes (32 bytes type_id + 32 bytes connected_key + padding)
-    if args.len() != 76 {
-        return Ok(false);
-    }
-    
-    // Parse ConnectedTypeID
-    match ConnectedTypeID::from_slice(&args) {
-        Ok(connected_type_id) => {
-            // Check if connected_key matches protocol type hash
-            let connected_key = connected_type_id.connected_key();
-            let connected_key_slice = connected_key.raw_data();
-            if connected_key_slice.as_ref() == protocol_type_hash {
-                return Ok(true);
+/// Check that `script` runs `code_hash` with a ConnectedTypeID connected to `connected_key`
+fn is_connected_to(script: &Script, code_hash: &[u8; 32], connected_key: &[u8]) -> bool {
+    script.code_hash().as_slice() == code_hash
+        && connected_type_id_from_args(&script.args().raw_data())
+            .map(|connected_type_id| connected_type_id.connected_key().as_slice() == connected_key)
+            .unwrap_or(false)
+}
+
+/// Index of the first cell in `source` with the given type hash
+fn find_by_type_hash(source: Source, type_hash: &[u8]) -> Option<usize> {
+    QueryIter::new(load_cell_type_hash, source)
+        .position(|hash| hash.map(|hash| &hash[..] == type_hash).unwrap_or(false))
+}
+
+/// Total of this points UDT in `source`, optionally only under `lock_hash`
+fn points_amount(source: Source, lock_hash: Option<&[u8; 32]>) -> Result<u128, Error> {
+    let script_hash = load_script_hash().map_err(|_| Error::ItemMissing)?;
+    let mut amount: u128 = 0;
+    for (index, type_hash) in QueryIter::new(load_cell_type_hash, source).enumerate() {
+        if type_hash != Some(script_hash) {
+            continue;
+        }
+        if let Some(lock_hash) = lock_hash {
+            if load_cell_lock_hash(index, source).map_err(|_| Error::ItemMissing)? != *lock_hash {
+                continue;
             }
         }
-        Err(_) => {
-            debug!("Failed to parse ConnectedTypeID");
+        let data = load_cell_data(index, source).map_err(|_| Error::ItemMissing)?;
+        if data.len() < 16 {
+            debug!("Points UDT cell {} has {} bytes of data, expected at least 16", index, data.len());
+            return Err(Error::Encoding);
         }
+        amount = amount.saturating_add(unpack_u128(&data));
     }
-    
-    Ok(false)
+    Ok(amount)
 }
 
 /// Validate standard UDT rules (balance checks)
@@ -262,7 +337,7 @@ pub fn validate_udt_rules() -> Result<(), Error> {
         index += 1;
     }
     
-    // For minting, the check is already done in validate_protocol_owner_mode
+    // For minting, the check is already done in validate_points_mint
     // For transfers, input must be >= output
     if !is_minting_operation()? && input_amount < output_amount {
         debug!("UDT rule violation: input {} < output {}", input_amount, output_amount);
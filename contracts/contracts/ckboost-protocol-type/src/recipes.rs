@@ -101,6 +101,12 @@ pub mod update_protocol {
                 vec!["protocol".to_string()],
                 business_logic::tipping_proposal_immutability,
             )
+            .with_business_rule(
+                "attestor_registry_validation".to_string(),
+                "Registered attestors must hold a single verification bit and a valid public key".to_string(),
+                vec!["protocol".to_string()],
+                business_logic::attestor_registry_validation,
+            )
     }
 
     pub mod cell_relationship {}
@@ -162,6 +168,34 @@ pub mod update_protocol {
 
             Ok(())
         }
+
+        // **Attestor registry validation**: Every attestor vouches for exactly one verification
+        // bit with a 33-byte compressed secp256k1 public key
+        pub fn attestor_registry_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            use ckb_deterministic::debug_trace;
+            use ckboost_shared::attestation::is_attestable_bit;
+            use ckboost_shared::generated::ckboost::ProtocolData;
+            use molecule::prelude::*;
+
+            let output_protocol_cells = context
+                .output_cells
+                .get_custom("protocol")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_protocol_data = ProtocolData::from_slice(&output_protocol_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            for attestor in output_protocol_data.attestors().into_iter() {
+                let verification_bit = attestor.verification_bit().as_slice()[0];
+                if !is_attestable_bit(verification_bit) || attestor.pubkey().raw_data().len() != 33 {
+                    debug_trace!("Invalid attestor for verification bit {:#b}", verification_bit);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
     }
 }
 
@@ -532,6 +566,12 @@ pub mod update_tipping_proposal {
                 return Err(DeterministicError::BusinessRuleViolation);
             }
 
+            // attestors must be unchanged
+            if input_protocol_data.attestors().as_slice()
+                != output_protocol_data.attestors().as_slice() {
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
             // Note: last_updated is allowed to change as it tracks update timestamp
 
             Ok(())
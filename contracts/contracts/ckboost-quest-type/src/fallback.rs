@@ -0,0 +1,54 @@
+extern crate alloc;
+
+use ckb_deterministic::{
+    debug_trace, transaction_recipe::TransactionRecipeExt
+};
+use ckb_std::high_level::load_script;
+use ckboost_shared::{
+    campaign_data::connected_type_id_from_args,
+    error::Error,
+    transaction_context::create_campaign_transaction_context,
+};
+
+use crate::{modules::CKBoostQuestType, ssri::CKBoostQuest};
+
+/// Fallback validation implementation for CKBoost Quest Type
+pub fn fallback() -> Result<(), Error> {
+    debug_trace!("CKBoost Quest Type: Starting fallback validation");
+    
+    // Quest cells are connected to their campaign, which leads to the protocol cell
+    let connected_type_id = connected_type_id_from_args(&load_script()?.args().raw_data())
+        .ok_or(Error::InvalidConnectedTypeId)?;
+    let context = match create_campaign_transaction_context(connected_type_id.connected_key().as_slice()) {
+        Ok(ctx) => ctx,
+        Err(e) => {
+            debug_trace!("ERROR: Failed to create transaction context: {:?}", e);
+            return Err(e);
+        }
+    };
+    
+    let method_path = context.recipe.method_path_bytes();
+    debug_trace!("Method path: {:?}", core::str::from_utf8(&method_path).unwrap_or("<invalid UTF-8>"));
+    
+    let result = match method_path.as_slice() {
+        b"CKBoostQuest.update_quest" => {
+            debug_trace!("Executing verify_update_quest");
+            let verify_result = CKBoostQuestType::verify_update_quest(&context);
+            debug_trace!("verify_update_quest result: {:?}", verify_result);
+            verify_result
+        }
+        b"CKBoostQuest.approve_completion" => {
+            debug_trace!("Executing verify_approve_completion");
+            let verify_result = CKBoostQuestType::verify_approve_completion(&context);
+            debug_trace!("verify_approve_completion result: {:?}", verify_result);
+            verify_result
+        }
+        _ => {
+            debug_trace!("No matching validation rules found for method path: {:?}", core::str::from_utf8(&method_path).unwrap_or("<invalid UTF-8>"));
+            Err(Error::SSRIMethodsNotImplemented)
+        }
+    };
+    
+    debug_trace!("Fallback validation result: {:?}", result);
+    result
+}
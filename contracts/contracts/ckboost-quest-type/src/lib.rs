@@ -0,0 +1,14 @@
+#![cfg_attr(not(feature = "library"), no_std)]
+#![allow(special_module_name)]
+#![allow(unused_attributes)]
+#[cfg(feature = "library")]
+mod main;
+#[cfg(feature = "library")]
+pub use main::program_entry;
+
+extern crate alloc;
+
+pub mod ssri;
+pub mod modules;
+pub mod recipes;
+pub mod fallback;
\ No newline at end of file
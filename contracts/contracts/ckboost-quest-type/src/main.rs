@@ -0,0 +1,86 @@
+#![cfg_attr(not(any(feature = "library", test)), no_std)]
+#![cfg_attr(not(test), no_main)]
+
+#[cfg(any(feature = "library", test))]
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use ckb_deterministic::{debug_info, debug_trace};
+use ckb_std::high_level::load_script;
+use ckboost_shared::type_id::validate_type_id;
+use ckboost_shared::types::ConnectedTypeID;
+use ckboost_shared::Error;
+use ckb_ssri_std::utils::should_fallback;
+use ckb_ssri_std_proc_macro::ssri_methods;
+use ckb_std::syscalls::{pipe, write};
+use molecule::prelude::Entity;
+
+#[cfg(not(any(feature = "library", test)))]
+ckb_std::entry!(program_entry);
+#[cfg(not(any(feature = "library", test)))]
+ckb_std::default_alloc!(16384, 1258306, 64);
+
+#[cfg(not(feature = "library"))]
+pub mod ssri;
+#[cfg(not(feature = "library"))]
+pub mod modules;
+#[cfg(not(feature = "library"))]
+pub mod recipes;
+#[cfg(not(feature = "library"))]
+pub mod fallback;
+
+use crate::fallback::fallback;
+
+fn program_entry_wrap() -> Result<(), Error> {
+    let argv = ckb_std::env::argv();
+
+    if should_fallback()? {
+        debug_trace!("Should fallback!");
+        // # Validation Rules
+        // 
+        // 1. **Type ID mechanism**: Ensures the quest cell uses the correct type ID
+        // 2. **Campaign link**: The connected_key of the args is the campaign cell's type hash
+        let script = load_script()?;
+        let args = script.args();
+        let args_raw = args.raw_data();
+        debug_info!("Script args length: {} bytes", args_raw.len());
+        
+        if args_raw.len() != 76 {
+            debug_info!("ERROR: Invalid args length for ConnectedTypeID. Expected 76, got {}", args_raw.len());
+            return Err(Error::InvalidConnectedTypeId);
+        }
+        let connected_type_id = ConnectedTypeID::from_slice(&args_raw).map_err(|_| Error::InvalidConnectedTypeId)?;
+        
+        match validate_type_id(connected_type_id.type_id().into()) {
+            Ok(_) => fallback()?,
+            Err(err) => {
+                debug_trace!("Type ID validation failed with error: {:?}", err);
+                return Err(err);
+            }
+        }
+        return Ok(());
+    }
+
+    debug_trace!("Entering SSRI methods for CKBoost Quest");
+    
+    // Quest cell transactions are assembled off-chain from the campaign's quest data
+    let res: Cow<'static, [u8]> = ssri_methods!(
+        argv: &argv,
+        invalid_method: Error::SSRIMethodsNotFound,
+        invalid_args: Error::SSRIMethodsArgsInvalid,
+    )?;
+    
+    let pipe = pipe()?;
+    write(pipe.1, &res)?;
+    Ok(())
+}
+
+pub fn program_entry() -> i8 {
+    match program_entry_wrap() {
+        Ok(_) => 0,
+        Err(err) => {
+            debug_trace!("Contract execution failed with error: {:?}", err);
+            err as i8
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use ckb_deterministic::{
+    cell_classifier::RuleBasedClassifier, debug_trace, transaction_context::TransactionContext,
+};
+use ckboost_shared::Error;
+
+use crate::{recipes, ssri::CKBoostQuest};
+
+pub struct CKBoostQuestType;
+
+impl CKBoostQuest for CKBoostQuestType {
+    fn verify_update_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_update_quest");
+
+        let rules = recipes::update_quest::get_rules();
+        rules.validate(context)?;
+
+        debug_trace!("verify_update_quest completed successfully");
+        Ok(())
+    }
+
+    fn verify_approve_completion(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_approve_completion");
+
+        let rules = recipes::approve_completion::get_rules();
+        rules.validate(context)?;
+
+        debug_trace!("verify_approve_completion completed successfully");
+        Ok(())
+    }
+}
@@ -0,0 +1,576 @@
+extern crate alloc;
+
+pub mod helper {
+    use alloc::vec::Vec;
+    use ckb_deterministic::cell_classifier::{CellInfo, RuleBasedClassifier};
+    use ckb_deterministic::debug_trace;
+    use ckb_deterministic::errors::Error as DeterministicError;
+    use ckb_std::high_level::load_script;
+    use ckboost_shared::campaign_data::connected_type_id_from_args;
+    use ckboost_shared::transaction_context::TransactionContext;
+    use ckboost_shared::types::{Byte32, CampaignData, QuestData, UserData};
+    use molecule::prelude::*;
+
+    // Type hash of the campaign this quest cell belongs to, the connected_key of its args
+    pub fn linked_campaign_type_hash() -> Result<Byte32, DeterministicError> {
+        let script = load_script().map_err(|_| DeterministicError::Encoding)?;
+        connected_type_id_from_args(&script.args().raw_data())
+            .map(|connected_type_id| connected_type_id.connected_key())
+            .ok_or_else(|| {
+                debug_trace!("Quest type args are not a ConnectedTypeID");
+                DeterministicError::Encoding
+            })
+    }
+
+    // The cell of the linked campaign among `cells`
+    pub fn find_linked_campaign_cell<'a>(
+        cells: Option<&'a Vec<CellInfo>>,
+        campaign_type_hash: &Byte32,
+    ) -> Option<&'a CellInfo> {
+        cells?.iter().find(|cell| {
+            cell.type_hash
+                .as_ref()
+                .map(|hash| hash.as_slice() == campaign_type_hash.as_slice())
+                .unwrap_or(false)
+        })
+    }
+
+    // Campaign data and type ID of the linked campaign, provided as a cell dep or, when it
+    // allocates a new quest's ID, spent as an input
+    pub fn linked_campaign(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(CampaignData, Byte32), DeterministicError> {
+        let campaign_type_hash = linked_campaign_type_hash()?;
+        let campaign_cell = find_linked_campaign_cell(context.cell_deps.get_custom("campaign"), &campaign_type_hash)
+            .or_else(|| find_linked_campaign_cell(context.input_cells.get_custom("campaign"), &campaign_type_hash))
+            .ok_or_else(|| {
+                debug_trace!("Linked campaign {:?} not found in cell deps or inputs", campaign_type_hash);
+                DeterministicError::CellRelationshipRuleViolation
+            })?;
+
+        let campaign_data =
+            CampaignData::from_slice(&campaign_cell.data).map_err(|_| DeterministicError::Encoding)?;
+        let campaign_type_id = campaign_cell
+            .type_script
+            .as_ref()
+            .and_then(|script| connected_type_id_from_args(&script.args().raw_data()))
+            .map(|connected_type_id| connected_type_id.type_id())
+            .ok_or(DeterministicError::Encoding)?;
+        Ok((campaign_data, campaign_type_id))
+    }
+
+    // Quest data of the input quest cell, if the quest already exists
+    pub fn input_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<Option<QuestData>, DeterministicError> {
+        match context.input_cells.get_custom("quest") {
+            Some(cells) => QuestData::from_slice(&cells[0].data)
+                .map(Some)
+                .map_err(|_| DeterministicError::Encoding),
+            None => Ok(None),
+        }
+    }
+
+    // Quest data of the output quest cell
+    pub fn output_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<QuestData, DeterministicError> {
+        let cells = context
+            .output_cells
+            .get_custom("quest")
+            .ok_or(DeterministicError::CellCountViolation)?;
+        QuestData::from_slice(&cells[0].data).map_err(|_| DeterministicError::Encoding)
+    }
+
+    // Find the user cell of a participant, provided as a cell dep
+    pub fn find_participant_cell<'a>(
+        context: &'a TransactionContext<RuleBasedClassifier>,
+        user_type_id: &[u8],
+    ) -> Option<&'a CellInfo> {
+        context.cell_deps.get_custom("user")?.iter().find(|cell| {
+            cell.type_script
+                .as_ref()
+                .and_then(|script| connected_type_id_from_args(&script.args().raw_data()))
+                .map(|connected_type_id| connected_type_id.type_id().as_slice() == user_type_id)
+                .unwrap_or(false)
+        })
+    }
+
+    // User data of a participant, provided as a cell dep
+    pub fn find_participant_data(
+        context: &TransactionContext<RuleBasedClassifier>,
+        user_type_id: &[u8],
+    ) -> Option<UserData> {
+        find_participant_cell(context, user_type_id)
+            .and_then(|cell| UserData::from_slice(&cell.data).ok())
+    }
+}
+
+pub mod common {
+    use crate::recipes::helper::linked_campaign;
+    use ckb_deterministic::assertions::expect;
+    use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+    use ckb_deterministic::debug_trace;
+    use ckb_deterministic::errors::Error as DeterministicError;
+    use ckboost_shared::authority::inputs_hold_role;
+    use ckboost_shared::campaign_data::{ROLE_APPROVE_COMPLETIONS, ROLE_EDIT_METADATA};
+    use ckboost_shared::transaction_context::TransactionContext;
+    use ckboost_shared::CampaignDataExt;
+
+    // **Quest link**: The linked campaign is a cell dep and keeps its quests in quest cells
+    pub fn quest_link_validation(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let (campaign_data, _) = linked_campaign(context)?;
+        if !campaign_data.uses_quest_cells() {
+            debug_trace!("Linked campaign keeps its quests inline");
+            return Err(DeterministicError::CellRelationshipRuleViolation);
+        }
+        Ok(())
+    }
+
+    // **Editor authorization**: Creating or editing a quest requires an input locked by a
+    // campaign manager with the edit metadata role
+    pub fn editor_authorization(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let (campaign_data, _) = linked_campaign(context)?;
+        if !inputs_hold_role(&campaign_data, ROLE_EDIT_METADATA) {
+            debug_trace!("No input is locked by a manager allowed to edit quests");
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+        Ok(())
+    }
+
+    // **Reviewer authorization**: Approving completions requires an input locked by a campaign
+    // manager with the approve completions role
+    pub fn reviewer_authorization(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let (campaign_data, _) = linked_campaign(context)?;
+        if !inputs_hold_role(&campaign_data, ROLE_APPROVE_COMPLETIONS) {
+            debug_trace!("No input is locked by a manager allowed to review completions");
+            return Err(DeterministicError::BusinessRuleViolation);
+        }
+        Ok(())
+    }
+
+    // **Script immutability**: An updated quest cell keeps its lock and type script
+    pub fn script_immutability(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), DeterministicError> {
+        let input_cell = match context.input_cells.get_custom("quest") {
+            Some(cells) => &cells[0],
+            // Quest creation
+            None => return Ok(()),
+        };
+        let output_cell = &context
+            .output_cells
+            .get_custom("quest")
+            .ok_or(DeterministicError::CellCountViolation)?[0];
+
+        expect(&input_cell.lock_hash)
+            .to_equal(&output_cell.lock_hash)
+            .map_err(|_| {
+                debug_trace!("CellRelationshipRuleViolation: Quest cell lock hash changed");
+                DeterministicError::CellRelationshipRuleViolation
+            })?;
+        match (&input_cell.type_hash, &output_cell.type_hash) {
+            (Some(input_hash), Some(output_hash)) if input_hash.as_slice() == output_hash.as_slice() => {
+                Ok(())
+            }
+            _ => {
+                debug_trace!("CellRelationshipRuleViolation: Quest cell type hash changed");
+                Err(DeterministicError::CellRelationshipRuleViolation)
+            }
+        }
+    }
+}
+
+pub mod update_quest {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostQuest.update_quest".to_vec())
+            .with_arguments(1)
+            // Quest cells: at most 1 in, exactly 1 out
+            .with_custom_cell(
+                "quest",
+                CellCountConstraint::at_most(1),
+                CellCountConstraint::exactly(1),
+            )
+            // The campaign is spent to allocate a new quest's ID and otherwise only referenced
+            // as a cell dep
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::at_most(1),
+                CellCountConstraint::at_most(1),
+            )
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            .with_cell_relationship(
+                "quest_link_validation".to_string(),
+                "Quest cell must be linked to a campaign with quest cells in CellDeps".to_string(),
+                vec!["quest".to_string()],
+                common::quest_link_validation,
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during quest updates".to_string(),
+                vec!["quest".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "editor_authorization".to_string(),
+                "A manager with the edit metadata role must authorize quest edits".to_string(),
+                vec!["quest".to_string()],
+                common::editor_authorization,
+            )
+            .with_business_rule(
+                "quest_update_validation".to_string(),
+                "Quest edits cannot touch the review state".to_string(),
+                vec!["quest".to_string()],
+                business_logic::quest_update_validation,
+            )
+    }
+
+    pub mod business_logic {
+        use crate::recipes::helper::{
+            find_linked_campaign_cell, input_quest, linked_campaign_type_hash, output_quest,
+        };
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::types::{CampaignData, QuestData};
+        use ckboost_shared::{CampaignDataExt, QuestDataExt};
+        use molecule::prelude::*;
+
+        // Fields written by reviews rather than by quest edits
+        fn review_state(quest: &QuestData) -> [alloc::vec::Vec<u8>; 6] {
+            [
+                quest.accepted_submission_user_type_ids().as_slice().to_vec(),
+                quest.completion_count().as_slice().to_vec(),
+                quest.rejected_submissions().as_slice().to_vec(),
+                quest.pending_approvals().as_slice().to_vec(),
+                quest.sub_task_approvals().as_slice().to_vec(),
+                quest.nft_awards().as_slice().to_vec(),
+            ]
+        }
+
+        // **Quest update validation**: New quests start without reviews under the ID the campaign
+        // allocates in the same transaction, edits keep the quest ID and the review state and
+        // leave the campaign alone, sub-task IDs are unique, and features needing other quests or
+        // escrow are not used
+        pub fn quest_update_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let output_quest = output_quest(context)?;
+
+            match input_quest(context)? {
+                Some(input_quest) => {
+                    if context.input_cells.get_custom("campaign").is_some() {
+                        debug_trace!("Quest edits only reference the campaign as a cell dep");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                    if input_quest.quest_id().as_slice() != output_quest.quest_id().as_slice() {
+                        debug_trace!("Quest ID changed during quest update");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                    if review_state(&input_quest) != review_state(&output_quest) {
+                        debug_trace!("Review state changed during quest update");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                }
+                None => {
+                    if review_state(&output_quest) != review_state(&QuestData::default()) {
+                        debug_trace!("New quest must start without reviews");
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                    // The linked campaign is spent to hand out its next_quest_id to this quest
+                    let campaign_type_hash = linked_campaign_type_hash()?;
+                    let input_campaign_cell = find_linked_campaign_cell(
+                        context.input_cells.get_custom("campaign"),
+                        &campaign_type_hash,
+                    )
+                    .ok_or_else(|| {
+                        debug_trace!("New quests must spend the linked campaign to allocate their ID");
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                    let output_campaign_cell = find_linked_campaign_cell(
+                        context.output_cells.get_custom("campaign"),
+                        &campaign_type_hash,
+                    )
+                    .ok_or(DeterministicError::CellCountViolation)?;
+                    let input_campaign_data = CampaignData::from_slice(&input_campaign_cell.data)
+                        .map_err(|_| DeterministicError::Encoding)?;
+                    let output_campaign_data = CampaignData::from_slice(&output_campaign_cell.data)
+                        .map_err(|_| DeterministicError::Encoding)?;
+                    let quest_id = input_campaign_data.next_quest_id_u32();
+                    if output_quest.quest_id_u32() != quest_id {
+                        debug_trace!("Quest ID {} is not the campaign's next quest ID {}", output_quest.quest_id_u32(), quest_id);
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                    if quest_id.checked_add(1) != Some(output_campaign_data.next_quest_id_u32()) {
+                        debug_trace!("Campaign next_quest_id does not move past quest {}", quest_id);
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                }
+            }
+
+            if !output_quest.has_unique_sub_task_ids() {
+                debug_trace!("Quest has duplicate sub-task IDs");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            // Prerequisites would span other quest cells and NFT awards need an escrow pool
+            if !output_quest.prerequisite_quest_ids_u32().is_empty() {
+                debug_trace!("Quest cells do not support prerequisites");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if !output_quest.nft_pool().is_empty() {
+                debug_trace!("Quest cells do not support NFT rewards");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod approve_completion {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        let udt_identifiers = ckboost_shared::cell_collector::get_all_udt_identifiers().unwrap();
+        let mut rules =
+            TransactionValidationRules::new(b"CKBoostQuest.approve_completion".to_vec())
+                .with_arguments(2)
+                // Quest cells: exactly 1 in, exactly 1 out
+                .with_custom_cell(
+                    "quest",
+                    CellCountConstraint::exactly(1),
+                    CellCountConstraint::exactly(1),
+                )
+                // The campaign and the approved users are only referenced as cell deps
+                .with_custom_cell(
+                    "campaign",
+                    CellCountConstraint::exactly(0),
+                    CellCountConstraint::exactly(0),
+                )
+                .with_custom_cell(
+                    "user",
+                    CellCountConstraint::exactly(0),
+                    CellCountConstraint::exactly(0),
+                )
+                .with_custom_cell(
+                    "protocol",
+                    CellCountConstraint::exactly(0),
+                    CellCountConstraint::exactly(0),
+                )
+                .with_cell_relationship(
+                    "quest_link_validation".to_string(),
+                    "Quest cell must be linked to a campaign with quest cells in CellDeps".to_string(),
+                    vec!["quest".to_string()],
+                    common::quest_link_validation,
+                )
+                .with_cell_relationship(
+                    "script_immutability".to_string(),
+                    "Script immutability must be maintained during approval".to_string(),
+                    vec!["quest".to_string()],
+                    common::script_immutability,
+                )
+                .with_business_rule(
+                    "reviewer_authorization".to_string(),
+                    "A manager with the approve completions role must authorize reviews".to_string(),
+                    vec!["quest".to_string()],
+                    common::reviewer_authorization,
+                )
+                .with_business_rule(
+                    "quest_approval_validation".to_string(),
+                    "Approved users must have submitted and are appended to the quest".to_string(),
+                    vec!["quest".to_string(), "user".to_string()],
+                    business_logic::quest_approval_validation,
+                )
+                .with_business_rule(
+                    "reward_distribution_validation".to_string(),
+                    "Approved participants must receive exactly the quest rewards".to_string(),
+                    vec!["quest".to_string(), "user".to_string()],
+                    business_logic::reward_distribution_validation,
+                );
+        for udt_identifier in udt_identifiers {
+            rules = rules.with_custom_cell(
+                udt_identifier,
+                CellCountConstraint::at_least(0),
+                CellCountConstraint::at_least(0),
+            );
+        }
+        rules
+    }
+
+    pub mod business_logic {
+        use crate::recipes::helper::{
+            find_participant_cell, find_participant_data, input_quest, linked_campaign,
+            output_quest,
+        };
+        use alloc::vec::Vec;
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use ckb_deterministic::transaction_recipe::TransactionRecipeExt;
+        use ckb_std::ckb_constants::Source;
+        use ckboost_shared::rewards::{rewarded_in_quest, CarriedAssets, RewardEntitlement};
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::types::{Byte32, Byte32Vec, Uint32};
+        use ckboost_shared::{CampaignDataExt, QuestDataExt, UserDataExt};
+        use molecule::prelude::*;
+
+        // **Quest approval validation**: Every approved user submitted for the quest, was not
+        // accepted before and completed all sub-tasks; the output quest appends them in order
+        pub fn quest_approval_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let user_ids_arg = context.recipe.arguments().get(1).ok_or_else(|| {
+                debug_trace!("InvalidArgumentCount: Missing user_type_ids argument");
+                DeterministicError::InvalidArgumentCount
+            })?;
+            if user_ids_arg.arg_type().as_slice()[0] != 0 {
+                debug_trace!("Approval arguments must be inline data");
+                return Err(DeterministicError::InvalidArgumentCount);
+            }
+            let approved_user_type_ids: Vec<Byte32> =
+                Byte32Vec::from_slice(&user_ids_arg.data().raw_data())
+                    .map_err(|_| DeterministicError::InvalidArgumentCount)?
+                    .into_iter()
+                    .collect();
+            if approved_user_type_ids.is_empty() {
+                debug_trace!("No users to approve");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let (campaign_data, campaign_type_id) = linked_campaign(context)?;
+            if campaign_data.approval_dispute_window_u64() != 0 {
+                debug_trace!("Quest cells do not support approval dispute windows");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let input_quest = input_quest(context)?.ok_or(DeterministicError::CellCountViolation)?;
+            let output_quest = output_quest(context)?;
+            let quest_id = input_quest.quest_id_u32();
+
+            let mut expected_quest = input_quest.clone();
+            for (index, user_type_id) in approved_user_type_ids.iter().enumerate() {
+                let user_id = user_type_id.as_slice();
+                if input_quest.is_accepted(user_id)
+                    || approved_user_type_ids[..index]
+                        .iter()
+                        .any(|approved| approved.as_slice() == user_id)
+                {
+                    debug_trace!("User {:?} is already accepted for quest {}", user_type_id, quest_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+
+                let user_data = find_participant_data(context, user_id).ok_or_else(|| {
+                    debug_trace!("Approved user {:?} has no user cell in cell deps", user_type_id);
+                    DeterministicError::CellRelationshipRuleViolation
+                })?;
+                // A previously rejected user can only be approved on a newer resubmission
+                if user_data.submission_count(campaign_type_id.as_slice(), quest_id)
+                    <= input_quest.rejection_count_of(user_id)
+                {
+                    debug_trace!("Approved user {:?} has no pending submission for quest {}", user_type_id, quest_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                if !user_data.latest_submission_revealed(campaign_type_id.as_slice(), quest_id, user_id) {
+                    debug_trace!("Approved user {:?} has not revealed the submission for quest {}", user_type_id, quest_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                if !input_quest.sub_tasks_complete(user_id) {
+                    debug_trace!("Approved user {:?} has unapproved sub-tasks in quest {}", user_type_id, quest_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+
+                let accepted_ids = expected_quest
+                    .accepted_submission_user_type_ids()
+                    .as_builder()
+                    .push(user_type_id.clone())
+                    .build();
+                expected_quest = expected_quest
+                    .clear_rejection(user_id)
+                    .as_builder()
+                    .accepted_submission_user_type_ids(accepted_ids)
+                    .build();
+            }
+            let completion_count = input_quest
+                .completion_count_u32()
+                .checked_add(approved_user_type_ids.len() as u32)
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+            let expected_quest = expected_quest
+                .as_builder()
+                .completion_count(Uint32::from(completion_count.to_le_bytes()))
+                .build();
+
+            if output_quest.as_slice() != expected_quest.as_slice() {
+                debug_trace!("Output quest does not match the expected approval update");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+
+        // **Reward distribution**: Every newly approved participant receives exactly the quest's
+        // UDT rewards and at least its CKB reward at the lock of their user cell
+        pub fn reward_distribution_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_quest = input_quest(context)?;
+            let output_quest = output_quest(context)?;
+
+            // Participants sharing a lock are paid together
+            let mut owed_by_lock: Vec<(Vec<u8>, RewardEntitlement)> = Vec::new();
+            for (user_type_id, entitlement) in rewarded_in_quest(input_quest.as_ref(), &output_quest) {
+                if entitlement.is_empty() {
+                    continue;
+                }
+                let user_cell = find_participant_cell(context, &user_type_id).ok_or_else(|| {
+                    debug_trace!("Rewarded user {:?} has no user cell in cell deps", user_type_id);
+                    DeterministicError::CellRelationshipRuleViolation
+                })?;
+                let lock_hash = user_cell.lock_hash.as_slice();
+                match owed_by_lock.iter_mut().find(|(owed_lock, _)| owed_lock.as_slice() == lock_hash) {
+                    Some((_, owed)) => owed.merge(&entitlement),
+                    None => owed_by_lock.push((lock_hash.to_vec(), entitlement)),
+                }
+            }
+
+            for (lock_hash, owed) in owed_by_lock.iter() {
+                let received = CarriedAssets::locked_by(Source::Output, lock_hash)
+                    .map_err(|_| DeterministicError::Encoding)?;
+                if !received.delivers(owed) {
+                    debug_trace!("Rewards delivered to lock {:?} do not match {:?}", lock_hash, owed);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use ckb_deterministic::{
+    cell_classifier::RuleBasedClassifier, transaction_context::TransactionContext,
+};
+use ckboost_shared::Error;
+
+/// CKBoost Quest trait for quest cell operations
+pub trait CKBoostQuest {
+    /// Verify quest cell creation or edit transaction in Type Script
+    /// 
+    /// The recipe's only argument references the output quest cell. The linked
+    /// campaign must be provided as a cell dep.
+    fn verify_update_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+    
+    /// Verify quest completion approval transaction in Type Script
+    /// 
+    /// Arguments are the output quest cell reference and the approved user type IDs.
+    /// Every approved user's cell must be a cell dep carrying a submission record for
+    /// the campaign and quest.
+    fn verify_approve_completion(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+}
@@ -30,15 +30,39 @@ pub fn fallback() -> Result<(), Error> {
         }
         b"CKBoostUser.update_user_verification" => {
             debug_trace!("Matched CKBoostUser.update_user_verification");
-            CKBoostUserType::verify_update_user_verification()
+            CKBoostUserType::verify_update_user_verification(&context)
         }
         b"CKBoostUser.update_user" => {
             debug_trace!("Matched CKBoostUser.update_user");
             CKBoostUserType::verify_update_user(&context)
         }
+        b"CKBoostUser.archive_submissions" => {
+            debug_trace!("Matched CKBoostUser.archive_submissions");
+            CKBoostUserType::verify_archive_submissions(&context)
+        }
+        b"CKBoostUser.reveal_submission" => {
+            debug_trace!("Matched CKBoostUser.reveal_submission");
+            CKBoostUserType::verify_reveal_submission(&context)
+        }
+        b"CKBoostCampaign.complete_quest" => {
+            debug_trace!("Matched CKBoostCampaign.complete_quest - calling verify_complete_quest");
+            CKBoostUserType::verify_complete_quest(&context)
+        }
+        b"CKBoostCampaign.approve_completion" => {
+            debug_trace!("Matched CKBoostCampaign.approve_completion - calling verify_approve_completion");
+            CKBoostUserType::verify_approve_completion(&context)
+        }
+        b"CKBoostCampaign.reject_submission" => {
+            debug_trace!("Matched CKBoostCampaign.reject_submission - calling verify_reject_submission");
+            CKBoostUserType::verify_reject_submission(&context)
+        }
+        b"CKBoostCampaign.approve_sub_tasks" => {
+            debug_trace!("Matched CKBoostCampaign.approve_sub_tasks - calling verify_approve_sub_tasks");
+            CKBoostUserType::verify_approve_sub_tasks(&context)
+        }
         _ => {
             debug_trace!("No matching validation rules found for method path");
-            debug_trace!("Expected one of: CKBoostUser.submit_quest, CKBoostUser.update_user_verification, CKBoostUser.update_user");
+            debug_trace!("Expected one of: CKBoostUser.submit_quest, CKBoostUser.update_user_verification, CKBoostUser.update_user, CKBoostUser.archive_submissions, CKBoostUser.reveal_submission, CKBoostCampaign.complete_quest, CKBoostCampaign.approve_completion, CKBoostCampaign.reject_submission, CKBoostCampaign.approve_sub_tasks");
             Err(Error::WrongMethodPath)
         }
     }
@@ -77,6 +77,130 @@ fn program_entry_wrap() -> Result<(), Error> {
             let result_tx = crate::modules::CKBoostUserType::submit_quest(tx, user_data)?;
             Ok(Cow::from(result_tx.as_bytes().to_vec()))
         },
+
+        "CKBoostUser.update_user" => {
+            debug_trace!("Entered CKBoostUser.update_user");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse updated user_data from molecule serialized bytes (argv[2])
+            let user_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let user_data = ckboost_shared::types::UserData::from_slice(&user_data_bytes)
+                .map_err(|_| Error::InvalidUserData)?;
+            
+            // Call the update_user method and return the transaction
+            let result_tx = crate::modules::CKBoostUserType::update_user(tx, user_data)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+
+        "CKBoostUser.archive_submissions" => {
+            debug_trace!("Entered CKBoostUser.archive_submissions");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse current user_data from molecule serialized bytes (argv[2])
+            let user_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let user_data = ckboost_shared::types::UserData::from_slice(&user_data_bytes)
+                .map_err(|_| Error::InvalidUserData)?;
+            
+            // Parse count from argv[3] (u32)
+            let count_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            if count_bytes.len() != 4 {
+                return Err(Error::SSRIMethodsArgsInvalid);
+            }
+            let count = u32::from_le_bytes([count_bytes[0], count_bytes[1], count_bytes[2], count_bytes[3]]);
+            
+            // Call the archive_submissions method and return the transaction
+            let result_tx = crate::modules::CKBoostUserType::archive_submissions(tx, user_data, count)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+
+        "CKBoostUser.reveal_submission" => {
+            debug_trace!("Entered CKBoostUser.reveal_submission");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse current user_data from molecule serialized bytes (argv[2])
+            let user_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let user_data = ckboost_shared::types::UserData::from_slice(&user_data_bytes)
+                .map_err(|_| Error::InvalidUserData)?;
+            
+            // Parse record index from argv[3] (u32)
+            let index_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            if index_bytes.len() != 4 {
+                return Err(Error::SSRIMethodsArgsInvalid);
+            }
+            let index = u32::from_le_bytes([index_bytes[0], index_bytes[1], index_bytes[2], index_bytes[3]]);
+            
+            // Parse salt from argv[4] (32 bytes)
+            let salt_bytes = ckb_std::high_level::decode_hex(argv[4].as_ref())?;
+            let salt: [u8; 32] = salt_bytes
+                .as_slice()
+                .try_into()
+                .map_err(|_| Error::SSRIMethodsArgsInvalid)?;
+            
+            // Parse revealed content, or its URI in content-hash mode, from argv[5]
+            let content = ckb_std::high_level::decode_hex(argv[5].as_ref())?;
+            
+            // Parse content hash from argv[6] (32 bytes, zero for inline content)
+            let content_hash_bytes = ckb_std::high_level::decode_hex(argv[6].as_ref())?;
+            let content_hash: [u8; 32] = content_hash_bytes
+                .as_slice()
+                .try_into()
+                .map_err(|_| Error::SSRIMethodsArgsInvalid)?;
+            
+            // Call the reveal_submission method and return the transaction
+            let result_tx = crate::modules::CKBoostUserType::reveal_submission(tx, user_data, index, salt, content_hash, content)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
+
+        "CKBoostUser.update_user_verification" => {
+            debug_trace!("Entered CKBoostUser.update_user_verification");
+            
+            // Parse optional transaction (argv[1])
+            let tx: Option<ckb_std::ckb_types::packed::Transaction> = if argv[1].is_empty() || argv[1].as_ref().to_str().map_err(|_| Error::Utf8Error)? == "" {
+                None
+            } else {
+                let parsed_tx = ckb_std::ckb_types::packed::Transaction::from_compatible_slice(&ckb_std::high_level::decode_hex(argv[1].as_ref())?)
+                    .map_err(|_| Error::InvalidBaseTransactionForSSRI)?;
+                Some(parsed_tx)
+            };
+            
+            // Parse current user_data from molecule serialized bytes (argv[2])
+            let user_data_bytes = ckb_std::high_level::decode_hex(argv[2].as_ref())?;
+            let user_data = ckboost_shared::types::UserData::from_slice(&user_data_bytes)
+                .map_err(|_| Error::InvalidUserData)?;
+            
+            // Parse the new user_verification_data from molecule serialized bytes (argv[3])
+            let user_verification_data_bytes = ckb_std::high_level::decode_hex(argv[3].as_ref())?;
+            let user_verification_data = ckboost_shared::types::UserVerificationData::from_slice(&user_verification_data_bytes)
+                .map_err(|_| Error::InvalidUserData)?;
+            
+            // Call the update_user_verification method and return the transaction
+            let result_tx = crate::modules::CKBoostUserType::update_user_verification(tx, user_data, user_verification_data)?;
+            Ok(Cow::from(result_tx.as_bytes().to_vec()))
+        },
     )?;
     
     let pipe = pipe()?;
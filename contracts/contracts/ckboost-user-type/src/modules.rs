@@ -1,7 +1,7 @@
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 use blake2b_ref::Blake2bBuilder;
 use ckb_deterministic::{
-    cell_classifier::RuleBasedClassifier, create_recipe_with_args, create_recipe_with_reference, debug_trace, serialize_transaction_recipe, transaction_context::TransactionContext
+    cell_classifier::RuleBasedClassifier, create_inline_argument, create_recipe_with_args, create_recipe_with_reference, debug_trace, serialize_transaction_recipe, transaction_context::TransactionContext
 };
 use ckb_ssri_std::utils::high_level::{find_cell_by_out_point, find_out_point_by_type};
 use ckb_std::{
@@ -18,8 +18,10 @@ use ckb_std::{
     high_level::load_script,
 };
 use ckboost_shared::{
+    attestation::is_attestable_bit,
+    submission_content::has_valid_content_reference,
     types::{Byte32 as SharedByte32, ConnectedTypeID, UserData, UserVerificationData},
-    Error,
+    Error, UserDataExt, UserSubmissionRecordExt,
 };
 
 pub struct CKBoostUserType;
@@ -28,15 +30,52 @@ use crate::{recipes, ssri::CKBoostUser};
 
 impl CKBoostUser for CKBoostUserType {
     fn update_user_verification(
-        _user_verification_data: UserVerificationData,
-    ) -> Result<(), Error> {
-        debug_trace!("CKBoostUserType::update_user_verification - SSRI method not implemented");
-        Err(Error::SSRIMethodsNotImplemented)
+        tx: Option<Transaction>,
+        user_data: UserData,
+        user_verification_data: UserVerificationData,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostUserType::update_user_verification - Starting verification update");
+
+        // Every verified bit must be backed by exactly one attestation
+        let mut attested_flags = 0u8;
+        for attestation in user_verification_data.attestations().into_iter() {
+            let verification_bit = attestation.verification_bit().as_slice()[0];
+            if !is_attestable_bit(verification_bit) || attested_flags & verification_bit != 0 {
+                debug_trace!("Invalid or repeated attestation for bit {:#b}", verification_bit);
+                return Err(Error::InvalidUserData);
+            }
+            attested_flags |= verification_bit;
+        }
+        if user_verification_data.verified_flags().as_slice()[0] != attested_flags {
+            debug_trace!("Verified flags do not match the attested bits");
+            return Err(Error::InvalidUserData);
+        }
+
+        let updated_user_data = user_data
+            .as_builder()
+            .verification_data(user_verification_data)
+            .build();
+
+        let user_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let recipe = create_recipe_with_args(
+            "CKBoostUser.update_user_verification",
+            vec![create_recipe_with_reference(Source::Output, user_output_index)],
+        )?;
+
+        assemble_user_transition(tx, &updated_user_data, &serialize_transaction_recipe(&recipe))
     }
     
-    fn verify_update_user_verification() -> Result<(), Error> {
-        debug_trace!("CKBoostUserType::verify_update_user_verification - SSRI method not implemented");
-        Err(Error::SSRIMethodsNotImplemented)
+    fn verify_update_user_verification(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_update_user_verification");
+
+        // Use the recipe validation rules
+        let validation_rules = recipes::update_user_verification::get_rules();
+        validation_rules.validate(&context)?;
+
+        debug_trace!("User verification update validated successfully");
+        Ok(())
     }
     
     fn submit_quest(
@@ -330,17 +369,306 @@ impl CKBoostUser for CKBoostUserType {
     }
     
     fn update_user(
-        _tx: Option<Transaction>,
-        _user_data: UserData,
+        tx: Option<Transaction>,
+        user_data: UserData,
     ) -> Result<Transaction, Error> {
-        debug_trace!("CKBoostUserType::update_user - SSRI method not implemented");
-        Err(Error::SSRIMethodsNotImplemented)
+        debug_trace!("CKBoostUserType::update_user - Starting profile update");
+
+        let user_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let recipe = create_recipe_with_args(
+            "CKBoostUser.update_user",
+            vec![create_recipe_with_reference(Source::Output, user_output_index)],
+        )?;
+
+        assemble_user_transition(tx, &user_data, &serialize_transaction_recipe(&recipe))
     }
     
     fn verify_update_user(
-        _context: &TransactionContext<RuleBasedClassifier>,
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_update_user");
+
+        // Use the recipe validation rules
+        let validation_rules = recipes::update_user::get_rules();
+        validation_rules.validate(&context)?;
+
+        debug_trace!("User profile update validated successfully");
+        Ok(())
+    }
+    
+    fn archive_submissions(
+        tx: Option<Transaction>,
+        user_data: UserData,
+        count: u32,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostUserType::archive_submissions - Archiving {} records", count);
+
+        if count == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        let updated_user_data = user_data
+            .with_archived_submissions(count)
+            .ok_or(Error::InvalidArgument)?;
+
+        let user_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let count_bytes = count.to_le_bytes();
+        let recipe = create_recipe_with_args(
+            "CKBoostUser.archive_submissions",
+            vec![
+                create_recipe_with_reference(Source::Output, user_output_index),
+                create_inline_argument(&count_bytes[..]),
+            ],
+        )?;
+
+        assemble_user_transition(tx, &updated_user_data, &serialize_transaction_recipe(&recipe))
+    }
+    
+    fn verify_archive_submissions(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_archive_submissions");
+
+        // Use the recipe validation rules
+        let validation_rules = recipes::archive_submissions::get_rules();
+        validation_rules.validate(&context)?;
+
+        debug_trace!("Submission archival validated successfully");
+        Ok(())
+    }
+    
+    fn reveal_submission(
+        tx: Option<Transaction>,
+        user_data: UserData,
+        index: u32,
+        salt: [u8; 32],
+        content_hash: [u8; 32],
+        content: Vec<u8>,
+    ) -> Result<Transaction, Error> {
+        debug_trace!("CKBoostUserType::reveal_submission - Revealing record {}", index);
+
+        let committed_record = user_data
+            .submission_records()
+            .get(index as usize)
+            .ok_or(Error::InvalidArgument)?;
+        if !committed_record.is_committed() {
+            return Err(Error::InvalidUserData);
+        }
+        let revealed_record = committed_record
+            .as_builder()
+            .submission_content(content)
+            .content_salt(SharedByte32::from(salt))
+            .content_hash(SharedByte32::from(content_hash))
+            .build();
+        if !has_valid_content_reference(&revealed_record) {
+            return Err(Error::InvalidArgument);
+        }
+
+        // Reject reveals that would not open the commitment before building the transaction
+        let current_script = load_script()?;
+        let connected_type_id = ConnectedTypeID::from_slice(&current_script.args().raw_data())
+            .map_err(|_| Error::InvalidUserData)?;
+        if !revealed_record.is_revealed_by(connected_type_id.type_id().as_slice()) {
+            return Err(Error::InvalidUserData);
+        }
+        let updated_user_data = user_data
+            .with_submission_record(index as usize, revealed_record)
+            .ok_or(Error::InvalidArgument)?;
+
+        let user_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+        let index_bytes = index.to_le_bytes();
+        let recipe = create_recipe_with_args(
+            "CKBoostUser.reveal_submission",
+            vec![
+                create_recipe_with_reference(Source::Output, user_output_index),
+                create_inline_argument(&index_bytes[..]),
+            ],
+        )?;
+
+        assemble_user_transition(tx, &updated_user_data, &serialize_transaction_recipe(&recipe))
+    }
+    
+    fn verify_reveal_submission(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_reveal_submission");
+
+        // Use the recipe validation rules
+        let validation_rules = recipes::reveal_submission::get_rules();
+        validation_rules.validate(&context)?;
+
+        debug_trace!("Submission reveal validated successfully");
+        Ok(())
+    }
+    
+    fn verify_complete_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_complete_quest");
+
+        // Use the recipe validation rules
+        let validation_rules = recipes::complete_quest::get_rules();
+        validation_rules.validate(&context)?;
+
+        debug_trace!("Quest completion user update validated successfully");
+        Ok(())
+    }
+    
+    fn verify_approve_completion(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_approve_completion");
+
+        // Use the recipe validation rules
+        let validation_rules = recipes::approve_completion::get_rules();
+        validation_rules.validate(&context)?;
+
+        debug_trace!("Approval user cells validated successfully");
+        Ok(())
+    }
+    
+    fn verify_reject_submission(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error> {
+        debug_trace!("Starting verify_reject_submission");
+
+        // Use the recipe validation rules
+        let validation_rules = recipes::reject_submission::get_rules();
+        validation_rules.validate(&context)?;
+
+        debug_trace!("Rejection user cells validated successfully");
+        Ok(())
+    }
+    
+    fn verify_approve_sub_tasks(
+        context: &TransactionContext<RuleBasedClassifier>,
     ) -> Result<(), Error> {
-        debug_trace!("CKBoostUserType::verify_update_user - SSRI method not implemented");
-        Err(Error::SSRIMethodsNotImplemented)
+        debug_trace!("Starting verify_approve_sub_tasks");
+
+        // Use the recipe validation rules
+        let validation_rules = recipes::approve_sub_tasks::get_rules();
+        validation_rules.validate(&context)?;
+
+        debug_trace!("Sub-task approval user cells validated successfully");
+        Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Spend the current user cell and recreate it with `updated_user_data`
+///
+/// The user output is appended after the existing outputs of `tx`, and the
+/// serialized recipe is placed in the witness at the same index.
+fn assemble_user_transition(
+    tx: Option<Transaction>,
+    updated_user_data: &UserData,
+    recipe_bytes: &[u8],
+) -> Result<Transaction, Error> {
+    // Initialize transaction builders
+    let tx_builder = match tx {
+        Some(ref tx) => tx.clone().as_builder(),
+        None => TransactionBuilder::default(),
+    };
+    let raw_tx_builder = match tx {
+        Some(ref tx) => tx.clone().raw().as_builder(),
+        None => RawTransactionBuilder::default(),
+    };
+    let mut cell_input_vec_builder = match tx {
+        Some(ref tx) => tx.clone().raw().inputs().as_builder(),
+        None => CellInputVecBuilder::default(),
+    };
+    let mut cell_output_vec_builder = match tx {
+        Some(ref tx) => tx.clone().raw().outputs().as_builder(),
+        None => CellOutputVecBuilder::default(),
+    };
+    let mut outputs_data_builder = match tx {
+        Some(ref tx) => tx.clone().raw().outputs_data().as_builder(),
+        None => BytesVecBuilder::default(),
+    };
+    let cell_dep_vec_builder = match tx {
+        Some(ref tx) => tx.clone().raw().cell_deps().as_builder(),
+        None => CellDepVecBuilder::default(),
+    };
+
+    // Find and add existing user cell as input
+    let current_script = load_script()?;
+    let user_outpoint = find_out_point_by_type(current_script.clone())
+        .map_err(|_| Error::UserCellNotFound)?;
+    let user_input = CellInput::new_builder()
+        .previous_output(user_outpoint.clone())
+        .build();
+    cell_input_vec_builder = cell_input_vec_builder.push(user_input);
+
+    // Get the current user cell to preserve lock script
+    let current_user_cell = find_cell_by_out_point(user_outpoint)
+        .map_err(|_| Error::UserCellNotFound)?;
+
+    // Create output user cell with updated data
+    let user_output_index = tx.as_ref().map(|t| t.raw().outputs().len()).unwrap_or(0) as u32;
+    let user_output = CellOutputBuilder::default()
+        .type_(
+            ScriptOptBuilder::default()
+                .set(Some(current_script))
+                .build(),
+        )
+        .lock(current_user_cell.lock())
+        .capacity(0u64.pack()) // Placeholder capacity
+        .build();
+    cell_output_vec_builder = cell_output_vec_builder.push(user_output);
+    outputs_data_builder = outputs_data_builder.push(updated_user_data.as_bytes().pack());
+
+    // Create WitnessArgs with recipe in output_type field
+    let witness_args = WitnessArgsBuilder::default()
+        .lock(BytesOpt::default())
+        .input_type(BytesOpt::default())
+        .output_type(
+            BytesOpt::new_builder()
+                .set(Some(recipe_bytes.pack()))
+                .build(),
+        )
+        .build();
+
+    // Build witnesses vector with recipe witness at user output index
+    let witnesses_builder = match tx {
+        Some(ref tx) => {
+            let mut builder = BytesVecBuilder::default();
+            let witnesses = tx.witnesses();
+            for i in 0..user_output_index as usize {
+                match witnesses.get(i) {
+                    Some(witness) => {
+                        builder = builder.push(witness);
+                    }
+                    None => {
+                        let empty_witness = WitnessArgsBuilder::default().build();
+                        builder = builder.push(empty_witness.as_bytes().pack());
+                    }
+                }
+            }
+            builder = builder.push(witness_args.as_bytes().pack());
+            for i in (user_output_index + 1) as usize..witnesses.len() {
+                if let Some(witness) = witnesses.get(i) {
+                    builder = builder.push(witness);
+                }
+            }
+            builder
+        }
+        None => BytesVecBuilder::default().push(witness_args.as_bytes().pack()),
+    };
+
+    // Build the complete transaction
+    Ok(tx_builder
+        .raw(
+            raw_tx_builder
+                .version(tx.clone().map(|t| t.raw().version()).unwrap_or_default())
+                .cell_deps(cell_dep_vec_builder.build())
+                .header_deps(
+                    tx.clone()
+                        .map(|t| t.raw().header_deps())
+                        .unwrap_or_else(|| Byte32Vec::default()),
+                )
+                .inputs(cell_input_vec_builder.build())
+                .outputs(cell_output_vec_builder.build())
+                .outputs_data(outputs_data_builder.build())
+                .build(),
+        )
+        .witnesses(witnesses_builder.build())
+        .build())
+}
@@ -6,6 +6,138 @@ use ckb_deterministic::{
     validation::TransactionValidationRules,
 };
 
+pub mod helper {
+    use alloc::vec::Vec;
+    use ckb_deterministic::cell_classifier::{CellInfo, RuleBasedClassifier};
+    use ckb_deterministic::debug_trace;
+    use ckb_deterministic::errors::Error as DeterministicError;
+    use ckb_deterministic::transaction_recipe::TransactionRecipeExt;
+    use ckboost_shared::campaign_data::connected_type_id_from_args;
+    use ckboost_shared::transaction_context::TransactionContext;
+    use ckboost_shared::types::{Byte32, CampaignData, ProtocolData, QuestData};
+    use ckboost_shared::{CampaignDataExt, QuestDataExt};
+    use molecule::prelude::*;
+
+    // Find the campaign cell dep whose ConnectedTypeID type_id matches
+    pub fn find_campaign_cell_in_deps<'a>(
+        context: &'a TransactionContext<RuleBasedClassifier>,
+        campaign_type_id: &[u8],
+    ) -> Option<&'a CellInfo> {
+        context.cell_deps.get_custom("campaign")?.iter().find(|cell| {
+            cell.type_script
+                .as_ref()
+                .and_then(|script| connected_type_id_from_args(&script.args().raw_data()))
+                .map(|connected_type_id| connected_type_id.type_id().as_slice() == campaign_type_id)
+                .unwrap_or(false)
+        })
+    }
+
+    // Find the campaign data of the campaign cell dep whose ConnectedTypeID type_id matches
+    pub fn find_campaign_data_in_deps(
+        context: &TransactionContext<RuleBasedClassifier>,
+        campaign_type_id: &[u8],
+    ) -> Option<CampaignData> {
+        let campaign_cell = find_campaign_cell_in_deps(context, campaign_type_id)?;
+        CampaignData::from_slice(&campaign_cell.data).ok()
+    }
+
+    // Find a quest of a campaign cell dep: inline in its data, or, for campaigns keeping
+    // quests in quest cells, the quest cell dep linked to the campaign's type hash
+    pub fn find_quest_in_deps(
+        context: &TransactionContext<RuleBasedClassifier>,
+        campaign_type_id: &[u8],
+        campaign_data: &CampaignData,
+        quest_id: u32,
+    ) -> Option<QuestData> {
+        if !campaign_data.uses_quest_cells() {
+            return campaign_data.find_quest(quest_id);
+        }
+        let campaign_type_hash = find_campaign_cell_in_deps(context, campaign_type_id)?
+            .type_hash
+            .as_ref()?;
+        context.cell_deps.get_custom("quest")?.iter().find_map(|cell| {
+            let type_script = cell.type_script.as_ref()?;
+            let connected_type_id = connected_type_id_from_args(&type_script.args().raw_data())?;
+            if connected_type_id.connected_key().as_slice() != campaign_type_hash.as_slice() {
+                return None;
+            }
+            let quest = QuestData::from_slice(&cell.data).ok()?;
+            (quest.quest_id_u32() == quest_id).then_some(quest)
+        })
+    }
+
+    // Protocol data of the protocol cell provided as a cell dep
+    pub fn find_protocol_data_in_deps(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Option<ProtocolData> {
+        context
+            .cell_deps
+            .get_custom("protocol")?
+            .iter()
+            .find_map(|cell| ProtocolData::from_slice(&cell.data).ok())
+    }
+
+    // Data of the inline recipe argument at `index`
+    pub fn inline_argument(
+        context: &TransactionContext<RuleBasedClassifier>,
+        index: usize,
+    ) -> Result<Vec<u8>, DeterministicError> {
+        let argument = context.recipe.arguments().get(index).ok_or_else(|| {
+            debug_trace!("InvalidArgumentCount: Missing argument {}", index);
+            DeterministicError::InvalidArgumentCount
+        })?;
+        if argument.arg_type().as_slice()[0] != 0 {
+            debug_trace!("Argument {} must be inline data", index);
+            return Err(DeterministicError::InvalidArgumentCount);
+        }
+        Ok(argument.data().raw_data().to_vec())
+    }
+
+    // The ConnectedTypeID type_id of a user cell, as recorded in campaign review lists
+    pub fn user_type_id_of(cell: &CellInfo) -> Option<Byte32> {
+        let type_script = cell.type_script.as_ref()?;
+        Some(connected_type_id_from_args(&type_script.args().raw_data())?.type_id())
+    }
+
+    // The cell among `cells` carrying the same type script as `cell`
+    pub fn find_same_type_cell<'a>(cells: &'a [CellInfo], cell: &CellInfo) -> Option<&'a CellInfo> {
+        let type_hash = cell.type_hash.as_ref()?;
+        cells.iter().find(|candidate| {
+            candidate
+                .type_hash
+                .as_ref()
+                .map(|candidate_hash| candidate_hash.as_slice() == type_hash.as_slice())
+                .unwrap_or(false)
+        })
+    }
+
+    // Pair every input user cell with the output user cell of the same type script; both sides
+    // must hold the same user cells, whatever their order
+    pub fn pair_user_cells<'a>(
+        input_cells: &'a [CellInfo],
+        output_cells: &'a [CellInfo],
+    ) -> Result<Vec<(&'a CellInfo, &'a CellInfo)>, DeterministicError> {
+        if input_cells.len() != output_cells.len() {
+            debug_trace!(
+                "{} user cells in inputs but {} in outputs",
+                input_cells.len(),
+                output_cells.len()
+            );
+            return Err(DeterministicError::CellCountViolation);
+        }
+        input_cells
+            .iter()
+            .map(|input_cell| {
+                let output_cell = find_same_type_cell(output_cells, input_cell).ok_or_else(|| {
+                    debug_trace!("User cell has no output with the same type script");
+                    DeterministicError::CellRelationshipRuleViolation
+                })?;
+                Ok((input_cell, output_cell))
+            })
+            .collect()
+    }
+}
+
 pub mod common {
     use ckb_deterministic::errors::Error as DeterministicError;
     use ckb_deterministic::transaction_recipe::TransactionRecipeExt;
@@ -78,7 +210,7 @@ pub mod update_user_verification {
     };
 
     pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
-        TransactionValidationRules::new(b"updateUserVerification".to_vec())
+        TransactionValidationRules::new(b"CKBoostUser.update_user_verification".to_vec())
             .with_arguments(1)
             // Protocol cells: at most 1 in (for checking endorser whitelist), 0 out
             .with_custom_cell(
@@ -113,18 +245,459 @@ pub mod update_user_verification {
     }
 
     pub mod business_logic {
+        use crate::recipes::helper::{find_protocol_data_in_deps, user_type_id_of};
         use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
         use ckb_deterministic::errors::Error as DeterministicError;
+        use ckboost_shared::attestation::is_attestable_bit;
+        use ckboost_shared::authority::inputs_hold_whitelisted_endorser;
         use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::types::UserData;
+        use ckboost_shared::{ProtocolDataExt, UserDataExt};
+        use molecule::prelude::*;
 
-        // **Verification update validation**: Ensure only authorized verification updates
+        // **Verification update validation**: Only the verification data changes, and only
+        // under the authority of a whitelisted endorser or of the protocol's attestors
+        //
+        // 1. Points and submissions stay untouched
+        // 2. Every verified bit carries exactly one attestation
+        // 3. A whitelisted endorser spending one of its cells may edit any verification field,
+        //    including revoking bits and granting attestations without a signature
+        // 4. Otherwise the user may only add or renew attestations signed for them by an
+        //    attestor the protocol registered for the bit
+        // 5. Without an endorser, verified bits are never cleared and the remaining
+        //    verification fields stay unchanged
         pub fn verification_update_validation(
-            _context: &TransactionContext<RuleBasedClassifier>,
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_user_cells = context
+                .input_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let input_user_data = UserData::from_slice(&input_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_user_data = UserData::from_slice(&output_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            // Step 1: Verification updates leave the rest of the user cell alone
+            if input_user_data.total_points_earned().as_slice()
+                != output_user_data.total_points_earned().as_slice()
+                || !input_user_data.has_same_submission_history(&output_user_data)
+            {
+                debug_trace!("Verification update changed points or submissions");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let input_verification = input_user_data.verification_data();
+            let output_verification = output_user_data.verification_data();
+            if input_verification.as_slice() == output_verification.as_slice() {
+                return Ok(());
+            }
+
+            let protocol_data = find_protocol_data_in_deps(context).ok_or_else(|| {
+                debug_trace!("Verification updates need the protocol cell in cell deps");
+                DeterministicError::CellRelationshipRuleViolation
+            })?;
+            let endorsed = inputs_hold_whitelisted_endorser(&protocol_data);
+            debug_trace!("Verification update endorsed: {}", endorsed);
+
+            // Step 2: One attestation per verified bit
+            let user_type_id =
+                user_type_id_of(&output_user_cells[0]).ok_or(DeterministicError::Encoding)?;
+            let input_attestations = input_verification.attestations();
+            let mut attested_flags = 0u8;
+            for attestation in output_verification.attestations().into_iter() {
+                let verification_bit = attestation.verification_bit().as_slice()[0];
+                if !is_attestable_bit(verification_bit) || attested_flags & verification_bit != 0 {
+                    debug_trace!("Invalid or repeated attestation for bit {:#b}", verification_bit);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                attested_flags |= verification_bit;
+
+                // Step 4: Without an endorser, new attestations must be signed by an attestor
+                let unchanged = input_attestations
+                    .clone()
+                    .into_iter()
+                    .any(|existing| existing.as_slice() == attestation.as_slice());
+                if !endorsed
+                    && !unchanged
+                    && !protocol_data.accepts_attestation(user_type_id.as_slice(), &attestation)
+                {
+                    debug_trace!("Attestation for bit {:#b} is not signed by a registered attestor", verification_bit);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+            if output_user_data.verified_flags_u8() != attested_flags {
+                debug_trace!("Verified flags do not match the attested bits");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            // Step 3: Endorsers may grant, revoke and edit freely
+            if endorsed {
+                return Ok(());
+            }
+
+            // Step 5: Users cannot clear their own bits or edit the endorser-managed fields
+            let cleared = input_user_data.verified_flags_u8() & !attested_flags;
+            if cleared != 0 {
+                debug_trace!("Verified bits {:#b} can only be cleared by an endorser", cleared);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if input_verification.telegram_personal_chat_id().as_slice()
+                != output_verification.telegram_personal_chat_id().as_slice()
+                || input_verification.identity_verification_data().as_slice()
+                    != output_verification.identity_verification_data().as_slice()
+            {
+                debug_trace!("Verification details can only be changed by an endorser");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod update_user {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostUser.update_user".to_vec())
+            .with_arguments(1)
+            // Protocol cells not allowed in profile updates
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells not allowed in profile updates
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // User cells: exactly 1 in, 1 out (update by the owner unlocking it)
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during user updates".to_string(),
+                vec!["user".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "profile_update_validation".to_string(),
+                "Profile updates must keep points, submissions and verifications".to_string(),
+                vec!["user".to_string()],
+                business_logic::profile_update_validation,
+            )
+    }
+
+    pub mod business_logic {
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::types::UserData;
+        use ckboost_shared::UserDataExt;
+        use molecule::prelude::*;
+
+        // **Profile update validation**: The owner may only edit non-sensitive fields; points,
+        // submissions and verification data have their own recipes and stay untouched here
+        pub fn profile_update_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
         ) -> Result<(), DeterministicError> {
-            // TODO: Implement verification update validation
-            // 1. Check if updater is in endorser whitelist (from protocol cell)
-            // 2. Validate new verification data
-            // 3. Ensure verification level transitions are valid
+            let input_user_cells = context
+                .input_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let input_user_data = UserData::from_slice(&input_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_user_data = UserData::from_slice(&output_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            if input_user_data.total_points_earned().as_slice()
+                != output_user_data.total_points_earned().as_slice()
+            {
+                debug_trace!("Profile update changed total points earned");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if !input_user_data.has_same_submission_history(&output_user_data) {
+                debug_trace!("Profile update changed submission history");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if input_user_data.verification_data().as_slice()
+                != output_user_data.verification_data().as_slice()
+            {
+                debug_trace!("Profile update changed verification data");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod archive_submissions {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostUser.archive_submissions".to_vec())
+            .with_arguments(2)
+            // Protocol cells not allowed in archival
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: only read through cell deps to prove the approvals
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // User cells: exactly 1 in, 1 out (update)
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during archival".to_string(),
+                vec!["user".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "archival_validation".to_string(),
+                "Only approved submissions are compacted into the archive hash".to_string(),
+                vec!["user".to_string(), "campaign".to_string()],
+                business_logic::archival_validation,
+            )
+    }
+
+    pub mod business_logic {
+        use crate::recipes::helper::{
+            find_campaign_data_in_deps, find_quest_in_deps, inline_argument, user_type_id_of,
+        };
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use ckboost_shared::campaign_data::unpack_u32;
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::types::UserData;
+        use ckboost_shared::{QuestDataExt, UserDataExt};
+        use molecule::prelude::*;
+
+        // **Archival validation**: The oldest `count` records (argument 1, u32 LE) are folded
+        // into the rolling archive hash and nothing else changes. Each archived record must
+        // belong to a quest the user is approved for, as listed by its campaign in cell deps
+        pub fn archival_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_user_cells = context
+                .input_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let input_user_data = UserData::from_slice(&input_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_user_data = UserData::from_slice(&output_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            let count_bytes = inline_argument(context, 1)?;
+            if count_bytes.len() != 4 {
+                debug_trace!("Archive count must be a u32");
+                return Err(DeterministicError::Encoding);
+            }
+            let count = unpack_u32(&count_bytes);
+            if count == 0 {
+                debug_trace!("Archival must compact at least one record");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let expected_user_data =
+                input_user_data.with_archived_submissions(count).ok_or_else(|| {
+                    debug_trace!("Cannot archive {} records", count);
+                    DeterministicError::BusinessRuleViolation
+                })?;
+            if expected_user_data.as_slice() != output_user_data.as_slice() {
+                debug_trace!("Output user data does not match the archived records");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let user_type_id =
+                user_type_id_of(&input_user_cells[0]).ok_or(DeterministicError::Encoding)?;
+            for record in input_user_data.submission_records().into_iter().take(count as usize) {
+                let campaign_type_id = record.campaign_type_id();
+                let quest_id = unpack_u32(record.quest_id().as_slice());
+                let campaign_data = find_campaign_data_in_deps(context, campaign_type_id.as_slice())
+                    .ok_or_else(|| {
+                        debug_trace!("Archival requires the campaign cell as a cell dep");
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                let approved = find_quest_in_deps(
+                    context,
+                    campaign_type_id.as_slice(),
+                    &campaign_data,
+                    quest_id,
+                )
+                .map(|quest| quest.is_accepted(user_type_id.as_slice()))
+                .unwrap_or(false);
+                if !approved {
+                    debug_trace!("Quest {} is not approved, its records cannot be archived", quest_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod reveal_submission {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostUser.reveal_submission".to_vec())
+            .with_arguments(2)
+            // Protocol cells not allowed in reveals
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells not allowed in reveals
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // User cells: exactly 1 in, 1 out (update)
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during reveals".to_string(),
+                vec!["user".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "reveal_validation".to_string(),
+                "Revealed content must open the earlier commitment".to_string(),
+                vec!["user".to_string()],
+                business_logic::reveal_validation,
+            )
+    }
+
+    pub mod business_logic {
+        use crate::recipes::helper::{inline_argument, user_type_id_of};
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use ckboost_shared::campaign_data::unpack_u32;
+        use ckboost_shared::submission_content::has_valid_content_reference;
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::types::UserData;
+        use ckboost_shared::{UserDataExt, UserSubmissionRecordExt};
+        use molecule::prelude::*;
+
+        // **Reveal validation**: The record at index `argument 1` (u32 LE) was committed in an
+        // earlier transaction, and only its content, content hash and salt change, opening the
+        // commitment for this user. Off-chain content must be referenced by an accepted URI
+        pub fn reveal_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_user_cells = context
+                .input_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let input_user_data = UserData::from_slice(&input_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let output_user_data = UserData::from_slice(&output_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            let index_bytes = inline_argument(context, 1)?;
+            if index_bytes.len() != 4 {
+                debug_trace!("Record index must be a u32");
+                return Err(DeterministicError::Encoding);
+            }
+            let index = unpack_u32(&index_bytes) as usize;
+
+            let committed_record = input_user_data.submission_records().get(index).ok_or_else(|| {
+                debug_trace!("No submission record at index {}", index);
+                DeterministicError::BusinessRuleViolation
+            })?;
+            if !committed_record.is_committed() {
+                debug_trace!("Submission record {} has no pending commitment", index);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            let revealed_record = output_user_data
+                .submission_records()
+                .get(index)
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+
+            // Only the content, content hash and salt may change in the whole user cell
+            let expected_record = committed_record
+                .as_builder()
+                .submission_content(revealed_record.submission_content())
+                .content_salt(revealed_record.content_salt())
+                .content_hash(revealed_record.content_hash())
+                .build();
+            let expected_user_data = input_user_data
+                .with_submission_record(index, expected_record)
+                .ok_or(DeterministicError::BusinessRuleViolation)?;
+            if expected_user_data.as_slice() != output_user_data.as_slice() {
+                debug_trace!("Reveal changed more than the content and salt of record {}", index);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            if !has_valid_content_reference(&revealed_record) {
+                debug_trace!("Record {} has a content hash but no accepted content URI", index);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let user_type_id =
+                user_type_id_of(&input_user_cells[0]).ok_or(DeterministicError::Encoding)?;
+            if !revealed_record.is_revealed_by(user_type_id.as_slice()) {
+                debug_trace!("Revealed content does not match the commitment of record {}", index);
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
             Ok(())
         }
     }
@@ -174,19 +747,734 @@ pub mod submit_quest {
     }
 
     pub mod business_logic {
-        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use crate::recipes::helper::{
+            find_campaign_data_in_deps, find_quest_in_deps, pair_user_cells, user_type_id_of,
+        };
+        use ckb_deterministic::cell_classifier::{CellInfo, RuleBasedClassifier};
+        use ckb_deterministic::debug_trace;
         use ckb_deterministic::errors::Error as DeterministicError;
+        use ckboost_shared::campaign_data::unpack_u32;
         use ckboost_shared::transaction_context::TransactionContext;
+        use alloc::vec::Vec;
+        use ckboost_shared::generated::ckboost::{
+            SubTaskSubmission, UserSubmissionRecord, VerificationAttestationVec,
+        };
+        use ckboost_shared::header::max_header_dep_timestamp;
+        use ckboost_shared::types::UserData;
+        use ckboost_shared::{CampaignDataExt, QuestDataExt, UserDataExt, UserSubmissionRecordExt};
+        use molecule::prelude::*;
 
         // **Submission validation**: Ensure submission is valid
         pub fn submission_validation(
-            _context: &TransactionContext<RuleBasedClassifier>,
+            context: &TransactionContext<RuleBasedClassifier>,
         ) -> Result<(), DeterministicError> {
-            // TODO: Implement submission validation
-            // 1. Verify campaign exists and is active
-            // 2. Verify quest exists in campaign
+            append_only_validation(context)?;
+            commitment_validation(context)?;
+            campaign_validation(context)?;
+            resubmission_validation(context)?;
+            verification_validation(context)?;
+            prerequisite_validation(context)?;
+            sub_task_validation(context)
+        }
+
+        // **Append-only validation**: Submissions only append records; existing records keep
+        // everything but their sub-task entries, which `sub_task_validation` lets grow, and
+        // the archive only changes through `archive_submissions`
+        pub fn append_only_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_data = UserData::from_slice(&output_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let input_user_data = match context.input_cells.get_custom("user") {
+                Some(cells) if !cells.is_empty() => {
+                    UserData::from_slice(&cells[0].data).map_err(|_| DeterministicError::Encoding)?
+                }
+                // New users start without an archive
+                _ => UserData::default(),
+            };
+
+            if input_user_data.archived_submission_count().as_slice()
+                != output_user_data.archived_submission_count().as_slice()
+                || input_user_data.archived_submissions_hash().as_slice()
+                    != output_user_data.archived_submissions_hash().as_slice()
+            {
+                debug_trace!("Archived submissions changed during submission");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            let input_records = input_user_data.submission_records();
+            let output_records = output_user_data.submission_records();
+            if output_records.len() < input_records.len() {
+                debug_trace!("Submission records were removed");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+            let without_sub_tasks = |record: UserSubmissionRecord| {
+                record
+                    .as_builder()
+                    .sub_task_submissions(Default::default())
+                    .build()
+            };
+            for (index, input_record) in input_records.into_iter().enumerate() {
+                let output_record = output_records
+                    .get(index)
+                    .ok_or(DeterministicError::BusinessRuleViolation)?;
+                if without_sub_tasks(input_record).as_slice()
+                    != without_sub_tasks(output_record).as_slice()
+                {
+                    debug_trace!("Submission record {} was rewritten", index);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
             Ok(())
         }
+
+        // **Commitment validation**: New records only carry a commitment to their content,
+        // which is revealed by `reveal_submission` in a later transaction
+        pub fn commitment_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_data = UserData::from_slice(&output_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let input_record_count = match context.input_cells.get_custom("user") {
+                Some(cells) if !cells.is_empty() => UserData::from_slice(&cells[0].data)
+                    .map_err(|_| DeterministicError::Encoding)?
+                    .submission_records()
+                    .len(),
+                _ => 0,
+            };
+
+            for (index, record) in output_user_data.submission_records().into_iter().enumerate() {
+                if index >= input_record_count && !record.is_committed() {
+                    debug_trace!("New submission record {} must only hold a content commitment", index);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
+
+        // **Campaign validation**: Every new submission targets an active campaign provided as
+        // a cell dep, and an active quest of it the user has not been approved for yet, as far as
+        // the campaign lists its approved users
+        pub fn campaign_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_data = UserData::from_slice(&output_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let input_user_data = match context.input_cells.get_custom("user") {
+                Some(cells) if !cells.is_empty() => Some(
+                    UserData::from_slice(&cells[0].data).map_err(|_| DeterministicError::Encoding)?,
+                ),
+                _ => None,
+            };
+            let user_type_id =
+                user_type_id_of(&output_user_cells[0]).ok_or(DeterministicError::Encoding)?;
+
+            for record in output_user_data.submission_records().into_iter() {
+                let campaign_type_id = record.campaign_type_id();
+                let quest_id = unpack_u32(record.quest_id().as_slice());
+                let previous_count = input_user_data
+                    .as_ref()
+                    .map(|data| data.submission_count(campaign_type_id.as_slice(), quest_id))
+                    .unwrap_or(0);
+                if output_user_data.submission_count(campaign_type_id.as_slice(), quest_id)
+                    <= previous_count
+                {
+                    continue;
+                }
+
+                let campaign_data = find_campaign_data_in_deps(context, campaign_type_id.as_slice())
+                    .ok_or_else(|| {
+                        debug_trace!("Submission requires the campaign cell as a cell dep");
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                if !campaign_data.accepts_submissions() {
+                    debug_trace!("Campaign is not active, status: {}", campaign_data.status());
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+
+                let quest = find_quest_in_deps(
+                    context,
+                    campaign_type_id.as_slice(),
+                    &campaign_data,
+                    quest_id,
+                )
+                .ok_or_else(|| {
+                    debug_trace!("Quest {} not found in campaign", quest_id);
+                    DeterministicError::BusinessRuleViolation
+                })?;
+                if !quest.is_active() {
+                    debug_trace!("Quest {} is not active, status: {}", quest_id, quest.status_u8());
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                // SMT campaigns keep no accepted list, so this check is skipped for them. A
+                // submission alone grants nothing, and their approvals must prove the user absent
+                // under the quest's approvals_root, so an approved user is never approved again.
+                if !campaign_data.tracks_approvals_in_smt() && quest.is_accepted(user_type_id.as_slice()) {
+                    debug_trace!("User was already approved for quest {}", quest_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
+
+        // **Verification validation**: Submissions leave the user's verifications untouched, and
+        // a quest can only be submitted while the user holds an unexpired attestation for every
+        // verification the campaign requires, as recorded in the campaign cell dep
+        pub fn verification_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_data = UserData::from_slice(&output_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let input_user_data = match context.input_cells.get_custom("user") {
+                Some(cells) if !cells.is_empty() => Some(
+                    UserData::from_slice(&cells[0].data).map_err(|_| DeterministicError::Encoding)?,
+                ),
+                _ => None,
+            };
+
+            // Only verification updates grant verifications; new users start with none
+            let previous_flags = input_user_data
+                .as_ref()
+                .map(|data| data.verified_flags_u8())
+                .unwrap_or(0);
+            let previous_verification = input_user_data
+                .as_ref()
+                .map(|data| data.verification_data().attestations().as_slice().to_vec())
+                .unwrap_or_else(|| VerificationAttestationVec::default().as_slice().to_vec());
+            if output_user_data.verified_flags_u8() != previous_flags
+                || output_user_data.verification_data().attestations().as_slice() != previous_verification.as_slice()
+            {
+                debug_trace!("Verifications changed during submission");
+                return Err(DeterministicError::BusinessRuleViolation);
+            }
+
+            for record in output_user_data.submission_records().into_iter() {
+                let campaign_type_id = record.campaign_type_id();
+                let quest_id = unpack_u32(record.quest_id().as_slice());
+                let previous_count = input_user_data
+                    .as_ref()
+                    .map(|data| data.submission_count(campaign_type_id.as_slice(), quest_id))
+                    .unwrap_or(0);
+                if output_user_data.submission_count(campaign_type_id.as_slice(), quest_id)
+                    <= previous_count
+                {
+                    continue;
+                }
+
+                let campaign_data = find_campaign_data_in_deps(context, campaign_type_id.as_slice())
+                    .ok_or_else(|| {
+                        debug_trace!("Submission requires the campaign cell as a cell dep");
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                let required = campaign_data.required_verifications();
+                if required == 0 {
+                    continue;
+                }
+                // Attestations expire, so the header deps must prove the current time
+                let now = max_header_dep_timestamp().ok_or_else(|| {
+                    debug_trace!("Verification requirements need a header dep");
+                    DeterministicError::CellRelationshipRuleViolation
+                })?;
+                let missing = output_user_data.missing_verifications(required, now);
+                if missing != 0 {
+                    debug_trace!(
+                        "Quest {} requires verifications {:#b} the user lacks",
+                        quest_id,
+                        missing
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
+
+        // **Sub-task validation**: Sub-task entries may only be appended to a submission, each
+        // naming a distinct sub-task of the quest as recorded in the campaign cell dep
+        pub fn sub_task_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_data = UserData::from_slice(&output_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let input_user_data = match context.input_cells.get_custom("user") {
+                Some(cells) if !cells.is_empty() => Some(
+                    UserData::from_slice(&cells[0].data).map_err(|_| DeterministicError::Encoding)?,
+                ),
+                _ => None,
+            };
+
+            for (index, record) in output_user_data.submission_records().into_iter().enumerate() {
+                let output_entries: Vec<SubTaskSubmission> =
+                    record.sub_task_submissions().into_iter().collect();
+                let input_entries: Vec<SubTaskSubmission> = input_user_data
+                    .as_ref()
+                    .and_then(|data| data.submission_records().get(index))
+                    .filter(|input_record| {
+                        input_record.campaign_type_id().as_slice() == record.campaign_type_id().as_slice()
+                            && input_record.quest_id().as_slice() == record.quest_id().as_slice()
+                    })
+                    .map(|input_record| input_record.sub_task_submissions().into_iter().collect())
+                    .unwrap_or_default();
+
+                // Entries already submitted, and possibly approved, must stay untouched
+                if output_entries.len() < input_entries.len()
+                    || input_entries
+                        .iter()
+                        .zip(output_entries.iter())
+                        .any(|(before, after)| before.as_slice() != after.as_slice())
+                {
+                    debug_trace!("Existing sub-task entries of submission {} were modified", index);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                if output_entries.len() == input_entries.len() {
+                    continue;
+                }
+
+                let quest_id = unpack_u32(record.quest_id().as_slice());
+                let campaign_data =
+                    find_campaign_data_in_deps(context, record.campaign_type_id().as_slice())
+                        .ok_or_else(|| {
+                            debug_trace!("Sub-task submission requires the campaign cell as a cell dep");
+                            DeterministicError::CellRelationshipRuleViolation
+                        })?;
+                let quest_sub_task_ids = campaign_data
+                    .find_quest(quest_id)
+                    .ok_or_else(|| {
+                        debug_trace!("Quest {} not found in campaign", quest_id);
+                        DeterministicError::BusinessRuleViolation
+                    })?
+                    .sub_task_ids();
+
+                let mut seen_ids: Vec<u8> = Vec::new();
+                for entry in output_entries.iter() {
+                    let sub_task_id = entry.sub_task_id().as_slice()[0];
+                    if !quest_sub_task_ids.contains(&sub_task_id) || seen_ids.contains(&sub_task_id) {
+                        debug_trace!("Invalid sub-task {} for quest {}", sub_task_id, quest_id);
+                        return Err(DeterministicError::BusinessRuleViolation);
+                    }
+                    seen_ids.push(sub_task_id);
+                }
+            }
+
+            Ok(())
+        }
+
+        // **Prerequisite validation**: A quest can only be submitted once the user has been
+        // approved for every prerequisite quest, as recorded in the campaign cell dep
+        pub fn prerequisite_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_data = UserData::from_slice(&output_user_cells[0].data)
+                .map_err(|_| DeterministicError::Encoding)?;
+            let input_user_data = match context.input_cells.get_custom("user") {
+                Some(cells) if !cells.is_empty() => Some(
+                    UserData::from_slice(&cells[0].data).map_err(|_| DeterministicError::Encoding)?,
+                ),
+                _ => None,
+            };
+            let user_type_id =
+                user_type_id_of(&output_user_cells[0]).ok_or(DeterministicError::Encoding)?;
+
+            for record in output_user_data.submission_records().into_iter() {
+                let campaign_type_id = record.campaign_type_id();
+                let quest_id = unpack_u32(record.quest_id().as_slice());
+                let previous_count = input_user_data
+                    .as_ref()
+                    .map(|data| data.submission_count(campaign_type_id.as_slice(), quest_id))
+                    .unwrap_or(0);
+                if output_user_data.submission_count(campaign_type_id.as_slice(), quest_id)
+                    <= previous_count
+                {
+                    continue;
+                }
+
+                // Only the campaign knows the quest's prerequisites, so every new submission needs it
+                let campaign_data = find_campaign_data_in_deps(context, campaign_type_id.as_slice())
+                    .ok_or_else(|| {
+                        debug_trace!("Submission requires the campaign cell as a cell dep");
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                let quest = campaign_data.find_quest(quest_id).ok_or_else(|| {
+                    debug_trace!("Quest {} not found in campaign", quest_id);
+                    DeterministicError::BusinessRuleViolation
+                })?;
+                if let Some(prerequisite_id) =
+                    campaign_data.missing_prerequisite(&quest, user_type_id.as_slice())
+                {
+                    debug_trace!(
+                        "Quest {} requires approval of prerequisite quest {}",
+                        quest_id,
+                        prerequisite_id
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
+
+        // **Resubmission validation**: A quest can only be submitted again after every
+        // earlier submission was rejected, and at most `max_resubmissions` times; every output
+        // user cell is checked against the input with the same type script
+        pub fn resubmission_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            match context.input_cells.get_custom("user") {
+                Some(input_user_cells) if !input_user_cells.is_empty() => {
+                    for (input_cell, output_cell) in
+                        pair_user_cells(input_user_cells, output_user_cells)?
+                    {
+                        let input_user_data = UserData::from_slice(&input_cell.data)
+                            .map_err(|_| DeterministicError::Encoding)?;
+                        validate_user_resubmissions(context, Some(&input_user_data), output_cell)?;
+                    }
+                }
+                // New users have no earlier submissions
+                _ => {
+                    for output_cell in output_user_cells.iter() {
+                        validate_user_resubmissions(context, None, output_cell)?;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        fn validate_user_resubmissions(
+            context: &TransactionContext<RuleBasedClassifier>,
+            input_user_data: Option<&UserData>,
+            output_cell: &CellInfo,
+        ) -> Result<(), DeterministicError> {
+            let output_user_data = UserData::from_slice(&output_cell.data)
+                .map_err(|_| DeterministicError::Encoding)?;
+
+            for record in output_user_data.submission_records().into_iter() {
+                let campaign_type_id = record.campaign_type_id();
+                let quest_id = unpack_u32(record.quest_id().as_slice());
+                let output_count =
+                    output_user_data.submission_count(campaign_type_id.as_slice(), quest_id);
+                let previous_count = input_user_data
+                    .map(|data| data.submission_count(campaign_type_id.as_slice(), quest_id))
+                    .unwrap_or(0);
+
+                if output_count <= previous_count {
+                    continue;
+                }
+                if output_count > previous_count + 1 {
+                    debug_trace!("More than one new submission for quest {}", quest_id);
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                if previous_count == 0 {
+                    continue;
+                }
+
+                // Resubmission: the campaign must show every earlier submission as rejected
+                let campaign_data = find_campaign_data_in_deps(context, campaign_type_id.as_slice())
+                    .ok_or_else(|| {
+                        debug_trace!("Resubmission requires the campaign cell as a cell dep");
+                        DeterministicError::CellRelationshipRuleViolation
+                    })?;
+                let quest = campaign_data.find_quest(quest_id).ok_or_else(|| {
+                    debug_trace!("Quest {} not found in campaign", quest_id);
+                    DeterministicError::BusinessRuleViolation
+                })?;
+                let user_type_id = user_type_id_of(output_cell).ok_or(DeterministicError::Encoding)?;
+                let rejection_count = quest.rejection_count_of(user_type_id.as_slice());
+                if rejection_count != previous_count {
+                    debug_trace!(
+                        "Quest {} has {} submissions but {} rejections",
+                        quest_id,
+                        previous_count,
+                        rejection_count
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+                if rejection_count > quest.max_resubmissions_u32() {
+                    debug_trace!(
+                        "Resubmission limit of {} reached for quest {}",
+                        quest.max_resubmissions_u32(),
+                        quest_id
+                    );
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod complete_quest {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.complete_quest".to_vec())
+            .with_arguments(2)
+            // Protocol cells not allowed in quest completion
+            .with_custom_cell(
+                "protocol",
+                CellCountConstraint::exactly(0),
+                CellCountConstraint::exactly(0),
+            )
+            // Campaign cells: the campaign type script validates the completion itself
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells: the completing user is updated in place
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::at_least(1),
+                CellCountConstraint::at_least(1),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during quest completion".to_string(),
+                vec!["user".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "completion_update_validation".to_string(),
+                "Only the points balance of the user may change on quest completion".to_string(),
+                vec!["user".to_string()],
+                business_logic::completion_update_validation,
+            )
+    }
+
+    pub mod business_logic {
+        use crate::recipes::helper::pair_user_cells;
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use ckboost_shared::transaction_context::TransactionContext;
+        use ckboost_shared::types::UserData;
+        use ckboost_shared::UserDataExt;
+        use molecule::prelude::*;
+
+        // **Completion update validation**: Every user cell is recreated with the same type
+        // script, and only its total_points_earned may grow
+        pub fn completion_update_validation(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_user_cells = context
+                .input_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .ok_or(DeterministicError::CellCountViolation)?;
+
+            for (input_cell, output_cell) in pair_user_cells(input_user_cells, output_user_cells)? {
+                let input_user_data =
+                    UserData::from_slice(&input_cell.data).map_err(|_| DeterministicError::Encoding)?;
+                let output_user_data =
+                    UserData::from_slice(&output_cell.data).map_err(|_| DeterministicError::Encoding)?;
+
+                if input_user_data.verification_data().as_slice()
+                    != output_user_data.verification_data().as_slice()
+                    || !input_user_data.has_same_submission_history(&output_user_data)
+                    || input_user_data.last_activity_timestamp().as_slice()
+                        != output_user_data.last_activity_timestamp().as_slice()
+                {
+                    debug_trace!("User data other than points changed during quest completion");
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+
+                if output_user_data.total_points_earned_u128()
+                    < input_user_data.total_points_earned_u128()
+                {
+                    debug_trace!("User points decreased during quest completion");
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod approve_completion {
+    use super::common;
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.approve_completion".to_vec())
+            .with_arguments(3)
+            // Campaign cells: the campaign type script validates the approval itself
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells: may be spent as submission proof, but must be recreated
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::at_least(0),
+                CellCountConstraint::at_least(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during approval".to_string(),
+                vec!["user".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "user_data_unchanged".to_string(),
+                "User cells spent as submission proof must keep their data".to_string(),
+                vec!["user".to_string()],
+                business_logic::user_data_unchanged,
+            )
+    }
+
+    pub mod business_logic {
+        use ckb_deterministic::cell_classifier::RuleBasedClassifier;
+        use ckb_deterministic::debug_trace;
+        use ckb_deterministic::errors::Error as DeterministicError;
+        use ckboost_shared::transaction_context::TransactionContext;
+
+        // **User data unchanged**: Review only reads user cells, so every spent user cell is
+        // recreated with the same type script and data
+        pub fn user_data_unchanged(
+            context: &TransactionContext<RuleBasedClassifier>,
+        ) -> Result<(), DeterministicError> {
+            let input_user_cells = context
+                .input_cells
+                .get_custom("user")
+                .map(|cells| cells.as_slice())
+                .unwrap_or(&[]);
+            let output_user_cells = context
+                .output_cells
+                .get_custom("user")
+                .map(|cells| cells.as_slice())
+                .unwrap_or(&[]);
+
+            for (input_cell, output_cell) in pair_user_cells(input_user_cells, output_user_cells)? {
+                if input_cell.data != output_cell.data {
+                    debug_trace!("User cell data changed during approval");
+                    return Err(DeterministicError::BusinessRuleViolation);
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+pub mod reject_submission {
+    use super::{approve_completion, common};
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.reject_submission".to_vec())
+            .with_arguments(4)
+            // Campaign cells: the campaign type script validates the rejection itself
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells: may be spent as submission proof, but must be recreated
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::at_least(0),
+                CellCountConstraint::at_least(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during rejection".to_string(),
+                vec!["user".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "user_data_unchanged".to_string(),
+                "User cells spent as submission proof must keep their data".to_string(),
+                vec!["user".to_string()],
+                approve_completion::business_logic::user_data_unchanged,
+            )
+    }
+}
+
+pub mod approve_sub_tasks {
+    use super::{approve_completion, common};
+    use alloc::{string::ToString, vec};
+    use ckb_deterministic::{
+        cell_classifier::RuleBasedClassifier,
+        validation::{CellCountConstraint, TransactionValidationRules},
+    };
+
+    pub fn get_rules() -> TransactionValidationRules<RuleBasedClassifier> {
+        TransactionValidationRules::new(b"CKBoostCampaign.approve_sub_tasks".to_vec())
+            .with_arguments(4)
+            // Campaign cells: the campaign type script validates the sub-task approval itself
+            .with_custom_cell(
+                "campaign",
+                CellCountConstraint::exactly(1),
+                CellCountConstraint::exactly(1),
+            )
+            // User cells: may be spent as submission proof, but must be recreated
+            .with_custom_cell(
+                "user",
+                CellCountConstraint::at_least(0),
+                CellCountConstraint::at_least(0),
+            )
+            .with_cell_relationship(
+                "script_immutability".to_string(),
+                "Script immutability must be maintained during sub-task approval".to_string(),
+                vec!["user".to_string()],
+                common::script_immutability,
+            )
+            .with_business_rule(
+                "user_data_unchanged".to_string(),
+                "User cells spent as submission proof must keep their data".to_string(),
+                vec!["user".to_string()],
+                approve_completion::business_logic::user_data_unchanged,
+            )
     }
 }
 
@@ -195,5 +1483,12 @@ pub fn get_all_rules() -> Vec<TransactionValidationRules<RuleBasedClassifier>> {
     vec![
         update_user_verification::get_rules(),
         submit_quest::get_rules(),
+        update_user::get_rules(),
+        archive_submissions::get_rules(),
+        reveal_submission::get_rules(),
+        complete_quest::get_rules(),
+        approve_completion::get_rules(),
+        reject_submission::get_rules(),
+        approve_sub_tasks::get_rules(),
     ]
 }
\ No newline at end of file
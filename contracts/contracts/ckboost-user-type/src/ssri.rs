@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use ckb_deterministic::{
     cell_classifier::RuleBasedClassifier, transaction_context::TransactionContext,
 };
@@ -11,16 +12,30 @@ use ckb_std::ckb_types::packed::Transaction;
 pub trait CKBoostUser {
     /// Update user verification status
     /// 
+    /// Only the verification data of the existing user cell is replaced. New attestations
+    /// must be signed by a registered attestor unless a whitelisted endorser also spends
+    /// one of its cells in the transaction, and only an endorser can clear verified bits.
+    /// 
     /// # Arguments
     /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `user_data` - The current user data
     /// * `user_verification_data` - The user verification data including profile and proof
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the user cell updated
     fn update_user_verification(
+        tx: Option<Transaction>,
+        user_data: UserData,
         user_verification_data: UserVerificationData,
-    ) -> Result<(), Error>;
+    ) -> Result<Transaction, Error>;
     
     /// Verify user verification update transaction in Type Script
     /// This method is called automatically by the type script to validate transactions
-    fn verify_update_user_verification() -> Result<(), Error>;
+    fn verify_update_user_verification(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
     
     /// Submit a quest completion
     /// 
@@ -43,7 +58,10 @@ pub trait CKBoostUser {
         context: &TransactionContext<RuleBasedClassifier>,
     ) -> Result<(), Error>;
     
-    /// Update user data (for future extensions)
+    /// Update the non-sensitive fields of the user's own cell
+    /// 
+    /// Total points earned, submission records and verification data must be left as they
+    /// are; those only change through their dedicated methods.
     /// 
     /// # Arguments
     /// 
@@ -63,4 +81,83 @@ pub trait CKBoostUser {
     fn verify_update_user(
         context: &TransactionContext<RuleBasedClassifier>,
     ) -> Result<(), Error>;
-}
\ No newline at end of file
+    
+    /// Compact the oldest submission records into the archive hash
+    /// 
+    /// Only records of quests the user has been approved for can be archived, so the
+    /// campaign cells of the archived records must be added as cell deps.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `user_data` - The current user data
+    /// * `count` - Number of records to archive, oldest first
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the user cell updated
+    fn archive_submissions(
+        tx: Option<Transaction>,
+        user_data: UserData,
+        count: u32,
+    ) -> Result<Transaction, Error>;
+    
+    /// Verify submission archival transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_archive_submissions(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+    
+    /// Reveal the content of a committed submission record
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tx` - Optional existing transaction to build upon
+    /// * `user_data` - The current user data
+    /// * `index` - Index of the committed record in `submission_records`
+    /// * `salt` - Salt used when the commitment was created
+    /// * `content_hash` - Hash of the off-chain content, zero when `content` is inline
+    /// * `content` - Submission content, or its URI in content-hash mode, matching the commitment
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a transaction with the user cell updated
+    fn reveal_submission(
+        tx: Option<Transaction>,
+        user_data: UserData,
+        index: u32,
+        salt: [u8; 32],
+        content_hash: [u8; 32],
+        content: Vec<u8>,
+    ) -> Result<Transaction, Error>;
+    
+    /// Verify submission reveal transaction in Type Script
+    /// This method is called automatically by the type script to validate transactions
+    fn verify_reveal_submission(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+    
+    /// Verify the user cell update of a quest completion in Type Script
+    /// The campaign type script validates the completion; this only guards the user cell
+    fn verify_complete_quest(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+    
+    /// Verify user cells spent as submission proof in an approval transaction
+    /// The campaign type script validates the approval; this only guards the user cells
+    fn verify_approve_completion(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+    
+    /// Verify user cells spent as submission proof in a rejection transaction
+    /// The campaign type script validates the rejection; this only guards the user cells
+    fn verify_reject_submission(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+    
+    /// Verify user cells spent as submission proof in a sub-task approval transaction
+    /// The campaign type script validates the approval; this only guards the user cells
+    fn verify_approve_sub_tasks(
+        context: &TransactionContext<RuleBasedClassifier>,
+    ) -> Result<(), Error>;
+}
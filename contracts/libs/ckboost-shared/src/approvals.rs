@@ -0,0 +1,206 @@
+use crate::campaign_data::{unpack_u32, CampaignData, CampaignDataExt, QuestDataExt};
+use crate::error::Error;
+use crate::generated::ckboost::ApprovalProof;
+use crate::rewards::{merge_points, merge_rewarded, RewardEntitlement};
+use alloc::vec::Vec;
+use blake2b_ref::{Blake2b, Blake2bBuilder};
+use ckb_std::{
+    ckb_constants::Source,
+    high_level::{load_cell_type_hash, load_witness_args, QueryIter},
+};
+use molecule::prelude::*;
+use sparse_merkle_tree::{traits::Hasher, CompiledMerkleProof, H256};
+
+/// Blake2b with CKB's personalization, the hasher of the approvals SMT
+pub struct CkbBlake2bHasher(Blake2b);
+
+impl Default for CkbBlake2bHasher {
+    fn default() -> Self {
+        Self(Blake2bBuilder::new(32).personal(b"ckb-default-hash").build())
+    }
+}
+
+impl Hasher for CkbBlake2bHasher {
+    fn write_h256(&mut self, h: &H256) {
+        self.0.update(h.as_slice());
+    }
+
+    fn write_byte(&mut self, b: u8) {
+        self.0.update(&[b][..]);
+    }
+
+    fn finish(self) -> H256 {
+        let mut hash = [0u8; 32];
+        self.0.finalize(&mut hash);
+        hash.into()
+    }
+}
+
+/// SMT key of a user, their user type ID
+pub fn approval_key(user_type_id: &[u8]) -> H256 {
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&user_type_id[0..32]);
+    key.into()
+}
+
+/// SMT value of an approved user; users never approved hold the zero value
+pub fn approved_value() -> H256 {
+    let mut value = [0u8; 32];
+    value[0] = 1;
+    value.into()
+}
+
+/// Root of the tree holding `value` for every user, as computed from `proof`
+fn root_with(user_type_ids: &[Vec<u8>], value: H256, proof: &[u8]) -> Option<H256> {
+    let leaves = user_type_ids
+        .iter()
+        .map(|user_type_id| (approval_key(user_type_id), value))
+        .collect();
+    CompiledMerkleProof(proof.to_vec())
+        .compute_root::<CkbBlake2bHasher>(leaves)
+        .ok()
+}
+
+/// Check that `proof` takes `input_root` to `output_root` by approving exactly `user_type_ids`
+///
+/// The same proof shows the users absent under the input root and approved under the
+/// output root, so no user can be approved twice and no other leaf can change.
+pub fn verify_approval_transition(
+    input_root: &[u8],
+    output_root: &[u8],
+    user_type_ids: &[Vec<u8>],
+    proof: &[u8],
+) -> bool {
+    if user_type_ids.is_empty() || user_type_ids.iter().any(|user_type_id| user_type_id.len() != 32) {
+        return false;
+    }
+    let distinct = user_type_ids
+        .iter()
+        .enumerate()
+        .all(|(i, user_type_id)| !user_type_ids[..i].contains(user_type_id));
+    if !distinct {
+        return false;
+    }
+    let before = root_with(user_type_ids, H256::zero(), proof);
+    let after = root_with(user_type_ids, approved_value(), proof);
+    match (before, after) {
+        (Some(before), Some(after)) => before.as_slice() == input_root && after.as_slice() == output_root,
+        _ => false,
+    }
+}
+
+/// Check that `proof` shows the user approved under `root`
+pub fn verify_approved(root: &[u8], user_type_id: &[u8], proof: &[u8]) -> bool {
+    if user_type_id.len() != 32 {
+        return false;
+    }
+    root_with(&[user_type_id.to_vec()], approved_value(), proof)
+        .map(|computed| computed.as_slice() == root)
+        .unwrap_or(false)
+}
+
+/// Users approved by `proof` in the transition from `input` to `output`
+///
+/// Returns `None` unless the campaign tracks approvals in SMTs and the proof takes the
+/// quest's input root to its output root.
+pub fn proven_approvals(
+    input: &CampaignData,
+    output: &CampaignData,
+    proof: &ApprovalProof,
+) -> Option<Vec<Vec<u8>>> {
+    if !input.tracks_approvals_in_smt() || !output.tracks_approvals_in_smt() {
+        return None;
+    }
+    let quest_id = unpack_u32(proof.quest_id().as_slice());
+    let input_quest = input.find_quest(quest_id)?;
+    let output_quest = output.find_quest(quest_id)?;
+    let user_type_ids: Vec<Vec<u8>> = proof
+        .user_type_ids()
+        .into_iter()
+        .map(|user_type_id| user_type_id.as_slice().to_vec())
+        .collect();
+    if !verify_approval_transition(
+        input_quest.approvals_root().as_slice(),
+        output_quest.approvals_root().as_slice(),
+        &user_type_ids,
+        &proof.proof().raw_data(),
+    ) {
+        return None;
+    }
+    Some(user_type_ids)
+}
+
+/// Participants whose rewards become payable through a verified approval proof
+///
+/// SMT campaigns have no dispute window and no NFT rewards, so every proven user is
+/// owed the input quest's rewards at once.
+pub fn rewarded_by_proof(
+    input: &CampaignData,
+    output: &CampaignData,
+    proof: &ApprovalProof,
+) -> Vec<(Vec<u8>, RewardEntitlement)> {
+    let mut participants: Vec<(Vec<u8>, RewardEntitlement)> = Vec::new();
+    let user_type_ids = match proven_approvals(input, output, proof) {
+        Some(user_type_ids) => user_type_ids,
+        None => return participants,
+    };
+    let quest = match input.find_quest(unpack_u32(proof.quest_id().as_slice())) {
+        Some(quest) => quest,
+        None => return participants,
+    };
+    let entitlement = RewardEntitlement::for_quest(&quest);
+    merge_rewarded(
+        &mut participants,
+        user_type_ids
+            .into_iter()
+            .map(|user_type_id| (user_type_id, entitlement.clone()))
+            .collect(),
+    );
+    participants
+}
+
+/// Points owed through a verified approval proof, the input quest's points per proven user
+pub fn points_owed_by_proof(
+    input: &CampaignData,
+    output: &CampaignData,
+    proof: &ApprovalProof,
+) -> Vec<(Vec<u8>, u128)> {
+    let mut owed: Vec<(Vec<u8>, u128)> = Vec::new();
+    let user_type_ids = match proven_approvals(input, output, proof) {
+        Some(user_type_ids) => user_type_ids,
+        None => return owed,
+    };
+    let quest = match input.find_quest(unpack_u32(proof.quest_id().as_slice())) {
+        Some(quest) => quest,
+        None => return owed,
+    };
+    let points = quest.points_u128();
+    merge_points(
+        &mut owed,
+        user_type_ids
+            .into_iter()
+            .map(|user_type_id| (user_type_id, points))
+            .collect(),
+    );
+    owed
+}
+
+/// Approval proof in the input_type witness of the campaign cell, if any
+pub fn load_approval_proof(campaign_type_hash: &[u8]) -> Result<Option<ApprovalProof>, Error> {
+    let campaign_index = match QueryIter::new(load_cell_type_hash, Source::Input)
+        .position(|type_hash| type_hash.map(|hash| &hash[..] == campaign_type_hash).unwrap_or(false))
+    {
+        Some(index) => index,
+        None => return Ok(None),
+    };
+    let witness_args = match load_witness_args(campaign_index, Source::Input) {
+        Ok(witness_args) => witness_args,
+        Err(_) => return Ok(None),
+    };
+    match witness_args.input_type().to_opt() {
+        Some(input_type) => ApprovalProof::from_slice(&input_type.raw_data())
+            .map(Some)
+            .map_err(|_| Error::Encoding),
+        None => Ok(None),
+    }
+}
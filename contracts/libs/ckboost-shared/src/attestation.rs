@@ -0,0 +1,55 @@
+use crate::generated::ckboost::{AttestorInfo, VerificationAttestation};
+use crate::user_data::VERIFICATION_MANUAL_REVIEW;
+use blake2b_ref::Blake2bBuilder;
+use k256::ecdsa::signature::hazmat::PrehashVerifier;
+use k256::ecdsa::{Signature, VerifyingKey};
+use molecule::prelude::*;
+
+/// Domain separator of attestation messages, so signatures cannot be replayed elsewhere
+const ATTESTATION_DOMAIN: &[u8] = b"ckboost-verification-attestation";
+
+/// Check that `bit` is exactly one verification bit a user can be attested for
+pub fn is_attestable_bit(bit: u8) -> bool {
+    bit.count_ones() == 1 && bit != VERIFICATION_MANUAL_REVIEW
+}
+
+/// Digest an attestor signs to vouch for a user's verification until `expires_at` (ms)
+pub fn attestation_message(user_type_id: &[u8], verification_bit: u8, expires_at: u64) -> [u8; 32] {
+    let mut hasher = Blake2bBuilder::new(32).personal(b"ckb-default-hash").build();
+    hasher.update(ATTESTATION_DOMAIN);
+    hasher.update(user_type_id);
+    hasher.update(&[verification_bit]);
+    hasher.update(&expires_at.to_le_bytes());
+    let mut message = [0u8; 32];
+    hasher.finalize(&mut message);
+    message
+}
+
+/// Expiry (ms) of an attestation
+pub fn expires_at(attestation: &VerificationAttestation) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(attestation.expires_at().as_slice());
+    u64::from_le_bytes(bytes)
+}
+
+/// Check that `attestation` is signed for the user by `attestor`, the registered key of its bit
+pub fn verify_attestation(
+    attestor: &AttestorInfo,
+    user_type_id: &[u8],
+    attestation: &VerificationAttestation,
+) -> bool {
+    let verification_bit = attestation.verification_bit().as_slice()[0];
+    if attestor.verification_bit().as_slice()[0] != verification_bit {
+        return false;
+    }
+    let verifying_key = match VerifyingKey::from_sec1_bytes(&attestor.pubkey().raw_data()) {
+        Ok(verifying_key) => verifying_key,
+        Err(_) => return false,
+    };
+    let signature = match Signature::from_slice(&attestation.signature().raw_data()) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    let message = attestation_message(user_type_id, verification_bit, expires_at(attestation));
+    verifying_key.verify_prehash(&message, &signature).is_ok()
+}
@@ -0,0 +1,26 @@
+use crate::campaign_data::{CampaignData, CampaignDataExt};
+use crate::protocol_data::{ProtocolData, ProtocolDataExt};
+use ckb_std::{
+    ckb_constants::Source,
+    high_level::{load_cell_lock_hash, QueryIter},
+};
+
+/// Check whether some input cell is locked by a lock hash holding `role` in the campaign
+///
+/// Unlocking an input proves control of its lock, so any manager can authorize
+/// an action by spending one of their own cells alongside the campaign cell.
+pub fn inputs_hold_role(campaign_data: &CampaignData, role: u8) -> bool {
+    QueryIter::new(load_cell_lock_hash, Source::Input)
+        .any(|lock_hash| campaign_data.has_role(&lock_hash, role))
+}
+
+/// Check whether some input cell is locked by this lock hash
+pub fn inputs_include_lock(lock_hash: &[u8]) -> bool {
+    QueryIter::new(load_cell_lock_hash, Source::Input).any(|input_lock_hash| input_lock_hash.as_slice() == lock_hash)
+}
+
+/// Check whether some input cell is locked by an endorser on the protocol whitelist
+pub fn inputs_hold_whitelisted_endorser(protocol_data: &ProtocolData) -> bool {
+    QueryIter::new(load_cell_lock_hash, Source::Input)
+        .any(|lock_hash| protocol_data.is_endorser_whitelisted(&lock_hash))
+}
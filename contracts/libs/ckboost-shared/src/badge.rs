@@ -0,0 +1,40 @@
+use crate::generated::ckboost::SporeData;
+use alloc::vec::Vec;
+use molecule::prelude::*;
+
+/// Content type of completion badges, a DOB/0 digital object
+pub const BADGE_CONTENT_TYPE: &[u8] = b"dob/0";
+
+/// DOB/0 content of the completion badge of a quest
+///
+/// The DNA is the campaign type ID followed by the little-endian quest ID, hex
+/// encoded, so a badge can be traced back to the campaign and quest it proves.
+pub fn badge_content(campaign_type_id: &[u8], quest_id: u32) -> Vec<u8> {
+    let mut content = Vec::from(&b"{\"dna\":\""[..]);
+    for byte in campaign_type_id.iter().chain(quest_id.to_le_bytes().iter()) {
+        content.push(hex_digit(byte >> 4));
+        content.push(hex_digit(byte & 0x0f));
+    }
+    content.extend_from_slice(b"\"}");
+    content
+}
+
+/// Content of a spore cell if it is a completion badge of this cluster
+pub fn parse_badge(data: &[u8], cluster_id: &[u8]) -> Option<Vec<u8>> {
+    let spore_data = SporeData::from_slice(data).ok()?;
+    if spore_data.content_type().raw_data().as_ref() != BADGE_CONTENT_TYPE {
+        return None;
+    }
+    let spore_cluster_id = spore_data.cluster_id().to_opt()?;
+    if spore_cluster_id.raw_data().as_ref() != cluster_id {
+        return None;
+    }
+    Some(spore_data.content().raw_data().to_vec())
+}
+
+fn hex_digit(nibble: u8) -> u8 {
+    match nibble {
+        0..=9 => b'0' + nibble,
+        _ => b'a' + nibble - 10,
+    }
+}
@@ -0,0 +1,549 @@
+pub use crate::generated::ckboost::{
+    ApprovalRecord, CampaignData, CampaignManager, ConnectedTypeID, NftAward, QuestData, SubTaskApproval,
+    SubmissionRejection,
+};
+use crate::generated::ckboost::{Script, Uint32, Uint64};
+use crate::user_data::VERIFICATION_MANUAL_REVIEW;
+use alloc::vec::Vec;
+use molecule::prelude::*;
+
+/// Decode a little-endian u32 from a molecule Uint32 slice
+pub fn unpack_u32(slice: &[u8]) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&slice[0..4]);
+    u32::from_le_bytes(bytes)
+}
+
+/// Decode a little-endian u64 from a molecule Uint64 slice
+pub fn unpack_u64(slice: &[u8]) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&slice[0..8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Decode a little-endian u128 from a molecule Uint128 slice
+pub fn unpack_u128(slice: &[u8]) -> u128 {
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&slice[0..16]);
+    u128::from_le_bytes(bytes)
+}
+
+/// Parse the ConnectedTypeID stored in a type script's args
+///
+/// Campaign and user cells carry a 76-byte ConnectedTypeID as type args.
+/// Returns `None` for any other args layout.
+pub fn connected_type_id_from_args(args: &[u8]) -> Option<ConnectedTypeID> {
+    if args.len() != 76 {
+        return None;
+    }
+    ConnectedTypeID::from_slice(args).ok()
+}
+
+/// Role flag: edit campaign metadata and quests
+pub const ROLE_EDIT_METADATA: u8 = 1;
+/// Role flag: approve, reject, revoke and finalize quest completions
+pub const ROLE_APPROVE_COMPLETIONS: u8 = 2;
+/// Role flag: move funds held by the campaign lock
+pub const ROLE_MANAGE_FUNDS: u8 = 4;
+/// Every role, as held by the endorser
+pub const ALL_ROLES: u8 = ROLE_EDIT_METADATA | ROLE_APPROVE_COMPLETIONS | ROLE_MANAGE_FUNDS;
+
+/// Quest layout: quests are stored inline in `CampaignData::quests`
+pub const QUEST_LAYOUT_INLINE: u8 = 0;
+/// Quest layout: every quest lives in its own quest cell linked to the campaign
+pub const QUEST_LAYOUT_CELLS: u8 = 1;
+
+/// Approval tracking: accepted users are listed in `accepted_submission_user_type_ids`
+pub const APPROVAL_TRACKING_LIST: u8 = 0;
+/// Approval tracking: accepted users are leaves of the quest's `approvals_root` SMT
+pub const APPROVAL_TRACKING_SMT: u8 = 1;
+
+/// Campaign status: live and accepting quest submissions
+pub const CAMPAIGN_STATUS_ACTIVE: u8 = 4;
+/// Quest status: open for submissions
+pub const QUEST_STATUS_ACTIVE: u8 = 1;
+
+/// Extension trait for CampaignData with helpers shared by the campaign scripts
+pub trait CampaignDataExt {
+    /// Find a quest by its quest_id
+    fn find_quest(&self, quest_id: u32) -> Option<QuestData>;
+
+    /// Total completions across all quests as u32
+    fn total_completions_u32(&self) -> u32;
+
+    /// Participants count as u32
+    fn participants_count_u32(&self) -> u32;
+
+    /// Approval dispute window in seconds, 0 when disabled
+    fn approval_dispute_window_u64(&self) -> u64;
+
+    /// Latest header timestamp (ms) the dispute window rules relied on, 0 before any
+    fn last_recorded_at_u64(&self) -> u64;
+
+    /// Rebuild with the campaign clock moved to `timestamp` (ms)
+    fn with_recorded_at(&self, timestamp: u64) -> CampaignData;
+
+    /// Rebuild the quest list with `quest` replacing the quest of the same quest_id
+    fn with_quest(&self, quest: QuestData) -> CampaignData;
+
+    /// Sum of the completion counts of all quests
+    fn quest_completions_sum(&self) -> u32;
+
+    /// Number of distinct users listed as accepted for at least one quest, 0 in SMT campaigns
+    fn distinct_participants(&self) -> u32;
+
+    /// Rebuild with total_completions and participants_count recounted from the quests
+    fn with_recounted_stats(&self) -> CampaignData;
+
+    /// Check whether quests live in separate quest cells
+    fn uses_quest_cells(&self) -> bool;
+
+    /// Check whether approvals are tracked in per-quest SMT roots
+    fn tracks_approvals_in_smt(&self) -> bool;
+
+    /// Next quest ID to allocate as u32
+    fn next_quest_id_u32(&self) -> u32;
+
+    /// Verification bits a user must hold to submit, from the metadata's requirements
+    fn required_verifications(&self) -> u8;
+
+    /// Check that quest IDs are strictly ascending and all allocated below next_quest_id
+    fn has_allocated_quest_ids(&self) -> bool;
+
+    /// Check whether the campaign is active and so accepts quest submissions
+    fn accepts_submissions(&self) -> bool;
+
+    /// Role flags held by a lock hash; the endorser holds every role
+    fn roles_of(&self, lock_hash: &[u8]) -> u8;
+
+    /// Check whether a lock hash holds `role`
+    fn has_role(&self, lock_hash: &[u8], role: u8) -> bool {
+        self.roles_of(lock_hash) & role == role
+    }
+
+    /// First prerequisite of `quest` the user has not been approved for, if any
+    ///
+    /// A prerequisite that no longer exists in the campaign counts as unmet.
+    fn missing_prerequisite(&self, quest: &QuestData, user_type_id: &[u8]) -> Option<u32> {
+        quest.prerequisite_quest_ids_u32().into_iter().find(|prerequisite_id| {
+            !self
+                .find_quest(*prerequisite_id)
+                .map(|prerequisite| prerequisite.is_accepted(user_type_id))
+                .unwrap_or(false)
+        })
+    }
+}
+
+impl CampaignDataExt for CampaignData {
+    fn find_quest(&self, quest_id: u32) -> Option<QuestData> {
+        self.quests()
+            .into_iter()
+            .find(|quest| quest.quest_id_u32() == quest_id)
+    }
+
+    fn total_completions_u32(&self) -> u32 {
+        unpack_u32(self.total_completions().as_slice())
+    }
+
+    fn participants_count_u32(&self) -> u32 {
+        unpack_u32(self.participants_count().as_slice())
+    }
+
+    fn approval_dispute_window_u64(&self) -> u64 {
+        unpack_u64(self.approval_dispute_window().as_slice())
+    }
+
+    fn last_recorded_at_u64(&self) -> u64 {
+        unpack_u64(self.last_recorded_at().as_slice())
+    }
+
+    fn with_recorded_at(&self, timestamp: u64) -> CampaignData {
+        self.clone()
+            .as_builder()
+            .last_recorded_at(Uint64::from(timestamp.to_le_bytes()))
+            .build()
+    }
+
+    fn quest_completions_sum(&self) -> u32 {
+        self.quests()
+            .into_iter()
+            .fold(0u32, |total, quest| total.saturating_add(quest.completion_count_u32()))
+    }
+
+    fn distinct_participants(&self) -> u32 {
+        let mut participants: Vec<Vec<u8>> = Vec::new();
+        for quest in self.quests().into_iter() {
+            for user_type_id in quest.accepted_submission_user_type_ids().into_iter() {
+                let user_type_id = user_type_id.as_slice().to_vec();
+                if !participants.contains(&user_type_id) {
+                    participants.push(user_type_id);
+                }
+            }
+        }
+        participants.len() as u32
+    }
+
+    fn with_recounted_stats(&self) -> CampaignData {
+        self.clone()
+            .as_builder()
+            .total_completions(Uint32::from(self.quest_completions_sum().to_le_bytes()))
+            .participants_count(Uint32::from(self.distinct_participants().to_le_bytes()))
+            .build()
+    }
+
+    fn uses_quest_cells(&self) -> bool {
+        self.quest_layout().as_slice()[0] == QUEST_LAYOUT_CELLS
+    }
+
+    fn tracks_approvals_in_smt(&self) -> bool {
+        self.approval_tracking().as_slice()[0] == APPROVAL_TRACKING_SMT
+    }
+
+    fn next_quest_id_u32(&self) -> u32 {
+        unpack_u32(self.next_quest_id().as_slice())
+    }
+
+    fn required_verifications(&self) -> u8 {
+        self.metadata()
+            .verification_requirements()
+            .into_iter()
+            .fold(0, |required, bits| required | bits.as_slice()[0])
+            & !VERIFICATION_MANUAL_REVIEW
+    }
+
+    fn accepts_submissions(&self) -> bool {
+        self.status().as_slice()[0] == CAMPAIGN_STATUS_ACTIVE
+    }
+
+    fn has_allocated_quest_ids(&self) -> bool {
+        let mut previous: Option<u32> = None;
+        for quest in self.quests().into_iter() {
+            let quest_id = quest.quest_id_u32();
+            if previous.map(|previous| quest_id <= previous).unwrap_or(false)
+                || quest_id >= self.next_quest_id_u32()
+            {
+                return false;
+            }
+            previous = Some(quest_id);
+        }
+        true
+    }
+
+    fn roles_of(&self, lock_hash: &[u8]) -> u8 {
+        if self.endorser().endorser_lock_hash().as_slice() == lock_hash {
+            return ALL_ROLES;
+        }
+        self.managers()
+            .into_iter()
+            .filter(|manager| manager.lock_hash().as_slice() == lock_hash)
+            .fold(0, |roles, manager| roles | manager.roles().as_slice()[0])
+    }
+
+    fn with_quest(&self, quest: QuestData) -> CampaignData {
+        let quest_id = quest.quest_id_u32();
+        let quests: Vec<QuestData> = self
+            .quests()
+            .into_iter()
+            .map(|existing| {
+                if existing.quest_id_u32() == quest_id {
+                    quest.clone()
+                } else {
+                    existing
+                }
+            })
+            .collect();
+        self.clone()
+            .as_builder()
+            .quests(
+                crate::generated::ckboost::QuestDataVec::new_builder()
+                    .extend(quests)
+                    .build(),
+            )
+            .build()
+    }
+}
+
+/// Extension trait for QuestData with helpers shared by the campaign scripts
+pub trait QuestDataExt {
+    /// Quest ID as u32
+    fn quest_id_u32(&self) -> u32;
+
+    /// Completion count as u32
+    fn completion_count_u32(&self) -> u32;
+
+    /// Quest points as u128
+    fn points_u128(&self) -> u128;
+
+    /// Check whether a user type ID is in the accepted submission list
+    fn is_accepted(&self, user_type_id: &[u8]) -> bool;
+
+    /// Quest status as u8
+    fn status_u8(&self) -> u8;
+
+    /// Check whether the quest is open for submissions
+    fn is_active(&self) -> bool {
+        self.status_u8() == QUEST_STATUS_ACTIVE
+    }
+
+    /// Resubmissions allowed after a rejection as u32
+    fn max_resubmissions_u32(&self) -> u32;
+
+    /// Find the rejection entry of a user type ID
+    fn find_rejection(&self, user_type_id: &[u8]) -> Option<SubmissionRejection>;
+
+    /// Find the approval of a user type ID that is still inside the dispute window
+    fn find_pending_approval(&self, user_type_id: &[u8]) -> Option<ApprovalRecord>;
+
+    /// Rebuild the quest without the rejection entry of a user type ID
+    fn clear_rejection(&self, user_type_id: &[u8]) -> QuestData;
+
+    /// Prerequisite quest IDs as u32 values
+    fn prerequisite_quest_ids_u32(&self) -> Vec<u32>;
+
+    /// IDs of the quest's sub-tasks
+    fn sub_task_ids(&self) -> Vec<u8>;
+
+    /// Check that no two sub-tasks of the quest share an ID
+    fn has_unique_sub_task_ids(&self) -> bool {
+        let ids = self.sub_task_ids();
+        ids.iter().enumerate().all(|(index, id)| !ids[..index].contains(id))
+    }
+
+    /// Sub-task IDs approved so far for a user type ID
+    fn approved_sub_task_ids_of(&self, user_type_id: &[u8]) -> Vec<u8>;
+
+    /// Rebuild the quest with `sub_task_ids` added to a user's approved sub-tasks, `None` when
+    /// `user_type_id` is not 32 bytes
+    fn with_sub_tasks_approved(&self, user_type_id: &[u8], sub_task_ids: &[u8]) -> Option<QuestData>;
+
+    /// Rebuild the quest without the sub-task progress of a user type ID
+    fn clear_sub_task_approvals(&self, user_type_id: &[u8]) -> QuestData;
+
+    /// NFT type scripts offered by the quest's `rewards_on_completion`, without duplicates
+    fn nft_pool(&self) -> Vec<Script>;
+
+    /// NFT type scripts awarded to a user type ID
+    fn awarded_nfts_of(&self, user_type_id: &[u8]) -> Vec<Script>;
+
+    /// Rebuild the quest with an NFT awarded to a user type ID, `None` when it is not 32 bytes
+    fn with_nft_awarded(&self, user_type_id: &[u8], nft_script: Script) -> Option<QuestData>;
+
+    /// Rebuild the quest without the NFT awards of a user type ID
+    fn clear_nft_awards(&self, user_type_id: &[u8]) -> QuestData;
+
+    /// Check whether an NFT, given as serialized type script, is already awarded
+    fn is_nft_awarded(&self, nft_script: &[u8]) -> bool;
+
+    /// NFTs of the pool that no user has been awarded yet, in pool order
+    fn unawarded_nfts(&self) -> Vec<Script> {
+        self.nft_pool()
+            .into_iter()
+            .filter(|nft_script| !self.is_nft_awarded(nft_script.as_slice()))
+            .collect()
+    }
+
+    /// Check whether every sub-task is approved for a user; quests without sub-tasks always are
+    fn sub_tasks_complete(&self, user_type_id: &[u8]) -> bool {
+        let approved = self.approved_sub_task_ids_of(user_type_id);
+        self.sub_task_ids().iter().all(|id| approved.contains(id))
+    }
+
+    /// Number of times a user's submission has been rejected, 0 if never
+    fn rejection_count_of(&self, user_type_id: &[u8]) -> u32 {
+        self.find_rejection(user_type_id)
+            .map(|rejection| unpack_u32(rejection.rejection_count().as_slice()))
+            .unwrap_or(0)
+    }
+}
+
+impl QuestDataExt for QuestData {
+    fn quest_id_u32(&self) -> u32 {
+        unpack_u32(self.quest_id().as_slice())
+    }
+
+    fn completion_count_u32(&self) -> u32 {
+        unpack_u32(self.completion_count().as_slice())
+    }
+
+    fn points_u128(&self) -> u128 {
+        unpack_u128(self.points().as_slice())
+    }
+
+    fn is_accepted(&self, user_type_id: &[u8]) -> bool {
+        self.accepted_submission_user_type_ids()
+            .into_iter()
+            .any(|accepted_id| accepted_id.as_slice() == user_type_id)
+    }
+
+    fn max_resubmissions_u32(&self) -> u32 {
+        unpack_u32(self.max_resubmissions().as_slice())
+    }
+
+    fn status_u8(&self) -> u8 {
+        self.status().as_slice()[0]
+    }
+
+    fn prerequisite_quest_ids_u32(&self) -> Vec<u32> {
+        self.prerequisite_quest_ids()
+            .into_iter()
+            .map(|quest_id| unpack_u32(quest_id.as_slice()))
+            .collect()
+    }
+
+    fn clear_rejection(&self, user_type_id: &[u8]) -> QuestData {
+        let remaining: Vec<SubmissionRejection> = self
+            .rejected_submissions()
+            .into_iter()
+            .filter(|rejection| rejection.user_type_id().as_slice() != user_type_id)
+            .collect();
+        self.clone()
+            .as_builder()
+            .rejected_submissions(
+                crate::generated::ckboost::SubmissionRejectionVec::new_builder()
+                    .extend(remaining)
+                    .build(),
+            )
+            .build()
+    }
+
+    fn sub_task_ids(&self) -> Vec<u8> {
+        self.sub_tasks()
+            .into_iter()
+            .map(|sub_task| sub_task.id().as_slice()[0])
+            .collect()
+    }
+
+    fn approved_sub_task_ids_of(&self, user_type_id: &[u8]) -> Vec<u8> {
+        self.sub_task_approvals()
+            .into_iter()
+            .find(|approval| approval.user_type_id().as_slice() == user_type_id)
+            .map(|approval| {
+                approval
+                    .approved_sub_task_ids()
+                    .into_iter()
+                    .map(|id| id.as_slice()[0])
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn with_sub_tasks_approved(&self, user_type_id: &[u8], sub_task_ids: &[u8]) -> Option<QuestData> {
+        let user_type_id_bytes = crate::generated::ckboost::Byte32::from_slice(user_type_id).ok()?;
+        let mut approved_ids = self.approved_sub_task_ids_of(user_type_id);
+        for id in sub_task_ids {
+            if !approved_ids.contains(id) {
+                approved_ids.push(*id);
+            }
+        }
+        let approval = SubTaskApproval::new_builder()
+            .user_type_id(user_type_id_bytes)
+            .approved_sub_task_ids(
+                crate::generated::ckboost::Uint8Vec::new_builder()
+                    .extend(approved_ids.into_iter().map(|id| crate::generated::ckboost::Uint8::from([id])))
+                    .build(),
+            )
+            .build();
+
+        let mut approvals: Vec<SubTaskApproval> = self.sub_task_approvals().into_iter().collect();
+        match approvals
+            .iter()
+            .position(|existing| existing.user_type_id().as_slice() == user_type_id)
+        {
+            Some(position) => approvals[position] = approval,
+            None => approvals.push(approval),
+        }
+        Some(
+            self.clone()
+                .as_builder()
+                .sub_task_approvals(
+                    crate::generated::ckboost::SubTaskApprovalVec::new_builder()
+                        .extend(approvals)
+                        .build(),
+                )
+                .build(),
+        )
+    }
+
+    fn clear_sub_task_approvals(&self, user_type_id: &[u8]) -> QuestData {
+        let remaining: Vec<SubTaskApproval> = self
+            .sub_task_approvals()
+            .into_iter()
+            .filter(|approval| approval.user_type_id().as_slice() != user_type_id)
+            .collect();
+        self.clone()
+            .as_builder()
+            .sub_task_approvals(
+                crate::generated::ckboost::SubTaskApprovalVec::new_builder()
+                    .extend(remaining)
+                    .build(),
+            )
+            .build()
+    }
+
+    fn nft_pool(&self) -> Vec<Script> {
+        let mut pool: Vec<Script> = Vec::new();
+        for asset_list in self.rewards_on_completion().into_iter() {
+            for nft_script in asset_list.nft_assets().into_iter() {
+                if !pool.iter().any(|existing| existing.as_slice() == nft_script.as_slice()) {
+                    pool.push(nft_script);
+                }
+            }
+        }
+        pool
+    }
+
+    fn awarded_nfts_of(&self, user_type_id: &[u8]) -> Vec<Script> {
+        self.nft_awards()
+            .into_iter()
+            .filter(|award| award.user_type_id().as_slice() == user_type_id)
+            .map(|award| award.nft_script())
+            .collect()
+    }
+
+    fn with_nft_awarded(&self, user_type_id: &[u8], nft_script: Script) -> Option<QuestData> {
+        let award = NftAward::new_builder()
+            .user_type_id(crate::generated::ckboost::Byte32::from_slice(user_type_id).ok()?)
+            .nft_script(nft_script)
+            .build();
+        Some(
+            self.clone()
+                .as_builder()
+                .nft_awards(self.nft_awards().as_builder().push(award).build())
+                .build(),
+        )
+    }
+
+    fn clear_nft_awards(&self, user_type_id: &[u8]) -> QuestData {
+        let remaining: Vec<NftAward> = self
+            .nft_awards()
+            .into_iter()
+            .filter(|award| award.user_type_id().as_slice() != user_type_id)
+            .collect();
+        self.clone()
+            .as_builder()
+            .nft_awards(
+                crate::generated::ckboost::NftAwardVec::new_builder()
+                    .extend(remaining)
+                    .build(),
+            )
+            .build()
+    }
+
+    fn is_nft_awarded(&self, nft_script: &[u8]) -> bool {
+        self.nft_awards()
+            .into_iter()
+            .any(|award| award.nft_script().as_slice() == nft_script)
+    }
+
+    fn find_pending_approval(&self, user_type_id: &[u8]) -> Option<ApprovalRecord> {
+        self.pending_approvals()
+            .into_iter()
+            .find(|record| record.user_type_id().as_slice() == user_type_id)
+    }
+
+    fn find_rejection(&self, user_type_id: &[u8]) -> Option<SubmissionRejection> {
+        self.rejected_submissions()
+            .into_iter()
+            .find(|rejection| rejection.user_type_id().as_slice() == user_type_id)
+    }
+}
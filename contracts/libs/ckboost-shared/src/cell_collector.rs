@@ -30,7 +30,8 @@ pub fn create_ckboost_classifier(
         .add_type_code_hash(data.protocol_type_code_hash(), CellClass::custom("protocol"))
         .add_type_code_hash(data.campaign_type_code_hash(), CellClass::custom("campaign"))
         .add_type_code_hash(data.user_type_code_hash(), CellClass::custom("user"))
-        .add_type_code_hash(data.points_udt_type_code_hash(), CellClass::custom("points"));
+        .add_type_code_hash(data.points_udt_type_code_hash(), CellClass::custom("points"))
+        .add_type_code_hash(data.quest_type_code_hash(), CellClass::custom("quest"));
 
     for script in accepted_udt_type_script.iter() {
         let std_script = Script::new_builder()
@@ -92,6 +93,7 @@ pub fn create_mock_ckboost_collector() -> CellCollector<RuleBasedClassifier> {
         .ckb_boost_campaign_type_code_hash(Byte32::from([2u8; 32]))
         .ckb_boost_campaign_lock_code_hash(Byte32::from([12u8; 32]))
         .ckb_boost_user_type_code_hash(Byte32::from([3u8; 32]))
+        .ckb_boost_quest_type_code_hash(Byte32::from([4u8; 32]))
         .accepted_udt_type_scripts(ScriptVec::new_builder().build())
         .accepted_dob_type_scripts(ScriptVec::new_builder().build())
         .build();
@@ -167,6 +167,11 @@ impl From < [Byte ; 1usize] > for Uint8 { fn from (value : [Byte ; 1usize]) -> S
 # [derive (Clone , Debug , Default)] pub struct Uint8VecBuilder (pub (crate) Vec < Uint8 >) ; impl Uint8VecBuilder { pub const ITEM_SIZE : usize = 1 ; pub fn set (mut self , v : Vec < Uint8 >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint8 > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = Uint8 >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < Uint8 > where T : :: core :: convert :: Into < Uint8 > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for Uint8VecBuilder { type Entity = Uint8Vec ; const NAME : & 'static str = "Uint8VecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * self . 0 . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { writer . write_all (& molecule :: pack_number (self . 0 . len () as molecule :: Number)) ? ; for inner in & self . 0 [..] { writer . write_all (inner . as_slice ()) ? ; } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; Uint8Vec :: new_unchecked (inner . into ()) } }
 pub struct Uint8VecIterator (Uint8Vec , usize , usize) ; impl :: core :: iter :: Iterator for Uint8VecIterator { type Item = Uint8 ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for Uint8VecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for Uint8Vec { type Item = Uint8 ; type IntoIter = Uint8VecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; Uint8VecIterator (self , 0 , len) } } impl < 'r > Uint8VecReader < 'r > { pub fn iter < 't > (& 't self) -> Uint8VecReaderIterator < 't , 'r > { Uint8VecReaderIterator (& self , 0 , self . len ()) } } pub struct Uint8VecReaderIterator < 't , 'r > (& 't Uint8VecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for Uint8VecReaderIterator < 't , 'r > { type Item = Uint8Reader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for Uint8VecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
 impl :: core :: iter :: FromIterator < Uint8 > for Uint8Vec { fn from_iter < T : IntoIterator < Item = Uint8 >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < Uint8 >> for Uint8Vec { fn from (v : Vec < Uint8 >) -> Self { Self :: new_builder () . set (v) . build () } }
+# [derive (Clone)] pub struct Uint32Vec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for Uint32Vec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for Uint32Vec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for Uint32Vec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for Uint32Vec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; Uint32Vec :: new_unchecked (v) } } impl Uint32Vec { const DEFAULT_VALUE : [u8 ; 4] = [0 , 0 , 0 , 0 ,] ; pub const ITEM_SIZE : usize = 4 ; pub fn total_size (& self) -> usize { molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * self . item_count () } pub fn item_count (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < Uint32 > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> Uint32 { let start = molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * idx ; let end = start + Self :: ITEM_SIZE ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn as_reader < 'r > (& 'r self) -> Uint32VecReader < 'r > { Uint32VecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for Uint32Vec { type Builder = Uint32VecBuilder ; const NAME : & 'static str = "Uint32Vec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { Uint32Vec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { Uint32VecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { Uint32VecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
+# [derive (Clone , Copy)] pub struct Uint32VecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for Uint32VecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for Uint32VecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for Uint32VecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > Uint32VecReader < 'r > { pub const ITEM_SIZE : usize = 4 ; pub fn total_size (& self) -> usize { molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * self . item_count () } pub fn item_count (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < Uint32Reader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> Uint32Reader < 'r > { let start = molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * idx ; let end = start + Self :: ITEM_SIZE ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } } impl < 'r > molecule :: prelude :: Reader < 'r > for Uint32VecReader < 'r > { type Entity = Uint32Vec ; const NAME : & 'static str = "Uint32VecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { Uint32VecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , _compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let item_count = molecule :: unpack_number (slice) as usize ; if item_count == 0 { if slice_len != molecule :: NUMBER_SIZE { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE , slice_len) ; } return Ok (()) ; } let total_size = molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * item_count ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct Uint32VecBuilder (pub (crate) Vec < Uint32 >) ; impl Uint32VecBuilder { pub const ITEM_SIZE : usize = 4 ; pub fn set (mut self , v : Vec < Uint32 >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = Uint32 >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < Uint32 > where T : :: core :: convert :: Into < Uint32 > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for Uint32VecBuilder { type Entity = Uint32Vec ; const NAME : & 'static str = "Uint32VecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * self . 0 . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { writer . write_all (& molecule :: pack_number (self . 0 . len () as molecule :: Number)) ? ; for inner in & self . 0 [..] { writer . write_all (inner . as_slice ()) ? ; } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; Uint32Vec :: new_unchecked (inner . into ()) } }
+pub struct Uint32VecIterator (Uint32Vec , usize , usize) ; impl :: core :: iter :: Iterator for Uint32VecIterator { type Item = Uint32 ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for Uint32VecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for Uint32Vec { type Item = Uint32 ; type IntoIter = Uint32VecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; Uint32VecIterator (self , 0 , len) } } impl < 'r > Uint32VecReader < 'r > { pub fn iter < 't > (& 't self) -> Uint32VecReaderIterator < 't , 'r > { Uint32VecReaderIterator (& self , 0 , self . len ()) } } pub struct Uint32VecReaderIterator < 't , 'r > (& 't Uint32VecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for Uint32VecReaderIterator < 't , 'r > { type Item = Uint32Reader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for Uint32VecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
+impl :: core :: iter :: FromIterator < Uint32 > for Uint32Vec { fn from_iter < T : IntoIterator < Item = Uint32 >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < Uint32 >> for Uint32Vec { fn from (v : Vec < Uint32 >) -> Self { Self :: new_builder () . set (v) . build () } }
 # [derive (Clone)] pub struct String (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for String { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for String { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for String { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; let raw_data = hex_string (& self . raw_data ()) ; write ! (f , "{}(0x{})" , Self :: NAME , raw_data) } } impl :: core :: default :: Default for String { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; String :: new_unchecked (v) } } impl String { const DEFAULT_VALUE : [u8 ; 4] = [0 , 0 , 0 , 0 ,] ; pub const ITEM_SIZE : usize = 1 ; pub fn total_size (& self) -> usize { molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * self . item_count () } pub fn item_count (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < Byte > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> Byte { let start = molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * idx ; let end = start + Self :: ITEM_SIZE ; Byte :: new_unchecked (self . 0 . slice (start .. end)) } pub fn raw_data (& self) -> molecule :: bytes :: Bytes { self . 0 . slice (molecule :: NUMBER_SIZE ..) } pub fn as_reader < 'r > (& 'r self) -> StringReader < 'r > { StringReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for String { type Builder = StringBuilder ; const NAME : & 'static str = "String" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { String (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { StringReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { StringReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
 # [derive (Clone , Copy)] pub struct StringReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for StringReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for StringReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for StringReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; let raw_data = hex_string (& self . raw_data ()) ; write ! (f , "{}(0x{})" , Self :: NAME , raw_data) } } impl < 'r > StringReader < 'r > { pub const ITEM_SIZE : usize = 1 ; pub fn total_size (& self) -> usize { molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * self . item_count () } pub fn item_count (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < ByteReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> ByteReader < 'r > { let start = molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * idx ; let end = start + Self :: ITEM_SIZE ; ByteReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn raw_data (& self) -> & 'r [u8] { & self . as_slice () [molecule :: NUMBER_SIZE ..] } } impl < 'r > molecule :: prelude :: Reader < 'r > for StringReader < 'r > { type Entity = String ; const NAME : & 'static str = "StringReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { StringReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , _compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let item_count = molecule :: unpack_number (slice) as usize ; if item_count == 0 { if slice_len != molecule :: NUMBER_SIZE { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE , slice_len) ; } return Ok (()) ; } let total_size = molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * item_count ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } Ok (()) } }
 # [derive (Clone , Debug , Default)] pub struct StringBuilder (pub (crate) Vec < Byte >) ; impl StringBuilder { pub const ITEM_SIZE : usize = 1 ; pub fn set (mut self , v : Vec < Byte >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = Byte >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < Byte > where T : :: core :: convert :: Into < Byte > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for StringBuilder { type Entity = String ; const NAME : & 'static str = "StringBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE + Self :: ITEM_SIZE * self . 0 . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { writer . write_all (& molecule :: pack_number (self . 0 . len () as molecule :: Number)) ? ; for inner in & self . 0 [..] { writer . write_all (inner . as_slice ()) ? ; } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; String :: new_unchecked (inner . into ()) } }
@@ -204,9 +209,44 @@ impl :: core :: iter :: FromIterator < QuestSubTaskData > for QuestSubTaskDataVe
 # [derive (Clone)] pub struct QuestMetadata (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for QuestMetadata { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for QuestMetadata { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for QuestMetadata { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "title" , self . title ()) ? ; write ! (f , ", {}: {}" , "short_description" , self . short_description ()) ? ; write ! (f , ", {}: {}" , "long_description" , self . long_description ()) ? ; write ! (f , ", {}: {}" , "requirements" , self . requirements ()) ? ; write ! (f , ", {}: {}" , "difficulty" , self . difficulty ()) ? ; write ! (f , ", {}: {}" , "time_estimate" , self . time_estimate ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for QuestMetadata { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; QuestMetadata :: new_unchecked (v) } } impl QuestMetadata { const DEFAULT_VALUE : [u8 ; 49] = [49 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 32 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 40 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 45 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 6 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn title (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } pub fn short_description (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } pub fn long_description (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } pub fn requirements (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } pub fn difficulty (& self) -> Uint8 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint8 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn time_estimate (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } else { Uint32 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> QuestMetadataReader < 'r > { QuestMetadataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for QuestMetadata { type Builder = QuestMetadataBuilder ; const NAME : & 'static str = "QuestMetadata" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { QuestMetadata (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { QuestMetadataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { QuestMetadataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . title (self . title ()) . short_description (self . short_description ()) . long_description (self . long_description ()) . requirements (self . requirements ()) . difficulty (self . difficulty ()) . time_estimate (self . time_estimate ()) } }
 # [derive (Clone , Copy)] pub struct QuestMetadataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for QuestMetadataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for QuestMetadataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for QuestMetadataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "title" , self . title ()) ? ; write ! (f , ", {}: {}" , "short_description" , self . short_description ()) ? ; write ! (f , ", {}: {}" , "long_description" , self . long_description ()) ? ; write ! (f , ", {}: {}" , "requirements" , self . requirements ()) ? ; write ! (f , ", {}: {}" , "difficulty" , self . difficulty ()) ? ; write ! (f , ", {}: {}" , "time_estimate" , self . time_estimate ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > QuestMetadataReader < 'r > { pub const FIELD_COUNT : usize = 6 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn title (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn short_description (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn long_description (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn requirements (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn difficulty (& self) -> Uint8Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint8Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn time_estimate (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Uint32Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for QuestMetadataReader < 'r > { type Entity = QuestMetadata ; const NAME : & 'static str = "QuestMetadataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { QuestMetadataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } StringReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; StringReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; StringReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; StringReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Uint8Reader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; Ok (()) } }
 # [derive (Clone , Debug , Default)] pub struct QuestMetadataBuilder { pub (crate) title : String , pub (crate) short_description : String , pub (crate) long_description : String , pub (crate) requirements : String , pub (crate) difficulty : Uint8 , pub (crate) time_estimate : Uint32 , } impl QuestMetadataBuilder { pub const FIELD_COUNT : usize = 6 ; pub fn title < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . title = v . into () ; self } pub fn short_description < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . short_description = v . into () ; self } pub fn long_description < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . long_description = v . into () ; self } pub fn requirements < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . requirements = v . into () ; self } pub fn difficulty < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint8 > { self . difficulty = v . into () ; self } pub fn time_estimate < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . time_estimate = v . into () ; self } } impl molecule :: prelude :: Builder for QuestMetadataBuilder { type Entity = QuestMetadata ; const NAME : & 'static str = "QuestMetadataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . title . as_slice () . len () + self . short_description . as_slice () . len () + self . long_description . as_slice () . len () + self . requirements . as_slice () . len () + self . difficulty . as_slice () . len () + self . time_estimate . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . title . as_slice () . len () ; offsets . push (total_size) ; total_size += self . short_description . as_slice () . len () ; offsets . push (total_size) ; total_size += self . long_description . as_slice () . len () ; offsets . push (total_size) ; total_size += self . requirements . as_slice () . len () ; offsets . push (total_size) ; total_size += self . difficulty . as_slice () . len () ; offsets . push (total_size) ; total_size += self . time_estimate . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . title . as_slice ()) ? ; writer . write_all (self . short_description . as_slice ()) ? ; writer . write_all (self . long_description . as_slice ()) ? ; writer . write_all (self . requirements . as_slice ()) ? ; writer . write_all (self . difficulty . as_slice ()) ? ; writer . write_all (self . time_estimate . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; QuestMetadata :: new_unchecked (inner . into ()) } }
-# [derive (Clone)] pub struct QuestData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for QuestData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for QuestData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for QuestData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "quest_id" , self . quest_id ()) ? ; write ! (f , ", {}: {}" , "metadata" , self . metadata ()) ? ; write ! (f , ", {}: {}" , "rewards_on_completion" , self . rewards_on_completion ()) ? ; write ! (f , ", {}: {}" , "accepted_submission_user_type_ids" , self . accepted_submission_user_type_ids ()) ? ; write ! (f , ", {}: {}" , "completion_deadline" , self . completion_deadline ()) ? ; write ! (f , ", {}: {}" , "status" , self . status ()) ? ; write ! (f , ", {}: {}" , "sub_tasks" , self . sub_tasks ()) ? ; write ! (f , ", {}: {}" , "points" , self . points ()) ? ; write ! (f , ", {}: {}" , "completion_count" , self . completion_count ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for QuestData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; QuestData :: new_unchecked (v) } } impl QuestData { const DEFAULT_VALUE : [u8 ; 134] = [134 , 0 , 0 , 0 , 40 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 93 , 0 , 0 , 0 , 97 , 0 , 0 , 0 , 101 , 0 , 0 , 0 , 109 , 0 , 0 , 0 , 110 , 0 , 0 , 0 , 114 , 0 , 0 , 0 , 130 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 49 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 32 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 40 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 45 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 9 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn quest_id (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn metadata (& self) -> QuestMetadata { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; QuestMetadata :: new_unchecked (self . 0 . slice (start .. end)) } pub fn rewards_on_completion (& self) -> AssetListVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; AssetListVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn accepted_submission_user_type_ids (& self) -> Byte32Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Byte32Vec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn completion_deadline (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn status (& self) -> Byte { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Byte :: new_unchecked (self . 0 . slice (start .. end)) } pub fn sub_tasks (& self) -> QuestSubTaskDataVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; QuestSubTaskDataVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn points (& self) -> Uint128 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; Uint128 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn completion_count (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } else { Uint32 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> QuestDataReader < 'r > { QuestDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for QuestData { type Builder = QuestDataBuilder ; const NAME : & 'static str = "QuestData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { QuestData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { QuestDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { QuestDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . quest_id (self . quest_id ()) . metadata (self . metadata ()) . rewards_on_completion (self . rewards_on_completion ()) . accepted_submission_user_type_ids (self . accepted_submission_user_type_ids ()) . completion_deadline (self . completion_deadline ()) . status (self . status ()) . sub_tasks (self . sub_tasks ()) . points (self . points ()) . completion_count (self . completion_count ()) } }
-# [derive (Clone , Copy)] pub struct QuestDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for QuestDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for QuestDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for QuestDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "quest_id" , self . quest_id ()) ? ; write ! (f , ", {}: {}" , "metadata" , self . metadata ()) ? ; write ! (f , ", {}: {}" , "rewards_on_completion" , self . rewards_on_completion ()) ? ; write ! (f , ", {}: {}" , "accepted_submission_user_type_ids" , self . accepted_submission_user_type_ids ()) ? ; write ! (f , ", {}: {}" , "completion_deadline" , self . completion_deadline ()) ? ; write ! (f , ", {}: {}" , "status" , self . status ()) ? ; write ! (f , ", {}: {}" , "sub_tasks" , self . sub_tasks ()) ? ; write ! (f , ", {}: {}" , "points" , self . points ()) ? ; write ! (f , ", {}: {}" , "completion_count" , self . completion_count ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > QuestDataReader < 'r > { pub const FIELD_COUNT : usize = 9 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn quest_id (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn metadata (& self) -> QuestMetadataReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; QuestMetadataReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn rewards_on_completion (& self) -> AssetListVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; AssetListVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn accepted_submission_user_type_ids (& self) -> Byte32VecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Byte32VecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn completion_deadline (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn status (& self) -> ByteReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; ByteReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn sub_tasks (& self) -> QuestSubTaskDataVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; QuestSubTaskDataVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn points (& self) -> Uint128Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; Uint128Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn completion_count (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Uint32Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for QuestDataReader < 'r > { type Entity = QuestData ; const NAME : & 'static str = "QuestDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { QuestDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Uint32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; QuestMetadataReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; AssetListVecReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Byte32VecReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; ByteReader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; QuestSubTaskDataVecReader :: verify (& slice [offsets [6] .. offsets [7]] , compatible) ? ; Uint128Reader :: verify (& slice [offsets [7] .. offsets [8]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [8] .. offsets [9]] , compatible) ? ; Ok (()) } }
-# [derive (Clone , Debug , Default)] pub struct QuestDataBuilder { pub (crate) quest_id : Uint32 , pub (crate) metadata : QuestMetadata , pub (crate) rewards_on_completion : AssetListVec , pub (crate) accepted_submission_user_type_ids : Byte32Vec , pub (crate) completion_deadline : Uint64 , pub (crate) status : Byte , pub (crate) sub_tasks : QuestSubTaskDataVec , pub (crate) points : Uint128 , pub (crate) completion_count : Uint32 , } impl QuestDataBuilder { pub const FIELD_COUNT : usize = 9 ; pub fn quest_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . quest_id = v . into () ; self } pub fn metadata < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < QuestMetadata > { self . metadata = v . into () ; self } pub fn rewards_on_completion < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < AssetListVec > { self . rewards_on_completion = v . into () ; self } pub fn accepted_submission_user_type_ids < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32Vec > { self . accepted_submission_user_type_ids = v . into () ; self } pub fn completion_deadline < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . completion_deadline = v . into () ; self } pub fn status < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte > { self . status = v . into () ; self } pub fn sub_tasks < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < QuestSubTaskDataVec > { self . sub_tasks = v . into () ; self } pub fn points < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint128 > { self . points = v . into () ; self } pub fn completion_count < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . completion_count = v . into () ; self } } impl molecule :: prelude :: Builder for QuestDataBuilder { type Entity = QuestData ; const NAME : & 'static str = "QuestDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . quest_id . as_slice () . len () + self . metadata . as_slice () . len () + self . rewards_on_completion . as_slice () . len () + self . accepted_submission_user_type_ids . as_slice () . len () + self . completion_deadline . as_slice () . len () + self . status . as_slice () . len () + self . sub_tasks . as_slice () . len () + self . points . as_slice () . len () + self . completion_count . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . quest_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . metadata . as_slice () . len () ; offsets . push (total_size) ; total_size += self . rewards_on_completion . as_slice () . len () ; offsets . push (total_size) ; total_size += self . accepted_submission_user_type_ids . as_slice () . len () ; offsets . push (total_size) ; total_size += self . completion_deadline . as_slice () . len () ; offsets . push (total_size) ; total_size += self . status . as_slice () . len () ; offsets . push (total_size) ; total_size += self . sub_tasks . as_slice () . len () ; offsets . push (total_size) ; total_size += self . points . as_slice () . len () ; offsets . push (total_size) ; total_size += self . completion_count . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . quest_id . as_slice ()) ? ; writer . write_all (self . metadata . as_slice ()) ? ; writer . write_all (self . rewards_on_completion . as_slice ()) ? ; writer . write_all (self . accepted_submission_user_type_ids . as_slice ()) ? ; writer . write_all (self . completion_deadline . as_slice ()) ? ; writer . write_all (self . status . as_slice ()) ? ; writer . write_all (self . sub_tasks . as_slice ()) ? ; writer . write_all (self . points . as_slice ()) ? ; writer . write_all (self . completion_count . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; QuestData :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct SubmissionRejection (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for SubmissionRejection { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for SubmissionRejection { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for SubmissionRejection { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "user_type_id" , self . user_type_id ()) ? ; write ! (f , ", {}: {}" , "reason_code" , self . reason_code ()) ? ; write ! (f , ", {}: {}" , "rejection_count" , self . rejection_count ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for SubmissionRejection { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; SubmissionRejection :: new_unchecked (v) } } impl SubmissionRejection { const DEFAULT_VALUE : [u8 ; 56] = [56 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 48 , 0 , 0 , 0 , 52 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 3 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn user_type_id (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn reason_code (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn rejection_count (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } else { Uint32 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> SubmissionRejectionReader < 'r > { SubmissionRejectionReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for SubmissionRejection { type Builder = SubmissionRejectionBuilder ; const NAME : & 'static str = "SubmissionRejection" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { SubmissionRejection (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubmissionRejectionReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubmissionRejectionReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . user_type_id (self . user_type_id ()) . reason_code (self . reason_code ()) . rejection_count (self . rejection_count ()) } }
+# [derive (Clone , Copy)] pub struct SubmissionRejectionReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for SubmissionRejectionReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for SubmissionRejectionReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for SubmissionRejectionReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "user_type_id" , self . user_type_id ()) ? ; write ! (f , ", {}: {}" , "reason_code" , self . reason_code ()) ? ; write ! (f , ", {}: {}" , "rejection_count" , self . rejection_count ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > SubmissionRejectionReader < 'r > { pub const FIELD_COUNT : usize = 3 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn user_type_id (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn reason_code (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn rejection_count (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Uint32Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for SubmissionRejectionReader < 'r > { type Entity = SubmissionRejection ; const NAME : & 'static str = "SubmissionRejectionReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { SubmissionRejectionReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct SubmissionRejectionBuilder { pub (crate) user_type_id : Byte32 , pub (crate) reason_code : Uint32 , pub (crate) rejection_count : Uint32 , } impl SubmissionRejectionBuilder { pub const FIELD_COUNT : usize = 3 ; pub fn user_type_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . user_type_id = v . into () ; self } pub fn reason_code < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . reason_code = v . into () ; self } pub fn rejection_count < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . rejection_count = v . into () ; self } } impl molecule :: prelude :: Builder for SubmissionRejectionBuilder { type Entity = SubmissionRejection ; const NAME : & 'static str = "SubmissionRejectionBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . user_type_id . as_slice () . len () + self . reason_code . as_slice () . len () + self . rejection_count . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . user_type_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . reason_code . as_slice () . len () ; offsets . push (total_size) ; total_size += self . rejection_count . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . user_type_id . as_slice ()) ? ; writer . write_all (self . reason_code . as_slice ()) ? ; writer . write_all (self . rejection_count . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; SubmissionRejection :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct SubmissionRejectionVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for SubmissionRejectionVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for SubmissionRejectionVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for SubmissionRejectionVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for SubmissionRejectionVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; SubmissionRejectionVec :: new_unchecked (v) } } impl SubmissionRejectionVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < SubmissionRejection > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> SubmissionRejection { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { SubmissionRejection :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; SubmissionRejection :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> SubmissionRejectionVecReader < 'r > { SubmissionRejectionVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for SubmissionRejectionVec { type Builder = SubmissionRejectionVecBuilder ; const NAME : & 'static str = "SubmissionRejectionVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { SubmissionRejectionVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubmissionRejectionVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubmissionRejectionVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
+# [derive (Clone , Copy)] pub struct SubmissionRejectionVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for SubmissionRejectionVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for SubmissionRejectionVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for SubmissionRejectionVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > SubmissionRejectionVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < SubmissionRejectionReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> SubmissionRejectionReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { SubmissionRejectionReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; SubmissionRejectionReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for SubmissionRejectionVecReader < 'r > { type Entity = SubmissionRejectionVec ; const NAME : & 'static str = "SubmissionRejectionVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { SubmissionRejectionVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; SubmissionRejectionReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct SubmissionRejectionVecBuilder (pub (crate) Vec < SubmissionRejection >) ; impl SubmissionRejectionVecBuilder { pub fn set (mut self , v : Vec < SubmissionRejection >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < SubmissionRejection > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = SubmissionRejection >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < SubmissionRejection > where T : :: core :: convert :: Into < SubmissionRejection > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for SubmissionRejectionVecBuilder { type Entity = SubmissionRejectionVec ; const NAME : & 'static str = "SubmissionRejectionVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; SubmissionRejectionVec :: new_unchecked (inner . into ()) } }
+pub struct SubmissionRejectionVecIterator (SubmissionRejectionVec , usize , usize) ; impl :: core :: iter :: Iterator for SubmissionRejectionVecIterator { type Item = SubmissionRejection ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for SubmissionRejectionVecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for SubmissionRejectionVec { type Item = SubmissionRejection ; type IntoIter = SubmissionRejectionVecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; SubmissionRejectionVecIterator (self , 0 , len) } } impl < 'r > SubmissionRejectionVecReader < 'r > { pub fn iter < 't > (& 't self) -> SubmissionRejectionVecReaderIterator < 't , 'r > { SubmissionRejectionVecReaderIterator (& self , 0 , self . len ()) } } pub struct SubmissionRejectionVecReaderIterator < 't , 'r > (& 't SubmissionRejectionVecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for SubmissionRejectionVecReaderIterator < 't , 'r > { type Item = SubmissionRejectionReader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for SubmissionRejectionVecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
+impl :: core :: iter :: FromIterator < SubmissionRejection > for SubmissionRejectionVec { fn from_iter < T : IntoIterator < Item = SubmissionRejection >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < SubmissionRejection >> for SubmissionRejectionVec { fn from (v : Vec < SubmissionRejection >) -> Self { Self :: new_builder () . set (v) . build () } }
+# [derive (Clone)] pub struct ApprovalRecord (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for ApprovalRecord { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for ApprovalRecord { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for ApprovalRecord { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "user_type_id" , self . user_type_id ()) ? ; write ! (f , ", {}: {}" , "approved_at" , self . approved_at ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for ApprovalRecord { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; ApprovalRecord :: new_unchecked (v) } } impl ApprovalRecord { const DEFAULT_VALUE : [u8 ; 52] = [52 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn user_type_id (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn approved_at (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } else { Uint64 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> ApprovalRecordReader < 'r > { ApprovalRecordReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for ApprovalRecord { type Builder = ApprovalRecordBuilder ; const NAME : & 'static str = "ApprovalRecord" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { ApprovalRecord (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ApprovalRecordReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ApprovalRecordReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . user_type_id (self . user_type_id ()) . approved_at (self . approved_at ()) } }
+# [derive (Clone , Copy)] pub struct ApprovalRecordReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for ApprovalRecordReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for ApprovalRecordReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for ApprovalRecordReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "user_type_id" , self . user_type_id ()) ? ; write ! (f , ", {}: {}" , "approved_at" , self . approved_at ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > ApprovalRecordReader < 'r > { pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn user_type_id (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn approved_at (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Uint64Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for ApprovalRecordReader < 'r > { type Entity = ApprovalRecord ; const NAME : & 'static str = "ApprovalRecordReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { ApprovalRecordReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct ApprovalRecordBuilder { pub (crate) user_type_id : Byte32 , pub (crate) approved_at : Uint64 , } impl ApprovalRecordBuilder { pub const FIELD_COUNT : usize = 2 ; pub fn user_type_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . user_type_id = v . into () ; self } pub fn approved_at < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . approved_at = v . into () ; self } } impl molecule :: prelude :: Builder for ApprovalRecordBuilder { type Entity = ApprovalRecord ; const NAME : & 'static str = "ApprovalRecordBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . user_type_id . as_slice () . len () + self . approved_at . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . user_type_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . approved_at . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . user_type_id . as_slice ()) ? ; writer . write_all (self . approved_at . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; ApprovalRecord :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct ApprovalRecordVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for ApprovalRecordVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for ApprovalRecordVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for ApprovalRecordVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for ApprovalRecordVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; ApprovalRecordVec :: new_unchecked (v) } } impl ApprovalRecordVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < ApprovalRecord > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> ApprovalRecord { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { ApprovalRecord :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; ApprovalRecord :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> ApprovalRecordVecReader < 'r > { ApprovalRecordVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for ApprovalRecordVec { type Builder = ApprovalRecordVecBuilder ; const NAME : & 'static str = "ApprovalRecordVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { ApprovalRecordVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ApprovalRecordVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ApprovalRecordVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
+# [derive (Clone , Copy)] pub struct ApprovalRecordVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for ApprovalRecordVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for ApprovalRecordVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for ApprovalRecordVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > ApprovalRecordVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < ApprovalRecordReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> ApprovalRecordReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { ApprovalRecordReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; ApprovalRecordReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for ApprovalRecordVecReader < 'r > { type Entity = ApprovalRecordVec ; const NAME : & 'static str = "ApprovalRecordVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { ApprovalRecordVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; ApprovalRecordReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct ApprovalRecordVecBuilder (pub (crate) Vec < ApprovalRecord >) ; impl ApprovalRecordVecBuilder { pub fn set (mut self , v : Vec < ApprovalRecord >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < ApprovalRecord > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = ApprovalRecord >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < ApprovalRecord > where T : :: core :: convert :: Into < ApprovalRecord > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for ApprovalRecordVecBuilder { type Entity = ApprovalRecordVec ; const NAME : & 'static str = "ApprovalRecordVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; ApprovalRecordVec :: new_unchecked (inner . into ()) } }
+pub struct ApprovalRecordVecIterator (ApprovalRecordVec , usize , usize) ; impl :: core :: iter :: Iterator for ApprovalRecordVecIterator { type Item = ApprovalRecord ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for ApprovalRecordVecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for ApprovalRecordVec { type Item = ApprovalRecord ; type IntoIter = ApprovalRecordVecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; ApprovalRecordVecIterator (self , 0 , len) } } impl < 'r > ApprovalRecordVecReader < 'r > { pub fn iter < 't > (& 't self) -> ApprovalRecordVecReaderIterator < 't , 'r > { ApprovalRecordVecReaderIterator (& self , 0 , self . len ()) } } pub struct ApprovalRecordVecReaderIterator < 't , 'r > (& 't ApprovalRecordVecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for ApprovalRecordVecReaderIterator < 't , 'r > { type Item = ApprovalRecordReader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for ApprovalRecordVecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
+impl :: core :: iter :: FromIterator < ApprovalRecord > for ApprovalRecordVec { fn from_iter < T : IntoIterator < Item = ApprovalRecord >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < ApprovalRecord >> for ApprovalRecordVec { fn from (v : Vec < ApprovalRecord >) -> Self { Self :: new_builder () . set (v) . build () } }
+# [derive (Clone)] pub struct SubTaskApproval (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for SubTaskApproval { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for SubTaskApproval { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for SubTaskApproval { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "user_type_id" , self . user_type_id ()) ? ; write ! (f , ", {}: {}" , "approved_sub_task_ids" , self . approved_sub_task_ids ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for SubTaskApproval { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; SubTaskApproval :: new_unchecked (v) } } impl SubTaskApproval { const DEFAULT_VALUE : [u8 ; 48] = [48 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn user_type_id (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn approved_sub_task_ids (& self) -> Uint8Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint8Vec :: new_unchecked (self . 0 . slice (start .. end)) } else { Uint8Vec :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> SubTaskApprovalReader < 'r > { SubTaskApprovalReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for SubTaskApproval { type Builder = SubTaskApprovalBuilder ; const NAME : & 'static str = "SubTaskApproval" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { SubTaskApproval (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubTaskApprovalReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubTaskApprovalReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . user_type_id (self . user_type_id ()) . approved_sub_task_ids (self . approved_sub_task_ids ()) } }
+# [derive (Clone , Copy)] pub struct SubTaskApprovalReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for SubTaskApprovalReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for SubTaskApprovalReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for SubTaskApprovalReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "user_type_id" , self . user_type_id ()) ? ; write ! (f , ", {}: {}" , "approved_sub_task_ids" , self . approved_sub_task_ids ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > SubTaskApprovalReader < 'r > { pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn user_type_id (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn approved_sub_task_ids (& self) -> Uint8VecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint8VecReader :: new_unchecked (& self . as_slice () [start .. end]) } else { Uint8VecReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for SubTaskApprovalReader < 'r > { type Entity = SubTaskApproval ; const NAME : & 'static str = "SubTaskApprovalReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { SubTaskApprovalReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint8VecReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct SubTaskApprovalBuilder { pub (crate) user_type_id : Byte32 , pub (crate) approved_sub_task_ids : Uint8Vec , } impl SubTaskApprovalBuilder { pub const FIELD_COUNT : usize = 2 ; pub fn user_type_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . user_type_id = v . into () ; self } pub fn approved_sub_task_ids < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint8Vec > { self . approved_sub_task_ids = v . into () ; self } } impl molecule :: prelude :: Builder for SubTaskApprovalBuilder { type Entity = SubTaskApproval ; const NAME : & 'static str = "SubTaskApprovalBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . user_type_id . as_slice () . len () + self . approved_sub_task_ids . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . user_type_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . approved_sub_task_ids . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . user_type_id . as_slice ()) ? ; writer . write_all (self . approved_sub_task_ids . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; SubTaskApproval :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct SubTaskApprovalVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for SubTaskApprovalVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for SubTaskApprovalVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for SubTaskApprovalVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for SubTaskApprovalVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; SubTaskApprovalVec :: new_unchecked (v) } } impl SubTaskApprovalVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < SubTaskApproval > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> SubTaskApproval { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { SubTaskApproval :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; SubTaskApproval :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> SubTaskApprovalVecReader < 'r > { SubTaskApprovalVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for SubTaskApprovalVec { type Builder = SubTaskApprovalVecBuilder ; const NAME : & 'static str = "SubTaskApprovalVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { SubTaskApprovalVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubTaskApprovalVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubTaskApprovalVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
+# [derive (Clone , Copy)] pub struct SubTaskApprovalVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for SubTaskApprovalVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for SubTaskApprovalVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for SubTaskApprovalVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > SubTaskApprovalVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < SubTaskApprovalReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> SubTaskApprovalReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { SubTaskApprovalReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; SubTaskApprovalReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for SubTaskApprovalVecReader < 'r > { type Entity = SubTaskApprovalVec ; const NAME : & 'static str = "SubTaskApprovalVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { SubTaskApprovalVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; SubTaskApprovalReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct SubTaskApprovalVecBuilder (pub (crate) Vec < SubTaskApproval >) ; impl SubTaskApprovalVecBuilder { pub fn set (mut self , v : Vec < SubTaskApproval >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < SubTaskApproval > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = SubTaskApproval >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < SubTaskApproval > where T : :: core :: convert :: Into < SubTaskApproval > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for SubTaskApprovalVecBuilder { type Entity = SubTaskApprovalVec ; const NAME : & 'static str = "SubTaskApprovalVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; SubTaskApprovalVec :: new_unchecked (inner . into ()) } }
+pub struct SubTaskApprovalVecIterator (SubTaskApprovalVec , usize , usize) ; impl :: core :: iter :: Iterator for SubTaskApprovalVecIterator { type Item = SubTaskApproval ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for SubTaskApprovalVecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for SubTaskApprovalVec { type Item = SubTaskApproval ; type IntoIter = SubTaskApprovalVecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; SubTaskApprovalVecIterator (self , 0 , len) } } impl < 'r > SubTaskApprovalVecReader < 'r > { pub fn iter < 't > (& 't self) -> SubTaskApprovalVecReaderIterator < 't , 'r > { SubTaskApprovalVecReaderIterator (& self , 0 , self . len ()) } } pub struct SubTaskApprovalVecReaderIterator < 't , 'r > (& 't SubTaskApprovalVecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for SubTaskApprovalVecReaderIterator < 't , 'r > { type Item = SubTaskApprovalReader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for SubTaskApprovalVecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
+impl :: core :: iter :: FromIterator < SubTaskApproval > for SubTaskApprovalVec { fn from_iter < T : IntoIterator < Item = SubTaskApproval >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < SubTaskApproval >> for SubTaskApprovalVec { fn from (v : Vec < SubTaskApproval >) -> Self { Self :: new_builder () . set (v) . build () } }
+# [derive (Clone)] pub struct SporeData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for SporeData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for SporeData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for SporeData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "content_type" , self . content_type ()) ? ; write ! (f , ", {}: {}" , "content" , self . content ()) ? ; write ! (f , ", {}: {}" , "cluster_id" , self . cluster_id ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for SporeData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; SporeData :: new_unchecked (v) } } impl SporeData { const DEFAULT_VALUE : [u8 ; 24] = [24 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 20 , 0 , 0 , 0 , 24 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 3 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn content_type (& self) -> Bytes { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Bytes :: new_unchecked (self . 0 . slice (start .. end)) } pub fn content (& self) -> Bytes { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Bytes :: new_unchecked (self . 0 . slice (start .. end)) } pub fn cluster_id (& self) -> BytesOpt { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [16 ..]) as usize ; BytesOpt :: new_unchecked (self . 0 . slice (start .. end)) } else { BytesOpt :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> SporeDataReader < 'r > { SporeDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for SporeData { type Builder = SporeDataBuilder ; const NAME : & 'static str = "SporeData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { SporeData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SporeDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SporeDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . content_type (self . content_type ()) . content (self . content ()) . cluster_id (self . cluster_id ()) } }
+# [derive (Clone , Copy)] pub struct SporeDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for SporeDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for SporeDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for SporeDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "content_type" , self . content_type ()) ? ; write ! (f , ", {}: {}" , "content" , self . content ()) ? ; write ! (f , ", {}: {}" , "cluster_id" , self . cluster_id ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > SporeDataReader < 'r > { pub const FIELD_COUNT : usize = 3 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn content_type (& self) -> BytesReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; BytesReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn content (& self) -> BytesReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; BytesReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn cluster_id (& self) -> BytesOptReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [16 ..]) as usize ; BytesOptReader :: new_unchecked (& self . as_slice () [start .. end]) } else { BytesOptReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for SporeDataReader < 'r > { type Entity = SporeData ; const NAME : & 'static str = "SporeDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { SporeDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } BytesReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; BytesReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; BytesOptReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct SporeDataBuilder { pub (crate) content_type : Bytes , pub (crate) content : Bytes , pub (crate) cluster_id : BytesOpt , } impl SporeDataBuilder { pub const FIELD_COUNT : usize = 3 ; pub fn content_type < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Bytes > { self . content_type = v . into () ; self } pub fn content < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Bytes > { self . content = v . into () ; self } pub fn cluster_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < BytesOpt > { self . cluster_id = v . into () ; self } } impl molecule :: prelude :: Builder for SporeDataBuilder { type Entity = SporeData ; const NAME : & 'static str = "SporeDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . content_type . as_slice () . len () + self . content . as_slice () . len () + self . cluster_id . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . content_type . as_slice () . len () ; offsets . push (total_size) ; total_size += self . content . as_slice () . len () ; offsets . push (total_size) ; total_size += self . cluster_id . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . content_type . as_slice ()) ? ; writer . write_all (self . content . as_slice ()) ? ; writer . write_all (self . cluster_id . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; SporeData :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct NftAward (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for NftAward { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for NftAward { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for NftAward { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "user_type_id" , self . user_type_id ()) ? ; write ! (f , ", {}: {}" , "nft_script" , self . nft_script ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for NftAward { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; NftAward :: new_unchecked (v) } } impl NftAward { const DEFAULT_VALUE : [u8 ; 97] = [97 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 53 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 48 , 0 , 0 , 0 , 49 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn user_type_id (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn nft_script (& self) -> Script { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Script :: new_unchecked (self . 0 . slice (start .. end)) } else { Script :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> NftAwardReader < 'r > { NftAwardReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for NftAward { type Builder = NftAwardBuilder ; const NAME : & 'static str = "NftAward" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { NftAward (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { NftAwardReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { NftAwardReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . user_type_id (self . user_type_id ()) . nft_script (self . nft_script ()) } }
+# [derive (Clone , Copy)] pub struct NftAwardReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for NftAwardReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for NftAwardReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for NftAwardReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "user_type_id" , self . user_type_id ()) ? ; write ! (f , ", {}: {}" , "nft_script" , self . nft_script ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > NftAwardReader < 'r > { pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn user_type_id (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn nft_script (& self) -> ScriptReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; ScriptReader :: new_unchecked (& self . as_slice () [start .. end]) } else { ScriptReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for NftAwardReader < 'r > { type Entity = NftAward ; const NAME : & 'static str = "NftAwardReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { NftAwardReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; ScriptReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct NftAwardBuilder { pub (crate) user_type_id : Byte32 , pub (crate) nft_script : Script , } impl NftAwardBuilder { pub const FIELD_COUNT : usize = 2 ; pub fn user_type_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . user_type_id = v . into () ; self } pub fn nft_script < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Script > { self . nft_script = v . into () ; self } } impl molecule :: prelude :: Builder for NftAwardBuilder { type Entity = NftAward ; const NAME : & 'static str = "NftAwardBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . user_type_id . as_slice () . len () + self . nft_script . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . user_type_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . nft_script . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . user_type_id . as_slice ()) ? ; writer . write_all (self . nft_script . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; NftAward :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct NftAwardVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for NftAwardVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for NftAwardVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for NftAwardVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for NftAwardVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; NftAwardVec :: new_unchecked (v) } } impl NftAwardVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < NftAward > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> NftAward { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { NftAward :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; NftAward :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> NftAwardVecReader < 'r > { NftAwardVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for NftAwardVec { type Builder = NftAwardVecBuilder ; const NAME : & 'static str = "NftAwardVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { NftAwardVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { NftAwardVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { NftAwardVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
+# [derive (Clone , Copy)] pub struct NftAwardVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for NftAwardVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for NftAwardVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for NftAwardVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > NftAwardVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < NftAwardReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> NftAwardReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { NftAwardReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; NftAwardReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for NftAwardVecReader < 'r > { type Entity = NftAwardVec ; const NAME : & 'static str = "NftAwardVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { NftAwardVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; NftAwardReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct NftAwardVecBuilder (pub (crate) Vec < NftAward >) ; impl NftAwardVecBuilder { pub fn set (mut self , v : Vec < NftAward >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < NftAward > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = NftAward >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < NftAward > where T : :: core :: convert :: Into < NftAward > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for NftAwardVecBuilder { type Entity = NftAwardVec ; const NAME : & 'static str = "NftAwardVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; NftAwardVec :: new_unchecked (inner . into ()) } }
+pub struct NftAwardVecIterator (NftAwardVec , usize , usize) ; impl :: core :: iter :: Iterator for NftAwardVecIterator { type Item = NftAward ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for NftAwardVecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for NftAwardVec { type Item = NftAward ; type IntoIter = NftAwardVecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; NftAwardVecIterator (self , 0 , len) } } impl < 'r > NftAwardVecReader < 'r > { pub fn iter < 't > (& 't self) -> NftAwardVecReaderIterator < 't , 'r > { NftAwardVecReaderIterator (& self , 0 , self . len ()) } } pub struct NftAwardVecReaderIterator < 't , 'r > (& 't NftAwardVecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for NftAwardVecReaderIterator < 't , 'r > { type Item = NftAwardReader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for NftAwardVecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
+impl :: core :: iter :: FromIterator < NftAward > for NftAwardVec { fn from_iter < T : IntoIterator < Item = NftAward >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < NftAward >> for NftAwardVec { fn from (v : Vec < NftAward >) -> Self { Self :: new_builder () . set (v) . build () } }
+# [derive (Clone)] pub struct QuestData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for QuestData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for QuestData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for QuestData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "quest_id" , self . quest_id ()) ? ; write ! (f , ", {}: {}" , "metadata" , self . metadata ()) ? ; write ! (f , ", {}: {}" , "rewards_on_completion" , self . rewards_on_completion ()) ? ; write ! (f , ", {}: {}" , "accepted_submission_user_type_ids" , self . accepted_submission_user_type_ids ()) ? ; write ! (f , ", {}: {}" , "completion_deadline" , self . completion_deadline ()) ? ; write ! (f , ", {}: {}" , "status" , self . status ()) ? ; write ! (f , ", {}: {}" , "sub_tasks" , self . sub_tasks ()) ? ; write ! (f , ", {}: {}" , "points" , self . points ()) ? ; write ! (f , ", {}: {}" , "completion_count" , self . completion_count ()) ? ; write ! (f , ", {}: {}" , "rejected_submissions" , self . rejected_submissions ()) ? ; write ! (f , ", {}: {}" , "max_resubmissions" , self . max_resubmissions ()) ? ; write ! (f , ", {}: {}" , "pending_approvals" , self . pending_approvals ()) ? ; write ! (f , ", {}: {}" , "prerequisite_quest_ids" , self . prerequisite_quest_ids ()) ? ; write ! (f , ", {}: {}" , "sub_task_approvals" , self . sub_task_approvals ()) ? ; write ! (f , ", {}: {}" , "nft_awards" , self . nft_awards ()) ? ; write ! (f , ", {}: {}" , "approvals_root" , self . approvals_root ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for QuestData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; QuestData :: new_unchecked (v) } } impl QuestData { const DEFAULT_VALUE : [u8 ; 218] = [218 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 121 , 0 , 0 , 0 , 125 , 0 , 0 , 0 , 129 , 0 , 0 , 0 , 137 , 0 , 0 , 0 , 138 , 0 , 0 , 0 , 142 , 0 , 0 , 0 , 158 , 0 , 0 , 0 , 162 , 0 , 0 , 0 , 166 , 0 , 0 , 0 , 170 , 0 , 0 , 0 , 174 , 0 , 0 , 0 , 178 , 0 , 0 , 0 , 182 , 0 , 0 , 0 , 186 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 49 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 32 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 40 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 45 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 16 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn quest_id (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn metadata (& self) -> QuestMetadata { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; QuestMetadata :: new_unchecked (self . 0 . slice (start .. end)) } pub fn rewards_on_completion (& self) -> AssetListVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; AssetListVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn accepted_submission_user_type_ids (& self) -> Byte32Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Byte32Vec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn completion_deadline (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn status (& self) -> Byte { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Byte :: new_unchecked (self . 0 . slice (start .. end)) } pub fn sub_tasks (& self) -> QuestSubTaskDataVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; QuestSubTaskDataVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn points (& self) -> Uint128 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; Uint128 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn completion_count (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn rejected_submissions (& self) -> SubmissionRejectionVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [40 ..]) as usize ; let end = molecule :: unpack_number (& slice [44 ..]) as usize ; SubmissionRejectionVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn max_resubmissions (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [44 ..]) as usize ; let end = molecule :: unpack_number (& slice [48 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn pending_approvals (& self) -> ApprovalRecordVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [48 ..]) as usize ; let end = molecule :: unpack_number (& slice [52 ..]) as usize ; ApprovalRecordVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn prerequisite_quest_ids (& self) -> Uint32Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [52 ..]) as usize ; let end = molecule :: unpack_number (& slice [56 ..]) as usize ; Uint32Vec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn sub_task_approvals (& self) -> SubTaskApprovalVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [56 ..]) as usize ; let end = molecule :: unpack_number (& slice [60 ..]) as usize ; SubTaskApprovalVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn nft_awards (& self) -> NftAwardVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [60 ..]) as usize ; let end = molecule :: unpack_number (& slice [64 ..]) as usize ; NftAwardVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn approvals_root (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [64 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [68 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } else { Byte32 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> QuestDataReader < 'r > { QuestDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for QuestData { type Builder = QuestDataBuilder ; const NAME : & 'static str = "QuestData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { QuestData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { QuestDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { QuestDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . quest_id (self . quest_id ()) . metadata (self . metadata ()) . rewards_on_completion (self . rewards_on_completion ()) . accepted_submission_user_type_ids (self . accepted_submission_user_type_ids ()) . completion_deadline (self . completion_deadline ()) . status (self . status ()) . sub_tasks (self . sub_tasks ()) . points (self . points ()) . completion_count (self . completion_count ()) . rejected_submissions (self . rejected_submissions ()) . max_resubmissions (self . max_resubmissions ()) . pending_approvals (self . pending_approvals ()) . prerequisite_quest_ids (self . prerequisite_quest_ids ()) . sub_task_approvals (self . sub_task_approvals ()) . nft_awards (self . nft_awards ()) . approvals_root (self . approvals_root ()) } }
+# [derive (Clone , Copy)] pub struct QuestDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for QuestDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for QuestDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for QuestDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "quest_id" , self . quest_id ()) ? ; write ! (f , ", {}: {}" , "metadata" , self . metadata ()) ? ; write ! (f , ", {}: {}" , "rewards_on_completion" , self . rewards_on_completion ()) ? ; write ! (f , ", {}: {}" , "accepted_submission_user_type_ids" , self . accepted_submission_user_type_ids ()) ? ; write ! (f , ", {}: {}" , "completion_deadline" , self . completion_deadline ()) ? ; write ! (f , ", {}: {}" , "status" , self . status ()) ? ; write ! (f , ", {}: {}" , "sub_tasks" , self . sub_tasks ()) ? ; write ! (f , ", {}: {}" , "points" , self . points ()) ? ; write ! (f , ", {}: {}" , "completion_count" , self . completion_count ()) ? ; write ! (f , ", {}: {}" , "rejected_submissions" , self . rejected_submissions ()) ? ; write ! (f , ", {}: {}" , "max_resubmissions" , self . max_resubmissions ()) ? ; write ! (f , ", {}: {}" , "pending_approvals" , self . pending_approvals ()) ? ; write ! (f , ", {}: {}" , "prerequisite_quest_ids" , self . prerequisite_quest_ids ()) ? ; write ! (f , ", {}: {}" , "sub_task_approvals" , self . sub_task_approvals ()) ? ; write ! (f , ", {}: {}" , "nft_awards" , self . nft_awards ()) ? ; write ! (f , ", {}: {}" , "approvals_root" , self . approvals_root ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > QuestDataReader < 'r > { pub const FIELD_COUNT : usize = 16 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn quest_id (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn metadata (& self) -> QuestMetadataReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; QuestMetadataReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn rewards_on_completion (& self) -> AssetListVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; AssetListVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn accepted_submission_user_type_ids (& self) -> Byte32VecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Byte32VecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn completion_deadline (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn status (& self) -> ByteReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; ByteReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn sub_tasks (& self) -> QuestSubTaskDataVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; QuestSubTaskDataVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn points (& self) -> Uint128Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; Uint128Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn completion_count (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn rejected_submissions (& self) -> SubmissionRejectionVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [40 ..]) as usize ; let end = molecule :: unpack_number (& slice [44 ..]) as usize ; SubmissionRejectionVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn max_resubmissions (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [44 ..]) as usize ; let end = molecule :: unpack_number (& slice [48 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn pending_approvals (& self) -> ApprovalRecordVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [48 ..]) as usize ; let end = molecule :: unpack_number (& slice [52 ..]) as usize ; ApprovalRecordVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn prerequisite_quest_ids (& self) -> Uint32VecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [52 ..]) as usize ; let end = molecule :: unpack_number (& slice [56 ..]) as usize ; Uint32VecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn sub_task_approvals (& self) -> SubTaskApprovalVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [56 ..]) as usize ; let end = molecule :: unpack_number (& slice [60 ..]) as usize ; SubTaskApprovalVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn nft_awards (& self) -> NftAwardVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [60 ..]) as usize ; let end = molecule :: unpack_number (& slice [64 ..]) as usize ; NftAwardVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn approvals_root (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [64 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [68 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Byte32Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for QuestDataReader < 'r > { type Entity = QuestData ; const NAME : & 'static str = "QuestDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { QuestDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Uint32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; QuestMetadataReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; AssetListVecReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Byte32VecReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; ByteReader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; QuestSubTaskDataVecReader :: verify (& slice [offsets [6] .. offsets [7]] , compatible) ? ; Uint128Reader :: verify (& slice [offsets [7] .. offsets [8]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [8] .. offsets [9]] , compatible) ? ; SubmissionRejectionVecReader :: verify (& slice [offsets [9] .. offsets [10]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [10] .. offsets [11]] , compatible) ? ; ApprovalRecordVecReader :: verify (& slice [offsets [11] .. offsets [12]] , compatible) ? ; Uint32VecReader :: verify (& slice [offsets [12] .. offsets [13]] , compatible) ? ; SubTaskApprovalVecReader :: verify (& slice [offsets [13] .. offsets [14]] , compatible) ? ; NftAwardVecReader :: verify (& slice [offsets [14] .. offsets [15]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [15] .. offsets [16]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct QuestDataBuilder { pub (crate) quest_id : Uint32 , pub (crate) metadata : QuestMetadata , pub (crate) rewards_on_completion : AssetListVec , pub (crate) accepted_submission_user_type_ids : Byte32Vec , pub (crate) completion_deadline : Uint64 , pub (crate) status : Byte , pub (crate) sub_tasks : QuestSubTaskDataVec , pub (crate) points : Uint128 , pub (crate) completion_count : Uint32 , pub (crate) rejected_submissions : SubmissionRejectionVec , pub (crate) max_resubmissions : Uint32 , pub (crate) pending_approvals : ApprovalRecordVec , pub (crate) prerequisite_quest_ids : Uint32Vec , pub (crate) sub_task_approvals : SubTaskApprovalVec , pub (crate) nft_awards : NftAwardVec , pub (crate) approvals_root : Byte32 , } impl QuestDataBuilder { pub const FIELD_COUNT : usize = 16 ; pub fn quest_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . quest_id = v . into () ; self } pub fn metadata < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < QuestMetadata > { self . metadata = v . into () ; self } pub fn rewards_on_completion < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < AssetListVec > { self . rewards_on_completion = v . into () ; self } pub fn accepted_submission_user_type_ids < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32Vec > { self . accepted_submission_user_type_ids = v . into () ; self } pub fn completion_deadline < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . completion_deadline = v . into () ; self } pub fn status < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte > { self . status = v . into () ; self } pub fn sub_tasks < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < QuestSubTaskDataVec > { self . sub_tasks = v . into () ; self } pub fn points < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint128 > { self . points = v . into () ; self } pub fn completion_count < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . completion_count = v . into () ; self } pub fn rejected_submissions < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < SubmissionRejectionVec > { self . rejected_submissions = v . into () ; self } pub fn max_resubmissions < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . max_resubmissions = v . into () ; self } pub fn pending_approvals < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < ApprovalRecordVec > { self . pending_approvals = v . into () ; self } pub fn prerequisite_quest_ids < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32Vec > { self . prerequisite_quest_ids = v . into () ; self } pub fn sub_task_approvals < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < SubTaskApprovalVec > { self . sub_task_approvals = v . into () ; self } pub fn nft_awards < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < NftAwardVec > { self . nft_awards = v . into () ; self } pub fn approvals_root < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . approvals_root = v . into () ; self } } impl molecule :: prelude :: Builder for QuestDataBuilder { type Entity = QuestData ; const NAME : & 'static str = "QuestDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . quest_id . as_slice () . len () + self . metadata . as_slice () . len () + self . rewards_on_completion . as_slice () . len () + self . accepted_submission_user_type_ids . as_slice () . len () + self . completion_deadline . as_slice () . len () + self . status . as_slice () . len () + self . sub_tasks . as_slice () . len () + self . points . as_slice () . len () + self . completion_count . as_slice () . len () + self . rejected_submissions . as_slice () . len () + self . max_resubmissions . as_slice () . len () + self . pending_approvals . as_slice () . len () + self . prerequisite_quest_ids . as_slice () . len () + self . sub_task_approvals . as_slice () . len () + self . nft_awards . as_slice () . len () + self . approvals_root . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . quest_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . metadata . as_slice () . len () ; offsets . push (total_size) ; total_size += self . rewards_on_completion . as_slice () . len () ; offsets . push (total_size) ; total_size += self . accepted_submission_user_type_ids . as_slice () . len () ; offsets . push (total_size) ; total_size += self . completion_deadline . as_slice () . len () ; offsets . push (total_size) ; total_size += self . status . as_slice () . len () ; offsets . push (total_size) ; total_size += self . sub_tasks . as_slice () . len () ; offsets . push (total_size) ; total_size += self . points . as_slice () . len () ; offsets . push (total_size) ; total_size += self . completion_count . as_slice () . len () ; offsets . push (total_size) ; total_size += self . rejected_submissions . as_slice () . len () ; offsets . push (total_size) ; total_size += self . max_resubmissions . as_slice () . len () ; offsets . push (total_size) ; total_size += self . pending_approvals . as_slice () . len () ; offsets . push (total_size) ; total_size += self . prerequisite_quest_ids . as_slice () . len () ; offsets . push (total_size) ; total_size += self . sub_task_approvals . as_slice () . len () ; offsets . push (total_size) ; total_size += self . nft_awards . as_slice () . len () ; offsets . push (total_size) ; total_size += self . approvals_root . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . quest_id . as_slice ()) ? ; writer . write_all (self . metadata . as_slice ()) ? ; writer . write_all (self . rewards_on_completion . as_slice ()) ? ; writer . write_all (self . accepted_submission_user_type_ids . as_slice ()) ? ; writer . write_all (self . completion_deadline . as_slice ()) ? ; writer . write_all (self . status . as_slice ()) ? ; writer . write_all (self . sub_tasks . as_slice ()) ? ; writer . write_all (self . points . as_slice ()) ? ; writer . write_all (self . completion_count . as_slice ()) ? ; writer . write_all (self . rejected_submissions . as_slice ()) ? ; writer . write_all (self . max_resubmissions . as_slice ()) ? ; writer . write_all (self . pending_approvals . as_slice ()) ? ; writer . write_all (self . prerequisite_quest_ids . as_slice ()) ? ; writer . write_all (self . sub_task_approvals . as_slice ()) ? ; writer . write_all (self . nft_awards . as_slice ()) ? ; writer . write_all (self . approvals_root . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; QuestData :: new_unchecked (inner . into ()) } }
 # [derive (Clone)] pub struct QuestDataVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for QuestDataVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for QuestDataVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for QuestDataVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for QuestDataVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; QuestDataVec :: new_unchecked (v) } } impl QuestDataVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < QuestData > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> QuestData { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { QuestData :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; QuestData :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> QuestDataVecReader < 'r > { QuestDataVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for QuestDataVec { type Builder = QuestDataVecBuilder ; const NAME : & 'static str = "QuestDataVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { QuestDataVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { QuestDataVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { QuestDataVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
 # [derive (Clone , Copy)] pub struct QuestDataVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for QuestDataVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for QuestDataVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for QuestDataVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > QuestDataVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < QuestDataReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> QuestDataReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { QuestDataReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; QuestDataReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for QuestDataVecReader < 'r > { type Entity = QuestDataVec ; const NAME : & 'static str = "QuestDataVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { QuestDataVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; QuestDataReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
 # [derive (Clone , Debug , Default)] pub struct QuestDataVecBuilder (pub (crate) Vec < QuestData >) ; impl QuestDataVecBuilder { pub fn set (mut self , v : Vec < QuestData >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < QuestData > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = QuestData >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < QuestData > where T : :: core :: convert :: Into < QuestData > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for QuestDataVecBuilder { type Entity = QuestDataVec ; const NAME : & 'static str = "QuestDataVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; QuestDataVec :: new_unchecked (inner . into ()) } }
@@ -223,14 +263,25 @@ impl :: core :: iter :: FromIterator < EndorserInfo > for EndorserInfoVec { fn f
 # [derive (Clone)] pub struct CampaignMetadata (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for CampaignMetadata { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for CampaignMetadata { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for CampaignMetadata { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "title" , self . title ()) ? ; write ! (f , ", {}: {}" , "endorser_info" , self . endorser_info ()) ? ; write ! (f , ", {}: {}" , "short_description" , self . short_description ()) ? ; write ! (f , ", {}: {}" , "long_description" , self . long_description ()) ? ; write ! (f , ", {}: {}" , "total_rewards" , self . total_rewards ()) ? ; write ! (f , ", {}: {}" , "verification_requirements" , self . verification_requirements ()) ? ; write ! (f , ", {}: {}" , "last_updated" , self . last_updated ()) ? ; write ! (f , ", {}: {}" , "categories" , self . categories ()) ? ; write ! (f , ", {}: {}" , "difficulty" , self . difficulty ()) ? ; write ! (f , ", {}: {}" , "image_url" , self . image_url ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for CampaignMetadata { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; CampaignMetadata :: new_unchecked (v) } } impl CampaignMetadata { const DEFAULT_VALUE : [u8 ; 206] = [206 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 48 , 0 , 0 , 0 , 125 , 0 , 0 , 0 , 129 , 0 , 0 , 0 , 133 , 0 , 0 , 0 , 185 , 0 , 0 , 0 , 189 , 0 , 0 , 0 , 197 , 0 , 0 , 0 , 201 , 0 , 0 , 0 , 202 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 77 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 60 , 0 , 0 , 0 , 64 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 76 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 52 , 0 , 0 , 0 , 20 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 48 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 10 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn title (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } pub fn endorser_info (& self) -> EndorserInfo { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; EndorserInfo :: new_unchecked (self . 0 . slice (start .. end)) } pub fn short_description (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } pub fn long_description (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } pub fn total_rewards (& self) -> AssetList { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; AssetList :: new_unchecked (self . 0 . slice (start .. end)) } pub fn verification_requirements (& self) -> Uint8Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Uint8Vec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn last_updated (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn categories (& self) -> StringVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; StringVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn difficulty (& self) -> Uint8 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Uint8 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn image_url (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [40 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [44 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } else { String :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> CampaignMetadataReader < 'r > { CampaignMetadataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for CampaignMetadata { type Builder = CampaignMetadataBuilder ; const NAME : & 'static str = "CampaignMetadata" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { CampaignMetadata (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignMetadataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignMetadataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . title (self . title ()) . endorser_info (self . endorser_info ()) . short_description (self . short_description ()) . long_description (self . long_description ()) . total_rewards (self . total_rewards ()) . verification_requirements (self . verification_requirements ()) . last_updated (self . last_updated ()) . categories (self . categories ()) . difficulty (self . difficulty ()) . image_url (self . image_url ()) } }
 # [derive (Clone , Copy)] pub struct CampaignMetadataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for CampaignMetadataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for CampaignMetadataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for CampaignMetadataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "title" , self . title ()) ? ; write ! (f , ", {}: {}" , "endorser_info" , self . endorser_info ()) ? ; write ! (f , ", {}: {}" , "short_description" , self . short_description ()) ? ; write ! (f , ", {}: {}" , "long_description" , self . long_description ()) ? ; write ! (f , ", {}: {}" , "total_rewards" , self . total_rewards ()) ? ; write ! (f , ", {}: {}" , "verification_requirements" , self . verification_requirements ()) ? ; write ! (f , ", {}: {}" , "last_updated" , self . last_updated ()) ? ; write ! (f , ", {}: {}" , "categories" , self . categories ()) ? ; write ! (f , ", {}: {}" , "difficulty" , self . difficulty ()) ? ; write ! (f , ", {}: {}" , "image_url" , self . image_url ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > CampaignMetadataReader < 'r > { pub const FIELD_COUNT : usize = 10 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn title (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn endorser_info (& self) -> EndorserInfoReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; EndorserInfoReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn short_description (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn long_description (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn total_rewards (& self) -> AssetListReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; AssetListReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn verification_requirements (& self) -> Uint8VecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Uint8VecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn last_updated (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn categories (& self) -> StringVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; StringVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn difficulty (& self) -> Uint8Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Uint8Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn image_url (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [40 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [44 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } else { StringReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for CampaignMetadataReader < 'r > { type Entity = CampaignMetadata ; const NAME : & 'static str = "CampaignMetadataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { CampaignMetadataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } StringReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; EndorserInfoReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; StringReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; StringReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; AssetListReader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; Uint8VecReader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [6] .. offsets [7]] , compatible) ? ; StringVecReader :: verify (& slice [offsets [7] .. offsets [8]] , compatible) ? ; Uint8Reader :: verify (& slice [offsets [8] .. offsets [9]] , compatible) ? ; StringReader :: verify (& slice [offsets [9] .. offsets [10]] , compatible) ? ; Ok (()) } }
 # [derive (Clone , Debug , Default)] pub struct CampaignMetadataBuilder { pub (crate) title : String , pub (crate) endorser_info : EndorserInfo , pub (crate) short_description : String , pub (crate) long_description : String , pub (crate) total_rewards : AssetList , pub (crate) verification_requirements : Uint8Vec , pub (crate) last_updated : Uint64 , pub (crate) categories : StringVec , pub (crate) difficulty : Uint8 , pub (crate) image_url : String , } impl CampaignMetadataBuilder { pub const FIELD_COUNT : usize = 10 ; pub fn title < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . title = v . into () ; self } pub fn endorser_info < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < EndorserInfo > { self . endorser_info = v . into () ; self } pub fn short_description < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . short_description = v . into () ; self } pub fn long_description < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . long_description = v . into () ; self } pub fn total_rewards < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < AssetList > { self . total_rewards = v . into () ; self } pub fn verification_requirements < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint8Vec > { self . verification_requirements = v . into () ; self } pub fn last_updated < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . last_updated = v . into () ; self } pub fn categories < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < StringVec > { self . categories = v . into () ; self } pub fn difficulty < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint8 > { self . difficulty = v . into () ; self } pub fn image_url < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . image_url = v . into () ; self } } impl molecule :: prelude :: Builder for CampaignMetadataBuilder { type Entity = CampaignMetadata ; const NAME : & 'static str = "CampaignMetadataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . title . as_slice () . len () + self . endorser_info . as_slice () . len () + self . short_description . as_slice () . len () + self . long_description . as_slice () . len () + self . total_rewards . as_slice () . len () + self . verification_requirements . as_slice () . len () + self . last_updated . as_slice () . len () + self . categories . as_slice () . len () + self . difficulty . as_slice () . len () + self . image_url . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . title . as_slice () . len () ; offsets . push (total_size) ; total_size += self . endorser_info . as_slice () . len () ; offsets . push (total_size) ; total_size += self . short_description . as_slice () . len () ; offsets . push (total_size) ; total_size += self . long_description . as_slice () . len () ; offsets . push (total_size) ; total_size += self . total_rewards . as_slice () . len () ; offsets . push (total_size) ; total_size += self . verification_requirements . as_slice () . len () ; offsets . push (total_size) ; total_size += self . last_updated . as_slice () . len () ; offsets . push (total_size) ; total_size += self . categories . as_slice () . len () ; offsets . push (total_size) ; total_size += self . difficulty . as_slice () . len () ; offsets . push (total_size) ; total_size += self . image_url . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . title . as_slice ()) ? ; writer . write_all (self . endorser_info . as_slice ()) ? ; writer . write_all (self . short_description . as_slice ()) ? ; writer . write_all (self . long_description . as_slice ()) ? ; writer . write_all (self . total_rewards . as_slice ()) ? ; writer . write_all (self . verification_requirements . as_slice ()) ? ; writer . write_all (self . last_updated . as_slice ()) ? ; writer . write_all (self . categories . as_slice ()) ? ; writer . write_all (self . difficulty . as_slice ()) ? ; writer . write_all (self . image_url . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; CampaignMetadata :: new_unchecked (inner . into ()) } }
-# [derive (Clone)] pub struct CampaignData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for CampaignData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for CampaignData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for CampaignData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "endorser" , self . endorser ()) ? ; write ! (f , ", {}: {}" , "created_at" , self . created_at ()) ? ; write ! (f , ", {}: {}" , "starting_time" , self . starting_time ()) ? ; write ! (f , ", {}: {}" , "ending_time" , self . ending_time ()) ? ; write ! (f , ", {}: {}" , "rules" , self . rules ()) ? ; write ! (f , ", {}: {}" , "metadata" , self . metadata ()) ? ; write ! (f , ", {}: {}" , "status" , self . status ()) ? ; write ! (f , ", {}: {}" , "quests" , self . quests ()) ? ; write ! (f , ", {}: {}" , "participants_count" , self . participants_count ()) ? ; write ! (f , ", {}: {}" , "total_completions" , self . total_completions ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for CampaignData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; CampaignData :: new_unchecked (v) } } impl CampaignData { const DEFAULT_VALUE : [u8 ; 368] = [112 , 1 , 0 , 0 , 44 , 0 , 0 , 0 , 121 , 0 , 0 , 0 , 129 , 0 , 0 , 0 , 137 , 0 , 0 , 0 , 145 , 0 , 0 , 0 , 149 , 0 , 0 , 0 , 99 , 1 , 0 , 0 , 100 , 1 , 0 , 0 , 104 , 1 , 0 , 0 , 108 , 1 , 0 , 0 , 77 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 60 , 0 , 0 , 0 , 64 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 76 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 206 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 48 , 0 , 0 , 0 , 125 , 0 , 0 , 0 , 129 , 0 , 0 , 0 , 133 , 0 , 0 , 0 , 185 , 0 , 0 , 0 , 189 , 0 , 0 , 0 , 197 , 0 , 0 , 0 , 201 , 0 , 0 , 0 , 202 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 77 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 60 , 0 , 0 , 0 , 64 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 76 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 52 , 0 , 0 , 0 , 20 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 48 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 10 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn endorser (& self) -> EndorserInfo { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; EndorserInfo :: new_unchecked (self . 0 . slice (start .. end)) } pub fn created_at (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn starting_time (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ending_time (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn rules (& self) -> StringVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; StringVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn metadata (& self) -> CampaignMetadata { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; CampaignMetadata :: new_unchecked (self . 0 . slice (start .. end)) } pub fn status (& self) -> Byte { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; Byte :: new_unchecked (self . 0 . slice (start .. end)) } pub fn quests (& self) -> QuestDataVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; QuestDataVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn participants_count (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn total_completions (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [40 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [44 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } else { Uint32 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> CampaignDataReader < 'r > { CampaignDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for CampaignData { type Builder = CampaignDataBuilder ; const NAME : & 'static str = "CampaignData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { CampaignData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . endorser (self . endorser ()) . created_at (self . created_at ()) . starting_time (self . starting_time ()) . ending_time (self . ending_time ()) . rules (self . rules ()) . metadata (self . metadata ()) . status (self . status ()) . quests (self . quests ()) . participants_count (self . participants_count ()) . total_completions (self . total_completions ()) } }
-# [derive (Clone , Copy)] pub struct CampaignDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for CampaignDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for CampaignDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for CampaignDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "endorser" , self . endorser ()) ? ; write ! (f , ", {}: {}" , "created_at" , self . created_at ()) ? ; write ! (f , ", {}: {}" , "starting_time" , self . starting_time ()) ? ; write ! (f , ", {}: {}" , "ending_time" , self . ending_time ()) ? ; write ! (f , ", {}: {}" , "rules" , self . rules ()) ? ; write ! (f , ", {}: {}" , "metadata" , self . metadata ()) ? ; write ! (f , ", {}: {}" , "status" , self . status ()) ? ; write ! (f , ", {}: {}" , "quests" , self . quests ()) ? ; write ! (f , ", {}: {}" , "participants_count" , self . participants_count ()) ? ; write ! (f , ", {}: {}" , "total_completions" , self . total_completions ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > CampaignDataReader < 'r > { pub const FIELD_COUNT : usize = 10 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn endorser (& self) -> EndorserInfoReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; EndorserInfoReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn created_at (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn starting_time (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ending_time (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn rules (& self) -> StringVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; StringVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn metadata (& self) -> CampaignMetadataReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; CampaignMetadataReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn status (& self) -> ByteReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; ByteReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn quests (& self) -> QuestDataVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; QuestDataVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn participants_count (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn total_completions (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [40 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [44 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Uint32Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for CampaignDataReader < 'r > { type Entity = CampaignData ; const NAME : & 'static str = "CampaignDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { CampaignDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } EndorserInfoReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; StringVecReader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; CampaignMetadataReader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; ByteReader :: verify (& slice [offsets [6] .. offsets [7]] , compatible) ? ; QuestDataVecReader :: verify (& slice [offsets [7] .. offsets [8]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [8] .. offsets [9]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [9] .. offsets [10]] , compatible) ? ; Ok (()) } }
-# [derive (Clone , Debug , Default)] pub struct CampaignDataBuilder { pub (crate) endorser : EndorserInfo , pub (crate) created_at : Uint64 , pub (crate) starting_time : Uint64 , pub (crate) ending_time : Uint64 , pub (crate) rules : StringVec , pub (crate) metadata : CampaignMetadata , pub (crate) status : Byte , pub (crate) quests : QuestDataVec , pub (crate) participants_count : Uint32 , pub (crate) total_completions : Uint32 , } impl CampaignDataBuilder { pub const FIELD_COUNT : usize = 10 ; pub fn endorser < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < EndorserInfo > { self . endorser = v . into () ; self } pub fn created_at < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . created_at = v . into () ; self } pub fn starting_time < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . starting_time = v . into () ; self } pub fn ending_time < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . ending_time = v . into () ; self } pub fn rules < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < StringVec > { self . rules = v . into () ; self } pub fn metadata < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < CampaignMetadata > { self . metadata = v . into () ; self } pub fn status < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte > { self . status = v . into () ; self } pub fn quests < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < QuestDataVec > { self . quests = v . into () ; self } pub fn participants_count < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . participants_count = v . into () ; self } pub fn total_completions < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . total_completions = v . into () ; self } } impl molecule :: prelude :: Builder for CampaignDataBuilder { type Entity = CampaignData ; const NAME : & 'static str = "CampaignDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . endorser . as_slice () . len () + self . created_at . as_slice () . len () + self . starting_time . as_slice () . len () + self . ending_time . as_slice () . len () + self . rules . as_slice () . len () + self . metadata . as_slice () . len () + self . status . as_slice () . len () + self . quests . as_slice () . len () + self . participants_count . as_slice () . len () + self . total_completions . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . endorser . as_slice () . len () ; offsets . push (total_size) ; total_size += self . created_at . as_slice () . len () ; offsets . push (total_size) ; total_size += self . starting_time . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ending_time . as_slice () . len () ; offsets . push (total_size) ; total_size += self . rules . as_slice () . len () ; offsets . push (total_size) ; total_size += self . metadata . as_slice () . len () ; offsets . push (total_size) ; total_size += self . status . as_slice () . len () ; offsets . push (total_size) ; total_size += self . quests . as_slice () . len () ; offsets . push (total_size) ; total_size += self . participants_count . as_slice () . len () ; offsets . push (total_size) ; total_size += self . total_completions . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . endorser . as_slice ()) ? ; writer . write_all (self . created_at . as_slice ()) ? ; writer . write_all (self . starting_time . as_slice ()) ? ; writer . write_all (self . ending_time . as_slice ()) ? ; writer . write_all (self . rules . as_slice ()) ? ; writer . write_all (self . metadata . as_slice ()) ? ; writer . write_all (self . status . as_slice ()) ? ; writer . write_all (self . quests . as_slice ()) ? ; writer . write_all (self . participants_count . as_slice ()) ? ; writer . write_all (self . total_completions . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; CampaignData :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct CampaignManager (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for CampaignManager { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for CampaignManager { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for CampaignManager { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "lock_hash" , self . lock_hash ()) ? ; write ! (f , ", {}: {}" , "roles" , self . roles ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for CampaignManager { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; CampaignManager :: new_unchecked (v) } } impl CampaignManager { const DEFAULT_VALUE : [u8 ; 45] = [45 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn lock_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn roles (& self) -> Uint8 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint8 :: new_unchecked (self . 0 . slice (start .. end)) } else { Uint8 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> CampaignManagerReader < 'r > { CampaignManagerReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for CampaignManager { type Builder = CampaignManagerBuilder ; const NAME : & 'static str = "CampaignManager" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { CampaignManager (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignManagerReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignManagerReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . lock_hash (self . lock_hash ()) . roles (self . roles ()) } }
+# [derive (Clone , Copy)] pub struct CampaignManagerReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for CampaignManagerReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for CampaignManagerReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for CampaignManagerReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "lock_hash" , self . lock_hash ()) ? ; write ! (f , ", {}: {}" , "roles" , self . roles ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > CampaignManagerReader < 'r > { pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn lock_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn roles (& self) -> Uint8Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint8Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Uint8Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for CampaignManagerReader < 'r > { type Entity = CampaignManager ; const NAME : & 'static str = "CampaignManagerReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { CampaignManagerReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint8Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct CampaignManagerBuilder { pub (crate) lock_hash : Byte32 , pub (crate) roles : Uint8 , } impl CampaignManagerBuilder { pub const FIELD_COUNT : usize = 2 ; pub fn lock_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . lock_hash = v . into () ; self } pub fn roles < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint8 > { self . roles = v . into () ; self } } impl molecule :: prelude :: Builder for CampaignManagerBuilder { type Entity = CampaignManager ; const NAME : & 'static str = "CampaignManagerBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . lock_hash . as_slice () . len () + self . roles . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . lock_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . roles . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . lock_hash . as_slice ()) ? ; writer . write_all (self . roles . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; CampaignManager :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct CampaignManagerVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for CampaignManagerVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for CampaignManagerVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for CampaignManagerVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for CampaignManagerVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; CampaignManagerVec :: new_unchecked (v) } } impl CampaignManagerVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < CampaignManager > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> CampaignManager { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { CampaignManager :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; CampaignManager :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> CampaignManagerVecReader < 'r > { CampaignManagerVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for CampaignManagerVec { type Builder = CampaignManagerVecBuilder ; const NAME : & 'static str = "CampaignManagerVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { CampaignManagerVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignManagerVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignManagerVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
+# [derive (Clone , Copy)] pub struct CampaignManagerVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for CampaignManagerVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for CampaignManagerVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for CampaignManagerVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > CampaignManagerVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < CampaignManagerReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> CampaignManagerReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { CampaignManagerReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; CampaignManagerReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for CampaignManagerVecReader < 'r > { type Entity = CampaignManagerVec ; const NAME : & 'static str = "CampaignManagerVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { CampaignManagerVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; CampaignManagerReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct CampaignManagerVecBuilder (pub (crate) Vec < CampaignManager >) ; impl CampaignManagerVecBuilder { pub fn set (mut self , v : Vec < CampaignManager >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < CampaignManager > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = CampaignManager >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < CampaignManager > where T : :: core :: convert :: Into < CampaignManager > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for CampaignManagerVecBuilder { type Entity = CampaignManagerVec ; const NAME : & 'static str = "CampaignManagerVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; CampaignManagerVec :: new_unchecked (inner . into ()) } }
+pub struct CampaignManagerVecIterator (CampaignManagerVec , usize , usize) ; impl :: core :: iter :: Iterator for CampaignManagerVecIterator { type Item = CampaignManager ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for CampaignManagerVecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for CampaignManagerVec { type Item = CampaignManager ; type IntoIter = CampaignManagerVecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; CampaignManagerVecIterator (self , 0 , len) } } impl < 'r > CampaignManagerVecReader < 'r > { pub fn iter < 't > (& 't self) -> CampaignManagerVecReaderIterator < 't , 'r > { CampaignManagerVecReaderIterator (& self , 0 , self . len ()) } } pub struct CampaignManagerVecReaderIterator < 't , 'r > (& 't CampaignManagerVecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for CampaignManagerVecReaderIterator < 't , 'r > { type Item = CampaignManagerReader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for CampaignManagerVecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
+impl :: core :: iter :: FromIterator < CampaignManager > for CampaignManagerVec { fn from_iter < T : IntoIterator < Item = CampaignManager >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < CampaignManager >> for CampaignManagerVec { fn from (v : Vec < CampaignManager >) -> Self { Self :: new_builder () . set (v) . build () } }
+# [derive (Clone)] pub struct CampaignData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for CampaignData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for CampaignData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for CampaignData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "endorser" , self . endorser ()) ? ; write ! (f , ", {}: {}" , "created_at" , self . created_at ()) ? ; write ! (f , ", {}: {}" , "starting_time" , self . starting_time ()) ? ; write ! (f , ", {}: {}" , "ending_time" , self . ending_time ()) ? ; write ! (f , ", {}: {}" , "rules" , self . rules ()) ? ; write ! (f , ", {}: {}" , "metadata" , self . metadata ()) ? ; write ! (f , ", {}: {}" , "status" , self . status ()) ? ; write ! (f , ", {}: {}" , "quests" , self . quests ()) ? ; write ! (f , ", {}: {}" , "participants_count" , self . participants_count ()) ? ; write ! (f , ", {}: {}" , "total_completions" , self . total_completions ()) ? ; write ! (f , ", {}: {}" , "approval_dispute_window" , self . approval_dispute_window ()) ? ; write ! (f , ", {}: {}" , "badge_cluster_id" , self . badge_cluster_id ()) ? ; write ! (f , ", {}: {}" , "managers" , self . managers ()) ? ; write ! (f , ", {}: {}" , "quest_layout" , self . quest_layout ()) ? ; write ! (f , ", {}: {}" , "approval_tracking" , self . approval_tracking ()) ? ; write ! (f , ", {}: {}" , "next_quest_id" , self . next_quest_id ()) ? ; write ! (f , ", {}: {}" , "last_recorded_at" , self . last_recorded_at ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for CampaignData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; CampaignData :: new_unchecked (v) } } impl CampaignData { const DEFAULT_VALUE : [u8 ; 422] = [166 , 1 , 0 , 0 , 72 , 0 , 0 , 0 , 149 , 0 , 0 , 0 , 157 , 0 , 0 , 0 , 165 , 0 , 0 , 0 , 173 , 0 , 0 , 0 , 177 , 0 , 0 , 0 , 127 , 1 , 0 , 0 , 128 , 1 , 0 , 0 , 132 , 1 , 0 , 0 , 136 , 1 , 0 , 0 , 140 , 1 , 0 , 0 , 148 , 1 , 0 , 0 , 148 , 1 , 0 , 0 , 152 , 1 , 0 , 0 , 153 , 1 , 0 , 0 , 154 , 1 , 0 , 0 , 158 , 1 , 0 , 0 , 77 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 60 , 0 , 0 , 0 , 64 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 76 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 206 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 48 , 0 , 0 , 0 , 125 , 0 , 0 , 0 , 129 , 0 , 0 , 0 , 133 , 0 , 0 , 0 , 185 , 0 , 0 , 0 , 189 , 0 , 0 , 0 , 197 , 0 , 0 , 0 , 201 , 0 , 0 , 0 , 202 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 77 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 60 , 0 , 0 , 0 , 64 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 76 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 52 , 0 , 0 , 0 , 20 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 48 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 17 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn endorser (& self) -> EndorserInfo { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; EndorserInfo :: new_unchecked (self . 0 . slice (start .. end)) } pub fn created_at (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn starting_time (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ending_time (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn rules (& self) -> StringVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; StringVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn metadata (& self) -> CampaignMetadata { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; CampaignMetadata :: new_unchecked (self . 0 . slice (start .. end)) } pub fn status (& self) -> Byte { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; Byte :: new_unchecked (self . 0 . slice (start .. end)) } pub fn quests (& self) -> QuestDataVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; QuestDataVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn participants_count (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn total_completions (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [40 ..]) as usize ; let end = molecule :: unpack_number (& slice [44 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn approval_dispute_window (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [44 ..]) as usize ; let end = molecule :: unpack_number (& slice [48 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn badge_cluster_id (& self) -> Byte32Opt { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [48 ..]) as usize ; let end = molecule :: unpack_number (& slice [52 ..]) as usize ; Byte32Opt :: new_unchecked (self . 0 . slice (start .. end)) } pub fn managers (& self) -> CampaignManagerVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [52 ..]) as usize ; let end = molecule :: unpack_number (& slice [56 ..]) as usize ; CampaignManagerVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn quest_layout (& self) -> Byte { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [56 ..]) as usize ; let end = molecule :: unpack_number (& slice [60 ..]) as usize ; Byte :: new_unchecked (self . 0 . slice (start .. end)) } pub fn approval_tracking (& self) -> Byte { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [60 ..]) as usize ; let end = molecule :: unpack_number (& slice [64 ..]) as usize ; Byte :: new_unchecked (self . 0 . slice (start .. end)) } pub fn next_quest_id (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [64 ..]) as usize ; let end = molecule :: unpack_number (& slice [68 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn last_recorded_at (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [68 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [72 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } else { Uint64 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> CampaignDataReader < 'r > { CampaignDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for CampaignData { type Builder = CampaignDataBuilder ; const NAME : & 'static str = "CampaignData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { CampaignData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . endorser (self . endorser ()) . created_at (self . created_at ()) . starting_time (self . starting_time ()) . ending_time (self . ending_time ()) . rules (self . rules ()) . metadata (self . metadata ()) . status (self . status ()) . quests (self . quests ()) . participants_count (self . participants_count ()) . total_completions (self . total_completions ()) . approval_dispute_window (self . approval_dispute_window ()) . badge_cluster_id (self . badge_cluster_id ()) . managers (self . managers ()) . quest_layout (self . quest_layout ()) . approval_tracking (self . approval_tracking ()) . next_quest_id (self . next_quest_id ()) . last_recorded_at (self . last_recorded_at ()) } }
+# [derive (Clone , Copy)] pub struct CampaignDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for CampaignDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for CampaignDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for CampaignDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "endorser" , self . endorser ()) ? ; write ! (f , ", {}: {}" , "created_at" , self . created_at ()) ? ; write ! (f , ", {}: {}" , "starting_time" , self . starting_time ()) ? ; write ! (f , ", {}: {}" , "ending_time" , self . ending_time ()) ? ; write ! (f , ", {}: {}" , "rules" , self . rules ()) ? ; write ! (f , ", {}: {}" , "metadata" , self . metadata ()) ? ; write ! (f , ", {}: {}" , "status" , self . status ()) ? ; write ! (f , ", {}: {}" , "quests" , self . quests ()) ? ; write ! (f , ", {}: {}" , "participants_count" , self . participants_count ()) ? ; write ! (f , ", {}: {}" , "total_completions" , self . total_completions ()) ? ; write ! (f , ", {}: {}" , "approval_dispute_window" , self . approval_dispute_window ()) ? ; write ! (f , ", {}: {}" , "badge_cluster_id" , self . badge_cluster_id ()) ? ; write ! (f , ", {}: {}" , "managers" , self . managers ()) ? ; write ! (f , ", {}: {}" , "quest_layout" , self . quest_layout ()) ? ; write ! (f , ", {}: {}" , "approval_tracking" , self . approval_tracking ()) ? ; write ! (f , ", {}: {}" , "next_quest_id" , self . next_quest_id ()) ? ; write ! (f , ", {}: {}" , "last_recorded_at" , self . last_recorded_at ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > CampaignDataReader < 'r > { pub const FIELD_COUNT : usize = 17 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn endorser (& self) -> EndorserInfoReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; EndorserInfoReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn created_at (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn starting_time (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ending_time (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn rules (& self) -> StringVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; StringVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn metadata (& self) -> CampaignMetadataReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; CampaignMetadataReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn status (& self) -> ByteReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; ByteReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn quests (& self) -> QuestDataVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; QuestDataVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn participants_count (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn total_completions (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [40 ..]) as usize ; let end = molecule :: unpack_number (& slice [44 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn approval_dispute_window (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [44 ..]) as usize ; let end = molecule :: unpack_number (& slice [48 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn badge_cluster_id (& self) -> Byte32OptReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [48 ..]) as usize ; let end = molecule :: unpack_number (& slice [52 ..]) as usize ; Byte32OptReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn managers (& self) -> CampaignManagerVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [52 ..]) as usize ; let end = molecule :: unpack_number (& slice [56 ..]) as usize ; CampaignManagerVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn quest_layout (& self) -> ByteReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [56 ..]) as usize ; let end = molecule :: unpack_number (& slice [60 ..]) as usize ; ByteReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn approval_tracking (& self) -> ByteReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [60 ..]) as usize ; let end = molecule :: unpack_number (& slice [64 ..]) as usize ; ByteReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn next_quest_id (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [64 ..]) as usize ; let end = molecule :: unpack_number (& slice [68 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn last_recorded_at (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [68 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [72 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Uint64Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for CampaignDataReader < 'r > { type Entity = CampaignData ; const NAME : & 'static str = "CampaignDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { CampaignDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } EndorserInfoReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; StringVecReader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; CampaignMetadataReader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; ByteReader :: verify (& slice [offsets [6] .. offsets [7]] , compatible) ? ; QuestDataVecReader :: verify (& slice [offsets [7] .. offsets [8]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [8] .. offsets [9]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [9] .. offsets [10]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [10] .. offsets [11]] , compatible) ? ; Byte32OptReader :: verify (& slice [offsets [11] .. offsets [12]] , compatible) ? ; CampaignManagerVecReader :: verify (& slice [offsets [12] .. offsets [13]] , compatible) ? ; ByteReader :: verify (& slice [offsets [13] .. offsets [14]] , compatible) ? ; ByteReader :: verify (& slice [offsets [14] .. offsets [15]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [15] .. offsets [16]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [16] .. offsets [17]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct CampaignDataBuilder { pub (crate) endorser : EndorserInfo , pub (crate) created_at : Uint64 , pub (crate) starting_time : Uint64 , pub (crate) ending_time : Uint64 , pub (crate) rules : StringVec , pub (crate) metadata : CampaignMetadata , pub (crate) status : Byte , pub (crate) quests : QuestDataVec , pub (crate) participants_count : Uint32 , pub (crate) total_completions : Uint32 , pub (crate) approval_dispute_window : Uint64 , pub (crate) badge_cluster_id : Byte32Opt , pub (crate) managers : CampaignManagerVec , pub (crate) quest_layout : Byte , pub (crate) approval_tracking : Byte , pub (crate) next_quest_id : Uint32 , pub (crate) last_recorded_at : Uint64 , } impl CampaignDataBuilder { pub const FIELD_COUNT : usize = 17 ; pub fn endorser < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < EndorserInfo > { self . endorser = v . into () ; self } pub fn created_at < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . created_at = v . into () ; self } pub fn starting_time < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . starting_time = v . into () ; self } pub fn ending_time < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . ending_time = v . into () ; self } pub fn rules < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < StringVec > { self . rules = v . into () ; self } pub fn metadata < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < CampaignMetadata > { self . metadata = v . into () ; self } pub fn status < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte > { self . status = v . into () ; self } pub fn quests < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < QuestDataVec > { self . quests = v . into () ; self } pub fn participants_count < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . participants_count = v . into () ; self } pub fn total_completions < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . total_completions = v . into () ; self } pub fn approval_dispute_window < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . approval_dispute_window = v . into () ; self } pub fn badge_cluster_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32Opt > { self . badge_cluster_id = v . into () ; self } pub fn managers < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < CampaignManagerVec > { self . managers = v . into () ; self } pub fn quest_layout < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte > { self . quest_layout = v . into () ; self } pub fn approval_tracking < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte > { self . approval_tracking = v . into () ; self } pub fn next_quest_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . next_quest_id = v . into () ; self } pub fn last_recorded_at < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . last_recorded_at = v . into () ; self } } impl molecule :: prelude :: Builder for CampaignDataBuilder { type Entity = CampaignData ; const NAME : & 'static str = "CampaignDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . endorser . as_slice () . len () + self . created_at . as_slice () . len () + self . starting_time . as_slice () . len () + self . ending_time . as_slice () . len () + self . rules . as_slice () . len () + self . metadata . as_slice () . len () + self . status . as_slice () . len () + self . quests . as_slice () . len () + self . participants_count . as_slice () . len () + self . total_completions . as_slice () . len () + self . approval_dispute_window . as_slice () . len () + self . badge_cluster_id . as_slice () . len () + self . managers . as_slice () . len () + self . quest_layout . as_slice () . len () + self . approval_tracking . as_slice () . len () + self . next_quest_id . as_slice () . len () + self . last_recorded_at . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . endorser . as_slice () . len () ; offsets . push (total_size) ; total_size += self . created_at . as_slice () . len () ; offsets . push (total_size) ; total_size += self . starting_time . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ending_time . as_slice () . len () ; offsets . push (total_size) ; total_size += self . rules . as_slice () . len () ; offsets . push (total_size) ; total_size += self . metadata . as_slice () . len () ; offsets . push (total_size) ; total_size += self . status . as_slice () . len () ; offsets . push (total_size) ; total_size += self . quests . as_slice () . len () ; offsets . push (total_size) ; total_size += self . participants_count . as_slice () . len () ; offsets . push (total_size) ; total_size += self . total_completions . as_slice () . len () ; offsets . push (total_size) ; total_size += self . approval_dispute_window . as_slice () . len () ; offsets . push (total_size) ; total_size += self . badge_cluster_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . managers . as_slice () . len () ; offsets . push (total_size) ; total_size += self . quest_layout . as_slice () . len () ; offsets . push (total_size) ; total_size += self . approval_tracking . as_slice () . len () ; offsets . push (total_size) ; total_size += self . next_quest_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . last_recorded_at . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . endorser . as_slice ()) ? ; writer . write_all (self . created_at . as_slice ()) ? ; writer . write_all (self . starting_time . as_slice ()) ? ; writer . write_all (self . ending_time . as_slice ()) ? ; writer . write_all (self . rules . as_slice ()) ? ; writer . write_all (self . metadata . as_slice ()) ? ; writer . write_all (self . status . as_slice ()) ? ; writer . write_all (self . quests . as_slice ()) ? ; writer . write_all (self . participants_count . as_slice ()) ? ; writer . write_all (self . total_completions . as_slice ()) ? ; writer . write_all (self . approval_dispute_window . as_slice ()) ? ; writer . write_all (self . badge_cluster_id . as_slice ()) ? ; writer . write_all (self . managers . as_slice ()) ? ; writer . write_all (self . quest_layout . as_slice ()) ? ; writer . write_all (self . approval_tracking . as_slice ()) ? ; writer . write_all (self . next_quest_id . as_slice ()) ? ; writer . write_all (self . last_recorded_at . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; CampaignData :: new_unchecked (inner . into ()) } }
 # [derive (Clone)] pub struct CampaignDataVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for CampaignDataVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for CampaignDataVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for CampaignDataVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for CampaignDataVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; CampaignDataVec :: new_unchecked (v) } } impl CampaignDataVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < CampaignData > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> CampaignData { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { CampaignData :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; CampaignData :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> CampaignDataVecReader < 'r > { CampaignDataVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for CampaignDataVec { type Builder = CampaignDataVecBuilder ; const NAME : & 'static str = "CampaignDataVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { CampaignDataVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignDataVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { CampaignDataVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
 # [derive (Clone , Copy)] pub struct CampaignDataVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for CampaignDataVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for CampaignDataVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for CampaignDataVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > CampaignDataVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < CampaignDataReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> CampaignDataReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { CampaignDataReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; CampaignDataReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for CampaignDataVecReader < 'r > { type Entity = CampaignDataVec ; const NAME : & 'static str = "CampaignDataVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { CampaignDataVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; CampaignDataReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
 # [derive (Clone , Debug , Default)] pub struct CampaignDataVecBuilder (pub (crate) Vec < CampaignData >) ; impl CampaignDataVecBuilder { pub fn set (mut self , v : Vec < CampaignData >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < CampaignData > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = CampaignData >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < CampaignData > where T : :: core :: convert :: Into < CampaignData > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for CampaignDataVecBuilder { type Entity = CampaignDataVec ; const NAME : & 'static str = "CampaignDataVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; CampaignDataVec :: new_unchecked (inner . into ()) } }
 pub struct CampaignDataVecIterator (CampaignDataVec , usize , usize) ; impl :: core :: iter :: Iterator for CampaignDataVecIterator { type Item = CampaignData ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for CampaignDataVecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for CampaignDataVec { type Item = CampaignData ; type IntoIter = CampaignDataVecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; CampaignDataVecIterator (self , 0 , len) } } impl < 'r > CampaignDataVecReader < 'r > { pub fn iter < 't > (& 't self) -> CampaignDataVecReaderIterator < 't , 'r > { CampaignDataVecReaderIterator (& self , 0 , self . len ()) } } pub struct CampaignDataVecReaderIterator < 't , 'r > (& 't CampaignDataVecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for CampaignDataVecReaderIterator < 't , 'r > { type Item = CampaignDataReader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for CampaignDataVecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
 impl :: core :: iter :: FromIterator < CampaignData > for CampaignDataVec { fn from_iter < T : IntoIterator < Item = CampaignData >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < CampaignData >> for CampaignDataVec { fn from (v : Vec < CampaignData >) -> Self { Self :: new_builder () . set (v) . build () } }
+# [derive (Clone)] pub struct ApprovalProof (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for ApprovalProof { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for ApprovalProof { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for ApprovalProof { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "quest_id" , self . quest_id ()) ? ; write ! (f , ", {}: {}" , "user_type_ids" , self . user_type_ids ()) ? ; write ! (f , ", {}: {}" , "proof" , self . proof ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for ApprovalProof { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; ApprovalProof :: new_unchecked (v) } } impl ApprovalProof { const DEFAULT_VALUE : [u8 ; 28] = [28 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 20 , 0 , 0 , 0 , 24 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 3 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn quest_id (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn user_type_ids (& self) -> Byte32Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Byte32Vec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn proof (& self) -> Bytes { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Bytes :: new_unchecked (self . 0 . slice (start .. end)) } else { Bytes :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> ApprovalProofReader < 'r > { ApprovalProofReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for ApprovalProof { type Builder = ApprovalProofBuilder ; const NAME : & 'static str = "ApprovalProof" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { ApprovalProof (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ApprovalProofReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ApprovalProofReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . quest_id (self . quest_id ()) . user_type_ids (self . user_type_ids ()) . proof (self . proof ()) } }
+# [derive (Clone , Copy)] pub struct ApprovalProofReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for ApprovalProofReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for ApprovalProofReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for ApprovalProofReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "quest_id" , self . quest_id ()) ? ; write ! (f , ", {}: {}" , "user_type_ids" , self . user_type_ids ()) ? ; write ! (f , ", {}: {}" , "proof" , self . proof ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > ApprovalProofReader < 'r > { pub const FIELD_COUNT : usize = 3 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn quest_id (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn user_type_ids (& self) -> Byte32VecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Byte32VecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn proof (& self) -> BytesReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [16 ..]) as usize ; BytesReader :: new_unchecked (& self . as_slice () [start .. end]) } else { BytesReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for ApprovalProofReader < 'r > { type Entity = ApprovalProof ; const NAME : & 'static str = "ApprovalProofReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { ApprovalProofReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Uint32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Byte32VecReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; BytesReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct ApprovalProofBuilder { pub (crate) quest_id : Uint32 , pub (crate) user_type_ids : Byte32Vec , pub (crate) proof : Bytes , } impl ApprovalProofBuilder { pub const FIELD_COUNT : usize = 3 ; pub fn quest_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . quest_id = v . into () ; self } pub fn user_type_ids < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32Vec > { self . user_type_ids = v . into () ; self } pub fn proof < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Bytes > { self . proof = v . into () ; self } } impl molecule :: prelude :: Builder for ApprovalProofBuilder { type Entity = ApprovalProof ; const NAME : & 'static str = "ApprovalProofBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . quest_id . as_slice () . len () + self . user_type_ids . as_slice () . len () + self . proof . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . quest_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . user_type_ids . as_slice () . len () ; offsets . push (total_size) ; total_size += self . proof . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . quest_id . as_slice ()) ? ; writer . write_all (self . user_type_ids . as_slice ()) ? ; writer . write_all (self . proof . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; ApprovalProof :: new_unchecked (inner . into ()) } }
 # [derive (Clone)] pub struct TippingProposalMetadata (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for TippingProposalMetadata { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for TippingProposalMetadata { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for TippingProposalMetadata { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "contribution_title" , self . contribution_title ()) ? ; write ! (f , ", {}: {}" , "contribution_type_tags" , self . contribution_type_tags ()) ? ; write ! (f , ", {}: {}" , "description" , self . description ()) ? ; write ! (f , ", {}: {}" , "proposal_creation_timestamp" , self . proposal_creation_timestamp ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for TippingProposalMetadata { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; TippingProposalMetadata :: new_unchecked (v) } } impl TippingProposalMetadata { const DEFAULT_VALUE : [u8 ; 40] = [40 , 0 , 0 , 0 , 20 , 0 , 0 , 0 , 24 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 32 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 4 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn contribution_title (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } pub fn contribution_type_tags (& self) -> StringVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; StringVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn description (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } pub fn proposal_creation_timestamp (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } else { Uint64 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> TippingProposalMetadataReader < 'r > { TippingProposalMetadataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for TippingProposalMetadata { type Builder = TippingProposalMetadataBuilder ; const NAME : & 'static str = "TippingProposalMetadata" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { TippingProposalMetadata (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { TippingProposalMetadataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { TippingProposalMetadataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . contribution_title (self . contribution_title ()) . contribution_type_tags (self . contribution_type_tags ()) . description (self . description ()) . proposal_creation_timestamp (self . proposal_creation_timestamp ()) } }
 # [derive (Clone , Copy)] pub struct TippingProposalMetadataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for TippingProposalMetadataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for TippingProposalMetadataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for TippingProposalMetadataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "contribution_title" , self . contribution_title ()) ? ; write ! (f , ", {}: {}" , "contribution_type_tags" , self . contribution_type_tags ()) ? ; write ! (f , ", {}: {}" , "description" , self . description ()) ? ; write ! (f , ", {}: {}" , "proposal_creation_timestamp" , self . proposal_creation_timestamp ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > TippingProposalMetadataReader < 'r > { pub const FIELD_COUNT : usize = 4 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn contribution_title (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn contribution_type_tags (& self) -> StringVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; StringVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn description (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn proposal_creation_timestamp (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Uint64Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for TippingProposalMetadataReader < 'r > { type Entity = TippingProposalMetadata ; const NAME : & 'static str = "TippingProposalMetadataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { TippingProposalMetadataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } StringReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; StringVecReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; StringReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Ok (()) } }
 # [derive (Clone , Debug , Default)] pub struct TippingProposalMetadataBuilder { pub (crate) contribution_title : String , pub (crate) contribution_type_tags : StringVec , pub (crate) description : String , pub (crate) proposal_creation_timestamp : Uint64 , } impl TippingProposalMetadataBuilder { pub const FIELD_COUNT : usize = 4 ; pub fn contribution_title < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . contribution_title = v . into () ; self } pub fn contribution_type_tags < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < StringVec > { self . contribution_type_tags = v . into () ; self } pub fn description < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . description = v . into () ; self } pub fn proposal_creation_timestamp < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . proposal_creation_timestamp = v . into () ; self } } impl molecule :: prelude :: Builder for TippingProposalMetadataBuilder { type Entity = TippingProposalMetadata ; const NAME : & 'static str = "TippingProposalMetadataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . contribution_title . as_slice () . len () + self . contribution_type_tags . as_slice () . len () + self . description . as_slice () . len () + self . proposal_creation_timestamp . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . contribution_title . as_slice () . len () ; offsets . push (total_size) ; total_size += self . contribution_type_tags . as_slice () . len () ; offsets . push (total_size) ; total_size += self . description . as_slice () . len () ; offsets . push (total_size) ; total_size += self . proposal_creation_timestamp . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . contribution_title . as_slice ()) ? ; writer . write_all (self . contribution_type_tags . as_slice ()) ? ; writer . write_all (self . description . as_slice ()) ? ; writer . write_all (self . proposal_creation_timestamp . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; TippingProposalMetadata :: new_unchecked (inner . into ()) } }
@@ -245,29 +296,53 @@ impl :: core :: iter :: FromIterator < TippingProposalData > for TippingProposal
 # [derive (Clone)] pub struct TippingConfig (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for TippingConfig { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for TippingConfig { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for TippingConfig { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "approval_requirement_thresholds" , self . approval_requirement_thresholds ()) ? ; write ! (f , ", {}: {}" , "expiration_duration" , self . expiration_duration ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for TippingConfig { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; TippingConfig :: new_unchecked (v) } } impl TippingConfig { const DEFAULT_VALUE : [u8 ; 24] = [24 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn approval_requirement_thresholds (& self) -> Uint128Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint128Vec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn expiration_duration (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } else { Uint64 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> TippingConfigReader < 'r > { TippingConfigReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for TippingConfig { type Builder = TippingConfigBuilder ; const NAME : & 'static str = "TippingConfig" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { TippingConfig (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { TippingConfigReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { TippingConfigReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . approval_requirement_thresholds (self . approval_requirement_thresholds ()) . expiration_duration (self . expiration_duration ()) } }
 # [derive (Clone , Copy)] pub struct TippingConfigReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for TippingConfigReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for TippingConfigReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for TippingConfigReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "approval_requirement_thresholds" , self . approval_requirement_thresholds ()) ? ; write ! (f , ", {}: {}" , "expiration_duration" , self . expiration_duration ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > TippingConfigReader < 'r > { pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn approval_requirement_thresholds (& self) -> Uint128VecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint128VecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn expiration_duration (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Uint64Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for TippingConfigReader < 'r > { type Entity = TippingConfig ; const NAME : & 'static str = "TippingConfigReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { TippingConfigReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Uint128VecReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Ok (()) } }
 # [derive (Clone , Debug , Default)] pub struct TippingConfigBuilder { pub (crate) approval_requirement_thresholds : Uint128Vec , pub (crate) expiration_duration : Uint64 , } impl TippingConfigBuilder { pub const FIELD_COUNT : usize = 2 ; pub fn approval_requirement_thresholds < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint128Vec > { self . approval_requirement_thresholds = v . into () ; self } pub fn expiration_duration < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . expiration_duration = v . into () ; self } } impl molecule :: prelude :: Builder for TippingConfigBuilder { type Entity = TippingConfig ; const NAME : & 'static str = "TippingConfigBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . approval_requirement_thresholds . as_slice () . len () + self . expiration_duration . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . approval_requirement_thresholds . as_slice () . len () ; offsets . push (total_size) ; total_size += self . expiration_duration . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . approval_requirement_thresholds . as_slice ()) ? ; writer . write_all (self . expiration_duration . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; TippingConfig :: new_unchecked (inner . into ()) } }
-# [derive (Clone)] pub struct ScriptCodeHashes (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for ScriptCodeHashes { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for ScriptCodeHashes { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for ScriptCodeHashes { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "ckb_boost_protocol_type_code_hash" , self . ckb_boost_protocol_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_protocol_lock_code_hash" , self . ckb_boost_protocol_lock_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_campaign_type_code_hash" , self . ckb_boost_campaign_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_campaign_lock_code_hash" , self . ckb_boost_campaign_lock_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_user_type_code_hash" , self . ckb_boost_user_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_points_udt_type_code_hash" , self . ckb_boost_points_udt_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "accepted_udt_type_scripts" , self . accepted_udt_type_scripts ()) ? ; write ! (f , ", {}: {}" , "accepted_dob_type_scripts" , self . accepted_dob_type_scripts ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for ScriptCodeHashes { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; ScriptCodeHashes :: new_unchecked (v) } } impl ScriptCodeHashes { const DEFAULT_VALUE : [u8 ; 236] = [236 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 100 , 0 , 0 , 0 , 132 , 0 , 0 , 0 , 164 , 0 , 0 , 0 , 196 , 0 , 0 , 0 , 228 , 0 , 0 , 0 , 232 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 4 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 8 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn ckb_boost_protocol_type_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_protocol_lock_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_campaign_type_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_campaign_lock_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_user_type_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_points_udt_type_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn accepted_udt_type_scripts (& self) -> ScriptVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; ScriptVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn accepted_dob_type_scripts (& self) -> ScriptVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [36 ..]) as usize ; ScriptVec :: new_unchecked (self . 0 . slice (start .. end)) } else { ScriptVec :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> ScriptCodeHashesReader < 'r > { ScriptCodeHashesReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for ScriptCodeHashes { type Builder = ScriptCodeHashesBuilder ; const NAME : & 'static str = "ScriptCodeHashes" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { ScriptCodeHashes (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ScriptCodeHashesReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ScriptCodeHashesReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . ckb_boost_protocol_type_code_hash (self . ckb_boost_protocol_type_code_hash ()) . ckb_boost_protocol_lock_code_hash (self . ckb_boost_protocol_lock_code_hash ()) . ckb_boost_campaign_type_code_hash (self . ckb_boost_campaign_type_code_hash ()) . ckb_boost_campaign_lock_code_hash (self . ckb_boost_campaign_lock_code_hash ()) . ckb_boost_user_type_code_hash (self . ckb_boost_user_type_code_hash ()) . ckb_boost_points_udt_type_code_hash (self . ckb_boost_points_udt_type_code_hash ()) . accepted_udt_type_scripts (self . accepted_udt_type_scripts ()) . accepted_dob_type_scripts (self . accepted_dob_type_scripts ()) } }
-# [derive (Clone , Copy)] pub struct ScriptCodeHashesReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for ScriptCodeHashesReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for ScriptCodeHashesReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for ScriptCodeHashesReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "ckb_boost_protocol_type_code_hash" , self . ckb_boost_protocol_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_protocol_lock_code_hash" , self . ckb_boost_protocol_lock_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_campaign_type_code_hash" , self . ckb_boost_campaign_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_campaign_lock_code_hash" , self . ckb_boost_campaign_lock_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_user_type_code_hash" , self . ckb_boost_user_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_points_udt_type_code_hash" , self . ckb_boost_points_udt_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "accepted_udt_type_scripts" , self . accepted_udt_type_scripts ()) ? ; write ! (f , ", {}: {}" , "accepted_dob_type_scripts" , self . accepted_dob_type_scripts ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > ScriptCodeHashesReader < 'r > { pub const FIELD_COUNT : usize = 8 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn ckb_boost_protocol_type_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_protocol_lock_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_campaign_type_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_campaign_lock_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_user_type_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_points_udt_type_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn accepted_udt_type_scripts (& self) -> ScriptVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; ScriptVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn accepted_dob_type_scripts (& self) -> ScriptVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [36 ..]) as usize ; ScriptVecReader :: new_unchecked (& self . as_slice () [start .. end]) } else { ScriptVecReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for ScriptCodeHashesReader < 'r > { type Entity = ScriptCodeHashes ; const NAME : & 'static str = "ScriptCodeHashesReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { ScriptCodeHashesReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; ScriptVecReader :: verify (& slice [offsets [6] .. offsets [7]] , compatible) ? ; ScriptVecReader :: verify (& slice [offsets [7] .. offsets [8]] , compatible) ? ; Ok (()) } }
-# [derive (Clone , Debug , Default)] pub struct ScriptCodeHashesBuilder { pub (crate) ckb_boost_protocol_type_code_hash : Byte32 , pub (crate) ckb_boost_protocol_lock_code_hash : Byte32 , pub (crate) ckb_boost_campaign_type_code_hash : Byte32 , pub (crate) ckb_boost_campaign_lock_code_hash : Byte32 , pub (crate) ckb_boost_user_type_code_hash : Byte32 , pub (crate) ckb_boost_points_udt_type_code_hash : Byte32 , pub (crate) accepted_udt_type_scripts : ScriptVec , pub (crate) accepted_dob_type_scripts : ScriptVec , } impl ScriptCodeHashesBuilder { pub const FIELD_COUNT : usize = 8 ; pub fn ckb_boost_protocol_type_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_protocol_type_code_hash = v . into () ; self } pub fn ckb_boost_protocol_lock_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_protocol_lock_code_hash = v . into () ; self } pub fn ckb_boost_campaign_type_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_campaign_type_code_hash = v . into () ; self } pub fn ckb_boost_campaign_lock_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_campaign_lock_code_hash = v . into () ; self } pub fn ckb_boost_user_type_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_user_type_code_hash = v . into () ; self } pub fn ckb_boost_points_udt_type_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_points_udt_type_code_hash = v . into () ; self } pub fn accepted_udt_type_scripts < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < ScriptVec > { self . accepted_udt_type_scripts = v . into () ; self } pub fn accepted_dob_type_scripts < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < ScriptVec > { self . accepted_dob_type_scripts = v . into () ; self } } impl molecule :: prelude :: Builder for ScriptCodeHashesBuilder { type Entity = ScriptCodeHashes ; const NAME : & 'static str = "ScriptCodeHashesBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . ckb_boost_protocol_type_code_hash . as_slice () . len () + self . ckb_boost_protocol_lock_code_hash . as_slice () . len () + self . ckb_boost_campaign_type_code_hash . as_slice () . len () + self . ckb_boost_campaign_lock_code_hash . as_slice () . len () + self . ckb_boost_user_type_code_hash . as_slice () . len () + self . ckb_boost_points_udt_type_code_hash . as_slice () . len () + self . accepted_udt_type_scripts . as_slice () . len () + self . accepted_dob_type_scripts . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . ckb_boost_protocol_type_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_protocol_lock_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_campaign_type_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_campaign_lock_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_user_type_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_points_udt_type_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . accepted_udt_type_scripts . as_slice () . len () ; offsets . push (total_size) ; total_size += self . accepted_dob_type_scripts . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . ckb_boost_protocol_type_code_hash . as_slice ()) ? ; writer . write_all (self . ckb_boost_protocol_lock_code_hash . as_slice ()) ? ; writer . write_all (self . ckb_boost_campaign_type_code_hash . as_slice ()) ? ; writer . write_all (self . ckb_boost_campaign_lock_code_hash . as_slice ()) ? ; writer . write_all (self . ckb_boost_user_type_code_hash . as_slice ()) ? ; writer . write_all (self . ckb_boost_points_udt_type_code_hash . as_slice ()) ? ; writer . write_all (self . accepted_udt_type_scripts . as_slice ()) ? ; writer . write_all (self . accepted_dob_type_scripts . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; ScriptCodeHashes :: new_unchecked (inner . into ()) } }
-# [derive (Clone)] pub struct ProtocolConfig (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for ProtocolConfig { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for ProtocolConfig { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for ProtocolConfig { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "admin_lock_hash_vec" , self . admin_lock_hash_vec ()) ? ; write ! (f , ", {}: {}" , "script_code_hashes" , self . script_code_hashes ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for ProtocolConfig { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; ProtocolConfig :: new_unchecked (v) } } impl ProtocolConfig { const DEFAULT_VALUE : [u8 ; 252] = [252 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 236 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 100 , 0 , 0 , 0 , 132 , 0 , 0 , 0 , 164 , 0 , 0 , 0 , 196 , 0 , 0 , 0 , 228 , 0 , 0 , 0 , 232 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 4 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn admin_lock_hash_vec (& self) -> Byte32Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Vec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn script_code_hashes (& self) -> ScriptCodeHashes { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; ScriptCodeHashes :: new_unchecked (self . 0 . slice (start .. end)) } else { ScriptCodeHashes :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> ProtocolConfigReader < 'r > { ProtocolConfigReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for ProtocolConfig { type Builder = ProtocolConfigBuilder ; const NAME : & 'static str = "ProtocolConfig" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { ProtocolConfig (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ProtocolConfigReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ProtocolConfigReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . admin_lock_hash_vec (self . admin_lock_hash_vec ()) . script_code_hashes (self . script_code_hashes ()) } }
+# [derive (Clone)] pub struct ScriptCodeHashes (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for ScriptCodeHashes { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for ScriptCodeHashes { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for ScriptCodeHashes { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "ckb_boost_protocol_type_code_hash" , self . ckb_boost_protocol_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_protocol_lock_code_hash" , self . ckb_boost_protocol_lock_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_campaign_type_code_hash" , self . ckb_boost_campaign_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_campaign_lock_code_hash" , self . ckb_boost_campaign_lock_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_user_type_code_hash" , self . ckb_boost_user_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_points_udt_type_code_hash" , self . ckb_boost_points_udt_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "accepted_udt_type_scripts" , self . accepted_udt_type_scripts ()) ? ; write ! (f , ", {}: {}" , "accepted_dob_type_scripts" , self . accepted_dob_type_scripts ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_quest_type_code_hash" , self . ckb_boost_quest_type_code_hash ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for ScriptCodeHashes { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; ScriptCodeHashes :: new_unchecked (v) } } impl ScriptCodeHashes { const DEFAULT_VALUE : [u8 ; 272] = [16 , 1 , 0 , 0 , 40 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 104 , 0 , 0 , 0 , 136 , 0 , 0 , 0 , 168 , 0 , 0 , 0 , 200 , 0 , 0 , 0 , 232 , 0 , 0 , 0 , 236 , 0 , 0 , 0 , 240 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 9 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn ckb_boost_protocol_type_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_protocol_lock_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_campaign_type_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_campaign_lock_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_user_type_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_points_udt_type_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn accepted_udt_type_scripts (& self) -> ScriptVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; ScriptVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn accepted_dob_type_scripts (& self) -> ScriptVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; ScriptVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn ckb_boost_quest_type_code_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } else { Byte32 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> ScriptCodeHashesReader < 'r > { ScriptCodeHashesReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for ScriptCodeHashes { type Builder = ScriptCodeHashesBuilder ; const NAME : & 'static str = "ScriptCodeHashes" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { ScriptCodeHashes (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ScriptCodeHashesReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ScriptCodeHashesReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . ckb_boost_protocol_type_code_hash (self . ckb_boost_protocol_type_code_hash ()) . ckb_boost_protocol_lock_code_hash (self . ckb_boost_protocol_lock_code_hash ()) . ckb_boost_campaign_type_code_hash (self . ckb_boost_campaign_type_code_hash ()) . ckb_boost_campaign_lock_code_hash (self . ckb_boost_campaign_lock_code_hash ()) . ckb_boost_user_type_code_hash (self . ckb_boost_user_type_code_hash ()) . ckb_boost_points_udt_type_code_hash (self . ckb_boost_points_udt_type_code_hash ()) . accepted_udt_type_scripts (self . accepted_udt_type_scripts ()) . accepted_dob_type_scripts (self . accepted_dob_type_scripts ()) . ckb_boost_quest_type_code_hash (self . ckb_boost_quest_type_code_hash ()) } }
+# [derive (Clone , Copy)] pub struct ScriptCodeHashesReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for ScriptCodeHashesReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for ScriptCodeHashesReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for ScriptCodeHashesReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "ckb_boost_protocol_type_code_hash" , self . ckb_boost_protocol_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_protocol_lock_code_hash" , self . ckb_boost_protocol_lock_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_campaign_type_code_hash" , self . ckb_boost_campaign_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_campaign_lock_code_hash" , self . ckb_boost_campaign_lock_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_user_type_code_hash" , self . ckb_boost_user_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_points_udt_type_code_hash" , self . ckb_boost_points_udt_type_code_hash ()) ? ; write ! (f , ", {}: {}" , "accepted_udt_type_scripts" , self . accepted_udt_type_scripts ()) ? ; write ! (f , ", {}: {}" , "accepted_dob_type_scripts" , self . accepted_dob_type_scripts ()) ? ; write ! (f , ", {}: {}" , "ckb_boost_quest_type_code_hash" , self . ckb_boost_quest_type_code_hash ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > ScriptCodeHashesReader < 'r > { pub const FIELD_COUNT : usize = 9 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn ckb_boost_protocol_type_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_protocol_lock_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_campaign_type_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_campaign_lock_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_user_type_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_points_udt_type_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn accepted_udt_type_scripts (& self) -> ScriptVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; ScriptVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn accepted_dob_type_scripts (& self) -> ScriptVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; let end = molecule :: unpack_number (& slice [36 ..]) as usize ; ScriptVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn ckb_boost_quest_type_code_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [36 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [40 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Byte32Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for ScriptCodeHashesReader < 'r > { type Entity = ScriptCodeHashes ; const NAME : & 'static str = "ScriptCodeHashesReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { ScriptCodeHashesReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; ScriptVecReader :: verify (& slice [offsets [6] .. offsets [7]] , compatible) ? ; ScriptVecReader :: verify (& slice [offsets [7] .. offsets [8]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [8] .. offsets [9]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct ScriptCodeHashesBuilder { pub (crate) ckb_boost_protocol_type_code_hash : Byte32 , pub (crate) ckb_boost_protocol_lock_code_hash : Byte32 , pub (crate) ckb_boost_campaign_type_code_hash : Byte32 , pub (crate) ckb_boost_campaign_lock_code_hash : Byte32 , pub (crate) ckb_boost_user_type_code_hash : Byte32 , pub (crate) ckb_boost_points_udt_type_code_hash : Byte32 , pub (crate) accepted_udt_type_scripts : ScriptVec , pub (crate) accepted_dob_type_scripts : ScriptVec , pub (crate) ckb_boost_quest_type_code_hash : Byte32 , } impl ScriptCodeHashesBuilder { pub const FIELD_COUNT : usize = 9 ; pub fn ckb_boost_protocol_type_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_protocol_type_code_hash = v . into () ; self } pub fn ckb_boost_protocol_lock_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_protocol_lock_code_hash = v . into () ; self } pub fn ckb_boost_campaign_type_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_campaign_type_code_hash = v . into () ; self } pub fn ckb_boost_campaign_lock_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_campaign_lock_code_hash = v . into () ; self } pub fn ckb_boost_user_type_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_user_type_code_hash = v . into () ; self } pub fn ckb_boost_points_udt_type_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_points_udt_type_code_hash = v . into () ; self } pub fn accepted_udt_type_scripts < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < ScriptVec > { self . accepted_udt_type_scripts = v . into () ; self } pub fn accepted_dob_type_scripts < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < ScriptVec > { self . accepted_dob_type_scripts = v . into () ; self } pub fn ckb_boost_quest_type_code_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . ckb_boost_quest_type_code_hash = v . into () ; self } } impl molecule :: prelude :: Builder for ScriptCodeHashesBuilder { type Entity = ScriptCodeHashes ; const NAME : & 'static str = "ScriptCodeHashesBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . ckb_boost_protocol_type_code_hash . as_slice () . len () + self . ckb_boost_protocol_lock_code_hash . as_slice () . len () + self . ckb_boost_campaign_type_code_hash . as_slice () . len () + self . ckb_boost_campaign_lock_code_hash . as_slice () . len () + self . ckb_boost_user_type_code_hash . as_slice () . len () + self . ckb_boost_points_udt_type_code_hash . as_slice () . len () + self . accepted_udt_type_scripts . as_slice () . len () + self . accepted_dob_type_scripts . as_slice () . len () + self . ckb_boost_quest_type_code_hash . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . ckb_boost_protocol_type_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_protocol_lock_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_campaign_type_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_campaign_lock_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_user_type_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_points_udt_type_code_hash . as_slice () . len () ; offsets . push (total_size) ; total_size += self . accepted_udt_type_scripts . as_slice () . len () ; offsets . push (total_size) ; total_size += self . accepted_dob_type_scripts . as_slice () . len () ; offsets . push (total_size) ; total_size += self . ckb_boost_quest_type_code_hash . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . ckb_boost_protocol_type_code_hash . as_slice ()) ? ; writer . write_all (self . ckb_boost_protocol_lock_code_hash . as_slice ()) ? ; writer . write_all (self . ckb_boost_campaign_type_code_hash . as_slice ()) ? ; writer . write_all (self . ckb_boost_campaign_lock_code_hash . as_slice ()) ? ; writer . write_all (self . ckb_boost_user_type_code_hash . as_slice ()) ? ; writer . write_all (self . ckb_boost_points_udt_type_code_hash . as_slice ()) ? ; writer . write_all (self . accepted_udt_type_scripts . as_slice ()) ? ; writer . write_all (self . accepted_dob_type_scripts . as_slice ()) ? ; writer . write_all (self . ckb_boost_quest_type_code_hash . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; ScriptCodeHashes :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct ProtocolConfig (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for ProtocolConfig { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for ProtocolConfig { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for ProtocolConfig { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "admin_lock_hash_vec" , self . admin_lock_hash_vec ()) ? ; write ! (f , ", {}: {}" , "script_code_hashes" , self . script_code_hashes ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for ProtocolConfig { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; ProtocolConfig :: new_unchecked (v) } } impl ProtocolConfig { const DEFAULT_VALUE : [u8 ; 288] = [32 , 1 , 0 , 0 , 12 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 16 , 1 , 0 , 0 , 40 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 104 , 0 , 0 , 0 , 136 , 0 , 0 , 0 , 168 , 0 , 0 , 0 , 200 , 0 , 0 , 0 , 232 , 0 , 0 , 0 , 236 , 0 , 0 , 0 , 240 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn admin_lock_hash_vec (& self) -> Byte32Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Vec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn script_code_hashes (& self) -> ScriptCodeHashes { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; ScriptCodeHashes :: new_unchecked (self . 0 . slice (start .. end)) } else { ScriptCodeHashes :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> ProtocolConfigReader < 'r > { ProtocolConfigReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for ProtocolConfig { type Builder = ProtocolConfigBuilder ; const NAME : & 'static str = "ProtocolConfig" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { ProtocolConfig (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ProtocolConfigReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ProtocolConfigReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . admin_lock_hash_vec (self . admin_lock_hash_vec ()) . script_code_hashes (self . script_code_hashes ()) } }
 # [derive (Clone , Copy)] pub struct ProtocolConfigReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for ProtocolConfigReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for ProtocolConfigReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for ProtocolConfigReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "admin_lock_hash_vec" , self . admin_lock_hash_vec ()) ? ; write ! (f , ", {}: {}" , "script_code_hashes" , self . script_code_hashes ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > ProtocolConfigReader < 'r > { pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn admin_lock_hash_vec (& self) -> Byte32VecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32VecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn script_code_hashes (& self) -> ScriptCodeHashesReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; ScriptCodeHashesReader :: new_unchecked (& self . as_slice () [start .. end]) } else { ScriptCodeHashesReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for ProtocolConfigReader < 'r > { type Entity = ProtocolConfig ; const NAME : & 'static str = "ProtocolConfigReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { ProtocolConfigReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32VecReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; ScriptCodeHashesReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Ok (()) } }
 # [derive (Clone , Debug , Default)] pub struct ProtocolConfigBuilder { pub (crate) admin_lock_hash_vec : Byte32Vec , pub (crate) script_code_hashes : ScriptCodeHashes , } impl ProtocolConfigBuilder { pub const FIELD_COUNT : usize = 2 ; pub fn admin_lock_hash_vec < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32Vec > { self . admin_lock_hash_vec = v . into () ; self } pub fn script_code_hashes < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < ScriptCodeHashes > { self . script_code_hashes = v . into () ; self } } impl molecule :: prelude :: Builder for ProtocolConfigBuilder { type Entity = ProtocolConfig ; const NAME : & 'static str = "ProtocolConfigBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . admin_lock_hash_vec . as_slice () . len () + self . script_code_hashes . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . admin_lock_hash_vec . as_slice () . len () ; offsets . push (total_size) ; total_size += self . script_code_hashes . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . admin_lock_hash_vec . as_slice ()) ? ; writer . write_all (self . script_code_hashes . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; ProtocolConfig :: new_unchecked (inner . into ()) } }
-# [derive (Clone)] pub struct ProtocolData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for ProtocolData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for ProtocolData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for ProtocolData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "campaigns_approved" , self . campaigns_approved ()) ? ; write ! (f , ", {}: {}" , "tipping_proposals" , self . tipping_proposals ()) ? ; write ! (f , ", {}: {}" , "tipping_config" , self . tipping_config ()) ? ; write ! (f , ", {}: {}" , "endorsers_whitelist" , self . endorsers_whitelist ()) ? ; write ! (f , ", {}: {}" , "last_updated" , self . last_updated ()) ? ; write ! (f , ", {}: {}" , "protocol_config" , self . protocol_config ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for ProtocolData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; ProtocolData :: new_unchecked (v) } } impl ProtocolData { const DEFAULT_VALUE : [u8 ; 324] = [68 , 1 , 0 , 0 , 28 , 0 , 0 , 0 , 32 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 60 , 0 , 0 , 0 , 64 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 24 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 252 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 236 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 100 , 0 , 0 , 0 , 132 , 0 , 0 , 0 , 164 , 0 , 0 , 0 , 196 , 0 , 0 , 0 , 228 , 0 , 0 , 0 , 232 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 4 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 6 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn campaigns_approved (& self) -> Byte32Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Vec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn tipping_proposals (& self) -> TippingProposalDataVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; TippingProposalDataVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn tipping_config (& self) -> TippingConfig { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; TippingConfig :: new_unchecked (self . 0 . slice (start .. end)) } pub fn endorsers_whitelist (& self) -> EndorserInfoVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; EndorserInfoVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn last_updated (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn protocol_config (& self) -> ProtocolConfig { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [28 ..]) as usize ; ProtocolConfig :: new_unchecked (self . 0 . slice (start .. end)) } else { ProtocolConfig :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> ProtocolDataReader < 'r > { ProtocolDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for ProtocolData { type Builder = ProtocolDataBuilder ; const NAME : & 'static str = "ProtocolData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { ProtocolData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ProtocolDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ProtocolDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . campaigns_approved (self . campaigns_approved ()) . tipping_proposals (self . tipping_proposals ()) . tipping_config (self . tipping_config ()) . endorsers_whitelist (self . endorsers_whitelist ()) . last_updated (self . last_updated ()) . protocol_config (self . protocol_config ()) } }
-# [derive (Clone , Copy)] pub struct ProtocolDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for ProtocolDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for ProtocolDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for ProtocolDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "campaigns_approved" , self . campaigns_approved ()) ? ; write ! (f , ", {}: {}" , "tipping_proposals" , self . tipping_proposals ()) ? ; write ! (f , ", {}: {}" , "tipping_config" , self . tipping_config ()) ? ; write ! (f , ", {}: {}" , "endorsers_whitelist" , self . endorsers_whitelist ()) ? ; write ! (f , ", {}: {}" , "last_updated" , self . last_updated ()) ? ; write ! (f , ", {}: {}" , "protocol_config" , self . protocol_config ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > ProtocolDataReader < 'r > { pub const FIELD_COUNT : usize = 6 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn campaigns_approved (& self) -> Byte32VecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32VecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn tipping_proposals (& self) -> TippingProposalDataVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; TippingProposalDataVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn tipping_config (& self) -> TippingConfigReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; TippingConfigReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn endorsers_whitelist (& self) -> EndorserInfoVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; EndorserInfoVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn last_updated (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn protocol_config (& self) -> ProtocolConfigReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [28 ..]) as usize ; ProtocolConfigReader :: new_unchecked (& self . as_slice () [start .. end]) } else { ProtocolConfigReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for ProtocolDataReader < 'r > { type Entity = ProtocolData ; const NAME : & 'static str = "ProtocolDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { ProtocolDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32VecReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; TippingProposalDataVecReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; TippingConfigReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; EndorserInfoVecReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; ProtocolConfigReader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; Ok (()) } }
-# [derive (Clone , Debug , Default)] pub struct ProtocolDataBuilder { pub (crate) campaigns_approved : Byte32Vec , pub (crate) tipping_proposals : TippingProposalDataVec , pub (crate) tipping_config : TippingConfig , pub (crate) endorsers_whitelist : EndorserInfoVec , pub (crate) last_updated : Uint64 , pub (crate) protocol_config : ProtocolConfig , } impl ProtocolDataBuilder { pub const FIELD_COUNT : usize = 6 ; pub fn campaigns_approved < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32Vec > { self . campaigns_approved = v . into () ; self } pub fn tipping_proposals < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < TippingProposalDataVec > { self . tipping_proposals = v . into () ; self } pub fn tipping_config < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < TippingConfig > { self . tipping_config = v . into () ; self } pub fn endorsers_whitelist < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < EndorserInfoVec > { self . endorsers_whitelist = v . into () ; self } pub fn last_updated < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . last_updated = v . into () ; self } pub fn protocol_config < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < ProtocolConfig > { self . protocol_config = v . into () ; self } } impl molecule :: prelude :: Builder for ProtocolDataBuilder { type Entity = ProtocolData ; const NAME : & 'static str = "ProtocolDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . campaigns_approved . as_slice () . len () + self . tipping_proposals . as_slice () . len () + self . tipping_config . as_slice () . len () + self . endorsers_whitelist . as_slice () . len () + self . last_updated . as_slice () . len () + self . protocol_config . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . campaigns_approved . as_slice () . len () ; offsets . push (total_size) ; total_size += self . tipping_proposals . as_slice () . len () ; offsets . push (total_size) ; total_size += self . tipping_config . as_slice () . len () ; offsets . push (total_size) ; total_size += self . endorsers_whitelist . as_slice () . len () ; offsets . push (total_size) ; total_size += self . last_updated . as_slice () . len () ; offsets . push (total_size) ; total_size += self . protocol_config . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . campaigns_approved . as_slice ()) ? ; writer . write_all (self . tipping_proposals . as_slice ()) ? ; writer . write_all (self . tipping_config . as_slice ()) ? ; writer . write_all (self . endorsers_whitelist . as_slice ()) ? ; writer . write_all (self . last_updated . as_slice ()) ? ; writer . write_all (self . protocol_config . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; ProtocolData :: new_unchecked (inner . into ()) } }
-# [derive (Clone)] pub struct UserVerificationData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for UserVerificationData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for UserVerificationData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for UserVerificationData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "telegram_personal_chat_id" , self . telegram_personal_chat_id ()) ? ; write ! (f , ", {}: {}" , "identity_verification_data" , self . identity_verification_data ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for UserVerificationData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; UserVerificationData :: new_unchecked (v) } } impl UserVerificationData { const DEFAULT_VALUE : [u8 ; 32] = [32 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn telegram_personal_chat_id (& self) -> Uint128 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint128 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn identity_verification_data (& self) -> Bytes { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Bytes :: new_unchecked (self . 0 . slice (start .. end)) } else { Bytes :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> UserVerificationDataReader < 'r > { UserVerificationDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for UserVerificationData { type Builder = UserVerificationDataBuilder ; const NAME : & 'static str = "UserVerificationData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { UserVerificationData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserVerificationDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserVerificationDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . telegram_personal_chat_id (self . telegram_personal_chat_id ()) . identity_verification_data (self . identity_verification_data ()) } }
-# [derive (Clone , Copy)] pub struct UserVerificationDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for UserVerificationDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for UserVerificationDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for UserVerificationDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "telegram_personal_chat_id" , self . telegram_personal_chat_id ()) ? ; write ! (f , ", {}: {}" , "identity_verification_data" , self . identity_verification_data ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > UserVerificationDataReader < 'r > { pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn telegram_personal_chat_id (& self) -> Uint128Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint128Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn identity_verification_data (& self) -> BytesReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; BytesReader :: new_unchecked (& self . as_slice () [start .. end]) } else { BytesReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for UserVerificationDataReader < 'r > { type Entity = UserVerificationData ; const NAME : & 'static str = "UserVerificationDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { UserVerificationDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Uint128Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; BytesReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Ok (()) } }
-# [derive (Clone , Debug , Default)] pub struct UserVerificationDataBuilder { pub (crate) telegram_personal_chat_id : Uint128 , pub (crate) identity_verification_data : Bytes , } impl UserVerificationDataBuilder { pub const FIELD_COUNT : usize = 2 ; pub fn telegram_personal_chat_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint128 > { self . telegram_personal_chat_id = v . into () ; self } pub fn identity_verification_data < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Bytes > { self . identity_verification_data = v . into () ; self } } impl molecule :: prelude :: Builder for UserVerificationDataBuilder { type Entity = UserVerificationData ; const NAME : & 'static str = "UserVerificationDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . telegram_personal_chat_id . as_slice () . len () + self . identity_verification_data . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . telegram_personal_chat_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . identity_verification_data . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . telegram_personal_chat_id . as_slice ()) ? ; writer . write_all (self . identity_verification_data . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; UserVerificationData :: new_unchecked (inner . into ()) } }
-# [derive (Clone)] pub struct UserSubmissionRecord (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for UserSubmissionRecord { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for UserSubmissionRecord { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for UserSubmissionRecord { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "campaign_type_id" , self . campaign_type_id ()) ? ; write ! (f , ", {}: {}" , "quest_id" , self . quest_id ()) ? ; write ! (f , ", {}: {}" , "submission_timestamp" , self . submission_timestamp ()) ? ; write ! (f , ", {}: {}" , "submission_content" , self . submission_content ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for UserSubmissionRecord { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; UserSubmissionRecord :: new_unchecked (v) } } impl UserSubmissionRecord { const DEFAULT_VALUE : [u8 ; 68] = [68 , 0 , 0 , 0 , 20 , 0 , 0 , 0 , 52 , 0 , 0 , 0 , 56 , 0 , 0 , 0 , 64 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 4 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn campaign_type_id (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn quest_id (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn submission_timestamp (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn submission_content (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [20 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } else { String :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> UserSubmissionRecordReader < 'r > { UserSubmissionRecordReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for UserSubmissionRecord { type Builder = UserSubmissionRecordBuilder ; const NAME : & 'static str = "UserSubmissionRecord" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { UserSubmissionRecord (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserSubmissionRecordReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserSubmissionRecordReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . campaign_type_id (self . campaign_type_id ()) . quest_id (self . quest_id ()) . submission_timestamp (self . submission_timestamp ()) . submission_content (self . submission_content ()) } }
-# [derive (Clone , Copy)] pub struct UserSubmissionRecordReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for UserSubmissionRecordReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for UserSubmissionRecordReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for UserSubmissionRecordReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "campaign_type_id" , self . campaign_type_id ()) ? ; write ! (f , ", {}: {}" , "quest_id" , self . quest_id ()) ? ; write ! (f , ", {}: {}" , "submission_timestamp" , self . submission_timestamp ()) ? ; write ! (f , ", {}: {}" , "submission_content" , self . submission_content ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > UserSubmissionRecordReader < 'r > { pub const FIELD_COUNT : usize = 4 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn campaign_type_id (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn quest_id (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn submission_timestamp (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn submission_content (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [20 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } else { StringReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for UserSubmissionRecordReader < 'r > { type Entity = UserSubmissionRecord ; const NAME : & 'static str = "UserSubmissionRecordReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { UserSubmissionRecordReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; StringReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Ok (()) } }
-# [derive (Clone , Debug , Default)] pub struct UserSubmissionRecordBuilder { pub (crate) campaign_type_id : Byte32 , pub (crate) quest_id : Uint32 , pub (crate) submission_timestamp : Uint64 , pub (crate) submission_content : String , } impl UserSubmissionRecordBuilder { pub const FIELD_COUNT : usize = 4 ; pub fn campaign_type_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . campaign_type_id = v . into () ; self } pub fn quest_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . quest_id = v . into () ; self } pub fn submission_timestamp < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . submission_timestamp = v . into () ; self } pub fn submission_content < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . submission_content = v . into () ; self } } impl molecule :: prelude :: Builder for UserSubmissionRecordBuilder { type Entity = UserSubmissionRecord ; const NAME : & 'static str = "UserSubmissionRecordBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . campaign_type_id . as_slice () . len () + self . quest_id . as_slice () . len () + self . submission_timestamp . as_slice () . len () + self . submission_content . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . campaign_type_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . quest_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . submission_timestamp . as_slice () . len () ; offsets . push (total_size) ; total_size += self . submission_content . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . campaign_type_id . as_slice ()) ? ; writer . write_all (self . quest_id . as_slice ()) ? ; writer . write_all (self . submission_timestamp . as_slice ()) ? ; writer . write_all (self . submission_content . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; UserSubmissionRecord :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct AttestorInfo (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for AttestorInfo { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for AttestorInfo { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for AttestorInfo { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "verification_bit" , self . verification_bit ()) ? ; write ! (f , ", {}: {}" , "pubkey" , self . pubkey ()) ? ; write ! (f , ", {}: {}" , "name" , self . name ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for AttestorInfo { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; AttestorInfo :: new_unchecked (v) } } impl AttestorInfo { const DEFAULT_VALUE : [u8 ; 25] = [25 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 17 , 0 , 0 , 0 , 21 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 3 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn verification_bit (& self) -> Byte { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte :: new_unchecked (self . 0 . slice (start .. end)) } pub fn pubkey (& self) -> Bytes { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Bytes :: new_unchecked (self . 0 . slice (start .. end)) } pub fn name (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [16 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } else { String :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> AttestorInfoReader < 'r > { AttestorInfoReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for AttestorInfo { type Builder = AttestorInfoBuilder ; const NAME : & 'static str = "AttestorInfo" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { AttestorInfo (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { AttestorInfoReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { AttestorInfoReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . verification_bit (self . verification_bit ()) . pubkey (self . pubkey ()) . name (self . name ()) } }
+# [derive (Clone , Copy)] pub struct AttestorInfoReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for AttestorInfoReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for AttestorInfoReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for AttestorInfoReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "verification_bit" , self . verification_bit ()) ? ; write ! (f , ", {}: {}" , "pubkey" , self . pubkey ()) ? ; write ! (f , ", {}: {}" , "name" , self . name ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > AttestorInfoReader < 'r > { pub const FIELD_COUNT : usize = 3 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn verification_bit (& self) -> ByteReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; ByteReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn pubkey (& self) -> BytesReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; BytesReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn name (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [16 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } else { StringReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for AttestorInfoReader < 'r > { type Entity = AttestorInfo ; const NAME : & 'static str = "AttestorInfoReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { AttestorInfoReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } ByteReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; BytesReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; StringReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct AttestorInfoBuilder { pub (crate) verification_bit : Byte , pub (crate) pubkey : Bytes , pub (crate) name : String , } impl AttestorInfoBuilder { pub const FIELD_COUNT : usize = 3 ; pub fn verification_bit < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte > { self . verification_bit = v . into () ; self } pub fn pubkey < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Bytes > { self . pubkey = v . into () ; self } pub fn name < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . name = v . into () ; self } } impl molecule :: prelude :: Builder for AttestorInfoBuilder { type Entity = AttestorInfo ; const NAME : & 'static str = "AttestorInfoBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . verification_bit . as_slice () . len () + self . pubkey . as_slice () . len () + self . name . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . verification_bit . as_slice () . len () ; offsets . push (total_size) ; total_size += self . pubkey . as_slice () . len () ; offsets . push (total_size) ; total_size += self . name . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . verification_bit . as_slice ()) ? ; writer . write_all (self . pubkey . as_slice ()) ? ; writer . write_all (self . name . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; AttestorInfo :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct AttestorInfoVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for AttestorInfoVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for AttestorInfoVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for AttestorInfoVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for AttestorInfoVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; AttestorInfoVec :: new_unchecked (v) } } impl AttestorInfoVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < AttestorInfo > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> AttestorInfo { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { AttestorInfo :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; AttestorInfo :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> AttestorInfoVecReader < 'r > { AttestorInfoVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for AttestorInfoVec { type Builder = AttestorInfoVecBuilder ; const NAME : & 'static str = "AttestorInfoVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { AttestorInfoVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { AttestorInfoVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { AttestorInfoVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
+# [derive (Clone , Copy)] pub struct AttestorInfoVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for AttestorInfoVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for AttestorInfoVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for AttestorInfoVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > AttestorInfoVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < AttestorInfoReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> AttestorInfoReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { AttestorInfoReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; AttestorInfoReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for AttestorInfoVecReader < 'r > { type Entity = AttestorInfoVec ; const NAME : & 'static str = "AttestorInfoVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { AttestorInfoVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; AttestorInfoReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct AttestorInfoVecBuilder (pub (crate) Vec < AttestorInfo >) ; impl AttestorInfoVecBuilder { pub fn set (mut self , v : Vec < AttestorInfo >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < AttestorInfo > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = AttestorInfo >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < AttestorInfo > where T : :: core :: convert :: Into < AttestorInfo > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for AttestorInfoVecBuilder { type Entity = AttestorInfoVec ; const NAME : & 'static str = "AttestorInfoVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; AttestorInfoVec :: new_unchecked (inner . into ()) } }
+pub struct AttestorInfoVecIterator (AttestorInfoVec , usize , usize) ; impl :: core :: iter :: Iterator for AttestorInfoVecIterator { type Item = AttestorInfo ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for AttestorInfoVecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for AttestorInfoVec { type Item = AttestorInfo ; type IntoIter = AttestorInfoVecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; AttestorInfoVecIterator (self , 0 , len) } } impl < 'r > AttestorInfoVecReader < 'r > { pub fn iter < 't > (& 't self) -> AttestorInfoVecReaderIterator < 't , 'r > { AttestorInfoVecReaderIterator (& self , 0 , self . len ()) } } pub struct AttestorInfoVecReaderIterator < 't , 'r > (& 't AttestorInfoVecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for AttestorInfoVecReaderIterator < 't , 'r > { type Item = AttestorInfoReader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for AttestorInfoVecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
+impl :: core :: iter :: FromIterator < AttestorInfo > for AttestorInfoVec { fn from_iter < T : IntoIterator < Item = AttestorInfo >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < AttestorInfo >> for AttestorInfoVec { fn from (v : Vec < AttestorInfo >) -> Self { Self :: new_builder () . set (v) . build () } }
+# [derive (Clone)] pub struct ProtocolData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for ProtocolData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for ProtocolData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for ProtocolData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "campaigns_approved" , self . campaigns_approved ()) ? ; write ! (f , ", {}: {}" , "tipping_proposals" , self . tipping_proposals ()) ? ; write ! (f , ", {}: {}" , "tipping_config" , self . tipping_config ()) ? ; write ! (f , ", {}: {}" , "endorsers_whitelist" , self . endorsers_whitelist ()) ? ; write ! (f , ", {}: {}" , "last_updated" , self . last_updated ()) ? ; write ! (f , ", {}: {}" , "protocol_config" , self . protocol_config ()) ? ; write ! (f , ", {}: {}" , "attestors" , self . attestors ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for ProtocolData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; ProtocolData :: new_unchecked (v) } } impl ProtocolData { const DEFAULT_VALUE : [u8 ; 368] = [112 , 1 , 0 , 0 , 32 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 40 , 0 , 0 , 0 , 64 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 76 , 0 , 0 , 0 , 108 , 1 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 24 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 32 , 1 , 0 , 0 , 12 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 16 , 1 , 0 , 0 , 40 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 104 , 0 , 0 , 0 , 136 , 0 , 0 , 0 , 168 , 0 , 0 , 0 , 200 , 0 , 0 , 0 , 232 , 0 , 0 , 0 , 236 , 0 , 0 , 0 , 240 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 7 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn campaigns_approved (& self) -> Byte32Vec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Vec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn tipping_proposals (& self) -> TippingProposalDataVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; TippingProposalDataVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn tipping_config (& self) -> TippingConfig { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; TippingConfig :: new_unchecked (self . 0 . slice (start .. end)) } pub fn endorsers_whitelist (& self) -> EndorserInfoVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; EndorserInfoVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn last_updated (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn protocol_config (& self) -> ProtocolConfig { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; ProtocolConfig :: new_unchecked (self . 0 . slice (start .. end)) } pub fn attestors (& self) -> AttestorInfoVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [32 ..]) as usize ; AttestorInfoVec :: new_unchecked (self . 0 . slice (start .. end)) } else { AttestorInfoVec :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> ProtocolDataReader < 'r > { ProtocolDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for ProtocolData { type Builder = ProtocolDataBuilder ; const NAME : & 'static str = "ProtocolData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { ProtocolData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ProtocolDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ProtocolDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . campaigns_approved (self . campaigns_approved ()) . tipping_proposals (self . tipping_proposals ()) . tipping_config (self . tipping_config ()) . endorsers_whitelist (self . endorsers_whitelist ()) . last_updated (self . last_updated ()) . protocol_config (self . protocol_config ()) . attestors (self . attestors ()) } }
+# [derive (Clone , Copy)] pub struct ProtocolDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for ProtocolDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for ProtocolDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for ProtocolDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "campaigns_approved" , self . campaigns_approved ()) ? ; write ! (f , ", {}: {}" , "tipping_proposals" , self . tipping_proposals ()) ? ; write ! (f , ", {}: {}" , "tipping_config" , self . tipping_config ()) ? ; write ! (f , ", {}: {}" , "endorsers_whitelist" , self . endorsers_whitelist ()) ? ; write ! (f , ", {}: {}" , "last_updated" , self . last_updated ()) ? ; write ! (f , ", {}: {}" , "protocol_config" , self . protocol_config ()) ? ; write ! (f , ", {}: {}" , "attestors" , self . attestors ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > ProtocolDataReader < 'r > { pub const FIELD_COUNT : usize = 7 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn campaigns_approved (& self) -> Byte32VecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32VecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn tipping_proposals (& self) -> TippingProposalDataVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; TippingProposalDataVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn tipping_config (& self) -> TippingConfigReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; TippingConfigReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn endorsers_whitelist (& self) -> EndorserInfoVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; EndorserInfoVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn last_updated (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn protocol_config (& self) -> ProtocolConfigReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; ProtocolConfigReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn attestors (& self) -> AttestorInfoVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [32 ..]) as usize ; AttestorInfoVecReader :: new_unchecked (& self . as_slice () [start .. end]) } else { AttestorInfoVecReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for ProtocolDataReader < 'r > { type Entity = ProtocolData ; const NAME : & 'static str = "ProtocolDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { ProtocolDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32VecReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; TippingProposalDataVecReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; TippingConfigReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; EndorserInfoVecReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; ProtocolConfigReader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; AttestorInfoVecReader :: verify (& slice [offsets [6] .. offsets [7]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct ProtocolDataBuilder { pub (crate) campaigns_approved : Byte32Vec , pub (crate) tipping_proposals : TippingProposalDataVec , pub (crate) tipping_config : TippingConfig , pub (crate) endorsers_whitelist : EndorserInfoVec , pub (crate) last_updated : Uint64 , pub (crate) protocol_config : ProtocolConfig , pub (crate) attestors : AttestorInfoVec , } impl ProtocolDataBuilder { pub const FIELD_COUNT : usize = 7 ; pub fn campaigns_approved < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32Vec > { self . campaigns_approved = v . into () ; self } pub fn tipping_proposals < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < TippingProposalDataVec > { self . tipping_proposals = v . into () ; self } pub fn tipping_config < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < TippingConfig > { self . tipping_config = v . into () ; self } pub fn endorsers_whitelist < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < EndorserInfoVec > { self . endorsers_whitelist = v . into () ; self } pub fn last_updated < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . last_updated = v . into () ; self } pub fn protocol_config < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < ProtocolConfig > { self . protocol_config = v . into () ; self } pub fn attestors < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < AttestorInfoVec > { self . attestors = v . into () ; self } } impl molecule :: prelude :: Builder for ProtocolDataBuilder { type Entity = ProtocolData ; const NAME : & 'static str = "ProtocolDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . campaigns_approved . as_slice () . len () + self . tipping_proposals . as_slice () . len () + self . tipping_config . as_slice () . len () + self . endorsers_whitelist . as_slice () . len () + self . last_updated . as_slice () . len () + self . protocol_config . as_slice () . len () + self . attestors . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . campaigns_approved . as_slice () . len () ; offsets . push (total_size) ; total_size += self . tipping_proposals . as_slice () . len () ; offsets . push (total_size) ; total_size += self . tipping_config . as_slice () . len () ; offsets . push (total_size) ; total_size += self . endorsers_whitelist . as_slice () . len () ; offsets . push (total_size) ; total_size += self . last_updated . as_slice () . len () ; offsets . push (total_size) ; total_size += self . protocol_config . as_slice () . len () ; offsets . push (total_size) ; total_size += self . attestors . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . campaigns_approved . as_slice ()) ? ; writer . write_all (self . tipping_proposals . as_slice ()) ? ; writer . write_all (self . tipping_config . as_slice ()) ? ; writer . write_all (self . endorsers_whitelist . as_slice ()) ? ; writer . write_all (self . last_updated . as_slice ()) ? ; writer . write_all (self . protocol_config . as_slice ()) ? ; writer . write_all (self . attestors . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; ProtocolData :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct VerificationAttestation (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for VerificationAttestation { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for VerificationAttestation { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for VerificationAttestation { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "verification_bit" , self . verification_bit ()) ? ; write ! (f , ", {}: {}" , "expires_at" , self . expires_at ()) ? ; write ! (f , ", {}: {}" , "signature" , self . signature ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for VerificationAttestation { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; VerificationAttestation :: new_unchecked (v) } } impl VerificationAttestation { const DEFAULT_VALUE : [u8 ; 29] = [29 , 0 , 0 , 0 , 16 , 0 , 0 , 0 , 17 , 0 , 0 , 0 , 25 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 3 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn verification_bit (& self) -> Byte { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte :: new_unchecked (self . 0 . slice (start .. end)) } pub fn expires_at (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn signature (& self) -> Bytes { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Bytes :: new_unchecked (self . 0 . slice (start .. end)) } else { Bytes :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> VerificationAttestationReader < 'r > { VerificationAttestationReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for VerificationAttestation { type Builder = VerificationAttestationBuilder ; const NAME : & 'static str = "VerificationAttestation" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { VerificationAttestation (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { VerificationAttestationReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { VerificationAttestationReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . verification_bit (self . verification_bit ()) . expires_at (self . expires_at ()) . signature (self . signature ()) } }
+# [derive (Clone , Copy)] pub struct VerificationAttestationReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for VerificationAttestationReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for VerificationAttestationReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for VerificationAttestationReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "verification_bit" , self . verification_bit ()) ? ; write ! (f , ", {}: {}" , "expires_at" , self . expires_at ()) ? ; write ! (f , ", {}: {}" , "signature" , self . signature ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > VerificationAttestationReader < 'r > { pub const FIELD_COUNT : usize = 3 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn verification_bit (& self) -> ByteReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; ByteReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn expires_at (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn signature (& self) -> BytesReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [16 ..]) as usize ; BytesReader :: new_unchecked (& self . as_slice () [start .. end]) } else { BytesReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for VerificationAttestationReader < 'r > { type Entity = VerificationAttestation ; const NAME : & 'static str = "VerificationAttestationReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { VerificationAttestationReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } ByteReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; BytesReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct VerificationAttestationBuilder { pub (crate) verification_bit : Byte , pub (crate) expires_at : Uint64 , pub (crate) signature : Bytes , } impl VerificationAttestationBuilder { pub const FIELD_COUNT : usize = 3 ; pub fn verification_bit < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte > { self . verification_bit = v . into () ; self } pub fn expires_at < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . expires_at = v . into () ; self } pub fn signature < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Bytes > { self . signature = v . into () ; self } } impl molecule :: prelude :: Builder for VerificationAttestationBuilder { type Entity = VerificationAttestation ; const NAME : & 'static str = "VerificationAttestationBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . verification_bit . as_slice () . len () + self . expires_at . as_slice () . len () + self . signature . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . verification_bit . as_slice () . len () ; offsets . push (total_size) ; total_size += self . expires_at . as_slice () . len () ; offsets . push (total_size) ; total_size += self . signature . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . verification_bit . as_slice ()) ? ; writer . write_all (self . expires_at . as_slice ()) ? ; writer . write_all (self . signature . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; VerificationAttestation :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct VerificationAttestationVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for VerificationAttestationVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for VerificationAttestationVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for VerificationAttestationVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for VerificationAttestationVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; VerificationAttestationVec :: new_unchecked (v) } } impl VerificationAttestationVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < VerificationAttestation > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> VerificationAttestation { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { VerificationAttestation :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; VerificationAttestation :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> VerificationAttestationVecReader < 'r > { VerificationAttestationVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for VerificationAttestationVec { type Builder = VerificationAttestationVecBuilder ; const NAME : & 'static str = "VerificationAttestationVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { VerificationAttestationVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { VerificationAttestationVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { VerificationAttestationVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
+# [derive (Clone , Copy)] pub struct VerificationAttestationVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for VerificationAttestationVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for VerificationAttestationVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for VerificationAttestationVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > VerificationAttestationVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < VerificationAttestationReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> VerificationAttestationReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { VerificationAttestationReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; VerificationAttestationReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for VerificationAttestationVecReader < 'r > { type Entity = VerificationAttestationVec ; const NAME : & 'static str = "VerificationAttestationVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { VerificationAttestationVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; VerificationAttestationReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct VerificationAttestationVecBuilder (pub (crate) Vec < VerificationAttestation >) ; impl VerificationAttestationVecBuilder { pub fn set (mut self , v : Vec < VerificationAttestation >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < VerificationAttestation > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = VerificationAttestation >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < VerificationAttestation > where T : :: core :: convert :: Into < VerificationAttestation > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for VerificationAttestationVecBuilder { type Entity = VerificationAttestationVec ; const NAME : & 'static str = "VerificationAttestationVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; VerificationAttestationVec :: new_unchecked (inner . into ()) } }
+pub struct VerificationAttestationVecIterator (VerificationAttestationVec , usize , usize) ; impl :: core :: iter :: Iterator for VerificationAttestationVecIterator { type Item = VerificationAttestation ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for VerificationAttestationVecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for VerificationAttestationVec { type Item = VerificationAttestation ; type IntoIter = VerificationAttestationVecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; VerificationAttestationVecIterator (self , 0 , len) } } impl < 'r > VerificationAttestationVecReader < 'r > { pub fn iter < 't > (& 't self) -> VerificationAttestationVecReaderIterator < 't , 'r > { VerificationAttestationVecReaderIterator (& self , 0 , self . len ()) } } pub struct VerificationAttestationVecReaderIterator < 't , 'r > (& 't VerificationAttestationVecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for VerificationAttestationVecReaderIterator < 't , 'r > { type Item = VerificationAttestationReader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for VerificationAttestationVecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
+impl :: core :: iter :: FromIterator < VerificationAttestation > for VerificationAttestationVec { fn from_iter < T : IntoIterator < Item = VerificationAttestation >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < VerificationAttestation >> for VerificationAttestationVec { fn from (v : Vec < VerificationAttestation >) -> Self { Self :: new_builder () . set (v) . build () } }
+# [derive (Clone)] pub struct UserVerificationData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for UserVerificationData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for UserVerificationData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for UserVerificationData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "telegram_personal_chat_id" , self . telegram_personal_chat_id ()) ? ; write ! (f , ", {}: {}" , "identity_verification_data" , self . identity_verification_data ()) ? ; write ! (f , ", {}: {}" , "verified_flags" , self . verified_flags ()) ? ; write ! (f , ", {}: {}" , "attestations" , self . attestations ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for UserVerificationData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; UserVerificationData :: new_unchecked (v) } } impl UserVerificationData { const DEFAULT_VALUE : [u8 ; 45] = [45 , 0 , 0 , 0 , 20 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 40 , 0 , 0 , 0 , 41 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 4 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn telegram_personal_chat_id (& self) -> Uint128 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint128 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn identity_verification_data (& self) -> Bytes { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Bytes :: new_unchecked (self . 0 . slice (start .. end)) } pub fn verified_flags (& self) -> Byte { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Byte :: new_unchecked (self . 0 . slice (start .. end)) } pub fn attestations (& self) -> VerificationAttestationVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [20 ..]) as usize ; VerificationAttestationVec :: new_unchecked (self . 0 . slice (start .. end)) } else { VerificationAttestationVec :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> UserVerificationDataReader < 'r > { UserVerificationDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for UserVerificationData { type Builder = UserVerificationDataBuilder ; const NAME : & 'static str = "UserVerificationData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { UserVerificationData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserVerificationDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserVerificationDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . telegram_personal_chat_id (self . telegram_personal_chat_id ()) . identity_verification_data (self . identity_verification_data ()) . verified_flags (self . verified_flags ()) . attestations (self . attestations ()) } }
+# [derive (Clone , Copy)] pub struct UserVerificationDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for UserVerificationDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for UserVerificationDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for UserVerificationDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "telegram_personal_chat_id" , self . telegram_personal_chat_id ()) ? ; write ! (f , ", {}: {}" , "identity_verification_data" , self . identity_verification_data ()) ? ; write ! (f , ", {}: {}" , "verified_flags" , self . verified_flags ()) ? ; write ! (f , ", {}: {}" , "attestations" , self . attestations ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > UserVerificationDataReader < 'r > { pub const FIELD_COUNT : usize = 4 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn telegram_personal_chat_id (& self) -> Uint128Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint128Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn identity_verification_data (& self) -> BytesReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; BytesReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn verified_flags (& self) -> ByteReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; ByteReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn attestations (& self) -> VerificationAttestationVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [20 ..]) as usize ; VerificationAttestationVecReader :: new_unchecked (& self . as_slice () [start .. end]) } else { VerificationAttestationVecReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for UserVerificationDataReader < 'r > { type Entity = UserVerificationData ; const NAME : & 'static str = "UserVerificationDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { UserVerificationDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Uint128Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; BytesReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; ByteReader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; VerificationAttestationVecReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct UserVerificationDataBuilder { pub (crate) telegram_personal_chat_id : Uint128 , pub (crate) identity_verification_data : Bytes , pub (crate) verified_flags : Byte , pub (crate) attestations : VerificationAttestationVec , } impl UserVerificationDataBuilder { pub const FIELD_COUNT : usize = 4 ; pub fn telegram_personal_chat_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint128 > { self . telegram_personal_chat_id = v . into () ; self } pub fn identity_verification_data < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Bytes > { self . identity_verification_data = v . into () ; self } pub fn verified_flags < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte > { self . verified_flags = v . into () ; self } pub fn attestations < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < VerificationAttestationVec > { self . attestations = v . into () ; self } } impl molecule :: prelude :: Builder for UserVerificationDataBuilder { type Entity = UserVerificationData ; const NAME : & 'static str = "UserVerificationDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . telegram_personal_chat_id . as_slice () . len () + self . identity_verification_data . as_slice () . len () + self . verified_flags . as_slice () . len () + self . attestations . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . telegram_personal_chat_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . identity_verification_data . as_slice () . len () ; offsets . push (total_size) ; total_size += self . verified_flags . as_slice () . len () ; offsets . push (total_size) ; total_size += self . attestations . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . telegram_personal_chat_id . as_slice ()) ? ; writer . write_all (self . identity_verification_data . as_slice ()) ? ; writer . write_all (self . verified_flags . as_slice ()) ? ; writer . write_all (self . attestations . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; UserVerificationData :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct SubTaskSubmission (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for SubTaskSubmission { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for SubTaskSubmission { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for SubTaskSubmission { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "sub_task_id" , self . sub_task_id ()) ? ; write ! (f , ", {}: {}" , "submission_content" , self . submission_content ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for SubTaskSubmission { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; SubTaskSubmission :: new_unchecked (v) } } impl SubTaskSubmission { const DEFAULT_VALUE : [u8 ; 17] = [17 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 13 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn sub_task_id (& self) -> Uint8 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint8 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn submission_content (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } else { String :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> SubTaskSubmissionReader < 'r > { SubTaskSubmissionReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for SubTaskSubmission { type Builder = SubTaskSubmissionBuilder ; const NAME : & 'static str = "SubTaskSubmission" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { SubTaskSubmission (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubTaskSubmissionReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubTaskSubmissionReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . sub_task_id (self . sub_task_id ()) . submission_content (self . submission_content ()) } }
+# [derive (Clone , Copy)] pub struct SubTaskSubmissionReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for SubTaskSubmissionReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for SubTaskSubmissionReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for SubTaskSubmissionReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "sub_task_id" , self . sub_task_id ()) ? ; write ! (f , ", {}: {}" , "submission_content" , self . submission_content ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > SubTaskSubmissionReader < 'r > { pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn sub_task_id (& self) -> Uint8Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Uint8Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn submission_content (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } else { StringReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for SubTaskSubmissionReader < 'r > { type Entity = SubTaskSubmission ; const NAME : & 'static str = "SubTaskSubmissionReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { SubTaskSubmissionReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Uint8Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; StringReader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct SubTaskSubmissionBuilder { pub (crate) sub_task_id : Uint8 , pub (crate) submission_content : String , } impl SubTaskSubmissionBuilder { pub const FIELD_COUNT : usize = 2 ; pub fn sub_task_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint8 > { self . sub_task_id = v . into () ; self } pub fn submission_content < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . submission_content = v . into () ; self } } impl molecule :: prelude :: Builder for SubTaskSubmissionBuilder { type Entity = SubTaskSubmission ; const NAME : & 'static str = "SubTaskSubmissionBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . sub_task_id . as_slice () . len () + self . submission_content . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . sub_task_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . submission_content . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . sub_task_id . as_slice ()) ? ; writer . write_all (self . submission_content . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; SubTaskSubmission :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct SubTaskSubmissionVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for SubTaskSubmissionVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for SubTaskSubmissionVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for SubTaskSubmissionVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for SubTaskSubmissionVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; SubTaskSubmissionVec :: new_unchecked (v) } } impl SubTaskSubmissionVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < SubTaskSubmission > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> SubTaskSubmission { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { SubTaskSubmission :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; SubTaskSubmission :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> SubTaskSubmissionVecReader < 'r > { SubTaskSubmissionVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for SubTaskSubmissionVec { type Builder = SubTaskSubmissionVecBuilder ; const NAME : & 'static str = "SubTaskSubmissionVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { SubTaskSubmissionVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubTaskSubmissionVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { SubTaskSubmissionVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
+# [derive (Clone , Copy)] pub struct SubTaskSubmissionVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for SubTaskSubmissionVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for SubTaskSubmissionVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for SubTaskSubmissionVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > SubTaskSubmissionVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < SubTaskSubmissionReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> SubTaskSubmissionReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { SubTaskSubmissionReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; SubTaskSubmissionReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for SubTaskSubmissionVecReader < 'r > { type Entity = SubTaskSubmissionVec ; const NAME : & 'static str = "SubTaskSubmissionVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { SubTaskSubmissionVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; SubTaskSubmissionReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct SubTaskSubmissionVecBuilder (pub (crate) Vec < SubTaskSubmission >) ; impl SubTaskSubmissionVecBuilder { pub fn set (mut self , v : Vec < SubTaskSubmission >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < SubTaskSubmission > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = SubTaskSubmission >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < SubTaskSubmission > where T : :: core :: convert :: Into < SubTaskSubmission > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for SubTaskSubmissionVecBuilder { type Entity = SubTaskSubmissionVec ; const NAME : & 'static str = "SubTaskSubmissionVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; SubTaskSubmissionVec :: new_unchecked (inner . into ()) } }
+pub struct SubTaskSubmissionVecIterator (SubTaskSubmissionVec , usize , usize) ; impl :: core :: iter :: Iterator for SubTaskSubmissionVecIterator { type Item = SubTaskSubmission ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for SubTaskSubmissionVecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for SubTaskSubmissionVec { type Item = SubTaskSubmission ; type IntoIter = SubTaskSubmissionVecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; SubTaskSubmissionVecIterator (self , 0 , len) } } impl < 'r > SubTaskSubmissionVecReader < 'r > { pub fn iter < 't > (& 't self) -> SubTaskSubmissionVecReaderIterator < 't , 'r > { SubTaskSubmissionVecReaderIterator (& self , 0 , self . len ()) } } pub struct SubTaskSubmissionVecReaderIterator < 't , 'r > (& 't SubTaskSubmissionVecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for SubTaskSubmissionVecReaderIterator < 't , 'r > { type Item = SubTaskSubmissionReader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for SubTaskSubmissionVecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
+impl :: core :: iter :: FromIterator < SubTaskSubmission > for SubTaskSubmissionVec { fn from_iter < T : IntoIterator < Item = SubTaskSubmission >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < SubTaskSubmission >> for SubTaskSubmissionVec { fn from (v : Vec < SubTaskSubmission >) -> Self { Self :: new_builder () . set (v) . build () } }
+# [derive (Clone)] pub struct UserSubmissionRecord (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for UserSubmissionRecord { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for UserSubmissionRecord { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for UserSubmissionRecord { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "campaign_type_id" , self . campaign_type_id ()) ? ; write ! (f , ", {}: {}" , "quest_id" , self . quest_id ()) ? ; write ! (f , ", {}: {}" , "submission_timestamp" , self . submission_timestamp ()) ? ; write ! (f , ", {}: {}" , "submission_content" , self . submission_content ()) ? ; write ! (f , ", {}: {}" , "sub_task_submissions" , self . sub_task_submissions ()) ? ; write ! (f , ", {}: {}" , "content_commitment" , self . content_commitment ()) ? ; write ! (f , ", {}: {}" , "content_salt" , self . content_salt ()) ? ; write ! (f , ", {}: {}" , "content_hash" , self . content_hash ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for UserSubmissionRecord { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; UserSubmissionRecord :: new_unchecked (v) } } impl UserSubmissionRecord { const DEFAULT_VALUE : [u8 ; 184] = [184 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 72 , 0 , 0 , 0 , 80 , 0 , 0 , 0 , 84 , 0 , 0 , 0 , 88 , 0 , 0 , 0 , 120 , 0 , 0 , 0 , 152 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 8 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn campaign_type_id (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn quest_id (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn submission_timestamp (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn submission_content (& self) -> String { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; String :: new_unchecked (self . 0 . slice (start .. end)) } pub fn sub_task_submissions (& self) -> SubTaskSubmissionVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; SubTaskSubmissionVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn content_commitment (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn content_salt (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn content_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [36 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } else { Byte32 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> UserSubmissionRecordReader < 'r > { UserSubmissionRecordReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for UserSubmissionRecord { type Builder = UserSubmissionRecordBuilder ; const NAME : & 'static str = "UserSubmissionRecord" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { UserSubmissionRecord (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserSubmissionRecordReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserSubmissionRecordReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . campaign_type_id (self . campaign_type_id ()) . quest_id (self . quest_id ()) . submission_timestamp (self . submission_timestamp ()) . submission_content (self . submission_content ()) . sub_task_submissions (self . sub_task_submissions ()) . content_commitment (self . content_commitment ()) . content_salt (self . content_salt ()) . content_hash (self . content_hash ()) } }
+# [derive (Clone , Copy)] pub struct UserSubmissionRecordReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for UserSubmissionRecordReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for UserSubmissionRecordReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for UserSubmissionRecordReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "campaign_type_id" , self . campaign_type_id ()) ? ; write ! (f , ", {}: {}" , "quest_id" , self . quest_id ()) ? ; write ! (f , ", {}: {}" , "submission_timestamp" , self . submission_timestamp ()) ? ; write ! (f , ", {}: {}" , "submission_content" , self . submission_content ()) ? ; write ! (f , ", {}: {}" , "sub_task_submissions" , self . sub_task_submissions ()) ? ; write ! (f , ", {}: {}" , "content_commitment" , self . content_commitment ()) ? ; write ! (f , ", {}: {}" , "content_salt" , self . content_salt ()) ? ; write ! (f , ", {}: {}" , "content_hash" , self . content_hash ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > UserSubmissionRecordReader < 'r > { pub const FIELD_COUNT : usize = 8 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn campaign_type_id (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn quest_id (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn submission_timestamp (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn submission_content (& self) -> StringReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; StringReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn sub_task_submissions (& self) -> SubTaskSubmissionVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; SubTaskSubmissionVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn content_commitment (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn content_salt (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [28 ..]) as usize ; let end = molecule :: unpack_number (& slice [32 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn content_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [32 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [36 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Byte32Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for UserSubmissionRecordReader < 'r > { type Entity = UserSubmissionRecord ; const NAME : & 'static str = "UserSubmissionRecordReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { UserSubmissionRecordReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; StringReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; SubTaskSubmissionVecReader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [6] .. offsets [7]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [7] .. offsets [8]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct UserSubmissionRecordBuilder { pub (crate) campaign_type_id : Byte32 , pub (crate) quest_id : Uint32 , pub (crate) submission_timestamp : Uint64 , pub (crate) submission_content : String , pub (crate) sub_task_submissions : SubTaskSubmissionVec , pub (crate) content_commitment : Byte32 , pub (crate) content_salt : Byte32 , pub (crate) content_hash : Byte32 , } impl UserSubmissionRecordBuilder { pub const FIELD_COUNT : usize = 8 ; pub fn campaign_type_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . campaign_type_id = v . into () ; self } pub fn quest_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . quest_id = v . into () ; self } pub fn submission_timestamp < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . submission_timestamp = v . into () ; self } pub fn submission_content < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < String > { self . submission_content = v . into () ; self } pub fn sub_task_submissions < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < SubTaskSubmissionVec > { self . sub_task_submissions = v . into () ; self } pub fn content_commitment < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . content_commitment = v . into () ; self } pub fn content_salt < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . content_salt = v . into () ; self } pub fn content_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . content_hash = v . into () ; self } } impl molecule :: prelude :: Builder for UserSubmissionRecordBuilder { type Entity = UserSubmissionRecord ; const NAME : & 'static str = "UserSubmissionRecordBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . campaign_type_id . as_slice () . len () + self . quest_id . as_slice () . len () + self . submission_timestamp . as_slice () . len () + self . submission_content . as_slice () . len () + self . sub_task_submissions . as_slice () . len () + self . content_commitment . as_slice () . len () + self . content_salt . as_slice () . len () + self . content_hash . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . campaign_type_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . quest_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . submission_timestamp . as_slice () . len () ; offsets . push (total_size) ; total_size += self . submission_content . as_slice () . len () ; offsets . push (total_size) ; total_size += self . sub_task_submissions . as_slice () . len () ; offsets . push (total_size) ; total_size += self . content_commitment . as_slice () . len () ; offsets . push (total_size) ; total_size += self . content_salt . as_slice () . len () ; offsets . push (total_size) ; total_size += self . content_hash . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . campaign_type_id . as_slice ()) ? ; writer . write_all (self . quest_id . as_slice ()) ? ; writer . write_all (self . submission_timestamp . as_slice ()) ? ; writer . write_all (self . submission_content . as_slice ()) ? ; writer . write_all (self . sub_task_submissions . as_slice ()) ? ; writer . write_all (self . content_commitment . as_slice ()) ? ; writer . write_all (self . content_salt . as_slice ()) ? ; writer . write_all (self . content_hash . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; UserSubmissionRecord :: new_unchecked (inner . into ()) } }
 # [derive (Clone)] pub struct UserSubmissionRecordVec (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for UserSubmissionRecordVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for UserSubmissionRecordVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for UserSubmissionRecordVec { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl :: core :: default :: Default for UserSubmissionRecordVec { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; UserSubmissionRecordVec :: new_unchecked (v) } } impl UserSubmissionRecordVec { const DEFAULT_VALUE : [u8 ; 4] = [4 , 0 , 0 , 0 ,] ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < UserSubmissionRecord > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> UserSubmissionRecord { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { UserSubmissionRecord :: new_unchecked (self . 0 . slice (start ..)) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; UserSubmissionRecord :: new_unchecked (self . 0 . slice (start .. end)) } } pub fn as_reader < 'r > (& 'r self) -> UserSubmissionRecordVecReader < 'r > { UserSubmissionRecordVecReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for UserSubmissionRecordVec { type Builder = UserSubmissionRecordVecBuilder ; const NAME : & 'static str = "UserSubmissionRecordVec" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { UserSubmissionRecordVec (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserSubmissionRecordVecReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserSubmissionRecordVecReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . extend (self . into_iter ()) } }
 # [derive (Clone , Copy)] pub struct UserSubmissionRecordVecReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for UserSubmissionRecordVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for UserSubmissionRecordVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for UserSubmissionRecordVecReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} [" , Self :: NAME) ? ; for i in 0 .. self . len () { if i == 0 { write ! (f , "{}" , self . get_unchecked (i)) ? ; } else { write ! (f , ", {}" , self . get_unchecked (i)) ? ; } } write ! (f , "]") } } impl < 'r > UserSubmissionRecordVecReader < 'r > { pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn item_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn len (& self) -> usize { self . item_count () } pub fn is_empty (& self) -> bool { self . len () == 0 } pub fn get (& self , idx : usize) -> Option < UserSubmissionRecordReader < 'r > > { if idx >= self . len () { None } else { Some (self . get_unchecked (idx)) } } pub fn get_unchecked (& self , idx : usize) -> UserSubmissionRecordReader < 'r > { let slice = self . as_slice () ; let start_idx = molecule :: NUMBER_SIZE * (1 + idx) ; let start = molecule :: unpack_number (& slice [start_idx ..]) as usize ; if idx == self . len () - 1 { UserSubmissionRecordReader :: new_unchecked (& self . as_slice () [start ..]) } else { let end_idx = start_idx + molecule :: NUMBER_SIZE ; let end = molecule :: unpack_number (& slice [end_idx ..]) as usize ; UserSubmissionRecordReader :: new_unchecked (& self . as_slice () [start .. end]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for UserSubmissionRecordVecReader < 'r > { type Entity = UserSubmissionRecordVec ; const NAME : & 'static str = "UserSubmissionRecordVecReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { UserSubmissionRecordVecReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len == molecule :: NUMBER_SIZE { return Ok (()) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , TotalSizeNotMatch , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } for pair in offsets . windows (2) { let start = pair [0] ; let end = pair [1] ; UserSubmissionRecordReader :: verify (& slice [start .. end] , compatible) ? ; } Ok (()) } }
 # [derive (Clone , Debug , Default)] pub struct UserSubmissionRecordVecBuilder (pub (crate) Vec < UserSubmissionRecord >) ; impl UserSubmissionRecordVecBuilder { pub fn set (mut self , v : Vec < UserSubmissionRecord >) -> Self { self . 0 = v ; self } pub fn push < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < UserSubmissionRecord > { self . 0 . push (v . into ()) ; self } pub fn extend < T : :: core :: iter :: IntoIterator < Item = UserSubmissionRecord >> (mut self , iter : T) -> Self { self . 0 . extend (iter) ; self } pub fn replace < T > (& mut self , index : usize , v : T) -> Option < UserSubmissionRecord > where T : :: core :: convert :: Into < UserSubmissionRecord > { self . 0 . get_mut (index) . map (| item | :: core :: mem :: replace (item , v . into ())) } } impl molecule :: prelude :: Builder for UserSubmissionRecordVecBuilder { type Entity = UserSubmissionRecordVec ; const NAME : & 'static str = "UserSubmissionRecordVecBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (self . 0 . len () + 1) + self . 0 . iter () . map (| inner | inner . as_slice () . len ()) . sum :: < usize > () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let item_count = self . 0 . len () ; if item_count == 0 { writer . write_all (& molecule :: pack_number (molecule :: NUMBER_SIZE as molecule :: Number ,)) ? ; } else { let (total_size , offsets) = self . 0 . iter () . fold ((molecule :: NUMBER_SIZE * (item_count + 1) , Vec :: with_capacity (item_count) ,) , | (start , mut offsets) , inner | { offsets . push (start) ; (start + inner . as_slice () . len () , offsets) } ,) ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } for inner in self . 0 . iter () { writer . write_all (inner . as_slice ()) ? ; } } Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; UserSubmissionRecordVec :: new_unchecked (inner . into ()) } }
 pub struct UserSubmissionRecordVecIterator (UserSubmissionRecordVec , usize , usize) ; impl :: core :: iter :: Iterator for UserSubmissionRecordVecIterator { type Item = UserSubmissionRecord ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl :: core :: iter :: ExactSizeIterator for UserSubmissionRecordVecIterator { fn len (& self) -> usize { self . 2 - self . 1 } } impl :: core :: iter :: IntoIterator for UserSubmissionRecordVec { type Item = UserSubmissionRecord ; type IntoIter = UserSubmissionRecordVecIterator ; fn into_iter (self) -> Self :: IntoIter { let len = self . len () ; UserSubmissionRecordVecIterator (self , 0 , len) } } impl < 'r > UserSubmissionRecordVecReader < 'r > { pub fn iter < 't > (& 't self) -> UserSubmissionRecordVecReaderIterator < 't , 'r > { UserSubmissionRecordVecReaderIterator (& self , 0 , self . len ()) } } pub struct UserSubmissionRecordVecReaderIterator < 't , 'r > (& 't UserSubmissionRecordVecReader < 'r > , usize , usize) ; impl < 't : 'r , 'r > :: core :: iter :: Iterator for UserSubmissionRecordVecReaderIterator < 't , 'r > { type Item = UserSubmissionRecordReader < 't > ; fn next (& mut self) -> Option < Self :: Item > { if self . 1 >= self . 2 { None } else { let ret = self . 0 . get_unchecked (self . 1) ; self . 1 += 1 ; Some (ret) } } } impl < 't : 'r , 'r > :: core :: iter :: ExactSizeIterator for UserSubmissionRecordVecReaderIterator < 't , 'r > { fn len (& self) -> usize { self . 2 - self . 1 } }
 impl :: core :: iter :: FromIterator < UserSubmissionRecord > for UserSubmissionRecordVec { fn from_iter < T : IntoIterator < Item = UserSubmissionRecord >> (iter : T) -> Self { Self :: new_builder () . extend (iter) . build () } } impl From < Vec < UserSubmissionRecord >> for UserSubmissionRecordVec { fn from (v : Vec < UserSubmissionRecord >) -> Self { Self :: new_builder () . set (v) . build () } }
-# [derive (Clone)] pub struct UserData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for UserData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for UserData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for UserData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "verification_data" , self . verification_data ()) ? ; write ! (f , ", {}: {}" , "total_points_earned" , self . total_points_earned ()) ? ; write ! (f , ", {}: {}" , "last_activity_timestamp" , self . last_activity_timestamp ()) ? ; write ! (f , ", {}: {}" , "submission_records" , self . submission_records ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for UserData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; UserData :: new_unchecked (v) } } impl UserData { const DEFAULT_VALUE : [u8 ; 80] = [80 , 0 , 0 , 0 , 20 , 0 , 0 , 0 , 52 , 0 , 0 , 0 , 68 , 0 , 0 , 0 , 76 , 0 , 0 , 0 , 32 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 4 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn verification_data (& self) -> UserVerificationData { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; UserVerificationData :: new_unchecked (self . 0 . slice (start .. end)) } pub fn total_points_earned (& self) -> Uint128 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint128 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn last_activity_timestamp (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn submission_records (& self) -> UserSubmissionRecordVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [20 ..]) as usize ; UserSubmissionRecordVec :: new_unchecked (self . 0 . slice (start .. end)) } else { UserSubmissionRecordVec :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> UserDataReader < 'r > { UserDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for UserData { type Builder = UserDataBuilder ; const NAME : & 'static str = "UserData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { UserData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . verification_data (self . verification_data ()) . total_points_earned (self . total_points_earned ()) . last_activity_timestamp (self . last_activity_timestamp ()) . submission_records (self . submission_records ()) } }
-# [derive (Clone , Copy)] pub struct UserDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for UserDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for UserDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for UserDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "verification_data" , self . verification_data ()) ? ; write ! (f , ", {}: {}" , "total_points_earned" , self . total_points_earned ()) ? ; write ! (f , ", {}: {}" , "last_activity_timestamp" , self . last_activity_timestamp ()) ? ; write ! (f , ", {}: {}" , "submission_records" , self . submission_records ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > UserDataReader < 'r > { pub const FIELD_COUNT : usize = 4 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn verification_data (& self) -> UserVerificationDataReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; UserVerificationDataReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn total_points_earned (& self) -> Uint128Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint128Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn last_activity_timestamp (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn submission_records (& self) -> UserSubmissionRecordVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [20 ..]) as usize ; UserSubmissionRecordVecReader :: new_unchecked (& self . as_slice () [start .. end]) } else { UserSubmissionRecordVecReader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for UserDataReader < 'r > { type Entity = UserData ; const NAME : & 'static str = "UserDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { UserDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } UserVerificationDataReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint128Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; UserSubmissionRecordVecReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Ok (()) } }
-# [derive (Clone , Debug , Default)] pub struct UserDataBuilder { pub (crate) verification_data : UserVerificationData , pub (crate) total_points_earned : Uint128 , pub (crate) last_activity_timestamp : Uint64 , pub (crate) submission_records : UserSubmissionRecordVec , } impl UserDataBuilder { pub const FIELD_COUNT : usize = 4 ; pub fn verification_data < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < UserVerificationData > { self . verification_data = v . into () ; self } pub fn total_points_earned < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint128 > { self . total_points_earned = v . into () ; self } pub fn last_activity_timestamp < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . last_activity_timestamp = v . into () ; self } pub fn submission_records < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < UserSubmissionRecordVec > { self . submission_records = v . into () ; self } } impl molecule :: prelude :: Builder for UserDataBuilder { type Entity = UserData ; const NAME : & 'static str = "UserDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . verification_data . as_slice () . len () + self . total_points_earned . as_slice () . len () + self . last_activity_timestamp . as_slice () . len () + self . submission_records . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . verification_data . as_slice () . len () ; offsets . push (total_size) ; total_size += self . total_points_earned . as_slice () . len () ; offsets . push (total_size) ; total_size += self . last_activity_timestamp . as_slice () . len () ; offsets . push (total_size) ; total_size += self . submission_records . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . verification_data . as_slice ()) ? ; writer . write_all (self . total_points_earned . as_slice ()) ? ; writer . write_all (self . last_activity_timestamp . as_slice ()) ? ; writer . write_all (self . submission_records . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; UserData :: new_unchecked (inner . into ()) } }
+# [derive (Clone)] pub struct UserData (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for UserData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for UserData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for UserData { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "verification_data" , self . verification_data ()) ? ; write ! (f , ", {}: {}" , "total_points_earned" , self . total_points_earned ()) ? ; write ! (f , ", {}: {}" , "last_activity_timestamp" , self . last_activity_timestamp ()) ? ; write ! (f , ", {}: {}" , "submission_records" , self . submission_records ()) ? ; write ! (f , ", {}: {}" , "archived_submission_count" , self . archived_submission_count ()) ? ; write ! (f , ", {}: {}" , "archived_submissions_hash" , self . archived_submissions_hash ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for UserData { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; UserData :: new_unchecked (v) } } impl UserData { const DEFAULT_VALUE : [u8 ; 137] = [137 , 0 , 0 , 0 , 28 , 0 , 0 , 0 , 73 , 0 , 0 , 0 , 89 , 0 , 0 , 0 , 97 , 0 , 0 , 0 , 101 , 0 , 0 , 0 , 105 , 0 , 0 , 0 , 45 , 0 , 0 , 0 , 20 , 0 , 0 , 0 , 36 , 0 , 0 , 0 , 40 , 0 , 0 , 0 , 41 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 4 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 6 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn verification_data (& self) -> UserVerificationData { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; UserVerificationData :: new_unchecked (self . 0 . slice (start .. end)) } pub fn total_points_earned (& self) -> Uint128 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint128 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn last_activity_timestamp (& self) -> Uint64 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn submission_records (& self) -> UserSubmissionRecordVec { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; UserSubmissionRecordVec :: new_unchecked (self . 0 . slice (start .. end)) } pub fn archived_submission_count (& self) -> Uint32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn archived_submissions_hash (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } else { Byte32 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> UserDataReader < 'r > { UserDataReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for UserData { type Builder = UserDataBuilder ; const NAME : & 'static str = "UserData" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { UserData (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserDataReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { UserDataReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . verification_data (self . verification_data ()) . total_points_earned (self . total_points_earned ()) . last_activity_timestamp (self . last_activity_timestamp ()) . submission_records (self . submission_records ()) . archived_submission_count (self . archived_submission_count ()) . archived_submissions_hash (self . archived_submissions_hash ()) } }
+# [derive (Clone , Copy)] pub struct UserDataReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for UserDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for UserDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for UserDataReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "verification_data" , self . verification_data ()) ? ; write ! (f , ", {}: {}" , "total_points_earned" , self . total_points_earned ()) ? ; write ! (f , ", {}: {}" , "last_activity_timestamp" , self . last_activity_timestamp ()) ? ; write ! (f , ", {}: {}" , "submission_records" , self . submission_records ()) ? ; write ! (f , ", {}: {}" , "archived_submission_count" , self . archived_submission_count ()) ? ; write ! (f , ", {}: {}" , "archived_submissions_hash" , self . archived_submissions_hash ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > UserDataReader < 'r > { pub const FIELD_COUNT : usize = 6 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn verification_data (& self) -> UserVerificationDataReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; UserVerificationDataReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn total_points_earned (& self) -> Uint128Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Uint128Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn last_activity_timestamp (& self) -> Uint64Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [12 ..]) as usize ; let end = molecule :: unpack_number (& slice [16 ..]) as usize ; Uint64Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn submission_records (& self) -> UserSubmissionRecordVecReader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [16 ..]) as usize ; let end = molecule :: unpack_number (& slice [20 ..]) as usize ; UserSubmissionRecordVecReader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn archived_submission_count (& self) -> Uint32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [20 ..]) as usize ; let end = molecule :: unpack_number (& slice [24 ..]) as usize ; Uint32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn archived_submissions_hash (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [24 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [28 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Byte32Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for UserDataReader < 'r > { type Entity = UserData ; const NAME : & 'static str = "UserDataReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { UserDataReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } UserVerificationDataReader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Uint128Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Uint64Reader :: verify (& slice [offsets [2] .. offsets [3]] , compatible) ? ; UserSubmissionRecordVecReader :: verify (& slice [offsets [3] .. offsets [4]] , compatible) ? ; Uint32Reader :: verify (& slice [offsets [4] .. offsets [5]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [5] .. offsets [6]] , compatible) ? ; Ok (()) } }
+# [derive (Clone , Debug , Default)] pub struct UserDataBuilder { pub (crate) verification_data : UserVerificationData , pub (crate) total_points_earned : Uint128 , pub (crate) last_activity_timestamp : Uint64 , pub (crate) submission_records : UserSubmissionRecordVec , pub (crate) archived_submission_count : Uint32 , pub (crate) archived_submissions_hash : Byte32 , } impl UserDataBuilder { pub const FIELD_COUNT : usize = 6 ; pub fn verification_data < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < UserVerificationData > { self . verification_data = v . into () ; self } pub fn total_points_earned < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint128 > { self . total_points_earned = v . into () ; self } pub fn last_activity_timestamp < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint64 > { self . last_activity_timestamp = v . into () ; self } pub fn submission_records < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < UserSubmissionRecordVec > { self . submission_records = v . into () ; self } pub fn archived_submission_count < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Uint32 > { self . archived_submission_count = v . into () ; self } pub fn archived_submissions_hash < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . archived_submissions_hash = v . into () ; self } } impl molecule :: prelude :: Builder for UserDataBuilder { type Entity = UserData ; const NAME : & 'static str = "UserDataBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . verification_data . as_slice () . len () + self . total_points_earned . as_slice () . len () + self . last_activity_timestamp . as_slice () . len () + self . submission_records . as_slice () . len () + self . archived_submission_count . as_slice () . len () + self . archived_submissions_hash . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . verification_data . as_slice () . len () ; offsets . push (total_size) ; total_size += self . total_points_earned . as_slice () . len () ; offsets . push (total_size) ; total_size += self . last_activity_timestamp . as_slice () . len () ; offsets . push (total_size) ; total_size += self . submission_records . as_slice () . len () ; offsets . push (total_size) ; total_size += self . archived_submission_count . as_slice () . len () ; offsets . push (total_size) ; total_size += self . archived_submissions_hash . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . verification_data . as_slice ()) ? ; writer . write_all (self . total_points_earned . as_slice ()) ? ; writer . write_all (self . last_activity_timestamp . as_slice ()) ? ; writer . write_all (self . submission_records . as_slice ()) ? ; writer . write_all (self . archived_submission_count . as_slice ()) ? ; writer . write_all (self . archived_submissions_hash . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; UserData :: new_unchecked (inner . into ()) } }
 # [derive (Clone)] pub struct ConnectedTypeID (molecule :: bytes :: Bytes) ; impl :: core :: fmt :: LowerHex for ConnectedTypeID { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl :: core :: fmt :: Debug for ConnectedTypeID { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl :: core :: fmt :: Display for ConnectedTypeID { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "type_id" , self . type_id ()) ? ; write ! (f , ", {}: {}" , "connected_key" , self . connected_key ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl :: core :: default :: Default for ConnectedTypeID { fn default () -> Self { let v = molecule :: bytes :: Bytes :: from_static (& Self :: DEFAULT_VALUE) ; ConnectedTypeID :: new_unchecked (v) } } impl ConnectedTypeID { const DEFAULT_VALUE : [u8 ; 76] = [76 , 0 , 0 , 0 , 12 , 0 , 0 , 0 , 44 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ,] ; pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn type_id (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } pub fn connected_key (& self) -> Byte32 { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Byte32 :: new_unchecked (self . 0 . slice (start .. end)) } else { Byte32 :: new_unchecked (self . 0 . slice (start ..)) } } pub fn as_reader < 'r > (& 'r self) -> ConnectedTypeIDReader < 'r > { ConnectedTypeIDReader :: new_unchecked (self . as_slice ()) } } impl molecule :: prelude :: Entity for ConnectedTypeID { type Builder = ConnectedTypeIDBuilder ; const NAME : & 'static str = "ConnectedTypeID" ; fn new_unchecked (data : molecule :: bytes :: Bytes) -> Self { ConnectedTypeID (data) } fn as_bytes (& self) -> molecule :: bytes :: Bytes { self . 0 . clone () } fn as_slice (& self) -> & [u8] { & self . 0 [..] } fn from_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ConnectedTypeIDReader :: from_slice (slice) . map (| reader | reader . to_entity ()) } fn from_compatible_slice (slice : & [u8]) -> molecule :: error :: VerificationResult < Self > { ConnectedTypeIDReader :: from_compatible_slice (slice) . map (| reader | reader . to_entity ()) } fn new_builder () -> Self :: Builder { :: core :: default :: Default :: default () } fn as_builder (self) -> Self :: Builder { Self :: new_builder () . type_id (self . type_id ()) . connected_key (self . connected_key ()) } }
 # [derive (Clone , Copy)] pub struct ConnectedTypeIDReader < 'r > (& 'r [u8]) ; impl < 'r > :: core :: fmt :: LowerHex for ConnectedTypeIDReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { use molecule :: hex_string ; if f . alternate () { write ! (f , "0x") ? ; } write ! (f , "{}" , hex_string (self . as_slice ())) } } impl < 'r > :: core :: fmt :: Debug for ConnectedTypeIDReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{}({:#x})" , Self :: NAME , self) } } impl < 'r > :: core :: fmt :: Display for ConnectedTypeIDReader < 'r > { fn fmt (& self , f : & mut :: core :: fmt :: Formatter) -> :: core :: fmt :: Result { write ! (f , "{} {{ " , Self :: NAME) ? ; write ! (f , "{}: {}" , "type_id" , self . type_id ()) ? ; write ! (f , ", {}: {}" , "connected_key" , self . connected_key ()) ? ; let extra_count = self . count_extra_fields () ; if extra_count != 0 { write ! (f , ", .. ({} fields)" , extra_count) ? ; } write ! (f , " }}") } } impl < 'r > ConnectedTypeIDReader < 'r > { pub const FIELD_COUNT : usize = 2 ; pub fn total_size (& self) -> usize { molecule :: unpack_number (self . as_slice ()) as usize } pub fn field_count (& self) -> usize { if self . total_size () == molecule :: NUMBER_SIZE { 0 } else { (molecule :: unpack_number (& self . as_slice () [molecule :: NUMBER_SIZE ..]) as usize / 4) - 1 } } pub fn count_extra_fields (& self) -> usize { self . field_count () - Self :: FIELD_COUNT } pub fn has_extra_fields (& self) -> bool { Self :: FIELD_COUNT != self . field_count () } pub fn type_id (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [4 ..]) as usize ; let end = molecule :: unpack_number (& slice [8 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } pub fn connected_key (& self) -> Byte32Reader < 'r > { let slice = self . as_slice () ; let start = molecule :: unpack_number (& slice [8 ..]) as usize ; if self . has_extra_fields () { let end = molecule :: unpack_number (& slice [12 ..]) as usize ; Byte32Reader :: new_unchecked (& self . as_slice () [start .. end]) } else { Byte32Reader :: new_unchecked (& self . as_slice () [start ..]) } } } impl < 'r > molecule :: prelude :: Reader < 'r > for ConnectedTypeIDReader < 'r > { type Entity = ConnectedTypeID ; const NAME : & 'static str = "ConnectedTypeIDReader" ; fn to_entity (& self) -> Self :: Entity { Self :: Entity :: new_unchecked (self . as_slice () . to_owned () . into ()) } fn new_unchecked (slice : & 'r [u8]) -> Self { ConnectedTypeIDReader (slice) } fn as_slice (& self) -> & 'r [u8] { self . 0 } fn verify (slice : & [u8] , compatible : bool) -> molecule :: error :: VerificationResult < () > { use molecule :: verification_error as ve ; let slice_len = slice . len () ; if slice_len < molecule :: NUMBER_SIZE { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE , slice_len) ; } let total_size = molecule :: unpack_number (slice) as usize ; if slice_len != total_size { return ve ! (Self , TotalSizeNotMatch , total_size , slice_len) ; } if slice_len < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , HeaderIsBroken , molecule :: NUMBER_SIZE * 2 , slice_len) ; } let offset_first = molecule :: unpack_number (& slice [molecule :: NUMBER_SIZE ..]) as usize ; if offset_first % molecule :: NUMBER_SIZE != 0 || offset_first < molecule :: NUMBER_SIZE * 2 { return ve ! (Self , OffsetsNotMatch) ; } if slice_len < offset_first { return ve ! (Self , HeaderIsBroken , offset_first , slice_len) ; } let field_count = offset_first / molecule :: NUMBER_SIZE - 1 ; if field_count < Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } else if ! compatible && field_count > Self :: FIELD_COUNT { return ve ! (Self , FieldCountNotMatch , Self :: FIELD_COUNT , field_count) ; } ; let mut offsets : Vec < usize > = slice [molecule :: NUMBER_SIZE .. offset_first] . chunks_exact (molecule :: NUMBER_SIZE) . map (| x | molecule :: unpack_number (x) as usize) . collect () ; offsets . push (total_size) ; if offsets . windows (2) . any (| i | i [0] > i [1]) { return ve ! (Self , OffsetsNotMatch) ; } Byte32Reader :: verify (& slice [offsets [0] .. offsets [1]] , compatible) ? ; Byte32Reader :: verify (& slice [offsets [1] .. offsets [2]] , compatible) ? ; Ok (()) } }
 # [derive (Clone , Debug , Default)] pub struct ConnectedTypeIDBuilder { pub (crate) type_id : Byte32 , pub (crate) connected_key : Byte32 , } impl ConnectedTypeIDBuilder { pub const FIELD_COUNT : usize = 2 ; pub fn type_id < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . type_id = v . into () ; self } pub fn connected_key < T > (mut self , v : T) -> Self where T : :: core :: convert :: Into < Byte32 > { self . connected_key = v . into () ; self } } impl molecule :: prelude :: Builder for ConnectedTypeIDBuilder { type Entity = ConnectedTypeID ; const NAME : & 'static str = "ConnectedTypeIDBuilder" ; fn expected_length (& self) -> usize { molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) + self . type_id . as_slice () . len () + self . connected_key . as_slice () . len () } fn write < W : molecule :: io :: Write > (& self , writer : & mut W) -> molecule :: io :: Result < () > { let mut total_size = molecule :: NUMBER_SIZE * (Self :: FIELD_COUNT + 1) ; let mut offsets = Vec :: with_capacity (Self :: FIELD_COUNT) ; offsets . push (total_size) ; total_size += self . type_id . as_slice () . len () ; offsets . push (total_size) ; total_size += self . connected_key . as_slice () . len () ; writer . write_all (& molecule :: pack_number (total_size as molecule :: Number)) ? ; for offset in offsets . into_iter () { writer . write_all (& molecule :: pack_number (offset as molecule :: Number)) ? ; } writer . write_all (self . type_id . as_slice ()) ? ; writer . write_all (self . connected_key . as_slice ()) ? ; Ok (()) } fn build (& self) -> Self :: Entity { let mut inner = Vec :: with_capacity (self . expected_length ()) ; self . write (& mut inner) . unwrap_or_else (| _ | panic ! ("{} build should be ok" , Self :: NAME)) ; ConnectedTypeID :: new_unchecked (inner . into ()) } }
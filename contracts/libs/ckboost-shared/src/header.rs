@@ -0,0 +1,15 @@
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::*,
+    high_level::{load_header, QueryIter},
+};
+
+/// Latest block timestamp (ms) among the transaction's header deps
+///
+/// Header deps can only reference committed blocks, so this is a lower bound
+/// on the time the transaction is committed. Returns `None` without header deps.
+pub fn max_header_dep_timestamp() -> Option<u64> {
+    QueryIter::new(load_header, Source::HeaderDep)
+        .map(|header| header.raw().timestamp().unpack())
+        .max()
+}
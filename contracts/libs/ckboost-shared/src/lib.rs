@@ -10,6 +10,15 @@ pub mod type_id;
 pub mod ssri;
 pub mod cell_collector;
 pub mod protocol_data;
+pub mod campaign_data;
+pub mod user_data;
+pub mod header;
+pub mod rewards;
+pub mod badge;
+pub mod authority;
+pub mod approvals;
+pub mod attestation;
+pub mod submission_content;
 pub mod transaction_context;
 pub mod known_script;
 
@@ -21,3 +30,7 @@ pub use ckb_deterministic::validation;
 
 // Re-export extension trait for protocol data
 pub use protocol_data::ProtocolDataExt;
+
+// Re-export extension traits for campaign and user data
+pub use campaign_data::{CampaignDataExt, QuestDataExt};
+pub use user_data::{UserDataExt, UserSubmissionRecordExt};
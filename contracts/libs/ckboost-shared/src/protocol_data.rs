@@ -1,9 +1,12 @@
 // cspell:ignore celldeps udts
 pub use crate::generated::ckboost::{ProtocolData, ScriptCodeHashes, Byte32, Byte32Vec, ScriptVec, Script};
+use crate::attestation::verify_attestation;
+use crate::campaign_data::connected_type_id_from_args;
+use crate::generated::ckboost::VerificationAttestation;
 use crate::Error;
 use ckb_deterministic::debug_trace;
 use ckb_std::{
-    high_level::{load_cell_data, load_cell_type, load_cell_type_hash, load_script},
+    high_level::{load_cell_data, load_cell_type, load_cell_type_hash, load_script, QueryIter},
     ckb_constants::Source,
     ckb_types::prelude::*,
 };
@@ -178,12 +181,21 @@ pub trait ProtocolDataExt {
     /// Get points type code hash
     fn points_udt_type_code_hash(&self) -> [u8; 32];
     
+    /// Get quest type code hash
+    fn quest_type_code_hash(&self) -> [u8; 32];
+    
     /// Get accepted UDT type scripts
     fn accepted_udt_type_scripts(&self) -> Vec<Script>;
     
     /// Get accepted DOB (Digital Object) type scripts
     fn accepted_dob_type_scripts(&self) -> Vec<Script>;
     
+    /// Check if an endorser lock hash is in the endorsers whitelist
+    fn is_endorser_whitelisted(&self, endorser_lock_hash: &[u8]) -> bool;
+
+    /// Check if an attestation for the user is signed by an attestor registered for its bit
+    fn accepts_attestation(&self, user_type_id: &[u8], attestation: &VerificationAttestation) -> bool;
+    
     /// Check if all required type hashes are present
     fn validate_protocol(&self) -> Result<(), crate::error::Error>;
 }
@@ -233,6 +245,17 @@ impl ProtocolDataExt for ProtocolData {
         result
     }
     
+    /// Get quest type code hash
+    fn quest_type_code_hash(&self) -> [u8; 32] {
+        let hash = self
+            .protocol_config()
+            .script_code_hashes()
+            .ckb_boost_quest_type_code_hash();
+        let mut result = [0u8; 32];
+        result.copy_from_slice(hash.as_slice());
+        result
+    }
+    
     /// Get accepted UDT type scripts
     fn accepted_udt_type_scripts(&self) -> Vec<Script> {
         let scripts = self
@@ -263,6 +286,25 @@ impl ProtocolDataExt for ProtocolData {
         result
     }
     
+    /// Check if an endorser lock hash is in the endorsers whitelist
+    fn is_endorser_whitelisted(&self, endorser_lock_hash: &[u8]) -> bool {
+        let endorsers = self.endorsers_whitelist();
+        for i in 0..endorsers.len() {
+            let endorser = endorsers.get(i).unwrap();
+            if endorser.endorser_lock_hash().as_slice() == endorser_lock_hash {
+                return true;
+            }
+        }
+        false
+    }
+    
+    /// Check if an attestation for the user is signed by an attestor registered for its bit
+    fn accepts_attestation(&self, user_type_id: &[u8], attestation: &VerificationAttestation) -> bool {
+        self.attestors()
+            .into_iter()
+            .any(|attestor| verify_attestation(&attestor, user_type_id, attestation))
+    }
+
     /// Check if all required type hashes are present
     fn validate_protocol(&self) -> Result<(), crate::error::Error> {
         // Protocol, campaign, and user type hashes are always required
@@ -292,6 +334,43 @@ pub fn get_protocol_data() -> Result<ProtocolData, crate::error::Error> {
     ProtocolData::from_protocol_cell()
 }
 
+/// Get protocol data for scripts bound to a campaign instead of the protocol
+///
+/// Scripts such as the campaign lock only know the campaign type hash. The campaign cell is
+/// looked up in inputs, outputs and CellDeps, and its ConnectedTypeID names the protocol cell,
+/// which must be provided as a CellDep.
+///
+/// # Returns
+/// - `Ok(ProtocolData)` - Successfully loaded protocol data from the campaign's protocol cell
+/// - `Err(CampaignCellNotFound)` - No cell with the campaign type hash in the transaction
+/// - `Err(ProtocolCellNotFound)` - The campaign's protocol cell is not in CellDeps
+pub fn get_protocol_data_for_campaign(
+    campaign_type_hash: &[u8],
+) -> Result<ProtocolData, crate::error::Error> {
+    debug_trace!("Loading protocol data through campaign {:?}", campaign_type_hash);
+
+    let campaign_type = [Source::Input, Source::Output, Source::CellDep]
+        .into_iter()
+        .find_map(|source| {
+            QueryIter::new(load_cell_type_hash, source)
+                .position(|type_hash| type_hash.map(|hash| hash.as_slice() == campaign_type_hash).unwrap_or(false))
+                .and_then(|index| load_cell_type(index, source).ok().flatten())
+        })
+        .ok_or(crate::error::Error::CampaignCellNotFound)?;
+    let protocol_type_hash = connected_type_id_from_args(&campaign_type.args().raw_data())
+        .ok_or(crate::error::Error::InvalidConnectedTypeId)?
+        .connected_key();
+
+    let index = QueryIter::new(load_cell_type_hash, Source::CellDep)
+        .position(|type_hash| {
+            type_hash.map(|hash| hash.as_slice() == protocol_type_hash.as_slice()).unwrap_or(false)
+        })
+        .ok_or(crate::error::Error::ProtocolCellNotFound)?;
+    let data = load_cell_data(index, Source::CellDep)
+        .map_err(|_| crate::error::Error::ProtocolDataNotLoaded)?;
+    ProtocolData::from_slice(&data).map_err(|_| crate::error::Error::ProtocolDataInvalid)
+}
+
 /// Get protocol data using SSRI pattern
 /// 
 /// This function is designed for SSRI-based transaction generation where we have a protocol 
@@ -0,0 +1,325 @@
+use crate::campaign_data::{unpack_u128, unpack_u64, CampaignData, CampaignDataExt, QuestData, QuestDataExt};
+use alloc::vec::Vec;
+use ckb_deterministic::{generated::TransactionRecipe, transaction_recipe::TransactionRecipeExt};
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::packed::WitnessArgs,
+    error::SysError,
+    high_level::{
+        load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type, load_transaction,
+        QueryIter,
+    },
+};
+use molecule::prelude::*;
+
+/// Assets a participant is owed for completing quests
+///
+/// UDTs are keyed by their serialized type script and NFTs are identified by
+/// theirs. Points are not part of the entitlement; they are minted through the
+/// points UDT, see [`points_owed`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RewardEntitlement {
+    pub ckb_amount: u64,
+    pub udt_amounts: Vec<(Vec<u8>, u128)>,
+    pub nft_scripts: Vec<Vec<u8>>,
+}
+
+impl RewardEntitlement {
+    /// Sum the CKB and UDTs of every asset list of a quest's `rewards_on_completion`
+    ///
+    /// NFTs are not shared by all participants, see `QuestData::nft_awards`.
+    pub fn for_quest(quest: &QuestData) -> Self {
+        let mut entitlement = Self::default();
+        for asset_list in quest.rewards_on_completion().into_iter() {
+            entitlement.ckb_amount = entitlement
+                .ckb_amount
+                .saturating_add(unpack_u64(asset_list.ckb_amount().as_slice()));
+            for udt_asset in asset_list.udt_assets().into_iter() {
+                entitlement.add_udt(
+                    udt_asset.udt_script().as_slice(),
+                    unpack_u128(udt_asset.amount().as_slice()),
+                );
+            }
+        }
+        entitlement
+    }
+
+    /// Add another entitlement to this one
+    pub fn merge(&mut self, other: &RewardEntitlement) {
+        self.ckb_amount = self.ckb_amount.saturating_add(other.ckb_amount);
+        for (script, amount) in other.udt_amounts.iter() {
+            self.add_udt(script, *amount);
+        }
+        for nft_script in other.nft_scripts.iter() {
+            self.add_nft(nft_script);
+        }
+    }
+
+    /// Amount owed of the UDT with this serialized type script
+    pub fn udt_amount(&self, script: &[u8]) -> u128 {
+        self.udt_amounts
+            .iter()
+            .find(|(udt_script, _)| udt_script.as_slice() == script)
+            .map(|(_, amount)| *amount)
+            .unwrap_or(0)
+    }
+
+    /// Check whether an NFT with this serialized type script is owed
+    pub fn owes_nft(&self, script: &[u8]) -> bool {
+        self.nft_scripts.iter().any(|nft_script| nft_script.as_slice() == script)
+    }
+
+    /// Check whether nothing is owed
+    pub fn is_empty(&self) -> bool {
+        self.ckb_amount == 0
+            && self.udt_amounts.iter().all(|(_, amount)| *amount == 0)
+            && self.nft_scripts.is_empty()
+    }
+
+    fn add_nft(&mut self, script: &[u8]) {
+        if !self.owes_nft(script) {
+            self.nft_scripts.push(script.to_vec());
+        }
+    }
+
+    fn add_udt(&mut self, script: &[u8], amount: u128) {
+        match self
+            .udt_amounts
+            .iter_mut()
+            .find(|(udt_script, _)| udt_script.as_slice() == script)
+        {
+            Some((_, total)) => *total = total.saturating_add(amount),
+            None => self.udt_amounts.push((script.to_vec(), amount)),
+        }
+    }
+}
+
+/// Participants whose rewards become payable in a campaign transition
+///
+/// A participant is paid when newly accepted without a pending approval, or when
+/// a pending approval is cleared while they stay accepted. Besides the input quest's
+/// shared rewards they are owed the NFTs awarded to them. Entitlements are merged per
+/// user type ID.
+pub fn rewarded_participants(
+    input: &CampaignData,
+    output: &CampaignData,
+) -> Vec<(Vec<u8>, RewardEntitlement)> {
+    let mut participants: Vec<(Vec<u8>, RewardEntitlement)> = Vec::new();
+    for output_quest in output.quests().into_iter() {
+        let input_quest = input.find_quest(output_quest.quest_id_u32());
+        merge_rewarded(&mut participants, rewarded_in_quest(input_quest.as_ref(), &output_quest));
+    }
+    participants
+}
+
+/// Participants whose rewards become payable in a single quest transition
+///
+/// Used for quest cells, where each quest is updated on its own. Shared rewards are
+/// those of the input quest, so the transition cannot raise what it pays out. `input`
+/// is `None` when the quest is created in the transition, which owes no shared rewards.
+pub fn rewarded_in_quest(
+    input: Option<&QuestData>,
+    output: &QuestData,
+) -> Vec<(Vec<u8>, RewardEntitlement)> {
+    let mut participants: Vec<(Vec<u8>, RewardEntitlement)> = Vec::new();
+    let entitlement = input.map(RewardEntitlement::for_quest).unwrap_or_default();
+    for user_type_id in output.accepted_submission_user_type_ids().into_iter() {
+        let user_type_id = user_type_id.as_slice();
+        if output.find_pending_approval(user_type_id).is_some() {
+            continue;
+        }
+        let already_payable = input
+            .map(|quest| {
+                quest.is_accepted(user_type_id) && quest.find_pending_approval(user_type_id).is_none()
+            })
+            .unwrap_or(false);
+        if already_payable {
+            continue;
+        }
+
+        let mut user_entitlement = entitlement.clone();
+        for nft_script in output.awarded_nfts_of(user_type_id).iter() {
+            user_entitlement.add_nft(nft_script.as_slice());
+        }
+        merge_rewarded(&mut participants, vec![(user_type_id.to_vec(), user_entitlement)]);
+    }
+    participants
+}
+
+/// Merge rewarded participants into `participants`, summing entitlements per user type ID
+pub fn merge_rewarded(
+    participants: &mut Vec<(Vec<u8>, RewardEntitlement)>,
+    rewarded: Vec<(Vec<u8>, RewardEntitlement)>,
+) {
+    for (user_type_id, entitlement) in rewarded {
+        match participants
+            .iter_mut()
+            .find(|(participant, _)| participant.as_slice() == user_type_id.as_slice())
+        {
+            Some((_, total)) => total.merge(&entitlement),
+            None => participants.push((user_type_id, entitlement)),
+        }
+    }
+}
+
+/// Points each participant is owed in a campaign transition
+///
+/// Points are minted in the same transition that makes a participant's rewards
+/// payable, at the input quest's `points` for every quest they are newly paid for,
+/// so the transition cannot raise its own price. Quests created in the transition
+/// owe nothing.
+pub fn points_owed(input: &CampaignData, output: &CampaignData) -> Vec<(Vec<u8>, u128)> {
+    let mut owed: Vec<(Vec<u8>, u128)> = Vec::new();
+    for output_quest in output.quests().into_iter() {
+        let input_quest = input.find_quest(output_quest.quest_id_u32());
+        let points = input_quest.as_ref().map(|quest| quest.points_u128()).unwrap_or(0);
+        merge_points(
+            &mut owed,
+            rewarded_in_quest(input_quest.as_ref(), &output_quest)
+                .into_iter()
+                .map(|(user_type_id, _)| (user_type_id, points))
+                .collect(),
+        );
+    }
+    owed
+}
+
+/// Method paths of the recipes whose campaign or quest transitions make rewards payable
+pub const PAYOUT_METHOD_PATHS: [&[u8]; 4] = [
+    b"CKBoostCampaign.approve_completion",
+    b"CKBoostCampaign.complete_quest",
+    b"CKBoostCampaign.finalize_approvals",
+    b"CKBoostQuest.approve_completion",
+];
+
+/// Check whether the transaction recipe, in the output_type of the last witness, is one of
+/// the [`PAYOUT_METHOD_PATHS`]
+///
+/// Other recipes may rewrite campaign and quest data, so their transitions never owe points.
+pub fn is_payout_transaction() -> bool {
+    let witnesses = match load_transaction() {
+        Ok(transaction) => transaction.witnesses(),
+        Err(_) => return false,
+    };
+    let recipe = witnesses
+        .len()
+        .checked_sub(1)
+        .and_then(|index| witnesses.get(index))
+        .and_then(|witness| WitnessArgs::from_slice(&witness.raw_data()).ok())
+        .and_then(|witness_args| witness_args.output_type().to_opt())
+        .and_then(|recipe| TransactionRecipe::from_slice(&recipe.raw_data()).ok());
+    match recipe {
+        Some(recipe) => PAYOUT_METHOD_PATHS.contains(&recipe.method_path_bytes().as_slice()),
+        None => false,
+    }
+}
+
+/// Merge owed points into `owed`, summing them per user type ID
+pub fn merge_points(owed: &mut Vec<(Vec<u8>, u128)>, points: Vec<(Vec<u8>, u128)>) {
+    for (user_type_id, amount) in points {
+        match owed
+            .iter_mut()
+            .find(|(participant, _)| participant.as_slice() == user_type_id.as_slice())
+        {
+            Some((_, total)) => *total = total.saturating_add(amount),
+            None => owed.push((user_type_id, amount)),
+        }
+    }
+}
+
+/// A cell with a type script, as carried under some lock
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypedCell {
+    pub type_script: Vec<u8>,
+    pub data: Vec<u8>,
+    pub capacity: u64,
+}
+
+/// Assets carried by the cells of one lock on one side of a transaction
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CarriedAssets {
+    pub plain_capacity: u64,
+    pub typed_cells: Vec<TypedCell>,
+}
+
+impl CarriedAssets {
+    /// Collect the cells of `source` whose lock hash is `lock_hash`
+    pub fn locked_by(source: Source, lock_hash: &[u8]) -> Result<Self, SysError> {
+        let mut assets = Self::default();
+        for (index, cell_lock_hash) in QueryIter::new(load_cell_lock_hash, source).enumerate() {
+            if cell_lock_hash.as_slice() != lock_hash {
+                continue;
+            }
+            let capacity = load_cell_capacity(index, source)?;
+            match load_cell_type(index, source)? {
+                Some(type_script) => assets.typed_cells.push(TypedCell {
+                    type_script: type_script.as_slice().to_vec(),
+                    data: load_cell_data(index, source)?,
+                    capacity,
+                }),
+                None => assets.plain_capacity = assets.plain_capacity.saturating_add(capacity),
+            }
+        }
+        Ok(assets)
+    }
+
+    /// Drop the cells with this type script, such as the campaign cell itself
+    pub fn without_type(mut self, script: &[u8]) -> Self {
+        self.typed_cells.retain(|cell| cell.type_script.as_slice() != script);
+        self
+    }
+
+    /// Total UDT amount, read from the first 16 bytes of data, of cells with this type script
+    pub fn udt_amount(&self, script: &[u8]) -> u128 {
+        self.typed_cells
+            .iter()
+            .filter(|cell| cell.type_script.as_slice() == script && cell.data.len() >= 16)
+            .fold(0u128, |total, cell| total.saturating_add(unpack_u128(&cell.data)))
+    }
+
+    /// Number of cells with this type script
+    pub fn cell_count(&self, script: &[u8]) -> usize {
+        self.typed_cells
+            .iter()
+            .filter(|cell| cell.type_script.as_slice() == script)
+            .count()
+    }
+
+    /// Check that these assets deliver exactly the owed UDTs, every owed NFT and at
+    /// least the owed CKB
+    pub fn delivers(&self, owed: &RewardEntitlement) -> bool {
+        self.plain_capacity >= owed.ckb_amount
+            && owed
+                .udt_amounts
+                .iter()
+                .all(|(script, amount)| self.udt_amount(script) == *amount)
+            && owed
+                .nft_scripts
+                .iter()
+                .all(|nft_script| self.cell_count(nft_script) > 0)
+    }
+
+    /// Check that going from `input` to `output` releases no more than `owed`
+    ///
+    /// Plain capacity may drop by the owed CKB, each owed UDT by its owed amount
+    /// and owed NFTs may leave. Every other typed cell must stay, with no less UDT
+    /// amount.
+    pub fn releases_within(input: &Self, output: &Self, owed: &RewardEntitlement) -> bool {
+        if input.plain_capacity.saturating_sub(output.plain_capacity) > owed.ckb_amount {
+            return false;
+        }
+        input.typed_cells.iter().all(|cell| {
+            let script = cell.type_script.as_slice();
+            if owed.owes_nft(script) {
+                return true;
+            }
+            let released = input.udt_amount(script).saturating_sub(output.udt_amount(script));
+            let owed_amount = owed.udt_amount(script);
+            if owed_amount > 0 {
+                released <= owed_amount
+            } else {
+                released == 0 && output.cell_count(script) >= input.cell_count(script)
+            }
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::generated::ckboost::UserSubmissionRecord;
+use blake2b_ref::Blake2bBuilder;
+use molecule::prelude::*;
+
+/// URI schemes a content-hash mode record can point to
+pub const CONTENT_URI_SCHEMES: [&[u8]; 4] = [b"ipfs://", b"ar://", b"https://", b"nostr:"];
+
+/// Hash recorded on-chain for submission content kept off-chain
+pub fn hash_submission_content(content: &[u8]) -> [u8; 32] {
+    let mut hasher = Blake2bBuilder::new(32).personal(b"ckb-default-hash").build();
+    hasher.update(content);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    hash
+}
+
+/// Check that `uri` is non-empty after one of the accepted schemes
+pub fn is_content_uri(uri: &[u8]) -> bool {
+    CONTENT_URI_SCHEMES
+        .iter()
+        .any(|scheme| uri.len() > scheme.len() && uri.starts_with(scheme))
+}
+
+/// Check whether the record keeps its content off-chain, with a URI in `submission_content`
+pub fn is_content_hash_mode(record: &UserSubmissionRecord) -> bool {
+    record.content_hash().as_slice() != [0u8; 32]
+}
+
+/// Check that a revealed record is either inline or points to a URI of an accepted scheme
+pub fn has_valid_content_reference(record: &UserSubmissionRecord) -> bool {
+    !is_content_hash_mode(record) || is_content_uri(&record.submission_content().raw_data())
+}
+
+/// Check off-chain `content` fetched from the record's URI against the on-chain hash
+///
+/// Always false for inline records, whose content is read from `submission_content` directly.
+pub fn verify_offchain_content(record: &UserSubmissionRecord, content: &[u8]) -> bool {
+    is_content_hash_mode(record)
+        && record.content_hash().as_slice() == hash_submission_content(content)
+}
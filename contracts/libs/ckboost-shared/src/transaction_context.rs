@@ -20,4 +20,15 @@ pub fn create_transaction_context() -> Result<TransactionContext<RuleBasedClassi
     let context = TransactionContext::new(collector)?;
     
     Ok(context)
-}
\ No newline at end of file
+}
+/// Create a transaction context for scripts bound to a campaign, such as the campaign lock
+pub fn create_campaign_transaction_context(
+    campaign_type_hash: &[u8],
+) -> Result<TransactionContext<RuleBasedClassifier>, Error> {
+    let protocol_data = crate::protocol_data::get_protocol_data_for_campaign(campaign_type_hash)?;
+    let classifier = crate::cell_collector::create_ckboost_classifier(&protocol_data)?;
+    let collector = ckb_deterministic::cell_classifier::CellCollector::new(classifier);
+    let context = TransactionContext::new(collector)?;
+
+    Ok(context)
+}
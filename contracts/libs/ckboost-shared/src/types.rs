@@ -42,6 +42,8 @@ pub mod campaign {
         UserSubmissionRecord, UserSubmissionRecordVec,
         QuestData, QuestDataVec,
         CampaignMetadata, CampaignData, CampaignDataVec,
+        CampaignManager, CampaignManagerVec,
+        ApprovalProof,
         
         // Readers and Builders
         AssetListReader, AssetListBuilder,
@@ -56,7 +58,9 @@ pub mod campaign {
 pub mod protocol {
     pub use crate::generated::{
         UserVerificationData,
+        VerificationAttestation, VerificationAttestationVec,
         TippingProposalMetadata, TippingProposalData, TippingProposalDataVec,
+        AttestorInfo, AttestorInfoVec,
         ProtocolData,
         
         // Readers and Builders
@@ -0,0 +1,262 @@
+pub use crate::generated::ckboost::{UserData, UserSubmissionRecord};
+use crate::attestation::expires_at;
+use crate::campaign_data::{unpack_u128, unpack_u32};
+use crate::generated::ckboost::{Byte32, Uint32, UserSubmissionRecordVec};
+use alloc::vec::Vec;
+use blake2b_ref::Blake2bBuilder;
+use molecule::prelude::*;
+
+/// Verification bits shared by `CampaignMetadata.verification_requirements` and
+/// `UserVerificationData.verified_flags`
+pub const VERIFICATION_TELEGRAM: u8 = 1;
+pub const VERIFICATION_KYC: u8 = 2;
+pub const VERIFICATION_DID: u8 = 4;
+/// Campaign-side flag: submissions are reviewed by hand, nothing for the user to verify
+pub const VERIFICATION_MANUAL_REVIEW: u8 = 8;
+pub const VERIFICATION_TWITTER: u8 = 16;
+pub const VERIFICATION_DISCORD: u8 = 32;
+pub const VERIFICATION_REDDIT: u8 = 64;
+
+/// Domain separator of submission commitments
+const SUBMISSION_COMMITMENT_DOMAIN: &[u8] = b"ckboost-submission-commitment";
+
+/// Commitment a user records for `content` before revealing it in a later transaction
+///
+/// The user type ID is part of the preimage, so a commitment copied into another user
+/// cell can never be opened there, even once the content is public. In content-hash mode
+/// `content` is the URI and `content_hash` the hash of the off-chain content, zero otherwise.
+pub fn submission_commitment(
+    user_type_id: &[u8],
+    campaign_type_id: &[u8],
+    quest_id: u32,
+    salt: &[u8],
+    content_hash: &[u8],
+    content: &[u8],
+) -> [u8; 32] {
+    let mut hasher = Blake2bBuilder::new(32).personal(b"ckb-default-hash").build();
+    hasher.update(SUBMISSION_COMMITMENT_DOMAIN);
+    hasher.update(user_type_id);
+    hasher.update(campaign_type_id);
+    hasher.update(&quest_id.to_le_bytes());
+    hasher.update(salt);
+    hasher.update(content_hash);
+    hasher.update(content);
+    let mut commitment = [0u8; 32];
+    hasher.finalize(&mut commitment);
+    commitment
+}
+
+/// Fold `record` into the rolling hash of archived submissions
+///
+/// The archive starts from the zero hash, so anyone holding the archived records can
+/// replay them in order and check the result against `archived_submissions_hash`.
+pub fn archived_submissions_hash_with(previous: &[u8], record: &UserSubmissionRecord) -> [u8; 32] {
+    let mut hasher = Blake2bBuilder::new(32).personal(b"ckb-default-hash").build();
+    hasher.update(previous);
+    hasher.update(record.as_slice());
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    hash
+}
+
+/// Extension trait for UserData with helpers shared by the user and campaign scripts
+pub trait UserDataExt {
+    /// Total points earned as u128
+    fn total_points_earned_u128(&self) -> u128;
+
+    /// Find the submission record for a quest of a campaign
+    fn find_submission(&self, campaign_type_id: &[u8], quest_id: u32) -> Option<UserSubmissionRecord>;
+
+    /// Check whether the user has submitted for a quest of a campaign
+    fn has_submission(&self, campaign_type_id: &[u8], quest_id: u32) -> bool {
+        self.find_submission(campaign_type_id, quest_id).is_some()
+    }
+
+    /// Number of submission records for a quest of a campaign, counting resubmissions
+    fn submission_count(&self, campaign_type_id: &[u8], quest_id: u32) -> u32;
+
+    /// Verification bits the user has completed
+    fn verified_flags_u8(&self) -> u8;
+
+    /// Verified bits whose attestation has not expired at `now` (ms)
+    fn verified_flags_at(&self, now: u64) -> u8;
+
+    /// Bits of `required` the user has not verified, or whose attestation expired at `now` (ms)
+    fn missing_verifications(&self, required: u8, now: u64) -> u8 {
+        required & !self.verified_flags_at(now)
+    }
+
+    /// Number of records compacted into the archive as u32
+    fn archived_submission_count_u32(&self) -> u32;
+
+    /// Check that the submission records and the archive are the same in `other`
+    fn has_same_submission_history(&self, other: &UserData) -> bool;
+
+    /// Rebuild with the `count` oldest submission records folded into the archive hash,
+    /// `None` if there are fewer records than that
+    fn with_archived_submissions(&self, count: u32) -> Option<UserData>;
+
+    /// Find the most recent submission record for a quest of a campaign
+    fn find_latest_submission(
+        &self,
+        campaign_type_id: &[u8],
+        quest_id: u32,
+    ) -> Option<UserSubmissionRecord>;
+
+    /// Check whether the most recent submission for a quest of a campaign has been revealed
+    fn latest_submission_revealed(
+        &self,
+        campaign_type_id: &[u8],
+        quest_id: u32,
+        user_type_id: &[u8],
+    ) -> bool {
+        self.find_latest_submission(campaign_type_id, quest_id)
+            .map(|record| record.is_revealed_by(user_type_id))
+            .unwrap_or(false)
+    }
+
+    /// Rebuild with the submission record at `index` replaced, `None` if out of range
+    fn with_submission_record(&self, index: usize, record: UserSubmissionRecord) -> Option<UserData>;
+
+    /// Sub-task IDs covered by the most recent submission for a quest of a campaign
+    fn submitted_sub_task_ids(&self, campaign_type_id: &[u8], quest_id: u32) -> Vec<u8> {
+        self.find_latest_submission(campaign_type_id, quest_id)
+            .map(|record| {
+                record
+                    .sub_task_submissions()
+                    .into_iter()
+                    .map(|entry| entry.sub_task_id().as_slice()[0])
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Extension trait for UserSubmissionRecord with the commit-reveal checks
+pub trait UserSubmissionRecordExt {
+    /// Check whether the record holds a commitment whose content is not revealed yet
+    fn is_committed(&self) -> bool;
+
+    /// Check whether the revealed content, hash and salt open the record's commitment for the user
+    fn is_revealed_by(&self, user_type_id: &[u8]) -> bool;
+}
+
+impl UserSubmissionRecordExt for UserSubmissionRecord {
+    fn is_committed(&self) -> bool {
+        self.content_commitment().as_slice() != [0u8; 32]
+            && self.content_salt().as_slice() == [0u8; 32]
+            && self.submission_content().is_empty()
+            && self.content_hash().as_slice() == [0u8; 32]
+    }
+
+    fn is_revealed_by(&self, user_type_id: &[u8]) -> bool {
+        if self.content_commitment().as_slice() == [0u8; 32] {
+            return false;
+        }
+        let commitment = submission_commitment(
+            user_type_id,
+            self.campaign_type_id().as_slice(),
+            unpack_u32(self.quest_id().as_slice()),
+            self.content_salt().as_slice(),
+            self.content_hash().as_slice(),
+            &self.submission_content().raw_data(),
+        );
+        self.content_commitment().as_slice() == commitment
+    }
+}
+
+impl UserDataExt for UserData {
+    fn total_points_earned_u128(&self) -> u128 {
+        unpack_u128(self.total_points_earned().as_slice())
+    }
+
+    fn find_submission(&self, campaign_type_id: &[u8], quest_id: u32) -> Option<UserSubmissionRecord> {
+        self.submission_records().into_iter().find(|record| {
+            record.campaign_type_id().as_slice() == campaign_type_id
+                && unpack_u32(record.quest_id().as_slice()) == quest_id
+        })
+    }
+
+    fn submission_count(&self, campaign_type_id: &[u8], quest_id: u32) -> u32 {
+        self.submission_records()
+            .into_iter()
+            .filter(|record| {
+                record.campaign_type_id().as_slice() == campaign_type_id
+                    && unpack_u32(record.quest_id().as_slice()) == quest_id
+            })
+            .count() as u32
+    }
+
+    fn verified_flags_u8(&self) -> u8 {
+        self.verification_data().verified_flags().as_slice()[0]
+    }
+
+    fn verified_flags_at(&self, now: u64) -> u8 {
+        let attested = self
+            .verification_data()
+            .attestations()
+            .into_iter()
+            .filter(|attestation| expires_at(attestation) > now)
+            .fold(0, |flags, attestation| flags | attestation.verification_bit().as_slice()[0]);
+        self.verified_flags_u8() & attested
+    }
+
+    fn archived_submission_count_u32(&self) -> u32 {
+        unpack_u32(self.archived_submission_count().as_slice())
+    }
+
+    fn has_same_submission_history(&self, other: &UserData) -> bool {
+        self.submission_records().as_slice() == other.submission_records().as_slice()
+            && self.archived_submission_count().as_slice() == other.archived_submission_count().as_slice()
+            && self.archived_submissions_hash().as_slice() == other.archived_submissions_hash().as_slice()
+    }
+
+    fn with_submission_record(&self, index: usize, record: UserSubmissionRecord) -> Option<UserData> {
+        let mut records: Vec<UserSubmissionRecord> = self.submission_records().into_iter().collect();
+        *records.get_mut(index)? = record;
+        Some(
+            self.clone()
+                .as_builder()
+                .submission_records(UserSubmissionRecordVec::new_builder().set(records).build())
+                .build(),
+        )
+    }
+
+    fn with_archived_submissions(&self, count: u32) -> Option<UserData> {
+        let records: Vec<UserSubmissionRecord> = self.submission_records().into_iter().collect();
+        let count_usize = count as usize;
+        if count_usize > records.len() {
+            return None;
+        }
+        let archived_count = self.archived_submission_count_u32().checked_add(count)?;
+
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(self.archived_submissions_hash().as_slice());
+        for record in records[..count_usize].iter() {
+            hash = archived_submissions_hash_with(&hash, record);
+        }
+
+        Some(
+            self.clone()
+                .as_builder()
+                .submission_records(UserSubmissionRecordVec::new_builder().set(records[count_usize..].to_vec()).build())
+                .archived_submission_count(Uint32::from(archived_count.to_le_bytes()))
+                .archived_submissions_hash(Byte32::from(hash))
+                .build(),
+        )
+    }
+
+    fn find_latest_submission(
+        &self,
+        campaign_type_id: &[u8],
+        quest_id: u32,
+    ) -> Option<UserSubmissionRecord> {
+        self.submission_records()
+            .into_iter()
+            .filter(|record| {
+                record.campaign_type_id().as_slice() == campaign_type_id
+                    && unpack_u32(record.quest_id().as_slice()) == quest_id
+            })
+            .last()
+    }
+}
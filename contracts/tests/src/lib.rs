@@ -11,6 +11,9 @@ use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+#[cfg(test)]
+mod support;
+
 #[cfg(test)]
 mod tests;
 
@@ -20,6 +23,48 @@ mod protocol_type_tests;
 #[cfg(test)]
 mod transaction_context_integration_tests;
 
+#[cfg(test)]
+mod test_udt_distribution;
+
+#[cfg(test)]
+mod test_badges;
+
+#[cfg(test)]
+mod test_campaign_managers;
+
+#[cfg(test)]
+mod test_campaign_stats;
+
+#[cfg(test)]
+mod test_approvals_smt;
+
+#[cfg(test)]
+mod test_quest_ids;
+
+#[cfg(test)]
+mod test_verification_requirements;
+
+#[cfg(test)]
+mod test_submission_archive;
+
+#[cfg(test)]
+mod test_submission_commitment;
+
+#[cfg(test)]
+mod test_submission_content;
+
+#[cfg(test)]
+mod test_points_mint;
+
+#[cfg(test)]
+mod test_dispute_window;
+
+#[cfg(test)]
+mod test_complete_quest;
+
+#[cfg(test)]
+mod test_quest_cells;
+
 // The exact same Loader code from capsule's template, except that
 // now we use MODE as the environment variable
 const TEST_ENV_VAR: &str = "MODE";
@@ -116,3 +161,27 @@ pub fn verify_and_dump_failed_tx(
     }
     result
 }
+
+#[cfg(test)]
+mod test_review_dispatch;
+
+#[cfg(test)]
+mod test_submission_rejection;
+
+#[cfg(test)]
+mod test_quest_prerequisites;
+
+#[cfg(test)]
+mod test_sub_task_progress;
+
+#[cfg(test)]
+mod test_endorser_authorization;
+
+#[cfg(test)]
+mod test_campaign_patches;
+
+#[cfg(test)]
+mod test_verification_updates;
+
+#[cfg(test)]
+mod test_user_profile;
@@ -0,0 +1,429 @@
+// Shared fixtures for the campaign, quest and manager data used across the tests, and a
+// deployment of the CKBoost scripts to run transactions against
+
+use crate::Loader;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{Cycle, HeaderBuilder, TransactionBuilder, TransactionView},
+    packed,
+    prelude::{Builder as _, Entity as _, Pack},
+};
+use ckb_testtool::context::Context;
+use ckboost_shared::types::{
+    Byte32, Byte32Vec, CampaignData, CampaignManager, CampaignManagerVec, ConnectedTypeID,
+    EndorserInfo, EndorserInfoVec, ProtocolConfig, ProtocolData, QuestData, QuestDataVec,
+    ScriptCodeHashes, Uint32, Uint8, UserData, UserSubmissionRecord, UserSubmissionRecordVec,
+};
+use ckboost_shared::user_data::submission_commitment;
+use molecule::prelude::*;
+
+const MAX_CYCLES: u64 = 70_000_000;
+// Every cell carries the same capacity, so transactions spending and recreating the same
+// number of cells balance
+const CELL_CAPACITY: u64 = 100_000_000_000;
+
+/// User type ID made of a single repeated marker byte
+pub fn user_type_id(marker: u8) -> Byte32 {
+    Byte32::from([marker; 32])
+}
+
+/// Quest accepting the users with these markers, each counted as a completion
+pub fn quest(quest_id: u32, accepted: &[u8]) -> QuestData {
+    QuestData::new_builder()
+        .quest_id(Uint32::from(quest_id.to_le_bytes()))
+        .accepted_submission_user_type_ids(
+            Byte32Vec::new_builder()
+                .extend(accepted.iter().map(|marker| user_type_id(*marker)))
+                .build(),
+        )
+        .completion_count(Uint32::from((accepted.len() as u32).to_le_bytes()))
+        .build()
+}
+
+/// Campaign holding these inline quests
+pub fn campaign(quests: Vec<QuestData>) -> CampaignData {
+    CampaignData::new_builder()
+        .quests(QuestDataVec::new_builder().set(quests).build())
+        .build()
+}
+
+/// Campaign manager whose lock hash is made of a single repeated marker byte
+pub fn manager(marker: u8, roles: u8) -> CampaignManager {
+    CampaignManager::new_builder()
+        .lock_hash(Byte32::from([marker; 32]))
+        .roles(Uint8::from([roles]))
+        .build()
+}
+
+/// Campaign endorsed by `endorser_lock_hash` and co-managed by `managers`
+pub fn managed_campaign(endorser_lock_hash: [u8; 32], managers: Vec<CampaignManager>) -> CampaignData {
+    CampaignData::new_builder()
+        .endorser(
+            EndorserInfo::new_builder()
+                .endorser_lock_hash(Byte32::from(endorser_lock_hash))
+                .build(),
+        )
+        .managers(CampaignManagerVec::new_builder().set(managers).build())
+        .build()
+}
+
+/// User holding a revealed submission for a quest of a campaign
+pub fn submitted_user(user_type_id: [u8; 32], campaign_type_id: [u8; 32], quest_id: u32) -> UserData {
+    let salt = [9u8; 32];
+    let content = b"https://example.com/proof";
+    let record = UserSubmissionRecord::new_builder()
+        .campaign_type_id(Byte32::from(campaign_type_id))
+        .quest_id(Uint32::from(quest_id.to_le_bytes()))
+        .submission_content(content.to_vec())
+        .content_commitment(Byte32::from(submission_commitment(
+            &user_type_id,
+            &campaign_type_id,
+            quest_id,
+            &salt,
+            &[0u8; 32],
+            content,
+        )))
+        .content_salt(Byte32::from(salt))
+        .build();
+    UserData::new_builder()
+        .submission_records(UserSubmissionRecordVec::new_builder().push(record).build())
+        .build()
+}
+
+/// Submission record for a quest of a campaign that only commits to its content, as
+/// submit_quest records it
+pub fn committed_record(user_type_id: [u8; 32], campaign_type_id: [u8; 32], quest_id: u32) -> UserSubmissionRecord {
+    UserSubmissionRecord::new_builder()
+        .campaign_type_id(Byte32::from(campaign_type_id))
+        .quest_id(Uint32::from(quest_id.to_le_bytes()))
+        .content_commitment(Byte32::from(submission_commitment(
+            &user_type_id,
+            &campaign_type_id,
+            quest_id,
+            &[7u8; 32],
+            &[0u8; 32],
+            b"https://example.com/resubmission",
+        )))
+        .build()
+}
+
+/// `user_data` with `record` appended to its submission records
+pub fn with_record(user_data: &UserData, record: UserSubmissionRecord) -> UserData {
+    let records = user_data.submission_records().as_builder().push(record).build();
+    user_data.clone().as_builder().submission_records(records).build()
+}
+
+/// ConnectedTypeID script args linking `type_id` to the cell with type hash `connected_key`
+pub fn connected_args(type_id: [u8; 32], connected_key: [u8; 32]) -> Bytes {
+    let connected_type_id = ConnectedTypeID::new_builder()
+        .type_id(Byte32::from(type_id))
+        .connected_key(Byte32::from(connected_key))
+        .build();
+    Bytes::from(connected_type_id.as_slice().to_vec())
+}
+
+/// WitnessArgs carrying the transaction recipe in output_type, with inline arguments
+pub fn recipe_witness(method_path: &str, args: Vec<&[u8]>) -> Bytes {
+    use ckb_deterministic::generated::{
+        Bytes as DeterministicBytes, RecipeArgument, RecipeArgumentVec, TransactionRecipe,
+    };
+
+    let arguments: Vec<RecipeArgument> = args
+        .into_iter()
+        .map(|arg| {
+            RecipeArgument::new_builder()
+                .arg_type(0u8) // 0 = inline_data
+                .data(DeterministicBytes::from(arg.to_vec()))
+                .build()
+        })
+        .collect();
+    let recipe = TransactionRecipe::new_builder()
+        .method_path(DeterministicBytes::from(method_path.as_bytes().to_vec()))
+        .arguments(RecipeArgumentVec::from(arguments))
+        .build();
+    packed::WitnessArgs::new_builder()
+        .output_type(Some(Bytes::from(recipe.as_slice().to_vec())).pack())
+        .build()
+        .as_bytes()
+}
+
+/// Script hash as a plain array
+pub fn script_hash(script: &packed::Script) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(script.calc_script_hash().as_slice());
+    hash
+}
+
+/// Output cell with the shared capacity
+pub fn cell(lock: &packed::Script, type_script: Option<&packed::Script>) -> packed::CellOutput {
+    packed::CellOutput::new_builder()
+        .capacity(CELL_CAPACITY.pack())
+        .lock(lock.clone())
+        .type_(type_script.cloned().pack())
+        .build()
+}
+
+/// The CKBoost scripts deployed next to a protocol cell whose code hashes point at them
+pub struct Deployment {
+    pub context: Context,
+    protocol_dep: packed::CellDep,
+    protocol_type_hash: [u8; 32],
+    campaign_type: packed::OutPoint,
+    campaign_lock: packed::OutPoint,
+    user_type: packed::OutPoint,
+    points_udt: packed::OutPoint,
+    quest_type: packed::OutPoint,
+    always_success: packed::OutPoint,
+}
+
+impl Deployment {
+    /// Deploy every script and a protocol cell whitelisting these endorser lock hashes
+    pub fn new(endorser_lock_hashes: &[[u8; 32]]) -> Self {
+        let mut context = Context::default();
+        let loader = Loader::default();
+        let mut deploy = |name: &str| {
+            let binary = loader.load_binary(name);
+            let mut code_hash = [0u8; 32];
+            code_hash.copy_from_slice(packed::CellOutput::calc_data_hash(&binary).as_slice());
+            (context.deploy_cell(binary), code_hash)
+        };
+        let (protocol_type, protocol_type_code_hash) = deploy("ckboost-protocol-type");
+        let (_, protocol_lock_code_hash) = deploy("ckboost-protocol-lock");
+        let (campaign_type, campaign_type_code_hash) = deploy("ckboost-campaign-type");
+        let (campaign_lock, campaign_lock_code_hash) = deploy("ckboost-campaign-lock");
+        let (user_type, user_type_code_hash) = deploy("ckboost-user-type");
+        let (points_udt, points_udt_code_hash) = deploy("ckboost-points-udt");
+        let (quest_type, quest_type_code_hash) = deploy("ckboost-quest-type");
+        let always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+        let endorsers = endorser_lock_hashes.iter().map(|lock_hash| {
+            EndorserInfo::new_builder()
+                .endorser_lock_hash(Byte32::from(*lock_hash))
+                .build()
+        });
+        let protocol_data = ProtocolData::new_builder()
+            .endorsers_whitelist(EndorserInfoVec::new_builder().extend(endorsers).build())
+            .protocol_config(
+                ProtocolConfig::new_builder()
+                    .script_code_hashes(
+                        ScriptCodeHashes::new_builder()
+                            .ckb_boost_protocol_type_code_hash(Byte32::from(protocol_type_code_hash))
+                            .ckb_boost_protocol_lock_code_hash(Byte32::from(protocol_lock_code_hash))
+                            .ckb_boost_campaign_type_code_hash(Byte32::from(campaign_type_code_hash))
+                            .ckb_boost_campaign_lock_code_hash(Byte32::from(campaign_lock_code_hash))
+                            .ckb_boost_user_type_code_hash(Byte32::from(user_type_code_hash))
+                            .ckb_boost_points_udt_type_code_hash(Byte32::from(points_udt_code_hash))
+                            .ckb_boost_quest_type_code_hash(Byte32::from(quest_type_code_hash))
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build();
+
+        let always_success_lock = context
+            .build_script(&always_success, Bytes::new())
+            .expect("always success lock");
+        let protocol_type_script = context
+            .build_script(&protocol_type, Bytes::from(vec![0u8; 32]))
+            .expect("protocol type script");
+        let protocol_out_point = context.create_cell(
+            cell(&always_success_lock, Some(&protocol_type_script)),
+            Bytes::from(protocol_data.as_slice().to_vec()),
+        );
+
+        Deployment {
+            context,
+            protocol_dep: packed::CellDep::new_builder().out_point(protocol_out_point).build(),
+            protocol_type_hash: script_hash(&protocol_type_script),
+            campaign_type,
+            campaign_lock,
+            user_type,
+            points_udt,
+            quest_type,
+            always_success,
+        }
+    }
+
+    /// Type hash of the protocol cell
+    pub fn protocol_type_hash(&self) -> [u8; 32] {
+        self.protocol_type_hash
+    }
+
+    /// Always-success lock told apart by a marker byte, standing in for a signer's lock
+    pub fn lock(&self, marker: u8) -> packed::Script {
+        self.context
+            .build_script(&self.always_success, Bytes::from(vec![marker]))
+            .expect("signer lock")
+    }
+
+    /// Lock hash of the signer lock with this marker
+    pub fn lock_hash(&self, marker: u8) -> [u8; 32] {
+        script_hash(&self.lock(marker))
+    }
+
+    /// Campaign type script with this type ID, connected to the protocol cell
+    pub fn campaign_type(&self, type_id: [u8; 32]) -> packed::Script {
+        self.context
+            .build_script(&self.campaign_type, connected_args(type_id, self.protocol_type_hash))
+            .expect("campaign type script")
+    }
+
+    /// Campaign lock bound to the campaign with this type script
+    pub fn campaign_lock(&self, campaign_type: &packed::Script) -> packed::Script {
+        self.context
+            .build_script(&self.campaign_lock, Bytes::from(script_hash(campaign_type).to_vec()))
+            .expect("campaign lock script")
+    }
+
+    /// User type script with this type ID, connected to the protocol cell
+    pub fn user_type(&self, type_id: [u8; 32]) -> packed::Script {
+        self.context
+            .build_script(&self.user_type, connected_args(type_id, self.protocol_type_hash))
+            .expect("user type script")
+    }
+
+    /// Quest type script with this type ID, connected to the campaign with this type script
+    pub fn quest_type(&self, type_id: [u8; 32], campaign_type: &packed::Script) -> packed::Script {
+        self.context
+            .build_script(&self.quest_type, connected_args(type_id, script_hash(campaign_type)))
+            .expect("quest type script")
+    }
+
+    /// Points UDT type script issued by the protocol cell
+    pub fn points_type(&self) -> packed::Script {
+        self.context
+            .build_script(&self.points_udt, Bytes::from(self.protocol_type_hash.to_vec()))
+            .expect("points UDT type script")
+    }
+
+    /// Transaction with the protocol cell as its first cell dep
+    pub fn transaction(&self) -> TransactionBuilder {
+        TransactionBuilder::default().cell_dep(self.protocol_dep.clone())
+    }
+
+    /// Create a live cell and return the input spending it
+    pub fn spend(
+        &mut self,
+        lock: &packed::Script,
+        type_script: Option<&packed::Script>,
+        data: &[u8],
+    ) -> packed::CellInput {
+        let out_point = self
+            .context
+            .create_cell(cell(lock, type_script), Bytes::from(data.to_vec()));
+        packed::CellInput::new_builder().previous_output(out_point).build()
+    }
+
+    /// Create a live cell and return a cell dep referencing it
+    pub fn dep(
+        &mut self,
+        lock: &packed::Script,
+        type_script: Option<&packed::Script>,
+        data: &[u8],
+    ) -> packed::CellDep {
+        let out_point = self
+            .context
+            .create_cell(cell(lock, type_script), Bytes::from(data.to_vec()));
+        packed::CellDep::new_builder().out_point(out_point).build()
+    }
+
+    /// Spend the campaign cell holding `input` under its campaign lock together with a cell of
+    /// the `signer` lock, and recreate both with the campaign holding `output`
+    pub fn campaign_update(
+        &mut self,
+        campaign_type_id: [u8; 32],
+        input: &CampaignData,
+        output: &CampaignData,
+        signer: u8,
+    ) -> TransactionBuilder {
+        let campaign_type = self.campaign_type(campaign_type_id);
+        let campaign_lock = self.campaign_lock(&campaign_type);
+        let signer_lock = self.lock(signer);
+        let campaign_input = self.spend(&campaign_lock, Some(&campaign_type), input.as_slice());
+        let signer_input = self.spend(&signer_lock, None, &[]);
+        self.transaction()
+            .input(campaign_input)
+            .input(signer_input)
+            .output(cell(&campaign_lock, Some(&campaign_type)))
+            .output_data(Bytes::from(output.as_slice().to_vec()).pack())
+            .output(cell(&signer_lock, None))
+            .output_data(Bytes::new().pack())
+    }
+
+    /// Provide the user cell with this type ID, owned by the `owner` lock, as a cell dep
+    pub fn user_dep(&mut self, user_type_id: [u8; 32], owner: u8, data: &UserData) -> packed::CellDep {
+        let user_type = self.user_type(user_type_id);
+        let owner_lock = self.lock(owner);
+        self.dep(&owner_lock, Some(&user_type), data.as_slice())
+    }
+
+    /// Provide the campaign cell with this type ID, held by its campaign lock, as a cell dep
+    pub fn campaign_dep(&mut self, campaign_type_id: [u8; 32], data: &CampaignData) -> packed::CellDep {
+        let campaign_type = self.campaign_type(campaign_type_id);
+        let campaign_lock = self.campaign_lock(&campaign_type);
+        self.dep(&campaign_lock, Some(&campaign_type), data.as_slice())
+    }
+
+    /// Update the user cell with this type ID owned by the `owner` lock under `method_path`,
+    /// moving its data from `input` to `output`
+    pub fn user_update(
+        &mut self,
+        method_path: &str,
+        user_type_id: [u8; 32],
+        owner: u8,
+        input: &UserData,
+        output: &UserData,
+    ) -> TransactionBuilder {
+        self.user_update_with_args(method_path, user_type_id, owner, input, output, vec![])
+    }
+
+    /// Like `user_update`, with `extra_args` following the output user data in the recipe
+    pub fn user_update_with_args(
+        &mut self,
+        method_path: &str,
+        user_type_id: [u8; 32],
+        owner: u8,
+        input: &UserData,
+        output: &UserData,
+        extra_args: Vec<&[u8]>,
+    ) -> TransactionBuilder {
+        let user_type = self.user_type(user_type_id);
+        let owner_lock = self.lock(owner);
+        let user_input = self.spend(&owner_lock, Some(&user_type), input.as_slice());
+        let mut args = vec![output.as_slice()];
+        args.extend(extra_args);
+        self.transaction()
+            .input(user_input)
+            .output(cell(&owner_lock, Some(&user_type)))
+            .output_data(Bytes::from(output.as_slice().to_vec()).pack())
+            .witness(recipe_witness(method_path, args).pack())
+    }
+
+    /// Submit through the user cell with this type ID owned by the `owner` lock, moving its data
+    /// from `input` to `output`; the campaign cell deps are left to the caller
+    pub fn submission(
+        &mut self,
+        user_type_id: [u8; 32],
+        owner: u8,
+        input: &UserData,
+        output: &UserData,
+    ) -> TransactionBuilder {
+        self.user_update("CKBoostUser.submit_quest", user_type_id, owner, input, output)
+    }
+
+    /// Insert a block header with this timestamp (ms) and return its hash for header deps
+    pub fn header(&mut self, timestamp: u64) -> packed::Byte32 {
+        let header = HeaderBuilder::default().timestamp(timestamp.pack()).build();
+        let hash = header.hash();
+        self.context.insert_header(header);
+        hash
+    }
+
+    /// Add the script cell deps and run every script of the transaction
+    pub fn verify(&mut self, tx: TransactionView) -> Result<Cycle, Error> {
+        let tx = self.context.complete_tx(tx);
+        self.context.verify_tx(&tx, MAX_CYCLES)
+    }
+}
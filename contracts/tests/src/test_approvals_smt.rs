@@ -0,0 +1,191 @@
+// Tests for approvals tracked in per-quest sparse Merkle trees
+
+use ckboost_shared::approvals::{
+    approval_key, approved_value, proven_approvals, rewarded_by_proof, verify_approval_transition,
+    verify_approved, CkbBlake2bHasher,
+};
+use ckboost_shared::campaign_data::APPROVAL_TRACKING_SMT;
+use crate::support::{
+    campaign, managed_campaign, quest, recipe_witness, submitted_user, user_type_id, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{bytes::Bytes as TxBytes, core::Cycle, packed::WitnessArgs, prelude::Pack};
+use ckboost_shared::types::{
+    ApprovalProof, AssetList, AssetListVec, Byte32, Byte32Vec, Bytes, CampaignData, QuestDataVec,
+    Uint32, Uint64,
+};
+use molecule::prelude::*;
+use sparse_merkle_tree::{default_store::DefaultStore, SparseMerkleTree, H256};
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const PARTICIPANT: u8 = 9;
+
+type ApprovalsTree = SparseMerkleTree<CkbBlake2bHasher, H256, DefaultStore<H256>>;
+
+fn user(marker: u8) -> Vec<u8> {
+    vec![marker; 32]
+}
+
+fn approve(tree: &mut ApprovalsTree, users: &[Vec<u8>]) {
+    for user_type_id in users {
+        tree.update(approval_key(user_type_id), approved_value()).unwrap();
+    }
+}
+
+fn compiled_proof(tree: &ApprovalsTree, users: &[Vec<u8>]) -> Vec<u8> {
+    let keys: Vec<H256> = users.iter().map(|user_type_id| approval_key(user_type_id)).collect();
+    tree.merkle_proof(keys.clone()).unwrap().compile(keys).unwrap().0
+}
+
+fn smt_campaign(root: &H256, completion_count: u32) -> CampaignData {
+    let quest = quest(1, &[])
+        .as_builder()
+        .rewards_on_completion(
+            AssetListVec::new_builder()
+                .push(
+                    AssetList::new_builder()
+                        .ckb_amount(Uint64::from(100u64.to_le_bytes()))
+                        .build(),
+                )
+                .build(),
+        )
+        .completion_count(Uint32::from(completion_count.to_le_bytes()))
+        .approvals_root(Byte32::from(<[u8; 32]>::from(*root)))
+        .build();
+    campaign(vec![quest])
+        .as_builder()
+        .approval_tracking(APPROVAL_TRACKING_SMT)
+        .build()
+}
+
+fn approval_proof(users: &[Vec<u8>], proof: Vec<u8>) -> ApprovalProof {
+    ApprovalProof::new_builder()
+        .quest_id(Uint32::from(1u32.to_le_bytes()))
+        .user_type_ids(
+            Byte32Vec::new_builder()
+                .extend(users.iter().map(|user_type_id| Byte32::from_slice(user_type_id).unwrap()))
+                .build(),
+        )
+        .proof(Bytes::from(proof))
+        .build()
+}
+
+#[test]
+fn test_approval_transition_is_proven() {
+    let mut tree = ApprovalsTree::default();
+    approve(&mut tree, &[user(1)]);
+    let input_root = *tree.root();
+
+    let approved = vec![user(2), user(3)];
+    let proof = compiled_proof(&tree, &approved);
+    approve(&mut tree, &approved);
+    let output_root = *tree.root();
+
+    assert!(verify_approval_transition(input_root.as_slice(), output_root.as_slice(), &approved, &proof));
+    // The proof covers exactly the approved users
+    assert!(!verify_approval_transition(input_root.as_slice(), output_root.as_slice(), &[user(2)], &proof));
+    assert!(!verify_approval_transition(input_root.as_slice(), input_root.as_slice(), &approved, &proof));
+
+    // A user already in the tree cannot be approved again
+    let again = compiled_proof(&tree, &[user(1)]);
+    assert!(!verify_approval_transition(output_root.as_slice(), output_root.as_slice(), &[user(1)], &again));
+
+    // Claims prove inclusion of the claiming user
+    let inclusion = compiled_proof(&tree, &[user(3)]);
+    assert!(verify_approved(output_root.as_slice(), &user(3), &inclusion));
+    assert!(!verify_approved(output_root.as_slice(), &user(4), &inclusion));
+}
+
+#[test]
+fn test_proven_approvals_are_rewarded() {
+    let mut tree = ApprovalsTree::default();
+    let input = smt_campaign(tree.root(), 0);
+    let approved = vec![user(7), user(8)];
+    let proof = approval_proof(&approved, compiled_proof(&tree, &approved));
+    approve(&mut tree, &approved);
+    let output = smt_campaign(tree.root(), 2);
+
+    assert_eq!(proven_approvals(&input, &output, &proof), Some(approved.clone()));
+    let rewarded = rewarded_by_proof(&input, &output, &proof);
+    assert_eq!(rewarded.len(), 2);
+    assert_eq!(rewarded[0].1.ckb_amount, 100);
+
+    // Proofs do not count for roots they do not connect, nor for list campaigns
+    assert!(proven_approvals(&output, &output, &proof).is_none());
+    assert!(rewarded_by_proof(&output, &output, &proof).is_empty());
+    let list_output = output.clone().as_builder().approval_tracking(0u8).build();
+    assert!(proven_approvals(&input, &list_output, &proof).is_none());
+}
+
+// SMT campaign endorsed by `endorser_lock_hash` whose reward-free quest 1 has `completion_count`
+// approvals under `root`
+fn reviewed_smt_campaign(endorser_lock_hash: [u8; 32], root: &H256, completion_count: u32) -> CampaignData {
+    let quest = quest(1, &[])
+        .as_builder()
+        .completion_count(Uint32::from(completion_count.to_le_bytes()))
+        .approvals_root(Byte32::from(<[u8; 32]>::from(*root)))
+        .build();
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .quests(QuestDataVec::new_builder().push(quest).build())
+        .approval_tracking(APPROVAL_TRACKING_SMT)
+        .total_completions(Uint32::from(completion_count.to_le_bytes()))
+        .build()
+}
+
+// Approve user 5 for quest 1 of an SMT campaign, with the campaign input's witness proving the
+// approval of the user with marker `proven`, if any, and the output counting `participants`
+fn approve_proven(proven: Option<u8>, participants: u32) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let mut tree = ApprovalsTree::default();
+    let input = reviewed_smt_campaign(endorser, tree.root(), 0);
+    let proof = proven.map(|marker| approval_proof(&[user(marker)], compiled_proof(&tree, &[user(marker)])));
+    approve(&mut tree, &[user(5)]);
+    let output = reviewed_smt_campaign(endorser, tree.root(), 1)
+        .as_builder()
+        .participants_count(Uint32::from(participants.to_le_bytes()))
+        .build();
+    let user_ids = Byte32Vec::new_builder().push(user_type_id(5)).build();
+
+    // The campaign input comes first, so the first witness carries the proof
+    let proof_witness = WitnessArgs::new_builder()
+        .input_type(proof.map(|proof| TxBytes::from(proof.as_slice().to_vec())).pack())
+        .build();
+    let user_dep = deployment.user_dep([5; 32], PARTICIPANT, &submitted_user([5; 32], CAMPAIGN, 1));
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, ENDORSER)
+        .cell_dep(user_dep)
+        .witness(proof_witness.as_bytes().pack())
+        .witness(
+            recipe_witness(
+                "CKBoostCampaign.approve_completion",
+                vec![output.as_slice(), &1u32.to_le_bytes(), user_ids.as_slice()],
+            )
+            .pack(),
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_reviewer_approves_with_a_proof() {
+    approve_proven(Some(5), 0).expect("proof takes the quest root to the approved tree");
+}
+
+#[test]
+fn test_smt_approval_counts_no_participants() {
+    // Per-quest roots cannot show the user is new to the campaign, so the count stays 0
+    assert!(approve_proven(Some(5), 1).is_err());
+}
+
+#[test]
+fn test_smt_approval_requires_a_proof() {
+    assert!(approve_proven(None, 0).is_err());
+}
+
+#[test]
+fn test_proof_must_cover_the_approved_users() {
+    assert!(approve_proven(Some(6), 0).is_err());
+}
@@ -0,0 +1,107 @@
+// Tests for completion badges minted as Spore DOBs
+
+use ckboost_shared::badge::{badge_content, parse_badge, BADGE_CONTENT_TYPE};
+use crate::support::{
+    managed_campaign, quest, recipe_witness, submitted_user, user_type_id, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{core::Cycle, prelude::Pack};
+use ckboost_shared::types::{
+    Byte32, Byte32Opt, Byte32Vec, Bytes, BytesOpt, CampaignData, QuestDataVec, SporeData, Uint32,
+};
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const CLUSTER: [u8; 32] = [0x44; 32];
+const ENDORSER: u8 = 1;
+const PARTICIPANT: u8 = 9;
+
+fn spore_data(content_type: &[u8], content: Vec<u8>, cluster_id: Option<[u8; 32]>) -> Vec<u8> {
+    SporeData::new_builder()
+        .content_type(Bytes::from(content_type.to_vec()))
+        .content(Bytes::from(content))
+        .cluster_id(
+            BytesOpt::new_builder()
+                .set(cluster_id.map(|id| Bytes::from(id.to_vec())))
+                .build(),
+        )
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+#[test]
+fn test_badge_content_references_campaign_and_quest() {
+    let content = badge_content(&[0xab; 32], 2);
+    let expected = format!("{{\"dna\":\"{}02000000\"}}", "ab".repeat(32));
+    assert_eq!(content, expected.into_bytes());
+    assert_ne!(badge_content(&[0xab; 32], 3), badge_content(&[0xab; 32], 2));
+}
+
+#[test]
+fn test_parse_badge_requires_cluster_and_content_type() {
+    let cluster_id = [7u8; 32];
+    let content = badge_content(&[1; 32], 1);
+
+    let badge = spore_data(BADGE_CONTENT_TYPE, content.clone(), Some(cluster_id));
+    assert_eq!(parse_badge(&badge, &cluster_id), Some(content.clone()));
+
+    let other_cluster = spore_data(BADGE_CONTENT_TYPE, content.clone(), Some([8u8; 32]));
+    assert_eq!(parse_badge(&other_cluster, &cluster_id), None);
+
+    let no_cluster = spore_data(BADGE_CONTENT_TYPE, content.clone(), None);
+    assert_eq!(parse_badge(&no_cluster, &cluster_id), None);
+
+    let image = spore_data(b"image/png", content, Some(cluster_id));
+    assert_eq!(parse_badge(&image, &cluster_id), None);
+}
+
+// Campaign accepting `accepted` for quest 1, minting badges into `cluster_id` if any
+fn badge_campaign(endorser_lock_hash: [u8; 32], cluster_id: Option<[u8; 32]>, accepted: &[u8]) -> CampaignData {
+    let completions = accepted.len() as u32;
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .quests(QuestDataVec::new_builder().push(quest(1, accepted)).build())
+        .total_completions(Uint32::from(completions.to_le_bytes()))
+        .participants_count(Uint32::from(completions.to_le_bytes()))
+        .badge_cluster_id(
+            Byte32Opt::new_builder()
+                .set(cluster_id.map(Byte32::from))
+                .build(),
+        )
+        .build()
+}
+
+// Approve user 5 for quest 1 without minting any badge
+fn approve_without_badges(cluster_id: Option<[u8; 32]>) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let input = badge_campaign(endorser, cluster_id, &[]);
+    let output = badge_campaign(endorser, cluster_id, &[5]);
+    let user_ids = Byte32Vec::new_builder().push(user_type_id(5)).build();
+
+    let user_dep = deployment.user_dep([5; 32], PARTICIPANT, &submitted_user([5; 32], CAMPAIGN, 1));
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, ENDORSER)
+        .cell_dep(user_dep)
+        .witness(
+            recipe_witness(
+                "CKBoostCampaign.approve_completion",
+                vec![output.as_slice(), &1u32.to_le_bytes(), user_ids.as_slice()],
+            )
+            .pack(),
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_campaign_without_cluster_mints_no_badges() {
+    approve_without_badges(None).expect("badges are opt-in");
+}
+
+#[test]
+fn test_approval_must_mint_the_badge() {
+    // Campaigns with a badge cluster owe one badge per newly approved user
+    assert!(approve_without_badges(Some(CLUSTER)).is_err());
+}
@@ -0,0 +1,106 @@
+// Tests for campaign co-managers and their role flags
+
+use ckboost_shared::campaign_data::{
+    ALL_ROLES, ROLE_APPROVE_COMPLETIONS, ROLE_EDIT_METADATA, ROLE_MANAGE_FUNDS,
+};
+use crate::support::{
+    managed_campaign, manager, quest, recipe_witness, submitted_user, user_type_id, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{core::Cycle, prelude::Pack};
+use ckboost_shared::types::{
+    Byte32, Byte32Vec, CampaignData, CampaignManager, QuestDataVec, Uint32, Uint8,
+};
+use ckboost_shared::CampaignDataExt;
+use molecule::prelude::*;
+
+fn campaign(endorser: u8, managers: Vec<CampaignManager>) -> CampaignData {
+    managed_campaign([endorser; 32], managers)
+}
+
+#[test]
+fn test_endorser_holds_every_role() {
+    let campaign = campaign(1, vec![]);
+    assert_eq!(campaign.roles_of(&[1; 32]), ALL_ROLES);
+    assert!(campaign.has_role(&[1; 32], ROLE_MANAGE_FUNDS));
+    assert_eq!(campaign.roles_of(&[2; 32]), 0);
+}
+
+#[test]
+fn test_manager_roles_are_granted_per_flag() {
+    let campaign = campaign(
+        1,
+        vec![
+            manager(2, ROLE_APPROVE_COMPLETIONS),
+            manager(3, ROLE_EDIT_METADATA | ROLE_MANAGE_FUNDS),
+        ],
+    );
+
+    // A reviewer can approve completions but neither edit nor withdraw
+    assert!(campaign.has_role(&[2; 32], ROLE_APPROVE_COMPLETIONS));
+    assert!(!campaign.has_role(&[2; 32], ROLE_EDIT_METADATA));
+    assert!(!campaign.has_role(&[2; 32], ROLE_MANAGE_FUNDS));
+
+    assert!(campaign.has_role(&[3; 32], ROLE_EDIT_METADATA | ROLE_MANAGE_FUNDS));
+    assert!(!campaign.has_role(&[3; 32], ROLE_APPROVE_COMPLETIONS));
+    assert!(!campaign.has_role(&[4; 32], ROLE_APPROVE_COMPLETIONS));
+}
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const REVIEWER: u8 = 2;
+const OUTSIDER: u8 = 3;
+const PARTICIPANT: u8 = 9;
+
+// Approve user 5 for quest 1 of a campaign held by the campaign lock, signed by `signer`, with
+// the output campaign accepting `accepted`
+fn approve_as(signer: u8, accepted: &[u8]) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let managers = vec![CampaignManager::new_builder()
+        .lock_hash(Byte32::from(deployment.lock_hash(REVIEWER)))
+        .roles(Uint8::from([ROLE_APPROVE_COMPLETIONS]))
+        .build()];
+    let input = managed_campaign(deployment.lock_hash(ENDORSER), managers)
+        .as_builder()
+        .quests(QuestDataVec::new_builder().push(quest(1, &[])).build())
+        .build();
+    let output = input
+        .clone()
+        .as_builder()
+        .quests(QuestDataVec::new_builder().push(quest(1, accepted)).build())
+        .total_completions(Uint32::from((accepted.len() as u32).to_le_bytes()))
+        .participants_count(Uint32::from((accepted.len() as u32).to_le_bytes()))
+        .build();
+    let user_ids = Byte32Vec::new_builder().push(user_type_id(5)).build();
+
+    let user_dep = deployment.user_dep([5; 32], PARTICIPANT, &submitted_user([5; 32], CAMPAIGN, 1));
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, signer)
+        .cell_dep(user_dep)
+        .witness(
+            recipe_witness(
+                "CKBoostCampaign.approve_completion",
+                vec![output.as_slice(), &1u32.to_le_bytes(), user_ids.as_slice()],
+            )
+            .pack(),
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_reviewer_approves_without_the_endorser_lock() {
+    // Neither the campaign cell nor the reviewer's input is locked by the endorser
+    approve_as(REVIEWER, &[5]).expect("delegated reviewer approves the submission");
+}
+
+#[test]
+fn test_outsider_cannot_approve() {
+    assert!(approve_as(OUTSIDER, &[5]).is_err());
+}
+
+#[test]
+fn test_approval_cannot_accept_unlisted_users() {
+    // User 6 rides along in the output without being named or having submitted
+    assert!(approve_as(REVIEWER, &[5, 6]).is_err());
+}
@@ -0,0 +1,219 @@
+// Tests for the recipes patching a single part of the campaign
+
+use ckboost_shared::campaign_data::{ROLE_APPROVE_COMPLETIONS, ROLE_EDIT_METADATA};
+use crate::support::{managed_campaign, quest, recipe_witness, Deployment};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{core::Cycle, prelude::Pack};
+use ckboost_shared::types::{
+    Byte32, CampaignData, CampaignManager, CampaignMetadata, QuestData, QuestDataVec,
+    QuestMetadata, Uint128, Uint32, Uint8,
+};
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const EDITOR: u8 = 2;
+const REVIEWER: u8 = 3;
+
+fn campaign_manager(lock_hash: [u8; 32], roles: u8) -> CampaignManager {
+    CampaignManager::new_builder()
+        .lock_hash(Byte32::from(lock_hash))
+        .roles(Uint8::from([roles]))
+        .build()
+}
+
+fn quest_metadata(title: &str) -> QuestMetadata {
+    QuestMetadata::new_builder().title(title.as_bytes().to_vec()).build()
+}
+
+fn campaign_metadata(title: &str) -> CampaignMetadata {
+    CampaignMetadata::new_builder()
+        .title(title.as_bytes().to_vec())
+        .short_description(b"Short description".to_vec())
+        .long_description(b"Long description".to_vec())
+        .build()
+}
+
+// Campaign with `status` holding `quests` below quest ID 2, edited by EDITOR and reviewed by
+// REVIEWER
+fn patched_campaign(deployment: &Deployment, status: u8, quests: Vec<QuestData>) -> CampaignData {
+    let managers = vec![
+        campaign_manager(deployment.lock_hash(EDITOR), ROLE_EDIT_METADATA),
+        campaign_manager(deployment.lock_hash(REVIEWER), ROLE_APPROVE_COMPLETIONS),
+    ];
+    managed_campaign(deployment.lock_hash(ENDORSER), managers)
+        .as_builder()
+        .status(status)
+        .metadata(campaign_metadata("Campaign"))
+        .quests(QuestDataVec::new_builder().set(quests).build())
+        .next_quest_id(Uint32::from(2u32.to_le_bytes()))
+        .build()
+}
+
+// Patch the campaign under `method_path` as `signer`; `input` and `output` build the campaign
+// data in the deployment, and `args` follow the output campaign data
+fn patch(
+    method_path: &str,
+    signer: u8,
+    input: impl Fn(&Deployment) -> CampaignData,
+    output: impl Fn(&Deployment) -> CampaignData,
+    args: Vec<&[u8]>,
+) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let (input, output) = (input(&deployment), output(&deployment));
+    let mut recipe_args = vec![output.as_slice()];
+    recipe_args.extend(args);
+
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, signer)
+        .witness(recipe_witness(method_path, recipe_args).pack())
+        .build();
+    deployment.verify(tx)
+}
+
+// Add quest `quest_id` to a campaign holding quest 1, as `signer`
+fn add_quest(signer: u8, quest_id: u32) -> Result<Cycle, Error> {
+    let added = quest(quest_id, &[]);
+    patch(
+        "CKBoostCampaign.add_quest",
+        signer,
+        |deployment| patched_campaign(deployment, 0, vec![quest(1, &[])]),
+        |deployment| {
+            patched_campaign(deployment, 0, vec![quest(1, &[]), quest(quest_id, &[])])
+                .as_builder()
+                .next_quest_id(Uint32::from((quest_id + 1).to_le_bytes()))
+                .build()
+        },
+        vec![added.as_slice()],
+    )
+}
+
+// Retitle quest 1 as `signer`, with the output quest also carrying `points`
+fn edit_quest_metadata(signer: u8, points: u128) -> Result<Cycle, Error> {
+    let metadata = quest_metadata("Renamed quest");
+    let edited = quest(1, &[])
+        .as_builder()
+        .metadata(metadata.clone())
+        .points(Uint128::from(points.to_le_bytes()))
+        .build();
+    patch(
+        "CKBoostCampaign.edit_quest_metadata",
+        signer,
+        |deployment| patched_campaign(deployment, 0, vec![quest(1, &[])]),
+        move |deployment| patched_campaign(deployment, 0, vec![edited.clone()]),
+        vec![&1u32.to_le_bytes()[..], metadata.as_slice()],
+    )
+}
+
+// Retitle the campaign as `signer`, with the output campaign also moving to `output_status`
+fn update_campaign_metadata(signer: u8, output_status: u8) -> Result<Cycle, Error> {
+    let metadata = campaign_metadata("Renamed campaign");
+    patch(
+        "CKBoostCampaign.update_campaign_metadata",
+        signer,
+        |deployment| patched_campaign(deployment, 0, vec![quest(1, &[])]),
+        |deployment| {
+            patched_campaign(deployment, output_status, vec![quest(1, &[])])
+                .as_builder()
+                .metadata(campaign_metadata("Renamed campaign"))
+                .build()
+        },
+        vec![metadata.as_slice()],
+    )
+}
+
+// Move the campaign from `input_status` to `status` as `signer`
+fn set_status(signer: u8, input_status: u8, status: u8) -> Result<Cycle, Error> {
+    patch(
+        "CKBoostCampaign.set_status",
+        signer,
+        |deployment| patched_campaign(deployment, input_status, vec![quest(1, &[])]),
+        |deployment| patched_campaign(deployment, status, vec![quest(1, &[])]),
+        vec![&[status][..]],
+    )
+}
+
+// Rewrite the whole campaign as `signer`, with quest 1 accepting `accepted` in the output
+fn update_campaign(signer: u8, accepted: &[u8]) -> Result<Cycle, Error> {
+    let completions = accepted.len() as u32;
+    patch(
+        "CKBoostCampaign.update_campaign",
+        signer,
+        |deployment| patched_campaign(deployment, 0, vec![quest(1, &[])]),
+        |deployment| {
+            patched_campaign(deployment, 0, vec![quest(1, accepted)])
+                .as_builder()
+                .metadata(campaign_metadata("Renamed campaign"))
+                .total_completions(Uint32::from(completions.to_le_bytes()))
+                .participants_count(Uint32::from(completions.to_le_bytes()))
+                .build()
+        },
+        vec![],
+    )
+}
+
+#[test]
+fn test_editor_adds_quest_under_the_next_id() {
+    add_quest(EDITOR, 2).expect("quest 2 is the next quest ID");
+}
+
+#[test]
+fn test_reviewer_cannot_add_quest() {
+    assert!(add_quest(REVIEWER, 2).is_err());
+}
+
+#[test]
+fn test_added_quest_cannot_reuse_an_allocated_id() {
+    assert!(add_quest(EDITOR, 1).is_err());
+}
+
+#[test]
+fn test_editor_retitles_quest() {
+    edit_quest_metadata(EDITOR, 0).expect("only the quest metadata changes");
+}
+
+#[test]
+fn test_quest_edit_cannot_change_rewards() {
+    assert!(edit_quest_metadata(EDITOR, 1_000).is_err());
+}
+
+#[test]
+fn test_reviewer_cannot_retitle_quest() {
+    assert!(edit_quest_metadata(REVIEWER, 0).is_err());
+}
+
+#[test]
+fn test_endorser_retitles_campaign() {
+    update_campaign_metadata(ENDORSER, 0).expect("only the campaign metadata changes");
+}
+
+#[test]
+fn test_metadata_update_cannot_change_status() {
+    assert!(update_campaign_metadata(ENDORSER, 1).is_err());
+}
+
+#[test]
+fn test_editor_moves_status_forward() {
+    set_status(EDITOR, 1, 2).expect("funding moves to reviewing");
+}
+
+#[test]
+fn test_status_cannot_move_backwards() {
+    assert!(set_status(EDITOR, 2, 1).is_err());
+}
+
+#[test]
+fn test_reviewer_cannot_set_status() {
+    assert!(set_status(REVIEWER, 1, 2).is_err());
+}
+
+#[test]
+fn test_editor_updates_campaign() {
+    update_campaign(EDITOR, &[]).expect("review state is kept");
+}
+
+#[test]
+fn test_campaign_update_cannot_approve_users() {
+    // Approvals need the reviewer role and a submission, which update_campaign never checks
+    assert!(update_campaign(EDITOR, &[5]).is_err());
+}
@@ -0,0 +1,98 @@
+// Tests for campaign completion and participant accounting
+
+use crate::support::{
+    campaign, managed_campaign, quest, recipe_witness, submitted_user, user_type_id, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{core::Cycle, prelude::Pack};
+use ckboost_shared::types::{Byte32Vec, CampaignData, QuestData, QuestDataVec, Uint32};
+use ckboost_shared::CampaignDataExt;
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const PARTICIPANT: u8 = 9;
+const USER: [u8; 32] = [5; 32];
+
+fn counted_campaign(
+    endorser_lock_hash: [u8; 32],
+    quests: Vec<QuestData>,
+    total_completions: u32,
+    participants: u32,
+) -> CampaignData {
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .quests(QuestDataVec::new_builder().set(quests).build())
+        .total_completions(Uint32::from(total_completions.to_le_bytes()))
+        .participants_count(Uint32::from(participants.to_le_bytes()))
+        .build()
+}
+
+// Approve user 5, already accepted for quest 1, for quest 2, with the output campaign claiming
+// `total_completions` and `participants`
+fn approve_second_quest(total_completions: u32, participants: u32) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let input = counted_campaign(endorser, vec![quest(1, &[5]), quest(2, &[])], 1, 1);
+    let output = counted_campaign(
+        endorser,
+        vec![quest(1, &[5]), quest(2, &[5])],
+        total_completions,
+        participants,
+    );
+    let user_ids = Byte32Vec::new_builder().push(user_type_id(USER[0])).build();
+
+    let user_dep = deployment.user_dep(USER, PARTICIPANT, &submitted_user(USER, CAMPAIGN, 2));
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, ENDORSER)
+        .cell_dep(user_dep)
+        .witness(
+            recipe_witness(
+                "CKBoostCampaign.approve_completion",
+                vec![output.as_slice(), &2u32.to_le_bytes(), user_ids.as_slice()],
+            )
+            .pack(),
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_stats_are_recounted_from_quests() {
+    let campaign = campaign(vec![quest(1, &[1, 2]), quest(2, &[2, 3, 4])]);
+    assert_eq!(campaign.quest_completions_sum(), 5);
+    // A user accepted for two quests is one participant
+    assert_eq!(campaign.distinct_participants(), 4);
+
+    let recounted = campaign.with_recounted_stats();
+    assert_eq!(recounted.total_completions_u32(), 5);
+    assert_eq!(recounted.participants_count_u32(), 4);
+}
+
+#[test]
+fn test_participants_grow_only_with_new_users() {
+    let campaign = campaign(vec![quest(1, &[1]), quest(2, &[])]).with_recounted_stats();
+
+    // Accepting a known participant for another quest adds a completion only
+    let repeat = campaign.with_quest(quest(2, &[1])).with_recounted_stats();
+    assert_eq!(repeat.total_completions_u32(), 2);
+    assert_eq!(repeat.participants_count_u32(), campaign.participants_count_u32());
+
+    let newcomer = campaign.with_quest(quest(2, &[5])).with_recounted_stats();
+    assert_eq!(newcomer.participants_count_u32(), campaign.participants_count_u32() + 1);
+}
+
+#[test]
+fn test_approval_counts_a_returning_participant_once() {
+    approve_second_quest(2, 1).expect("one more completion by a known participant");
+}
+
+#[test]
+fn test_approval_cannot_double_count_participants() {
+    assert!(approve_second_quest(2, 2).is_err());
+}
+
+#[test]
+fn test_approval_must_count_the_completion() {
+    assert!(approve_second_quest(1, 1).is_err());
+}
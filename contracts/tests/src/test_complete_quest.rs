@@ -0,0 +1,86 @@
+// Tests for completing a quest for a single participant in one transaction
+
+use crate::support::{
+    cell, managed_campaign, quest, recipe_witness, submitted_user, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{bytes::Bytes, core::Cycle, prelude::Pack};
+use ckboost_shared::types::{CampaignData, QuestDataVec, Uint128, Uint32, Uint64};
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const OUTSIDER: u8 = 3;
+const PARTICIPANT: u8 = 9;
+const USER: [u8; 32] = [5; 32];
+const POINTS: u128 = 100;
+
+// Campaign endorsed by `endorser_lock_hash` whose quest 1 awards POINTS and has accepted `accepted`
+fn completable_campaign(endorser_lock_hash: [u8; 32], accepted: &[u8]) -> CampaignData {
+    let quest = quest(1, accepted)
+        .as_builder()
+        .points(Uint128::from(POINTS.to_le_bytes()))
+        .build();
+    let participants = accepted.len() as u32;
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .quests(QuestDataVec::new_builder().push(quest).build())
+        .total_completions(Uint32::from(participants.to_le_bytes()))
+        .participants_count(Uint32::from(participants.to_le_bytes()))
+        .build()
+}
+
+// Complete quest 1 for USER, signed by `signer`, after `adjust` is applied to both campaign cells
+fn complete_as(signer: u8, adjust: impl Fn(CampaignData) -> CampaignData) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let input = adjust(completable_campaign(endorser, &[]));
+    let output = adjust(completable_campaign(endorser, &[5]));
+
+    let user_type = deployment.user_type(USER);
+    let owner_lock = deployment.lock(PARTICIPANT);
+    let user_data = submitted_user(USER, CAMPAIGN, 1);
+    let credited = user_data
+        .clone()
+        .as_builder()
+        .total_points_earned(Uint128::from(POINTS.to_le_bytes()))
+        .build();
+    let user_input = deployment.spend(&owner_lock, Some(&user_type), user_data.as_slice());
+
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, signer)
+        .input(user_input)
+        .output(cell(&owner_lock, Some(&user_type)))
+        .output_data(Bytes::from(credited.as_slice().to_vec()).pack())
+        .witness(
+            recipe_witness(
+                "CKBoostCampaign.complete_quest",
+                vec![&1u32.to_le_bytes(), &USER],
+            )
+            .pack(),
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_reviewer_completes_quest() {
+    complete_as(ENDORSER, |campaign| campaign).expect("completion signed by the endorser");
+}
+
+#[test]
+fn test_outsider_cannot_complete_quest() {
+    assert!(complete_as(OUTSIDER, |campaign| campaign).is_err());
+}
+
+#[test]
+fn test_disputable_campaign_cannot_complete_quest() {
+    // Completions would skip the pending approval and pay out inside the dispute window
+    let result = complete_as(ENDORSER, |campaign| {
+        campaign
+            .as_builder()
+            .approval_dispute_window(Uint64::from(3600u64.to_le_bytes()))
+            .build()
+    });
+    assert!(result.is_err());
+}
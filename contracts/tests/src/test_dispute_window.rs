@@ -0,0 +1,167 @@
+// Tests for approvals held back while their dispute window is open
+
+use crate::support::{
+    managed_campaign, quest, recipe_witness, submitted_user, user_type_id, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{core::Cycle, prelude::Pack};
+use ckboost_shared::types::{
+    ApprovalRecord, ApprovalRecordVec, Byte32Vec, CampaignData, QuestData, QuestDataVec, Uint32,
+    Uint64, UserData,
+};
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const WINDOW_SECONDS: u64 = 3600;
+const APPROVED_AT: u64 = 1_000;
+// Inside the window of an approval made at APPROVED_AT
+const NOW: u64 = 2_000_000;
+// Past the window of an approval made at APPROVED_AT
+const LATER: u64 = 5_000_000;
+
+fn pending(marker: u8, approved_at: u64) -> ApprovalRecord {
+    ApprovalRecord::new_builder()
+        .user_type_id(user_type_id(marker))
+        .approved_at(Uint64::from(approved_at.to_le_bytes()))
+        .build()
+}
+
+fn pending_quest(accepted: &[u8], pending_approvals: Vec<ApprovalRecord>) -> QuestData {
+    quest(1, accepted)
+        .as_builder()
+        .pending_approvals(ApprovalRecordVec::new_builder().set(pending_approvals).build())
+        .build()
+}
+
+// Campaign with a dispute window holding `quest`, whose clock reads `clock`
+fn disputable_campaign(endorser_lock_hash: [u8; 32], quest: QuestData, clock: u64) -> CampaignData {
+    let participants = quest.accepted_submission_user_type_ids().len() as u32;
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .quests(QuestDataVec::new_builder().push(quest).build())
+        .approval_dispute_window(Uint64::from(WINDOW_SECONDS.to_le_bytes()))
+        .total_completions(Uint32::from(participants.to_le_bytes()))
+        .participants_count(Uint32::from(participants.to_le_bytes()))
+        .last_recorded_at(Uint64::from(clock.to_le_bytes()))
+        .build()
+}
+
+// Review users of quest 1 with a header dep at `header`, providing the user cells of `provided`
+fn review(
+    method_path: &str,
+    input: impl Fn([u8; 32]) -> CampaignData,
+    output: impl Fn([u8; 32]) -> CampaignData,
+    reviewed: &[u8],
+    provided: &[u8],
+    header: u64,
+) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let (input, output) = (input(endorser), output(endorser));
+    let user_ids = Byte32Vec::new_builder()
+        .extend(reviewed.iter().map(|marker| user_type_id(*marker)))
+        .build();
+
+    let mut tx = deployment.campaign_update(CAMPAIGN, &input, &output, ENDORSER);
+    for marker in provided {
+        let user_data = if reviewed.contains(marker) {
+            submitted_user([*marker; 32], CAMPAIGN, 1)
+        } else {
+            UserData::default()
+        };
+        tx = tx.cell_dep(deployment.user_dep([*marker; 32], *marker, &user_data));
+    }
+    let tx = tx
+        .header_dep(deployment.header(header))
+        .witness(
+            recipe_witness(
+                method_path,
+                vec![output.as_slice(), &1u32.to_le_bytes(), user_ids.as_slice()],
+            )
+            .pack(),
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+// Approve user 6 while user 5's approval is still pending
+fn approve_next(provided: &[u8], clock: u64) -> Result<Cycle, Error> {
+    review(
+        "CKBoostCampaign.approve_completion",
+        |endorser| disputable_campaign(endorser, pending_quest(&[5], vec![pending(5, APPROVED_AT)]), clock),
+        |endorser| {
+            disputable_campaign(
+                endorser,
+                pending_quest(&[5, 6], vec![pending(5, APPROVED_AT), pending(6, NOW)]),
+                NOW,
+            )
+        },
+        &[6],
+        provided,
+        NOW,
+    )
+}
+
+// Revoke user 5's pending approval from a campaign whose clock reads `clock`
+fn revoke(clock: u64, header: u64) -> Result<Cycle, Error> {
+    review(
+        "CKBoostCampaign.revoke_approval",
+        |endorser| disputable_campaign(endorser, pending_quest(&[5], vec![pending(5, APPROVED_AT)]), clock),
+        |endorser| disputable_campaign(endorser, pending_quest(&[], vec![]), header),
+        &[5],
+        &[],
+        header,
+    )
+}
+
+// Finalize user 5's approval, recording `recorded_at` as the new campaign clock
+fn finalize(recorded_at: u64) -> Result<Cycle, Error> {
+    review(
+        "CKBoostCampaign.finalize_approvals",
+        |endorser| disputable_campaign(endorser, pending_quest(&[5], vec![pending(5, APPROVED_AT)]), APPROVED_AT),
+        |endorser| disputable_campaign(endorser, pending_quest(&[5], vec![]), recorded_at),
+        &[5],
+        &[],
+        LATER,
+    )
+}
+
+#[test]
+fn test_approval_with_every_pending_user_cell_passes() {
+    approve_next(&[5, 6], APPROVED_AT).expect("approval with the pending user's cell");
+}
+
+#[test]
+fn test_missing_pending_user_cell_is_refused() {
+    // Without user 5's cell the campaign lock cannot tell whether they are paid out
+    assert!(approve_next(&[6], APPROVED_AT).is_err());
+}
+
+#[test]
+fn test_approval_cannot_be_backdated_before_the_campaign_clock() {
+    // The header dep at NOW is older than the time the campaign has already recorded
+    assert!(approve_next(&[5, 6], LATER).is_err());
+}
+
+#[test]
+fn test_revocation_inside_the_window_passes() {
+    revoke(APPROVED_AT, NOW).expect("revocation inside the dispute window");
+}
+
+#[test]
+fn test_revocation_after_the_window_is_refused() {
+    assert!(revoke(APPROVED_AT, LATER).is_err());
+}
+
+#[test]
+fn test_revocation_cannot_cite_a_header_older_than_the_campaign_clock() {
+    // The campaign clock already passed the window, so an old header cannot reopen it
+    assert!(revoke(LATER, NOW).is_err());
+}
+
+#[test]
+fn test_finalization_moves_the_campaign_clock() {
+    finalize(LATER).expect("finalization recording the header dep timestamp");
+    assert!(finalize(APPROVED_AT).is_err());
+}
@@ -0,0 +1,94 @@
+// Tests for campaign creation and updates signed by the campaign's endorser
+
+use crate::support::{cell, managed_campaign, quest, recipe_witness, Deployment};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{bytes::Bytes, core::Cycle, prelude::Pack};
+use ckboost_shared::type_id::calculate_type_id;
+use ckboost_shared::types::{CampaignData, CampaignMetadata, QuestDataVec, Uint32};
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const OUTSIDER: u8 = 3;
+
+// Campaign ready to be listed: described, with quest 1 allocated
+fn described_campaign(endorser_lock_hash: [u8; 32], title: &str) -> CampaignData {
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .metadata(
+            CampaignMetadata::new_builder()
+                .title(title.as_bytes().to_vec())
+                .short_description(b"Short description".to_vec())
+                .long_description(b"Long description".to_vec())
+                .build(),
+        )
+        .quests(QuestDataVec::new_builder().push(quest(1, &[])).build())
+        .next_quest_id(Uint32::from(2u32.to_le_bytes()))
+        .build()
+}
+
+// Retitle the campaign, signed by `signer`
+fn update_as(signer: u8) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let input = described_campaign(endorser, "Campaign");
+    let output = described_campaign(endorser, "Renamed campaign");
+
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, signer)
+        .witness(recipe_witness("CKBoostCampaign.update_campaign", vec![output.as_slice()]).pack())
+        .build();
+    deployment.verify(tx)
+}
+
+// Create a campaign endorsed by ENDORSER from two cells of the `signer` lock, with ENDORSER
+// on the protocol whitelist if `whitelisted`
+fn create_as(signer: u8, whitelisted: bool) -> Result<Cycle, Error> {
+    // Signer locks hash the same in every deployment
+    let endorser = Deployment::new(&[]).lock_hash(ENDORSER);
+    let whitelist: &[[u8; 32]] = if whitelisted { &[endorser] } else { &[] };
+    let mut deployment = Deployment::new(whitelist);
+    let signer_lock = deployment.lock(signer);
+    let first_input = deployment.spend(&signer_lock, None, &[]);
+    let second_input = deployment.spend(&signer_lock, None, &[]);
+
+    let campaign_type = deployment.campaign_type(calculate_type_id(first_input.as_slice(), 0));
+    let campaign_lock = deployment.campaign_lock(&campaign_type);
+    let campaign = described_campaign(endorser, "Campaign");
+    let tx = deployment
+        .transaction()
+        .input(first_input)
+        .input(second_input)
+        .output(cell(&campaign_lock, Some(&campaign_type)))
+        .output_data(Bytes::from(campaign.as_slice().to_vec()).pack())
+        .output(cell(&signer_lock, None))
+        .output_data(Bytes::new().pack())
+        .witness(recipe_witness("CKBoostCampaign.update_campaign", vec![campaign.as_slice()]).pack())
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_endorser_updates_campaign() {
+    update_as(ENDORSER).expect("update signed by the endorser");
+}
+
+#[test]
+fn test_outsider_cannot_update_campaign() {
+    assert!(update_as(OUTSIDER).is_err());
+}
+
+#[test]
+fn test_whitelisted_endorser_creates_campaign() {
+    create_as(ENDORSER, true).expect("creation signed by a whitelisted endorser");
+}
+
+#[test]
+fn test_endorser_must_be_whitelisted_to_create() {
+    assert!(create_as(ENDORSER, false).is_err());
+}
+
+#[test]
+fn test_creation_must_be_signed_by_the_endorser() {
+    assert!(create_as(OUTSIDER, true).is_err());
+}
@@ -0,0 +1,169 @@
+// Tests for points UDT minting
+// These exercise the points owed by campaign and quest transitions, which bound a mint, and mints
+// alongside an approval
+
+use ckboost_shared::rewards::{merge_points, points_owed, rewarded_in_quest};
+use crate::support::{
+    campaign, cell, managed_campaign, recipe_witness, submitted_user, user_type_id, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{bytes::Bytes, core::Cycle, prelude::Pack};
+use ckboost_shared::types::{
+    ApprovalRecord, ApprovalRecordVec, Byte32Vec, CampaignData, QuestData, QuestDataVec, Uint128,
+    Uint32, Uint64,
+};
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const OUTSIDER: u8 = 3;
+const PARTICIPANT: u8 = 9;
+
+fn quest(quest_id: u32, points: u128, accepted: &[u8]) -> QuestData {
+    crate::support::quest(quest_id, accepted)
+        .as_builder()
+        .points(Uint128::from(points.to_le_bytes()))
+        .build()
+}
+
+fn owed_to(owed: &[(Vec<u8>, u128)], marker: u8) -> Option<u128> {
+    owed.iter()
+        .find(|(user, _)| user.as_slice() == user_type_id(marker).as_slice())
+        .map(|(_, points)| *points)
+}
+
+#[test]
+fn test_points_owed_to_newly_approved_users() {
+    let input = campaign(vec![quest(1, 50, &[7])]);
+    let output = campaign(vec![quest(1, 50, &[7, 8, 9])]);
+
+    // Users already approved in the input are not owed points again
+    let owed = points_owed(&input, &output);
+    assert_eq!(owed.len(), 2);
+    assert_eq!(owed_to(&owed, 7), None);
+    assert_eq!(owed_to(&owed, 8), Some(50));
+    assert_eq!(owed_to(&owed, 9), Some(50));
+
+    // Without new approvals nothing may be minted
+    assert!(points_owed(&output, &output).is_empty());
+}
+
+#[test]
+fn test_points_priced_before_the_approval() {
+    // Raising the points in the approving transition does not raise what is owed
+    let input = campaign(vec![quest(1, 50, &[])]);
+    let output = campaign(vec![quest(1, 500, &[7])]);
+    assert_eq!(owed_to(&points_owed(&input, &output), 7), Some(50));
+
+    // Quests created in the transition owe nothing
+    assert_eq!(owed_to(&points_owed(&campaign(vec![]), &output), 7), Some(0));
+}
+
+#[test]
+fn test_points_summed_across_quests() {
+    let input = campaign(vec![quest(1, 50, &[]), quest(2, 30, &[])]);
+    let output = campaign(vec![quest(1, 50, &[7]), quest(2, 30, &[7, 8])]);
+
+    let owed = points_owed(&input, &output);
+    assert_eq!(owed_to(&owed, 7), Some(80));
+    assert_eq!(owed_to(&owed, 8), Some(30));
+    assert_eq!(owed.iter().map(|(_, points)| points).sum::<u128>(), 110);
+}
+
+#[test]
+fn test_pending_approvals_earn_no_points() {
+    let pending = ApprovalRecord::new_builder()
+        .user_type_id(user_type_id(7))
+        .approved_at(Uint64::from(1_000u64.to_le_bytes()))
+        .build();
+    let input = campaign(vec![quest(1, 50, &[])]);
+    let approved = campaign(vec![quest(1, 50, &[7])
+        .as_builder()
+        .pending_approvals(ApprovalRecordVec::new_builder().push(pending).build())
+        .build()]);
+    assert!(points_owed(&input, &approved).is_empty());
+
+    // Points are minted once the approval is finalized
+    let finalized = campaign(vec![quest(1, 50, &[7])]);
+    assert_eq!(owed_to(&points_owed(&approved, &finalized), 7), Some(50));
+}
+
+#[test]
+fn test_quest_cell_points_merge_per_user() {
+    let mut owed = points_owed(&campaign(vec![quest(1, 50, &[])]), &campaign(vec![quest(1, 50, &[7])]));
+
+    // A quest cell approving the same user adds its own points
+    let quest_cell = quest(2, 20, &[7, 8]);
+    merge_points(
+        &mut owed,
+        rewarded_in_quest(None, &quest_cell)
+            .into_iter()
+            .map(|(user, _)| (user, 20))
+            .collect(),
+    );
+    assert_eq!(owed.len(), 2);
+    assert_eq!(owed_to(&owed, 7), Some(70));
+    assert_eq!(owed_to(&owed, 8), Some(20));
+}
+
+// Campaign whose quest 1 is worth `points` and accepts the users with these markers
+fn rewarding_campaign(endorser_lock_hash: [u8; 32], points: u128, accepted: &[u8]) -> CampaignData {
+    let completions = accepted.len() as u32;
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .quests(QuestDataVec::new_builder().push(quest(1, points, accepted)).build())
+        .total_completions(Uint32::from(completions.to_le_bytes()))
+        .participants_count(Uint32::from(completions.to_le_bytes()))
+        .build()
+}
+
+// Approve user 5, owned by PARTICIPANT, for quest 1 worth 50 points, rewriting the quest to be
+// worth `output_points`, and mint `amount` points to the `recipient` lock
+fn approve_and_mint(output_points: u128, amount: u128, recipient: u8) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let input = rewarding_campaign(endorser, 50, &[]);
+    let output = rewarding_campaign(endorser, output_points, &[5]);
+    let user_ids = Byte32Vec::new_builder().push(user_type_id(5)).build();
+
+    let user_dep = deployment.user_dep([5; 32], PARTICIPANT, &submitted_user([5; 32], CAMPAIGN, 1));
+    let recipient_lock = deployment.lock(recipient);
+    let points_type = deployment.points_type();
+    // The recipient's cell pays for the points cell
+    let funding_input = deployment.spend(&recipient_lock, None, &[]);
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, ENDORSER)
+        .cell_dep(user_dep)
+        .input(funding_input)
+        .output(cell(&recipient_lock, Some(&points_type)))
+        .output_data(Bytes::from(amount.to_le_bytes().to_vec()).pack())
+        .witness(
+            recipe_witness(
+                "CKBoostCampaign.approve_completion",
+                vec![output.as_slice(), &1u32.to_le_bytes(), user_ids.as_slice()],
+            )
+            .pack(),
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_approval_mints_the_quest_points() {
+    approve_and_mint(50, 50, PARTICIPANT).expect("mint matches the points owed");
+}
+
+#[test]
+fn test_mint_must_equal_the_points_owed() {
+    assert!(approve_and_mint(50, 80, PARTICIPANT).is_err());
+}
+
+#[test]
+fn test_points_go_to_the_user_lock() {
+    assert!(approve_and_mint(50, 50, OUTSIDER).is_err());
+}
+
+#[test]
+fn test_approval_cannot_raise_the_points_it_mints() {
+    assert!(approve_and_mint(500, 500, PARTICIPANT).is_err());
+}
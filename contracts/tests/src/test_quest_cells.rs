@@ -0,0 +1,68 @@
+// Tests for quest cells allocating their IDs from the campaign
+
+use crate::support::{cell, managed_campaign, quest, recipe_witness, Deployment};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{bytes::Bytes, core::Cycle, prelude::Pack};
+use ckboost_shared::campaign_data::QUEST_LAYOUT_CELLS;
+use ckboost_shared::type_id::calculate_type_id;
+use ckboost_shared::types::{CampaignData, Uint32};
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const OUTSIDER: u8 = 3;
+const NEXT_QUEST_ID: u32 = 3;
+
+// Campaign keeping its quests in quest cells, about to hand out `next_quest_id`
+fn quest_cell_campaign(endorser_lock_hash: [u8; 32], next_quest_id: u32) -> CampaignData {
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .quest_layout(QUEST_LAYOUT_CELLS)
+        .next_quest_id(Uint32::from(next_quest_id.to_le_bytes()))
+        .build()
+}
+
+// Create quest `quest_id` as a quest cell while the campaign moves to `output_next_quest_id`
+fn create_quest(signer: u8, quest_id: u32, output_next_quest_id: u32) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let input = quest_cell_campaign(endorser, NEXT_QUEST_ID);
+    let output = quest_cell_campaign(endorser, output_next_quest_id);
+    let quest_data = quest(quest_id, &[]);
+
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, signer)
+        .witness(recipe_witness("CKBoostQuest.update_quest", vec![quest_data.as_slice()]).pack())
+        .build();
+    // The quest cell follows the campaign and signer outputs
+    let quest_type_id = calculate_type_id(tx.inputs().get(0).expect("campaign input").as_slice(), 2);
+    let campaign_type = deployment.campaign_type(CAMPAIGN);
+    let quest_type = deployment.quest_type(quest_type_id, &campaign_type);
+    let campaign_lock = deployment.campaign_lock(&campaign_type);
+    let tx = tx
+        .as_advanced_builder()
+        .output(cell(&campaign_lock, Some(&quest_type)))
+        .output_data(Bytes::from(quest_data.as_slice().to_vec()).pack())
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_quest_cell_takes_the_next_quest_id() {
+    create_quest(ENDORSER, NEXT_QUEST_ID, NEXT_QUEST_ID + 1).expect("quest cell under the allocated ID");
+}
+
+#[test]
+fn test_quest_cell_cannot_reuse_an_allocated_id() {
+    assert!(create_quest(ENDORSER, NEXT_QUEST_ID - 1, NEXT_QUEST_ID + 1).is_err());
+}
+
+#[test]
+fn test_quest_cell_requires_the_campaign_to_allocate_its_id() {
+    assert!(create_quest(ENDORSER, NEXT_QUEST_ID, NEXT_QUEST_ID).is_err());
+}
+
+#[test]
+fn test_outsider_cannot_create_quest_cells() {
+    assert!(create_quest(OUTSIDER, NEXT_QUEST_ID, NEXT_QUEST_ID + 1).is_err());
+}
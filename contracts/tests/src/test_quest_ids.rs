@@ -0,0 +1,95 @@
+// Tests for quest and sub-task ID allocation
+
+use crate::support::{campaign, managed_campaign, quest, recipe_witness, Deployment};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{core::Cycle, prelude::Pack};
+use ckboost_shared::types::{
+    CampaignData, CampaignMetadata, QuestData, QuestDataVec, QuestSubTaskData, QuestSubTaskDataVec,
+    Uint32, Uint8,
+};
+use ckboost_shared::{CampaignDataExt, QuestDataExt};
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+
+fn allocated(quest_ids: &[u32], next_quest_id: u32) -> CampaignData {
+    campaign(quest_ids.iter().map(|quest_id| quest(*quest_id, &[])).collect())
+        .as_builder()
+        .next_quest_id(Uint32::from(next_quest_id.to_le_bytes()))
+        .build()
+}
+
+#[test]
+fn test_quest_ids_are_allocated_in_order() {
+    assert!(allocated(&[1, 2, 5], 6).has_allocated_quest_ids());
+    // Gaps left by removed quests are fine, as long as the IDs stay below next_quest_id
+    assert!(allocated(&[2, 5], 6).has_allocated_quest_ids());
+
+    assert!(!allocated(&[1, 1], 2).has_allocated_quest_ids());
+    assert!(!allocated(&[2, 1], 3).has_allocated_quest_ids());
+    assert!(!allocated(&[1, 2], 2).has_allocated_quest_ids());
+}
+
+fn with_sub_tasks(ids: &[u8]) -> QuestData {
+    quest(1, &[])
+        .as_builder()
+        .sub_tasks(
+            QuestSubTaskDataVec::new_builder()
+                .extend(ids.iter().map(|id| QuestSubTaskData::new_builder().id(Uint8::from([*id])).build()))
+                .build(),
+        )
+        .build()
+}
+
+// Retitle a campaign holding `quests` below `next_quest_id` as its endorser
+fn retitle(quests: Vec<QuestData>, next_quest_id: u32) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let titled = |title: &str| {
+        managed_campaign(deployment.lock_hash(ENDORSER), vec![])
+            .as_builder()
+            .metadata(
+                CampaignMetadata::new_builder()
+                    .title(title.as_bytes().to_vec())
+                    .short_description(b"Short description".to_vec())
+                    .long_description(b"Long description".to_vec())
+                    .build(),
+            )
+            .quests(QuestDataVec::new_builder().set(quests.clone()).build())
+            .next_quest_id(Uint32::from(next_quest_id.to_le_bytes()))
+            .build()
+    };
+    let (input, output) = (titled("Campaign"), titled("Renamed campaign"));
+
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, ENDORSER)
+        .witness(recipe_witness("CKBoostCampaign.update_campaign", vec![output.as_slice()]).pack())
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_sub_task_ids_are_unique() {
+    assert!(with_sub_tasks(&[1, 2, 3]).has_unique_sub_task_ids());
+    assert!(!with_sub_tasks(&[1, 2, 1]).has_unique_sub_task_ids());
+}
+
+#[test]
+fn test_update_keeps_allocated_ids() {
+    retitle(vec![quest(1, &[]), quest(2, &[])], 3).expect("quest IDs ascend below next_quest_id");
+}
+
+#[test]
+fn test_update_rejects_duplicate_quest_ids() {
+    assert!(retitle(vec![quest(1, &[]), quest(1, &[])], 2).is_err());
+}
+
+#[test]
+fn test_update_rejects_unallocated_quest_ids() {
+    assert!(retitle(vec![quest(1, &[]), quest(2, &[])], 2).is_err());
+}
+
+#[test]
+fn test_update_rejects_duplicate_sub_task_ids() {
+    assert!(retitle(vec![with_sub_tasks(&[1, 2, 1])], 2).is_err());
+}
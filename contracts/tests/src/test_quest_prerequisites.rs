@@ -0,0 +1,108 @@
+// Tests for quests that require approval of earlier quests first
+
+use ckboost_shared::campaign_data::{CAMPAIGN_STATUS_ACTIVE, QUEST_STATUS_ACTIVE};
+use crate::support::{
+    committed_record, managed_campaign, quest, recipe_witness, submitted_user, user_type_id,
+    with_record, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{core::Cycle, prelude::Pack};
+use ckboost_shared::types::{Byte32Vec, CampaignData, QuestData, QuestDataVec, Uint32, Uint32Vec};
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const PARTICIPANT: u8 = 9;
+const USER: [u8; 32] = [5; 32];
+
+// Quest 2, which requires approval of quest 1
+fn follow_up_quest(accepted: &[u8]) -> QuestData {
+    quest(2, accepted)
+        .as_builder()
+        .status(QUEST_STATUS_ACTIVE)
+        .prerequisite_quest_ids(
+            Uint32Vec::new_builder()
+                .push(Uint32::from(1u32.to_le_bytes()))
+                .build(),
+        )
+        .build()
+}
+
+// Active campaign with quest 1 accepting `first_accepted` and quest 2 accepting `second_accepted`
+fn ordered_campaign(endorser_lock_hash: [u8; 32], first_accepted: &[u8], second_accepted: &[u8]) -> CampaignData {
+    let first = quest(1, first_accepted).as_builder().status(QUEST_STATUS_ACTIVE).build();
+    let total_completions = (first_accepted.len() + second_accepted.len()) as u32;
+    let participants = if total_completions == 0 { 0u32 } else { 1u32 };
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .status(CAMPAIGN_STATUS_ACTIVE)
+        .quests(
+            QuestDataVec::new_builder()
+                .push(first)
+                .push(follow_up_quest(second_accepted))
+                .build(),
+        )
+        .total_completions(Uint32::from(total_completions.to_le_bytes()))
+        .participants_count(Uint32::from(participants.to_le_bytes()))
+        .build()
+}
+
+// Submit quest 2 for user 5, who submitted quest 1 and was approved for it if `approved_first`
+fn submit_follow_up(approved_first: bool) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let first_accepted: &[u8] = if approved_first { &[5] } else { &[] };
+    let campaign = ordered_campaign(deployment.lock_hash(ENDORSER), first_accepted, &[]);
+    let input = submitted_user(USER, CAMPAIGN, 1);
+    let output = with_record(&input, committed_record(USER, CAMPAIGN, 2));
+
+    let campaign_dep = deployment.campaign_dep(CAMPAIGN, &campaign);
+    let tx = deployment
+        .submission(USER, PARTICIPANT, &input, &output)
+        .cell_dep(campaign_dep)
+        .build();
+    deployment.verify(tx)
+}
+
+// Approve user 5 for quest 2, who was approved for quest 1 if `approved_first`
+fn approve_follow_up(approved_first: bool) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let first_accepted: &[u8] = if approved_first { &[5] } else { &[] };
+    let input = ordered_campaign(endorser, first_accepted, &[]);
+    let output = ordered_campaign(endorser, first_accepted, &[5]);
+    let user_ids = Byte32Vec::new_builder().push(user_type_id(USER[0])).build();
+
+    let user_dep = deployment.user_dep(USER, PARTICIPANT, &submitted_user(USER, CAMPAIGN, 2));
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, ENDORSER)
+        .cell_dep(user_dep)
+        .witness(
+            recipe_witness(
+                "CKBoostCampaign.approve_completion",
+                vec![output.as_slice(), &2u32.to_le_bytes(), user_ids.as_slice()],
+            )
+            .pack(),
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_follow_up_quest_is_submitted_after_its_prerequisite() {
+    submit_follow_up(true).expect("prerequisite quest was approved");
+}
+
+#[test]
+fn test_follow_up_quest_cannot_be_submitted_first() {
+    assert!(submit_follow_up(false).is_err());
+}
+
+#[test]
+fn test_follow_up_quest_is_approved_after_its_prerequisite() {
+    approve_follow_up(true).expect("prerequisite quest was approved");
+}
+
+#[test]
+fn test_follow_up_quest_cannot_be_approved_first() {
+    assert!(approve_follow_up(false).is_err());
+}
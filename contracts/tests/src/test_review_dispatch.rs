@@ -0,0 +1,173 @@
+// Tests for user cells spent as submission proof in campaign reviews, dispatched to the user
+// type's review recipes
+
+use crate::support::{
+    cell, managed_campaign, quest, recipe_witness, submitted_user, user_type_id, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{bytes::Bytes, core::Cycle, prelude::Pack};
+use ckboost_shared::types::{
+    Byte32Vec, CampaignData, QuestData, QuestDataVec, QuestSubTaskData, QuestSubTaskDataVec,
+    SubTaskSubmission, SubTaskSubmissionVec, SubmissionRejection, SubmissionRejectionVec, Uint128,
+    Uint32, Uint8, UserData, UserSubmissionRecordVec,
+};
+use ckboost_shared::QuestDataExt;
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const PARTICIPANT: u8 = 9;
+const USER: [u8; 32] = [5; 32];
+const REASON_CODE: u32 = 7;
+const SUB_TASK: u8 = 1;
+
+fn reviewed_campaign(endorser_lock_hash: [u8; 32], quest: QuestData) -> CampaignData {
+    let participants = quest.accepted_submission_user_type_ids().len() as u32;
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .quests(QuestDataVec::new_builder().push(quest).build())
+        .total_completions(Uint32::from(participants.to_le_bytes()))
+        .participants_count(Uint32::from(participants.to_le_bytes()))
+        .build()
+}
+
+fn sub_task_quest() -> QuestData {
+    quest(1, &[])
+        .as_builder()
+        .sub_tasks(
+            QuestSubTaskDataVec::new_builder()
+                .push(QuestSubTaskData::new_builder().id(Uint8::from([SUB_TASK])).build())
+                .build(),
+        )
+        .build()
+}
+
+// User 5's submission for quest 1, with a proof for the sub-task
+fn reviewed_user() -> UserData {
+    let submitted = submitted_user(USER, CAMPAIGN, 1);
+    let record = submitted
+        .submission_records()
+        .get(0)
+        .expect("submitted record")
+        .as_builder()
+        .sub_task_submissions(
+            SubTaskSubmissionVec::new_builder()
+                .push(
+                    SubTaskSubmission::new_builder()
+                        .sub_task_id(Uint8::from([SUB_TASK]))
+                        .submission_content(b"https://example.com/sub-task".to_vec())
+                        .build(),
+                )
+                .build(),
+        )
+        .build();
+    submitted
+        .as_builder()
+        .submission_records(UserSubmissionRecordVec::new_builder().push(record).build())
+        .build()
+}
+
+fn tampered(user_data: UserData) -> UserData {
+    user_data
+        .as_builder()
+        .total_points_earned(Uint128::from(100u128.to_le_bytes()))
+        .build()
+}
+
+// Review user 5 for quest 1, spending the user cell as proof and recreating it as `recreated`
+fn review(
+    method_path: &str,
+    input_quest: QuestData,
+    output_quest: QuestData,
+    extra_args: Vec<&[u8]>,
+    recreated: fn(UserData) -> UserData,
+) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let input = reviewed_campaign(endorser, input_quest);
+    let output = reviewed_campaign(endorser, output_quest);
+    let user_ids = Byte32Vec::new_builder().push(user_type_id(USER[0])).build();
+
+    let user_type = deployment.user_type(USER);
+    let owner_lock = deployment.lock(PARTICIPANT);
+    let user_input = deployment.spend(&owner_lock, Some(&user_type), reviewed_user().as_slice());
+    let mut args = vec![output.as_slice(), &1u32.to_le_bytes()[..], user_ids.as_slice()];
+    args.extend(extra_args);
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, ENDORSER)
+        .input(user_input)
+        .output(cell(&owner_lock, Some(&user_type)))
+        .output_data(Bytes::from(recreated(reviewed_user()).as_slice().to_vec()).pack())
+        .witness(recipe_witness(method_path, args).pack())
+        .build();
+    deployment.verify(tx)
+}
+
+fn approve(recreated: fn(UserData) -> UserData) -> Result<Cycle, Error> {
+    review(
+        "CKBoostCampaign.approve_completion",
+        quest(1, &[]),
+        quest(1, &[USER[0]]),
+        vec![],
+        recreated,
+    )
+}
+
+fn reject(recreated: fn(UserData) -> UserData) -> Result<Cycle, Error> {
+    let rejection = SubmissionRejection::new_builder()
+        .user_type_id(user_type_id(USER[0]))
+        .reason_code(Uint32::from(REASON_CODE.to_le_bytes()))
+        .rejection_count(Uint32::from(1u32.to_le_bytes()))
+        .build();
+    let rejected = quest(1, &[])
+        .as_builder()
+        .rejected_submissions(SubmissionRejectionVec::new_builder().push(rejection).build())
+        .build();
+    review(
+        "CKBoostCampaign.reject_submission",
+        quest(1, &[]),
+        rejected,
+        vec![&REASON_CODE.to_le_bytes()[..]],
+        recreated,
+    )
+}
+
+fn approve_sub_task(recreated: fn(UserData) -> UserData) -> Result<Cycle, Error> {
+    review(
+        "CKBoostCampaign.approve_sub_tasks",
+        sub_task_quest(),
+        sub_task_quest().with_sub_tasks_approved(&USER, &[SUB_TASK]).unwrap(),
+        vec![&[SUB_TASK][..]],
+        recreated,
+    )
+}
+
+#[test]
+fn test_approval_recreates_spent_user_cell() {
+    approve(|user_data| user_data).expect("approval with the user cell spent as proof");
+}
+
+#[test]
+fn test_approval_cannot_rewrite_user_cell() {
+    assert!(approve(tampered).is_err());
+}
+
+#[test]
+fn test_rejection_recreates_spent_user_cell() {
+    reject(|user_data| user_data).expect("rejection with the user cell spent as proof");
+}
+
+#[test]
+fn test_rejection_cannot_rewrite_user_cell() {
+    assert!(reject(tampered).is_err());
+}
+
+#[test]
+fn test_sub_task_approval_recreates_spent_user_cell() {
+    approve_sub_task(|user_data| user_data).expect("sub-task approval with the user cell spent as proof");
+}
+
+#[test]
+fn test_sub_task_approval_cannot_rewrite_user_cell() {
+    assert!(approve_sub_task(tampered).is_err());
+}
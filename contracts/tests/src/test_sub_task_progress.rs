@@ -0,0 +1,165 @@
+// Tests for quests reviewed one sub-task at a time
+
+use ckboost_shared::campaign_data::{CAMPAIGN_STATUS_ACTIVE, QUEST_STATUS_ACTIVE};
+use crate::support::{
+    managed_campaign, quest, recipe_witness, submitted_user, user_type_id, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{core::Cycle, prelude::Pack};
+use ckboost_shared::types::{
+    Byte32Vec, CampaignData, QuestData, QuestDataVec, QuestSubTaskData, QuestSubTaskDataVec,
+    SubTaskSubmission, SubTaskSubmissionVec, Uint32, Uint8, UserData, UserSubmissionRecordVec,
+};
+use ckboost_shared::QuestDataExt;
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const PARTICIPANT: u8 = 9;
+const USER: [u8; 32] = [5; 32];
+const UNKNOWN_SUB_TASK: u8 = 9;
+
+// Quest 1 made of sub-tasks 1 and 2
+fn multi_step_quest(accepted: &[u8]) -> QuestData {
+    quest(1, accepted)
+        .as_builder()
+        .status(QUEST_STATUS_ACTIVE)
+        .sub_tasks(
+            QuestSubTaskDataVec::new_builder()
+                .push(QuestSubTaskData::new_builder().id(Uint8::from([1])).build())
+                .push(QuestSubTaskData::new_builder().id(Uint8::from([2])).build())
+                .build(),
+        )
+        .build()
+}
+
+fn multi_step_campaign(endorser_lock_hash: [u8; 32], quest: QuestData) -> CampaignData {
+    let participants = quest.accepted_submission_user_type_ids().len() as u32;
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .status(CAMPAIGN_STATUS_ACTIVE)
+        .quests(QuestDataVec::new_builder().push(quest).build())
+        .total_completions(Uint32::from(participants.to_le_bytes()))
+        .participants_count(Uint32::from(participants.to_le_bytes()))
+        .build()
+}
+
+// User 5's submission for quest 1 with entries for these sub-tasks
+fn user_with_sub_tasks(sub_task_ids: &[u8]) -> UserData {
+    let submitted = submitted_user(USER, CAMPAIGN, 1);
+    let entries = sub_task_ids.iter().map(|id| {
+        SubTaskSubmission::new_builder()
+            .sub_task_id(Uint8::from([*id]))
+            .submission_content(b"https://example.com/sub-task".to_vec())
+            .build()
+    });
+    let record = submitted
+        .submission_records()
+        .get(0)
+        .expect("submitted record")
+        .as_builder()
+        .sub_task_submissions(SubTaskSubmissionVec::new_builder().extend(entries).build())
+        .build();
+    submitted
+        .as_builder()
+        .submission_records(UserSubmissionRecordVec::new_builder().push(record).build())
+        .build()
+}
+
+// Review user 5 for quest 1 under `method_path`, reading the user cell holding `user_data`
+fn review(
+    method_path: &str,
+    input_quest: QuestData,
+    output_quest: QuestData,
+    user_data: &UserData,
+    extra_args: Vec<&[u8]>,
+) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let input = multi_step_campaign(endorser, input_quest);
+    let output = multi_step_campaign(endorser, output_quest);
+    let user_ids = Byte32Vec::new_builder().push(user_type_id(USER[0])).build();
+
+    let mut args = vec![output.as_slice(), &1u32.to_le_bytes()[..], user_ids.as_slice()];
+    args.extend(extra_args);
+    let user_dep = deployment.user_dep(USER, PARTICIPANT, user_data);
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, ENDORSER)
+        .cell_dep(user_dep)
+        .witness(recipe_witness(method_path, args).pack())
+        .build();
+    deployment.verify(tx)
+}
+
+// Approve sub-task 1 of user 5, whose submission has entries for `submitted`
+fn approve_first_step(submitted: &[u8]) -> Result<Cycle, Error> {
+    review(
+        "CKBoostCampaign.approve_sub_tasks",
+        multi_step_quest(&[]),
+        multi_step_quest(&[]).with_sub_tasks_approved(&USER, &[1]).unwrap(),
+        &user_with_sub_tasks(submitted),
+        vec![&[1u8][..]],
+    )
+}
+
+// Approve the whole quest for user 5, whose sub-tasks `approved` were approved
+fn approve_quest(approved: &[u8]) -> Result<Cycle, Error> {
+    review(
+        "CKBoostCampaign.approve_completion",
+        multi_step_quest(&[]).with_sub_tasks_approved(&USER, approved).unwrap(),
+        multi_step_quest(&[5]).with_sub_tasks_approved(&USER, approved).unwrap(),
+        &user_with_sub_tasks(&[1, 2]),
+        vec![],
+    )
+}
+
+// Add entries for `added` to user 5's submission for quest 1, which has one for sub-task 1
+fn add_sub_task_entries(added: &[u8]) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let campaign = multi_step_campaign(deployment.lock_hash(ENDORSER), multi_step_quest(&[]));
+    let mut sub_task_ids = vec![1u8];
+    sub_task_ids.extend_from_slice(added);
+
+    let campaign_dep = deployment.campaign_dep(CAMPAIGN, &campaign);
+    let tx = deployment
+        .submission(USER, PARTICIPANT, &user_with_sub_tasks(&[1]), &user_with_sub_tasks(&sub_task_ids))
+        .cell_dep(campaign_dep)
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_reviewer_approves_a_submitted_sub_task() {
+    approve_first_step(&[1]).expect("sub-task 1 was submitted");
+}
+
+#[test]
+fn test_unsubmitted_sub_task_cannot_be_approved() {
+    assert!(approve_first_step(&[2]).is_err());
+}
+
+#[test]
+fn test_quest_completes_once_every_sub_task_is_approved() {
+    approve_quest(&[1, 2]).expect("both sub-tasks were approved");
+}
+
+#[test]
+fn test_quest_cannot_complete_with_sub_tasks_pending() {
+    assert!(approve_quest(&[1]).is_err());
+}
+
+#[test]
+fn test_user_adds_an_entry_for_the_next_sub_task() {
+    add_sub_task_entries(&[2]).expect("sub-task 2 belongs to the quest");
+}
+
+#[test]
+fn test_entries_must_name_a_sub_task_of_the_quest() {
+    assert!(add_sub_task_entries(&[UNKNOWN_SUB_TASK]).is_err());
+}
+
+#[test]
+fn test_sub_task_approval_needs_a_full_user_type_id() {
+    // A truncated ID must not turn into the zero user type ID
+    assert!(multi_step_quest(&[]).with_sub_tasks_approved(&USER[..31], &[1]).is_none());
+}
@@ -0,0 +1,97 @@
+// Tests for compacting approved submission records into the archive hash
+
+use crate::support::{campaign, quest, submitted_user, Deployment};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::core::Cycle;
+use ckboost_shared::types::{Byte32, Uint32, UserData, UserSubmissionRecord, UserSubmissionRecordVec};
+use ckboost_shared::user_data::archived_submissions_hash_with;
+use ckboost_shared::UserDataExt;
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const PARTICIPANT: u8 = 9;
+const USER: [u8; 32] = [5; 32];
+
+fn record(quest_id: u32) -> UserSubmissionRecord {
+    UserSubmissionRecord::new_builder()
+        .campaign_type_id(Byte32::from([7u8; 32]))
+        .quest_id(Uint32::from(quest_id.to_le_bytes()))
+        .build()
+}
+
+fn user(quest_ids: &[u32]) -> UserData {
+    UserData::new_builder()
+        .submission_records(
+            UserSubmissionRecordVec::new_builder()
+                .extend(quest_ids.iter().map(|quest_id| record(*quest_id)))
+                .build(),
+        )
+        .build()
+}
+
+#[test]
+fn test_archival_compacts_the_oldest_records() {
+    let archived = user(&[1, 2, 3]).with_archived_submissions(2).unwrap();
+    assert_eq!(archived.submission_records().as_slice(), user(&[3]).submission_records().as_slice());
+    assert_eq!(archived.archived_submission_count_u32(), 2);
+
+    // The archive hash replays the archived records in order from the zero hash
+    let expected = archived_submissions_hash_with(&archived_submissions_hash_with(&[0u8; 32], &record(1)), &record(2));
+    assert_eq!(archived.archived_submissions_hash().as_slice(), &expected[..]);
+
+    assert!(user(&[1, 2]).with_archived_submissions(3).is_none());
+}
+
+#[test]
+fn test_archives_chain_across_archivals() {
+    let in_steps = user(&[1, 2, 3])
+        .with_archived_submissions(1)
+        .unwrap()
+        .with_archived_submissions(2)
+        .unwrap();
+    let at_once = user(&[1, 2, 3]).with_archived_submissions(3).unwrap();
+    assert_eq!(in_steps.as_slice(), at_once.as_slice());
+
+    // Archiving changes the history a profile or verification update must preserve
+    assert!(!user(&[1]).has_same_submission_history(&user(&[1]).with_archived_submissions(1).unwrap()));
+    assert!(user(&[1]).has_same_submission_history(&user(&[1])));
+}
+
+// Archive user 5's submission for quest 1 into `output`, with the campaign dep accepting
+// `accepted` for the quest
+fn archive(accepted: &[u8], output: &UserData) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let campaign_dep = deployment.campaign_dep(CAMPAIGN, &campaign(vec![quest(1, accepted)]));
+    let tx = deployment
+        .user_update_with_args(
+            "CKBoostUser.archive_submissions",
+            USER,
+            PARTICIPANT,
+            &submitted_user(USER, CAMPAIGN, 1),
+            output,
+            vec![&1u32.to_le_bytes()[..]],
+        )
+        .cell_dep(campaign_dep)
+        .build();
+    deployment.verify(tx)
+}
+
+fn archived_user() -> UserData {
+    submitted_user(USER, CAMPAIGN, 1).with_archived_submissions(1).unwrap()
+}
+
+#[test]
+fn test_owner_archives_an_approved_submission() {
+    archive(&[5], &archived_user()).expect("quest 1 approved user 5");
+}
+
+#[test]
+fn test_pending_submission_cannot_be_archived() {
+    assert!(archive(&[], &archived_user()).is_err());
+}
+
+#[test]
+fn test_archival_must_fold_records_into_the_hash() {
+    // Dropping the record without folding it in loses the history
+    assert!(archive(&[5], &UserData::new_builder().build()).is_err());
+}
@@ -0,0 +1,127 @@
+// Tests for committing to submission content and revealing it later
+
+use ckboost_shared::campaign_data::{CAMPAIGN_STATUS_ACTIVE, QUEST_STATUS_ACTIVE};
+use crate::support::{campaign, quest, with_record, Deployment};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::core::Cycle;
+use ckboost_shared::types::{Byte32, Uint32, UserData, UserSubmissionRecord, UserSubmissionRecordVec};
+use ckboost_shared::user_data::submission_commitment;
+use ckboost_shared::{UserDataExt, UserSubmissionRecordExt};
+use molecule::prelude::*;
+
+const USER: [u8; 32] = [1u8; 32];
+const CAMPAIGN: [u8; 32] = [7u8; 32];
+const SALT: [u8; 32] = [9u8; 32];
+const PARTICIPANT: u8 = 9;
+const ANSWER: &[u8] = b"https://example.com/answer";
+
+fn committed_record(quest_id: u32, content: &[u8]) -> UserSubmissionRecord {
+    UserSubmissionRecord::new_builder()
+        .campaign_type_id(Byte32::from(CAMPAIGN))
+        .quest_id(Uint32::from(quest_id.to_le_bytes()))
+        .content_commitment(Byte32::from(submission_commitment(&USER, &CAMPAIGN, quest_id, &SALT, &[0u8; 32], content)))
+        .build()
+}
+
+fn revealed(record: &UserSubmissionRecord, salt: [u8; 32], content: &[u8]) -> UserSubmissionRecord {
+    record
+        .clone()
+        .as_builder()
+        .submission_content(content.to_vec())
+        .content_salt(Byte32::from(salt))
+        .build()
+}
+
+#[test]
+fn test_reveal_opens_the_commitment() {
+    let record = committed_record(1, b"answer");
+    assert!(record.is_committed());
+    assert!(!record.is_revealed_by(&USER));
+
+    let opened = revealed(&record, SALT, b"answer");
+    assert!(!opened.is_committed());
+    assert!(opened.is_revealed_by(&USER));
+
+    // Wrong content, wrong salt, or another user's cell do not open it
+    assert!(!revealed(&record, SALT, b"copied").is_revealed_by(&USER));
+    assert!(!revealed(&record, [0u8; 32], b"answer").is_revealed_by(&USER));
+    assert!(!opened.is_revealed_by(&[2u8; 32]));
+}
+
+#[test]
+fn test_approval_sees_only_the_latest_record() {
+    let first = revealed(&committed_record(1, b"answer"), SALT, b"answer");
+    let resubmission = committed_record(1, b"better answer");
+    let user = UserData::new_builder()
+        .submission_records(
+            UserSubmissionRecordVec::new_builder()
+                .push(first)
+                .push(resubmission.clone())
+                .build(),
+        )
+        .build();
+    assert!(!user.latest_submission_revealed(&CAMPAIGN, 1, &USER));
+
+    let user = user
+        .with_submission_record(1, revealed(&resubmission, SALT, b"better answer"))
+        .unwrap();
+    assert!(user.latest_submission_revealed(&CAMPAIGN, 1, &USER));
+    assert!(user.with_submission_record(2, resubmission).is_none());
+}
+
+// Submit quest 1 of the active campaign for a fresh user, appending `record`
+fn submit(record: UserSubmissionRecord) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let active_quest = quest(1, &[]).as_builder().status(QUEST_STATUS_ACTIVE).build();
+    let active_campaign = campaign(vec![active_quest])
+        .as_builder()
+        .status(CAMPAIGN_STATUS_ACTIVE)
+        .build();
+    let input = UserData::new_builder().build();
+
+    let campaign_dep = deployment.campaign_dep(CAMPAIGN, &active_campaign);
+    let tx = deployment
+        .submission(USER, PARTICIPANT, &input, &with_record(&input, record))
+        .cell_dep(campaign_dep)
+        .build();
+    deployment.verify(tx)
+}
+
+// Reveal the committed submission for quest 1 as `salt` and `content`
+fn reveal(salt: [u8; 32], content: &[u8]) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let record = committed_record(1, ANSWER);
+    let input = with_record(&UserData::new_builder().build(), record.clone());
+    let output = input.with_submission_record(0, revealed(&record, salt, content)).unwrap();
+    let tx = deployment
+        .user_update_with_args(
+            "CKBoostUser.reveal_submission",
+            USER,
+            PARTICIPANT,
+            &input,
+            &output,
+            vec![&0u32.to_le_bytes()[..]],
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_user_submits_a_commitment() {
+    submit(committed_record(1, ANSWER)).expect("new record only commits to its content");
+}
+
+#[test]
+fn test_submission_cannot_reveal_content_up_front() {
+    assert!(submit(revealed(&committed_record(1, ANSWER), SALT, ANSWER)).is_err());
+}
+
+#[test]
+fn test_user_reveals_the_committed_content() {
+    reveal(SALT, ANSWER).expect("content and salt open the commitment");
+}
+
+#[test]
+fn test_reveal_must_open_the_commitment() {
+    assert!(reveal(SALT, b"https://example.com/copied").is_err());
+}
@@ -0,0 +1,97 @@
+// Tests for submissions whose content is kept off-chain behind a hash
+
+use ckboost_shared::submission_content::{
+    has_valid_content_reference, hash_submission_content, is_content_uri, verify_offchain_content,
+};
+use crate::support::{with_record, Deployment};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::core::Cycle;
+use ckboost_shared::types::{Byte32, Uint32, UserData, UserSubmissionRecord};
+use ckboost_shared::user_data::submission_commitment;
+use ckboost_shared::UserDataExt;
+use molecule::prelude::*;
+
+const USER: [u8; 32] = [5; 32];
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const PARTICIPANT: u8 = 9;
+const SALT: [u8; 32] = [9; 32];
+
+fn record(content: &[u8], content_hash: [u8; 32]) -> UserSubmissionRecord {
+    UserSubmissionRecord::new_builder()
+        .submission_content(content.to_vec())
+        .content_hash(Byte32::from(content_hash))
+        .build()
+}
+
+#[test]
+fn test_offchain_content_matches_the_hash() {
+    let content = b"<p>My answer</p>";
+    let hashed = record(b"ipfs://bafybeigdyrzt", hash_submission_content(content));
+    assert!(verify_offchain_content(&hashed, content));
+    assert!(!verify_offchain_content(&hashed, b"<p>Edited answer</p>"));
+
+    // Inline records have nothing off-chain to verify
+    assert!(!verify_offchain_content(&record(content, [0u8; 32]), content));
+}
+
+#[test]
+fn test_hash_mode_requires_an_accepted_uri() {
+    let hash = hash_submission_content(b"answer");
+    assert!(has_valid_content_reference(&record(b"nostr:nevent1qqs", hash)));
+    assert!(!has_valid_content_reference(&record(b"answer", hash)));
+    assert!(!has_valid_content_reference(&record(b"ipfs://", hash)));
+    assert!(has_valid_content_reference(&record(b"answer", [0u8; 32])));
+
+    assert!(is_content_uri(b"https://example.com/submission/1"));
+    assert!(!is_content_uri(b"http://example.com/submission/1"));
+}
+
+// Reveal user 5's committed submission for quest 1 as `content` behind the hash of `offchain`
+fn reveal_hashed(content: &[u8], offchain: &[u8]) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let content_hash = hash_submission_content(offchain);
+    let committed = UserSubmissionRecord::new_builder()
+        .campaign_type_id(Byte32::from(CAMPAIGN))
+        .quest_id(Uint32::from(1u32.to_le_bytes()))
+        .content_commitment(Byte32::from(submission_commitment(
+            &USER,
+            &CAMPAIGN,
+            1,
+            &SALT,
+            &content_hash,
+            content,
+        )))
+        .build();
+    let revealed = committed
+        .clone()
+        .as_builder()
+        .submission_content(content.to_vec())
+        .content_hash(Byte32::from(content_hash))
+        .content_salt(Byte32::from(SALT))
+        .build();
+    let input = with_record(&UserData::new_builder().build(), committed);
+    let output = input.with_submission_record(0, revealed).unwrap();
+
+    let tx = deployment
+        .user_update_with_args(
+            "CKBoostUser.reveal_submission",
+            USER,
+            PARTICIPANT,
+            &input,
+            &output,
+            vec![&0u32.to_le_bytes()[..]],
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_user_reveals_a_content_uri() {
+    reveal_hashed(b"ipfs://bafybeigdyrzt", b"<p>My answer</p>").expect("hash mode record points to IPFS");
+}
+
+#[test]
+fn test_hashed_reveal_requires_an_accepted_uri() {
+    // The commitment opens, but the content is not a URI the hash could be checked against
+    assert!(reveal_hashed(b"<p>My answer</p>", b"<p>My answer</p>").is_err());
+}
@@ -0,0 +1,124 @@
+// Tests for rejected submissions and the resubmissions they allow
+
+use ckboost_shared::campaign_data::{CAMPAIGN_STATUS_ACTIVE, QUEST_STATUS_ACTIVE};
+use crate::support::{
+    committed_record, managed_campaign, quest, recipe_witness, submitted_user, user_type_id,
+    with_record, Deployment,
+};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{core::Cycle, prelude::Pack};
+use ckboost_shared::types::{
+    Byte32Vec, CampaignData, QuestData, QuestDataVec, SubmissionRejection, SubmissionRejectionVec,
+    Uint32,
+};
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const ENDORSER: u8 = 1;
+const PARTICIPANT: u8 = 9;
+const USER: [u8; 32] = [5; 32];
+const REASON_CODE: u32 = 7;
+
+fn rejected_quest(accepted: &[u8], rejection_count: u32, max_resubmissions: u32) -> QuestData {
+    let rejections = if rejection_count == 0 {
+        vec![]
+    } else {
+        vec![SubmissionRejection::new_builder()
+            .user_type_id(user_type_id(USER[0]))
+            .reason_code(Uint32::from(REASON_CODE.to_le_bytes()))
+            .rejection_count(Uint32::from(rejection_count.to_le_bytes()))
+            .build()]
+    };
+    quest(1, accepted)
+        .as_builder()
+        .status(QUEST_STATUS_ACTIVE)
+        .rejected_submissions(SubmissionRejectionVec::new_builder().set(rejections).build())
+        .max_resubmissions(Uint32::from(max_resubmissions.to_le_bytes()))
+        .build()
+}
+
+fn reviewed_campaign(endorser_lock_hash: [u8; 32], quest: QuestData) -> CampaignData {
+    let participants = quest.accepted_submission_user_type_ids().len() as u32;
+    managed_campaign(endorser_lock_hash, vec![])
+        .as_builder()
+        .status(CAMPAIGN_STATUS_ACTIVE)
+        .quests(QuestDataVec::new_builder().push(quest).build())
+        .total_completions(Uint32::from(participants.to_le_bytes()))
+        .participants_count(Uint32::from(participants.to_le_bytes()))
+        .build()
+}
+
+// Reject user 5's submission for quest 1, moving the quest from `input` to `output`
+fn reject(input: QuestData, output: QuestData) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let endorser = deployment.lock_hash(ENDORSER);
+    let input = reviewed_campaign(endorser, input);
+    let output = reviewed_campaign(endorser, output);
+    let user_ids = Byte32Vec::new_builder().push(user_type_id(USER[0])).build();
+
+    let user_dep = deployment.user_dep(USER, PARTICIPANT, &submitted_user(USER, CAMPAIGN, 1));
+    let tx = deployment
+        .campaign_update(CAMPAIGN, &input, &output, ENDORSER)
+        .cell_dep(user_dep)
+        .witness(
+            recipe_witness(
+                "CKBoostCampaign.reject_submission",
+                vec![
+                    output.as_slice(),
+                    &1u32.to_le_bytes(),
+                    user_ids.as_slice(),
+                    &REASON_CODE.to_le_bytes(),
+                ],
+            )
+            .pack(),
+        )
+        .build();
+    deployment.verify(tx)
+}
+
+// Submit quest 1 again for user 5, whose first submission the campaign dep shows `quest` for
+fn resubmit(quest: QuestData) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let campaign = reviewed_campaign(deployment.lock_hash(ENDORSER), quest);
+    let input = submitted_user(USER, CAMPAIGN, 1);
+    let output = with_record(&input, committed_record(USER, CAMPAIGN, 1));
+
+    let campaign_dep = deployment.campaign_dep(CAMPAIGN, &campaign);
+    let tx = deployment
+        .submission(USER, PARTICIPANT, &input, &output)
+        .cell_dep(campaign_dep)
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_reviewer_rejects_pending_submission() {
+    reject(rejected_quest(&[], 0, 1), rejected_quest(&[], 1, 1)).expect("pending submission is rejected");
+}
+
+#[test]
+fn test_approved_submission_cannot_be_rejected() {
+    // The approved and rejected sets stay disjoint
+    assert!(reject(rejected_quest(&[5], 0, 1), rejected_quest(&[5], 1, 1)).is_err());
+}
+
+#[test]
+fn test_submission_is_rejected_only_once() {
+    // The only submission was already rejected, so nothing is pending
+    assert!(reject(rejected_quest(&[], 1, 1), rejected_quest(&[], 2, 1)).is_err());
+}
+
+#[test]
+fn test_rejected_user_resubmits() {
+    resubmit(rejected_quest(&[], 1, 1)).expect("resubmission after a rejection");
+}
+
+#[test]
+fn test_pending_submission_cannot_be_resubmitted() {
+    assert!(resubmit(rejected_quest(&[], 0, 1)).is_err());
+}
+
+#[test]
+fn test_resubmissions_are_limited_per_quest() {
+    assert!(resubmit(rejected_quest(&[], 1, 0)).is_err());
+}
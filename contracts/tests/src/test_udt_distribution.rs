@@ -1,67 +1,320 @@
-// Test structure for UDT distribution functionality
-// This is a skeleton file - implementation details to be added
-
-#[cfg(test)]
-mod test_udt_distribution {
-    use super::*;
-    
-    #[test]
-    fn test_udt_distribution_on_quest_approval() {
-        // TODO: Test UDT distribution when approving quest completions
-        // 1. Create and fund a campaign with UDTs
-        // 2. Have users submit quest completions
-        // 3. Admin approves completions
-        // 4. Verify UDTs are distributed to approved users
-        unimplemented!("Test UDT distribution on quest approval")
-    }
-    
-    #[test]
-    fn test_correct_udt_amounts_distributed() {
-        // TODO: Test that correct amounts are distributed per quest rewards
-        // 1. Create quest with specific UDT reward amounts
-        // 2. Fund campaign with sufficient UDTs
-        // 3. Approve multiple users
-        // 4. Verify each user receives correct amount
-        unimplemented!("Test correct UDT amounts distributed")
-    }
-    
-    #[test]
-    fn test_campaign_lock_unlock_for_distribution() {
-        // TODO: Test campaign-lock unlocking during distribution
-        // 1. Fund campaign (UDTs locked with campaign-lock)
-        // 2. Admin approves quest completions
-        // 3. Verify campaign-lock is properly unlocked
-        // 4. Verify UDTs transfer to user addresses
-        unimplemented!("Test campaign lock unlock for distribution")
-    }
-    
-    #[test]
-    fn test_partial_distribution_with_remaining_balance() {
-        // TODO: Test partial distribution leaves remaining balance locked
-        // 1. Fund campaign with 1000 USDC
-        // 2. Distribute 300 USDC to approved users
-        // 3. Verify 700 USDC remains locked with campaign-lock
-        // 4. Verify remaining can be distributed later
-        unimplemented!("Test partial distribution with remaining balance")
-    }
-    
-    #[test]
-    fn test_multi_user_batch_distribution() {
-        // TODO: Test distributing to multiple users in one transaction
-        // 1. Create campaign with UDT rewards
-        // 2. Have 10 users complete quests
-        // 3. Approve all 10 in one transaction
-        // 4. Verify all receive their UDTs atomically
-        unimplemented!("Test multi user batch distribution")
+// Tests for quest reward distribution
+// These exercise the payout accounting shared by the campaign type and campaign lock
+
+use ckboost_shared::rewards::{
+    merge_rewarded, rewarded_in_quest, rewarded_participants, CarriedAssets, RewardEntitlement,
+    TypedCell,
+};
+use ckboost_shared::QuestDataExt;
+use crate::support::{campaign, user_type_id};
+use ckboost_shared::types::{
+    ApprovalRecord, ApprovalRecordVec, AssetList, AssetListVec, Byte32, Bytes, QuestData, Script,
+    ScriptVec, UDTAsset, UDTAssetVec, Uint128, Uint64,
+};
+use molecule::prelude::*;
+
+fn udt_script(marker: u8) -> Script {
+    Script::new_builder()
+        .code_hash(Byte32::from([marker; 32]))
+        .hash_type(1u8)
+        .args(Bytes::from(vec![marker; 32]))
+        .build()
+}
+
+fn asset_list(ckb_amount: u64, udts: &[(Script, u128)]) -> AssetList {
+    AssetList::new_builder()
+        .ckb_amount(Uint64::from(ckb_amount.to_le_bytes()))
+        .udt_assets(
+            UDTAssetVec::new_builder()
+                .extend(udts.iter().map(|(script, amount)| {
+                    UDTAsset::new_builder()
+                        .udt_script(script.clone())
+                        .amount(Uint128::from(amount.to_le_bytes()))
+                        .build()
+                }))
+                .build(),
+        )
+        .build()
+}
+
+fn quest(quest_id: u32, rewards: Vec<AssetList>, accepted: &[u8]) -> QuestData {
+    crate::support::quest(quest_id, accepted)
+        .as_builder()
+        .rewards_on_completion(AssetListVec::new_builder().set(rewards).build())
+        .build()
+}
+
+fn udt_cell(script: &Script, amount: u128) -> TypedCell {
+    TypedCell {
+        type_script: script.as_slice().to_vec(),
+        data: amount.to_le_bytes().to_vec(),
+        capacity: 142_0000_0000,
     }
-    
-    #[test]
-    fn test_distribution_with_multiple_udt_types() {
-        // TODO: Test distributing multiple UDT types as rewards
-        // 1. Create quest with USDC and USDT rewards
-        // 2. Fund campaign with both tokens
-        // 3. Approve users
-        // 4. Verify users receive both token types
-        unimplemented!("Test distribution with multiple UDT types")
+}
+
+#[test]
+fn test_udt_distribution_on_quest_approval() {
+    let usdc = udt_script(1);
+    let rewards = vec![asset_list(0, &[(usdc.clone(), 100)])];
+    let input = campaign(vec![quest(1, rewards.clone(), &[])]);
+    let output = campaign(vec![quest(1, rewards, &[7])]);
+
+    let participants = rewarded_participants(&input, &output);
+    assert_eq!(participants.len(), 1);
+    assert_eq!(participants[0].0, user_type_id(7).as_slice().to_vec());
+    assert_eq!(participants[0].1.udt_amount(usdc.as_slice()), 100);
+
+    // Users already accepted in the input are not paid again
+    assert!(rewarded_participants(&output, &output).is_empty());
+}
+
+#[test]
+fn test_correct_udt_amounts_distributed() {
+    let usdc = udt_script(1);
+    let entitlement = RewardEntitlement::for_quest(&quest(
+        1,
+        vec![asset_list(1000, &[(usdc.clone(), 40)]), asset_list(500, &[(usdc.clone(), 60)])],
+        &[],
+    ));
+    assert_eq!(entitlement.ckb_amount, 1500);
+    assert_eq!(entitlement.udt_amount(usdc.as_slice()), 100);
+
+    let exact = CarriedAssets {
+        plain_capacity: 1500,
+        typed_cells: vec![udt_cell(&usdc, 100)],
+    };
+    assert!(exact.delivers(&entitlement));
+
+    // UDTs must match exactly, CKB may exceed the owed amount
+    let short = CarriedAssets {
+        plain_capacity: 1500,
+        typed_cells: vec![udt_cell(&usdc, 99)],
+    };
+    assert!(!short.delivers(&entitlement));
+    let over = CarriedAssets {
+        plain_capacity: 1500,
+        typed_cells: vec![udt_cell(&usdc, 101)],
+    };
+    assert!(!over.delivers(&entitlement));
+    let extra_ckb = CarriedAssets {
+        plain_capacity: 2000,
+        typed_cells: vec![udt_cell(&usdc, 100)],
+    };
+    assert!(extra_ckb.delivers(&entitlement));
+}
+
+#[test]
+fn test_campaign_lock_unlock_for_distribution() {
+    let usdc = udt_script(1);
+    let owed = RewardEntitlement::for_quest(&quest(1, vec![asset_list(0, &[(usdc.clone(), 100)])], &[]));
+    let locked = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&usdc, 1000)],
+    };
+
+    let change = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&usdc, 900)],
+    };
+    assert!(CarriedAssets::releases_within(&locked, &change, &owed));
+
+    // Nothing owed means nothing may leave the campaign lock
+    let drained = CarriedAssets::default();
+    assert!(!CarriedAssets::releases_within(&locked, &drained, &RewardEntitlement::default()));
+    assert!(!CarriedAssets::releases_within(&locked, &drained, &owed));
+}
+
+#[test]
+fn test_partial_distribution_with_remaining_balance() {
+    let usdc = udt_script(1);
+    let owed = RewardEntitlement::for_quest(&quest(1, vec![asset_list(0, &[(usdc.clone(), 300)])], &[]));
+    let locked = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&usdc, 1000)],
+    };
+
+    let remaining = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&usdc, 700)],
+    };
+    assert!(CarriedAssets::releases_within(&locked, &remaining, &owed));
+
+    let overdrawn = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&usdc, 699)],
+    };
+    assert!(!CarriedAssets::releases_within(&locked, &overdrawn, &owed));
+}
+
+#[test]
+fn test_multi_user_batch_distribution() {
+    let usdc = udt_script(1);
+    let rewards = vec![asset_list(100, &[(usdc.clone(), 10)])];
+    let users: Vec<u8> = (1..=10).collect();
+    let input = campaign(vec![quest(1, rewards.clone(), &[]), quest(2, rewards.clone(), &[])]);
+    let output = campaign(vec![quest(1, rewards.clone(), &users), quest(2, rewards, &[1])]);
+
+    let participants = rewarded_participants(&input, &output);
+    assert_eq!(participants.len(), 10);
+
+    // A user approved for two quests at once is owed both rewards
+    let first = participants
+        .iter()
+        .find(|(user, _)| user.as_slice() == user_type_id(1).as_slice())
+        .map(|(_, entitlement)| entitlement.clone())
+        .unwrap();
+    assert_eq!(first.ckb_amount, 200);
+    assert_eq!(first.udt_amount(usdc.as_slice()), 20);
+
+    let mut total = RewardEntitlement::default();
+    for (_, entitlement) in participants.iter() {
+        total.merge(entitlement);
     }
-}
\ No newline at end of file
+    assert_eq!(total.udt_amount(usdc.as_slice()), 110);
+}
+
+#[test]
+fn test_distribution_with_multiple_udt_types() {
+    let usdc = udt_script(1);
+    let usdt = udt_script(2);
+    let other = udt_script(3);
+    let owed = RewardEntitlement::for_quest(&quest(
+        1,
+        vec![asset_list(0, &[(usdc.clone(), 50), (usdt.clone(), 25)])],
+        &[],
+    ));
+
+    let delivered = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&usdt, 25), udt_cell(&usdc, 50)],
+    };
+    assert!(delivered.delivers(&owed));
+    let missing_one = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&usdc, 50)],
+    };
+    assert!(!missing_one.delivers(&owed));
+
+    // Funding in UDTs that are not owed stays locked
+    let locked = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&usdc, 500), udt_cell(&usdt, 500), udt_cell(&other, 500)],
+    };
+    let change = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&usdc, 450), udt_cell(&usdt, 475), udt_cell(&other, 500)],
+    };
+    assert!(CarriedAssets::releases_within(&locked, &change, &owed));
+    let leaks_other = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&usdc, 450), udt_cell(&usdt, 475), udt_cell(&other, 499)],
+    };
+    assert!(!CarriedAssets::releases_within(&locked, &leaks_other, &owed));
+}
+
+#[test]
+fn test_pending_approvals_are_not_paid() {
+    let usdc = udt_script(1);
+    let rewards = vec![asset_list(0, &[(usdc.clone(), 100)])];
+    let pending = ApprovalRecord::new_builder()
+        .user_type_id(user_type_id(7))
+        .approved_at(Uint64::from(1_000u64.to_le_bytes()))
+        .build();
+    let input = campaign(vec![quest(1, rewards.clone(), &[])]);
+    let approved = campaign(vec![quest(1, rewards.clone(), &[7])
+        .as_builder()
+        .pending_approvals(ApprovalRecordVec::new_builder().push(pending).build())
+        .build()]);
+    assert!(rewarded_participants(&input, &approved).is_empty());
+
+    // Finalizing the approval releases the reward
+    let finalized = campaign(vec![quest(1, rewards, &[7])]);
+    let participants = rewarded_participants(&approved, &finalized);
+    assert_eq!(participants.len(), 1);
+    assert_eq!(participants[0].1.udt_amount(usdc.as_slice()), 100);
+}
+
+#[test]
+fn test_nft_rewards_go_to_awarded_user() {
+    let spore = udt_script(9);
+    let other_spore = udt_script(10);
+    let rewards = vec![asset_list(0, &[])
+        .as_builder()
+        .nft_assets(ScriptVec::new_builder().set(vec![spore.clone(), other_spore.clone()]).build())
+        .build()];
+    let input = campaign(vec![quest(1, rewards.clone(), &[])]);
+    let output = campaign(vec![quest(1, rewards, &[7, 8])
+        .with_nft_awarded(user_type_id(7).as_slice(), spore.clone())
+        .unwrap()]);
+
+    // Only the awarded user is owed the NFT
+    let participants = rewarded_participants(&input, &output);
+    let owed_to = |marker: u8| {
+        participants
+            .iter()
+            .find(|(user, _)| user.as_slice() == user_type_id(marker).as_slice())
+            .map(|(_, entitlement)| entitlement.clone())
+            .unwrap()
+    };
+    assert!(owed_to(7).owes_nft(spore.as_slice()));
+    assert!(!owed_to(8).owes_nft(spore.as_slice()));
+    assert_eq!(output.quests().get(0).unwrap().unawarded_nfts().len(), 1);
+
+    let received = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&spore, 0)],
+    };
+    assert!(received.delivers(&owed_to(7)));
+    assert!(!CarriedAssets::default().delivers(&owed_to(7)));
+
+    // The awarded NFT may leave the campaign lock, the other one stays escrowed
+    let escrowed = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&spore, 0), udt_cell(&other_spore, 0)],
+    };
+    let after_award = CarriedAssets {
+        plain_capacity: 0,
+        typed_cells: vec![udt_cell(&other_spore, 0)],
+    };
+    assert!(CarriedAssets::releases_within(&escrowed, &after_award, &owed_to(7)));
+    assert!(!CarriedAssets::releases_within(&escrowed, &CarriedAssets::default(), &owed_to(7)));
+}
+
+#[test]
+fn test_quest_cell_rewards() {
+    let usdc = udt_script(1);
+    let rewards = vec![asset_list(0, &[(usdc.clone(), 100)])];
+    let input = quest(1, rewards.clone(), &[7]);
+    let output = quest(1, rewards.clone(), &[7, 8]);
+
+    // A quest cell pays only the users newly accepted in it
+    let participants = rewarded_in_quest(Some(&input), &output);
+    assert_eq!(participants.len(), 1);
+    assert_eq!(participants[0].0, user_type_id(8).as_slice().to_vec());
+
+    // A user approved in two quest cells at once is owed both rewards
+    let mut owed = participants;
+    merge_rewarded(
+        &mut owed,
+        rewarded_in_quest(Some(&quest(2, rewards.clone(), &[])), &quest(2, rewards, &[8])),
+    );
+    assert_eq!(owed.len(), 1);
+    assert_eq!(owed[0].1.udt_amount(usdc.as_slice()), 200);
+}
+
+#[test]
+fn test_quest_rewards_priced_before_the_approval() {
+    let usdc = udt_script(1);
+    let input = quest(1, vec![asset_list(0, &[(usdc.clone(), 100)])], &[]);
+    let rewritten = quest(1, vec![asset_list(0, &[(usdc.clone(), 500)])], &[8]);
+
+    // Rewriting the rewards in the approving transition does not raise what it pays
+    let participants = rewarded_in_quest(Some(&input), &rewritten);
+    assert_eq!(participants.len(), 1);
+    assert_eq!(participants[0].1.udt_amount(usdc.as_slice()), 100);
+
+    // A quest created in the transition owes no shared rewards
+    let participants = rewarded_in_quest(None, &rewritten);
+    assert_eq!(participants.len(), 1);
+    assert!(participants[0].1.is_empty());
+}
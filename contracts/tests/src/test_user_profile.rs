@@ -0,0 +1,64 @@
+// Tests for profile updates by the owner of a user cell
+
+use crate::support::{submitted_user, Deployment};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::core::Cycle;
+use ckboost_shared::types::{Uint128, Uint64, UserData, UserVerificationData};
+use ckboost_shared::user_data::VERIFICATION_MANUAL_REVIEW;
+use molecule::prelude::*;
+
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const PARTICIPANT: u8 = 9;
+const USER: [u8; 32] = [5; 32];
+
+// User 5 with a submission for quest 1, last active at `last_activity`
+fn profile(last_activity: u64) -> UserData {
+    submitted_user(USER, CAMPAIGN, 1)
+        .as_builder()
+        .last_activity_timestamp(Uint64::from(last_activity.to_le_bytes()))
+        .build()
+}
+
+fn update_profile(output: UserData) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let tx = deployment
+        .user_update("CKBoostUser.update_user", USER, PARTICIPANT, &profile(1_000), &output)
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_owner_updates_profile() {
+    update_profile(profile(2_000)).expect("last activity is not a sensitive field");
+}
+
+#[test]
+fn test_profile_update_cannot_credit_points() {
+    let output = profile(2_000)
+        .as_builder()
+        .total_points_earned(Uint128::from(100u128.to_le_bytes()))
+        .build();
+    assert!(update_profile(output).is_err());
+}
+
+#[test]
+fn test_profile_update_cannot_drop_submissions() {
+    let output = profile(2_000)
+        .as_builder()
+        .submission_records(Default::default())
+        .build();
+    assert!(update_profile(output).is_err());
+}
+
+#[test]
+fn test_profile_update_cannot_grant_verifications() {
+    let output = profile(2_000)
+        .as_builder()
+        .verification_data(
+            UserVerificationData::new_builder()
+                .verified_flags(VERIFICATION_MANUAL_REVIEW)
+                .build(),
+        )
+        .build();
+    assert!(update_profile(output).is_err());
+}
@@ -0,0 +1,168 @@
+// Tests for gating submissions on the campaign's verification requirements
+
+use ckboost_shared::attestation::{attestation_message, verify_attestation};
+use ckboost_shared::campaign_data::{CAMPAIGN_STATUS_ACTIVE, QUEST_STATUS_ACTIVE};
+use crate::support::{committed_record, quest, with_record, Deployment};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::core::Cycle;
+use ckboost_shared::types::{
+    AttestorInfo, CampaignData, CampaignMetadata, QuestDataVec, Uint64, Uint8, Uint8Vec, UserData,
+    UserVerificationData, VerificationAttestation, VerificationAttestationVec,
+};
+use ckboost_shared::user_data::{
+    VERIFICATION_KYC, VERIFICATION_MANUAL_REVIEW, VERIFICATION_TELEGRAM, VERIFICATION_TWITTER,
+};
+use ckboost_shared::{CampaignDataExt, UserDataExt};
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use k256::ecdsa::{Signature, SigningKey};
+use molecule::prelude::*;
+
+const NOW: u64 = 1_000_000;
+const CAMPAIGN: [u8; 32] = [0x33; 32];
+const PARTICIPANT: u8 = 9;
+const USER: [u8; 32] = [5; 32];
+
+fn campaign(requirements: &[u8]) -> CampaignData {
+    CampaignData::new_builder()
+        .metadata(
+            CampaignMetadata::new_builder()
+                .verification_requirements(
+                    Uint8Vec::new_builder()
+                        .extend(requirements.iter().map(|bits| Uint8::from([*bits])))
+                        .build(),
+                )
+                .build(),
+        )
+        .build()
+}
+
+fn attestation(verification_bit: u8, expires_at: u64, signature: Vec<u8>) -> VerificationAttestation {
+    VerificationAttestation::new_builder()
+        .verification_bit(verification_bit)
+        .expires_at(Uint64::from(expires_at.to_le_bytes()))
+        .signature(signature)
+        .build()
+}
+
+/// User holding `verified_flags`, each bit attested until `expires_at`
+fn user(verified_flags: u8, expires_at: u64) -> UserData {
+    let attestations = VerificationAttestationVec::new_builder()
+        .extend(
+            (0..8)
+                .map(|shift| 1u8 << shift)
+                .filter(|bit| verified_flags & bit != 0)
+                .map(|bit| attestation(bit, expires_at, vec![0u8; 64])),
+        )
+        .build();
+    UserData::new_builder()
+        .verification_data(
+            UserVerificationData::new_builder()
+                .verified_flags(verified_flags)
+                .attestations(attestations)
+                .build(),
+        )
+        .build()
+}
+
+#[test]
+fn test_required_verifications_ignore_manual_review() {
+    let required = campaign(&[VERIFICATION_TELEGRAM | VERIFICATION_MANUAL_REVIEW, VERIFICATION_KYC])
+        .required_verifications();
+    assert_eq!(required, VERIFICATION_TELEGRAM | VERIFICATION_KYC);
+    assert_eq!(campaign(&[VERIFICATION_MANUAL_REVIEW]).required_verifications(), 0);
+}
+
+#[test]
+fn test_users_must_hold_every_required_verification() {
+    let required = campaign(&[VERIFICATION_TELEGRAM | VERIFICATION_KYC]).required_verifications();
+    let holder = user(VERIFICATION_TELEGRAM | VERIFICATION_KYC | VERIFICATION_TWITTER, NOW + 1);
+    assert_eq!(holder.missing_verifications(required, NOW), 0);
+    assert_eq!(user(VERIFICATION_TELEGRAM, NOW + 1).missing_verifications(required, NOW), VERIFICATION_KYC);
+    assert_eq!(user(0, NOW + 1).missing_verifications(0, NOW), 0);
+}
+
+#[test]
+fn test_expired_attestations_no_longer_count() {
+    let required = VERIFICATION_TELEGRAM | VERIFICATION_KYC;
+    let holder = user(required, NOW);
+    assert_eq!(holder.verified_flags_at(NOW - 1), required);
+    assert_eq!(holder.missing_verifications(required, NOW), required);
+
+    // A flag without a backing attestation is never counted
+    let unattested = UserData::new_builder()
+        .verification_data(
+            UserVerificationData::new_builder()
+                .verified_flags(required)
+                .build(),
+        )
+        .build();
+    assert_eq!(unattested.missing_verifications(required, NOW), required);
+}
+
+#[test]
+fn test_attestation_signatures_bind_user_bit_and_expiry() {
+    let signing_key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+    let pubkey = signing_key.verifying_key().to_encoded_point(true).as_bytes().to_vec();
+    let attestor = AttestorInfo::new_builder()
+        .verification_bit(VERIFICATION_KYC)
+        .pubkey(pubkey)
+        .build();
+    let user_type_id = [3u8; 32];
+    let sign = |bit: u8, expires_at: u64| {
+        let signature: Signature = signing_key
+            .sign_prehash(&attestation_message(&user_type_id, bit, expires_at))
+            .unwrap();
+        attestation(bit, expires_at, signature.to_bytes().to_vec())
+    };
+
+    let valid = sign(VERIFICATION_KYC, NOW);
+    assert!(verify_attestation(&attestor, &user_type_id, &valid));
+    assert!(!verify_attestation(&attestor, &[4u8; 32], &valid));
+
+    // Extending the expiry invalidates the signature
+    let extended = valid.clone().as_builder().expires_at(Uint64::from((NOW + 1).to_le_bytes())).build();
+    assert!(!verify_attestation(&attestor, &user_type_id, &extended));
+
+    // The attestor is only trusted for its own bit
+    assert!(!verify_attestation(&attestor, &user_type_id, &sign(VERIFICATION_TELEGRAM, NOW)));
+}
+
+// Submit quest 1 of an active campaign requiring a Telegram verification, as a user holding
+// `verified_flags` attested until `expires_at`, at block time NOW
+fn submit_as(verified_flags: u8, expires_at: u64) -> Result<Cycle, Error> {
+    let mut deployment = Deployment::new(&[]);
+    let gated = campaign(&[VERIFICATION_TELEGRAM])
+        .as_builder()
+        .status(CAMPAIGN_STATUS_ACTIVE)
+        .quests(
+            QuestDataVec::new_builder()
+                .push(quest(1, &[]).as_builder().status(QUEST_STATUS_ACTIVE).build())
+                .build(),
+        )
+        .build();
+    let input = user(verified_flags, expires_at);
+    let output = with_record(&input, committed_record(USER, CAMPAIGN, 1));
+
+    let campaign_dep = deployment.campaign_dep(CAMPAIGN, &gated);
+    let tx = deployment
+        .submission(USER, PARTICIPANT, &input, &output)
+        .cell_dep(campaign_dep)
+        .header_dep(deployment.header(NOW))
+        .build();
+    deployment.verify(tx)
+}
+
+#[test]
+fn test_verified_user_submits() {
+    submit_as(VERIFICATION_TELEGRAM, NOW + 1).expect("Telegram attestation still valid");
+}
+
+#[test]
+fn test_unverified_user_cannot_submit() {
+    assert!(submit_as(VERIFICATION_TWITTER, NOW + 1).is_err());
+}
+
+#[test]
+fn test_expired_verification_cannot_submit() {
+    assert!(submit_as(VERIFICATION_TELEGRAM, NOW).is_err());
+}
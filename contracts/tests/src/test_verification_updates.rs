@@ -0,0 +1,95 @@
+// Tests for verification updates authorized by whitelisted endorsers
+
+use crate::support::{cell, Deployment};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{bytes::Bytes, core::Cycle, prelude::Pack};
+use ckboost_shared::types::{
+    Uint64, UserData, UserVerificationData, VerificationAttestation, VerificationAttestationVec,
+};
+use ckboost_shared::user_data::{VERIFICATION_TELEGRAM, VERIFICATION_TWITTER};
+use molecule::prelude::*;
+
+const ENDORSER: u8 = 1;
+const OUTSIDER: u8 = 3;
+const PARTICIPANT: u8 = 9;
+const USER: [u8; 32] = [5; 32];
+const EXPIRES_AT: u64 = 1_000_000;
+
+/// User holding `verified_flags`, each bit carrying an unsigned attestation
+fn verified_user(verified_flags: u8) -> UserData {
+    let attestations = VerificationAttestationVec::new_builder()
+        .extend(
+            (0..8)
+                .map(|shift| 1u8 << shift)
+                .filter(|bit| verified_flags & bit != 0)
+                .map(|bit| {
+                    VerificationAttestation::new_builder()
+                        .verification_bit(bit)
+                        .expires_at(Uint64::from(EXPIRES_AT.to_le_bytes()))
+                        .signature(vec![0u8; 64])
+                        .build()
+                }),
+        )
+        .build();
+    UserData::new_builder()
+        .verification_data(
+            UserVerificationData::new_builder()
+                .verified_flags(verified_flags)
+                .attestations(attestations)
+                .build(),
+        )
+        .build()
+}
+
+// Move user 5 from `input_flags` to `output_flags`, spending a cell of `signer` if any, with
+// ENDORSER on the protocol whitelist
+fn update_verification(signer: Option<u8>, input_flags: u8, output_flags: u8) -> Result<Cycle, Error> {
+    // Signer locks hash the same in every deployment
+    let endorser = Deployment::new(&[]).lock_hash(ENDORSER);
+    let mut deployment = Deployment::new(&[endorser]);
+    let mut tx = deployment.user_update(
+        "CKBoostUser.update_user_verification",
+        USER,
+        PARTICIPANT,
+        &verified_user(input_flags),
+        &verified_user(output_flags),
+    );
+    if let Some(signer) = signer {
+        let signer_lock = deployment.lock(signer);
+        let signer_input = deployment.spend(&signer_lock, None, &[]);
+        tx = tx
+            .input(signer_input)
+            .output(cell(&signer_lock, None))
+            .output_data(Bytes::new().pack());
+    }
+    deployment.verify(tx.build())
+}
+
+#[test]
+fn test_endorser_revokes_verification() {
+    update_verification(Some(ENDORSER), VERIFICATION_TELEGRAM, 0).expect("whitelisted endorser clears the bit");
+}
+
+#[test]
+fn test_user_cannot_clear_own_verification() {
+    assert!(update_verification(None, VERIFICATION_TELEGRAM, 0).is_err());
+}
+
+#[test]
+fn test_unlisted_signer_cannot_revoke_verification() {
+    assert!(update_verification(Some(OUTSIDER), VERIFICATION_TELEGRAM, 0).is_err());
+}
+
+#[test]
+fn test_endorser_grants_verification() {
+    let granted = VERIFICATION_TELEGRAM | VERIFICATION_TWITTER;
+    update_verification(Some(ENDORSER), VERIFICATION_TELEGRAM, granted)
+        .expect("whitelisted endorser attests without a signature");
+}
+
+#[test]
+fn test_user_cannot_grant_unsigned_verification() {
+    // The new attestation is not signed by an attestor registered for the bit
+    let granted = VERIFICATION_TELEGRAM | VERIFICATION_TWITTER;
+    assert!(update_verification(None, VERIFICATION_TELEGRAM, granted).is_err());
+}
@@ -109,9 +109,16 @@ export default function CampaignAdminPage() {
     rewards_on_completion: [],
     accepted_submission_user_type_ids: [],
     completion_deadline: BigInt(Math.floor(Date.now() / 1000 + 30 * 24 * 60 * 60)),
-    status: 0,
+    status: 1,
     sub_tasks: [],
     completion_count: 0,
+    rejected_submissions: [],
+    max_resubmissions: 0,
+    pending_approvals: [],
+    prerequisite_quest_ids: [],
+    sub_task_approvals: [],
+    nft_awards: [],
+    approvals_root: "0x" + "00".repeat(32),
     initial_quota: 10
   })
 
@@ -261,6 +268,13 @@ export default function CampaignAdminPage() {
             status: quest.status,
             sub_tasks: quest.sub_tasks || [],
             completion_count: quest.completion_count,
+            rejected_submissions: quest.rejected_submissions || [],
+            max_resubmissions: quest.max_resubmissions || 0,
+            pending_approvals: quest.pending_approvals || [],
+            prerequisite_quest_ids: quest.prerequisite_quest_ids || [],
+            sub_task_approvals: quest.sub_task_approvals || [],
+            nft_awards: quest.nft_awards || [],
+            approvals_root: quest.approvals_root || ("0x" + "00".repeat(32)),
           }))
         : [] // Explicitly return empty array
 
@@ -308,7 +322,14 @@ export default function CampaignAdminPage() {
         status: (Number(campaign?.status) || 0) as ccc.NumLike,
         quests: validatedQuests,
         participants_count: (Number(campaign?.participants_count) || 0) as ccc.NumLike,
-        total_completions: (Number(campaign?.total_completions) || 0) as ccc.NumLike
+        total_completions: (Number(campaign?.total_completions) || 0) as ccc.NumLike,
+        approval_dispute_window: (Number(campaign?.approval_dispute_window) || 0) as ccc.NumLike,
+        badge_cluster_id: campaign?.badge_cluster_id ?? null,
+        managers: campaign?.managers || [],
+        quest_layout: (Number(campaign?.quest_layout) || 0) as ccc.NumLike,
+        approval_tracking: (Number(campaign?.approval_tracking) || 0) as ccc.NumLike,
+        next_quest_id: nextQuestId() as ccc.NumLike,
+        last_recorded_at: (campaign?.last_recorded_at ?? 0) as ccc.NumLike
       }
 
       // Get necessary code hashes from protocol data
@@ -468,6 +489,7 @@ export default function CampaignAdminPage() {
         })) || [],
         tipping_config: protocolData.tipping_config,
         endorsers_whitelist: protocolData.endorsers_whitelist || [],
+        attestors: protocolData.attestors || [],
         last_updated: Math.floor(Date.now() / 1000),
         protocol_config: protocolData.protocol_config
       }
@@ -523,18 +545,24 @@ export default function CampaignAdminPage() {
     }
   }
 
+  // Quest IDs are allocated upwards and never reused, even for removed quests
+  const nextQuestId = () => Math.max(
+    Number(campaign?.next_quest_id) || 1,
+    ...localQuests.map(quest => Number(quest.quest_id) + 1)
+  )
+
   // Quest handlers
   const handleAddQuest = () => {
     const newQuest: QuestDataLike = {
       ...questForm,
-      quest_id: localQuests.length + 1
+      quest_id: nextQuestId()
     }
     setLocalQuests([...localQuests, newQuest])
     debug.log("Added quest to local state:", newQuest)
     
     // Reset form for next quest
     setQuestForm({
-      quest_id: localQuests.length + 2,
+      quest_id: nextQuestId() + 1,
       metadata: {
         title: "",
         short_description: "",
@@ -547,9 +575,16 @@ export default function CampaignAdminPage() {
       rewards_on_completion: [],
       accepted_submission_user_type_ids: [],
       completion_deadline: BigInt(Math.floor(Date.now() / 1000 + 30 * 24 * 60 * 60)),
-      status: 0,
+      status: 1,
       sub_tasks: [],
       completion_count: 0,
+      rejected_submissions: [],
+      max_resubmissions: 0,
+      pending_approvals: [],
+      prerequisite_quest_ids: [],
+      sub_task_approvals: [],
+      nft_awards: [],
+      approvals_root: "0x" + "00".repeat(32),
       initial_quota: 10
     })
     setIsAddingQuest(false)
@@ -593,9 +628,16 @@ export default function CampaignAdminPage() {
       rewards_on_completion: [],
       accepted_submission_user_type_ids: [],
       completion_deadline: BigInt(Math.floor(Date.now() / 1000 + 30 * 24 * 60 * 60)),
-      status: 0,
+      status: 1,
       sub_tasks: [],
       completion_count: 0,
+      rejected_submissions: [],
+      max_resubmissions: 0,
+      pending_approvals: [],
+      prerequisite_quest_ids: [],
+      sub_task_approvals: [],
+      nft_awards: [],
+      approvals_root: "0x" + "00".repeat(32),
       initial_quota: 10
     })
   }
@@ -899,9 +941,16 @@ export default function CampaignAdminPage() {
               rewards_on_completion: [],
               accepted_submission_user_type_ids: [],
               completion_deadline: BigInt(Math.floor(Date.now() / 1000 + 30 * 24 * 60 * 60)),
-              status: 0,
+              status: 1,
               sub_tasks: [],
               completion_count: 0,
+              rejected_submissions: [],
+              max_resubmissions: 0,
+              pending_approvals: [],
+              prerequisite_quest_ids: [],
+              sub_task_approvals: [],
+              nft_awards: [],
+              approvals_root: "0x" + "00".repeat(32),
               initial_quota: 10
             })
           }
@@ -108,6 +108,9 @@ const updateScriptCodeHashesSchema = z.object({
   ckb_boost_points_udt_type_code_hash: z
     .string()
     .regex(/^0x[a-fA-F0-9]{64}$/, "Invalid code hash format"),
+  ckb_boost_quest_type_code_hash: z
+    .string()
+    .regex(/^0x[a-fA-F0-9]{64}$/, "Invalid code hash format"),
   accepted_udt_type_code_hashes: z
     .array(z.string().regex(/^0x[a-fA-F0-9]{64}$/, "Invalid code hash format"))
     .default([]),
@@ -222,6 +225,9 @@ export function ProtocolManagement() {
       ckb_boost_points_udt_type_code_hash:
         deploymentTemplate.protocol_config.script_code_hashes
           .ckb_boost_points_udt_type_code_hash,
+      ckb_boost_quest_type_code_hash:
+        deploymentTemplate.protocol_config.script_code_hashes
+          .ckb_boost_quest_type_code_hash,
       accepted_udt_type_scripts: [],
       accepted_dob_type_scripts: [],
     },
@@ -365,6 +371,9 @@ export function ProtocolManagement() {
               ckb_boost_points_udt_type_code_hash:
                 deploymentTemplate.protocol_config.script_code_hashes
                   .ckb_boost_points_udt_type_code_hash,
+              ckb_boost_quest_type_code_hash:
+                deploymentTemplate.protocol_config.script_code_hashes
+                  .ckb_boost_quest_type_code_hash,
               accepted_udt_type_scripts: deploymentTemplate.protocol_config.script_code_hashes.accepted_udt_type_scripts || [],
               accepted_dob_type_scripts: deploymentTemplate.protocol_config.script_code_hashes.accepted_dob_type_scripts || [],
             },
@@ -413,6 +422,9 @@ export function ProtocolManagement() {
         ckb_boost_points_udt_type_code_hash:
           protocolData.protocol_config.script_code_hashes
             .ckb_boost_points_udt_type_code_hash,
+        ckb_boost_quest_type_code_hash:
+          protocolData.protocol_config.script_code_hashes
+            .ckb_boost_quest_type_code_hash,
         accepted_udt_type_scripts: [],
         accepted_dob_type_scripts: [],
       };
@@ -553,6 +565,7 @@ export function ProtocolManagement() {
       scriptCodeHashesValues.ckb_boost_campaign_lock_code_hash === baselineValues.scriptCodeHashes.ckb_boost_campaign_lock_code_hash &&
       scriptCodeHashesValues.ckb_boost_user_type_code_hash === baselineValues.scriptCodeHashes.ckb_boost_user_type_code_hash &&
       scriptCodeHashesValues.ckb_boost_points_udt_type_code_hash === baselineValues.scriptCodeHashes.ckb_boost_points_udt_type_code_hash &&
+      scriptCodeHashesValues.ckb_boost_quest_type_code_hash === baselineValues.scriptCodeHashes.ckb_boost_quest_type_code_hash &&
       compareScriptArrays(scriptCodeHashesValues.accepted_udt_type_scripts, baselineValues.scriptCodeHashes.accepted_udt_type_scripts, 'accepted_udt_type_scripts') &&
       compareScriptArrays(scriptCodeHashesValues.accepted_dob_type_scripts, baselineValues.scriptCodeHashes.accepted_dob_type_scripts, 'accepted_dob_type_scripts');
     
@@ -994,6 +1007,9 @@ export function ProtocolManagement() {
           ckb_boost_points_udt_type_code_hash:
             protocolData.protocol_config.script_code_hashes
               .ckb_boost_points_udt_type_code_hash,
+          ckb_boost_quest_type_code_hash:
+            protocolData.protocol_config.script_code_hashes
+              .ckb_boost_quest_type_code_hash,
           accepted_udt_type_scripts:
             protocolData.protocol_config.script_code_hashes
               .accepted_udt_type_scripts || [],
@@ -1022,6 +1038,8 @@ export function ProtocolManagement() {
             deploymentTemplate.protocol_config.script_code_hashes.ckb_boost_user_type_code_hash,
           ckb_boost_points_udt_type_code_hash:
             deploymentTemplate.protocol_config.script_code_hashes.ckb_boost_points_udt_type_code_hash,
+          ckb_boost_quest_type_code_hash:
+            deploymentTemplate.protocol_config.script_code_hashes.ckb_boost_quest_type_code_hash,
           accepted_udt_type_scripts: [],
           accepted_dob_type_scripts: [],
         });
@@ -1178,6 +1196,7 @@ export function ProtocolManagement() {
           social_links: [],
           verified: 0n,
         })),
+        attestors: [],
         protocol_config: {
           admin_lock_hash_vec: finalAdminLockHashes || [],
           script_code_hashes: scriptCodeHashesValues,
@@ -154,6 +154,26 @@ export function ScriptCodeHashes({
                 </FormItem>
               )}
             />
+            <FormField
+              control={form.control}
+              name="ckb_boost_quest_type_code_hash"
+              render={({ field }) => (
+                <FormItem>
+                  <FormLabel>Quest Type Code Hash</FormLabel>
+                  <FormControl>
+                    <Input 
+                      placeholder="0x..." 
+                      {...field}
+                      value={field.value ? String(field.value) : ""}
+                    />
+                  </FormControl>
+                  <FormDescription>
+                    The code hash of the quest type script
+                  </FormDescription>
+                  <FormMessage />
+                </FormItem>
+              )}
+            />
 
             {/* UDT Script Manager for accepted UDT and DOB scripts */}
             <div className="space-y-4 border-t pt-4">
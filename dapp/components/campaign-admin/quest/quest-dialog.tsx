@@ -230,6 +230,13 @@ export function QuestDialog({
         }
       ],
       completion_count: 0,
+      rejected_submissions: [],
+      max_resubmissions: 0,
+      pending_approvals: [],
+      prerequisite_quest_ids: [],
+      sub_task_approvals: [],
+      nft_awards: [],
+      approvals_root: "0x" + "00".repeat(32),
       initial_quota: 10
     })
   }
@@ -133,6 +133,13 @@ export function CampaignForm({
         status: 0,
         participants_count: 0,
         total_completions: 0,
+        approval_dispute_window: 0,
+        badge_cluster_id: null,
+        managers: [],
+        quest_layout: 0,
+        approval_tracking: 0,
+        next_quest_id: 1,
+        last_recorded_at: 0,
         ...partialCampaignData
       } as CampaignDataLike
 
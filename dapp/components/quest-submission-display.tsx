@@ -8,6 +8,7 @@ import { CheckCircle, ExternalLink, Eye, Clock, RefreshCcw, AlertCircle } from "
 import { useUser } from "@/lib/providers/user-provider"
 import { useNostrFetch } from "@/hooks/use-nostr-fetch"
 import { ccc } from "@ckb-ccc/connector-react"
+import { ckboost } from "ssri-ckboost"
 import { debug } from "@/lib/utils/debug"
 import { NostrSubmissionData, isNostrSubmissionData } from "@/types/submission"
 
@@ -24,7 +25,7 @@ export function QuestSubmissionDisplay({
 }: QuestSubmissionDisplayProps) {
   const { currentUserTypeId, getUserSubmissions, refreshUserData } = useUser()
   const { fetchSubmission } = useNostrFetch()
-  const [userSubmission, setUserSubmission] = useState<{ submission_timestamp?: number; submission_content?: string } | null>(null)
+  const [userSubmission, setUserSubmission] = useState<{ submission_timestamp?: number; submission_content?: string; content_hash?: ccc.Hex } | null>(null)
   const [isViewDialogOpen, setIsViewDialogOpen] = useState(false)
   const [submissionContent, setSubmissionContent] = useState<string>("")
   const [isLoadingContent, setIsLoadingContent] = useState(false)
@@ -51,7 +52,8 @@ export function QuestSubmissionDisplay({
         })
         setUserSubmission({
           submission_timestamp: Number(submission.submission_timestamp),
-          submission_content: submission.submission_content
+          submission_content: submission.submission_content,
+          content_hash: ccc.hexFrom(submission.content_hash)
         })
       } else {
         setUserSubmission(null)
@@ -86,7 +88,11 @@ export function QuestSubmissionDisplay({
       if (userSubmission.submission_content?.startsWith('nevent1')) {
         // Fetch from Nostr
         const nostrData = await fetchSubmission(userSubmission.submission_content)
-        if (nostrData) {
+        const contentHash = userSubmission.content_hash
+        const isHashed = !!contentHash && contentHash !== "0x" + "00".repeat(32)
+        if (nostrData && isHashed && ckboost.User.hashSubmissionContent(nostrData.content) !== contentHash) {
+          setSubmissionContent("Content on Nostr does not match the on-chain hash. Event ID: " + userSubmission.submission_content)
+        } else if (nostrData) {
           setSubmissionContent(nostrData.content)
         } else {
           setSubmissionContent("Failed to load content from Nostr. Event ID: " + userSubmission.submission_content)
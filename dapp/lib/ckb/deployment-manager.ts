@@ -6,7 +6,7 @@ import { ccc } from '@ckb-ccc/connector-react'
 
 export type Network = 'testnet' | 'mainnet'
 
-export type ContractType = 'ckboostProtocolType' | 'ckboostCampaignType' | 'ckboostUserType' | 'ckboostProtocolLock' | 'ckboostCampaignLock' | 'ckboostUserLock' | 'ckboostPointsUdt'
+export type ContractType = 'ckboostProtocolType' | 'ckboostCampaignType' | 'ckboostUserType' | 'ckboostProtocolLock' | 'ckboostCampaignLock' | 'ckboostUserLock' | 'ckboostPointsUdt' | 'ckboostQuestType'
 
 export interface DeploymentRecord {
   transactionHash: ccc.Hex
@@ -39,6 +39,7 @@ export interface DeploymentHistory {
       ckboostCampaignLock: DeploymentRecord | null
       ckboostUserLock: DeploymentRecord | null
       ckboostPointsUdt: DeploymentRecord | null
+      ckboostQuestType?: DeploymentRecord | null
       // Add other contract types as needed
     }
     mainnet: {
@@ -49,6 +50,7 @@ export interface DeploymentHistory {
       ckboostCampaignLock?: DeploymentRecord | null
       ckboostUserLock?: DeploymentRecord | null
       ckboostPointsUdt?: DeploymentRecord | null
+      ckboostQuestType?: DeploymentRecord | null
       // Add other contract types as needed
     }
   }
@@ -153,6 +153,7 @@ export function getProtocolDeploymentTemplate(): ProtocolDataLike {
   const campaignLockHash = deploymentManager.getContractCodeHash(network, "ckboostCampaignLock");
   const userTypeHash = deploymentManager.getContractCodeHash(network, "ckboostUserType");
   const pointsUdtHash = deploymentManager.getContractCodeHash(network, "ckboostPointsUdt");
+  const questTypeHash = deploymentManager.getContractCodeHash(network, "ckboostQuestType");
 
   return {
     protocol_config: {
@@ -177,6 +178,9 @@ export function getProtocolDeploymentTemplate(): ProtocolDataLike {
         ckb_boost_points_udt_type_code_hash:
           pointsUdtHash ||
           "0x0000000000000000000000000000000000000000000000000000000000000000",
+        ckb_boost_quest_type_code_hash:
+          questTypeHash ||
+          "0x0000000000000000000000000000000000000000000000000000000000000000",
         accepted_udt_type_scripts: [],
         accepted_dob_type_scripts: [],
       },
@@ -193,6 +197,7 @@ export function getProtocolDeploymentTemplate(): ProtocolDataLike {
       expiration_duration: 2592000, // 30 days in seconds
     },
     endorsers_whitelist: [],
+    attestors: [],
     last_updated: 0,
   };
 }
@@ -593,6 +598,7 @@ export function validateDeploymentParams(
     "ckb_boost_campaign_lock_code_hash",
     "ckb_boost_user_type_code_hash",
     "ckb_boost_points_udt_type_code_hash",
+    "ckb_boost_quest_type_code_hash",
   ] as const;
 
   scriptFields.forEach((field) => {
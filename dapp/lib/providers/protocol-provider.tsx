@@ -361,6 +361,7 @@ export function ProtocolProvider({ children }: { children: ReactNode }) {
         ckb_boost_campaign_lock_code_hash: string;
         ckb_boost_user_type_code_hash: string;
         ckb_boost_points_udt_type_code_hash: string;
+        ckb_boost_quest_type_code_hash: string;
         accepted_udt_type_scripts: ccc.ScriptLike[];
         accepted_dob_type_scripts: ccc.ScriptLike[];
       };
@@ -459,6 +460,11 @@ export function ProtocolProvider({ children }: { children: ReactNode }) {
           scriptCodeHashes.ckb_boost_points_udt_type_code_hash,
           data.scriptCodeHashes.ckb_boost_points_udt_type_code_hash
         ),
+        ckbBoostQuestTypeCodeHash: createFieldChange(
+          'scriptCodeHashes.ckbBoostQuestTypeCodeHash',
+          scriptCodeHashes.ckb_boost_quest_type_code_hash,
+          data.scriptCodeHashes.ckb_boost_quest_type_code_hash
+        ),
         acceptedUdtTypeScripts: createFieldChange(
           'scriptCodeHashes.acceptedUdtTypeScripts',
           scriptCodeHashes.accepted_udt_type_scripts || [],
@@ -246,7 +246,11 @@ export function UserProvider({ children }: { children: React.ReactNode }) {
       campaign_type_id: s.campaignTypeId as ccc.Hex,
       quest_id: s.questId,
       submission_timestamp: BigInt(s.timestamp),
-      submission_content: s.submissionContent
+      submission_content: s.submissionContent,
+      sub_task_submissions: [],
+      content_commitment: s.contentCommitment,
+      content_salt: s.contentSalt,
+      content_hash: s.contentHash
     }));
   };
 
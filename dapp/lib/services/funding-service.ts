@@ -7,8 +7,8 @@ import { sendTransactionWithFeeRetry } from "../ckb/transaction-wrapper";
 import { udtRegistry } from "./udt-registry";
 
 /**
- * Service for managing campaign funding with UDTs
- * Allows anyone to fund campaigns with various UDT types
+ * Service for managing campaign funding with UDTs and NFTs
+ * Allows anyone to fund campaigns with various UDT types and spore/DOB NFTs
  */
 export class FundingService {
   private signer: ccc.Signer;
@@ -333,6 +333,67 @@ export class FundingService {
     return tx;
   }
 
+  /**
+   * Add NFT (spore/DOB) funding to an existing transaction
+   * Each NFT cell is moved from the signer's wallet to the campaign lock as-is
+   * @param tx - The transaction to add NFT transfers to
+   * @param campaignOwnerLock - The lock the NFTs are escrowed under
+   * @param nftScripts - Type scripts of the NFT cells to fund
+   */
+  async addNFTFundingToTransaction(
+    tx: ccc.Transaction,
+    campaignOwnerLock: ccc.Script,
+    nftScripts: ccc.ScriptLike[]
+  ): Promise<ccc.Transaction> {
+    debug.log("Adding NFT funding for", nftScripts.length, "NFTs to existing transaction");
+
+    if (!nftScripts || nftScripts.length === 0) {
+      debug.log("No NFTs to fund, returning transaction unchanged");
+      return tx;
+    }
+
+    const addresses = await this.signer.getAddresses();
+    if (addresses.length === 0) {
+      throw new Error("No address available to fund NFTs from");
+    }
+    const { script: userLock } = await ccc.Address.fromString(addresses[0], this.signer.client);
+
+    for (const nftScriptLike of nftScripts) {
+      const nftScript = ccc.Script.from(nftScriptLike);
+
+      let nftCell: ccc.Cell | undefined;
+      for await (const cell of this.signer.client.findCellsByLock(userLock, nftScript, true)) {
+        if (cell.cellOutput.type?.eq(nftScript)) {
+          nftCell = cell;
+          break;
+        }
+      }
+      if (!nftCell) {
+        throw new Error(`NFT ${nftScript.args.slice(0, 10)}... not found in your wallet`);
+      }
+
+      tx.addInput(nftCell);
+      tx.addOutput(
+        {
+          lock: campaignOwnerLock,
+          type: nftCell.cellOutput.type,
+        },
+        nftCell.outputData
+      );
+
+      debug.log("NFT transfer added to transaction:", {
+        nft: nftScript.hash().slice(0, 10) + "...",
+        to: campaignOwnerLock.args.slice(0, 10) + "..."
+      });
+    }
+
+    // Spore cells need the spore script as a cell dep to be transferred
+    const sporeScript = await this.signer.client.getKnownScript(ccc.KnownScript.Spore);
+    await tx.addCellDepInfos(this.signer.client, sporeScript.cellDeps);
+
+    return tx;
+  }
+
   /**
    * Fund a campaign with UDT assets (standalone transaction)
    * Use this when funding an existing campaign separately
@@ -404,6 +465,50 @@ export class FundingService {
     }
   }
 
+  /**
+   * Fund a campaign with NFT rewards (standalone transaction)
+   * The NFTs are escrowed under the campaign lock until approvals hand them out
+   */
+  async fundCampaignWithNFTs(
+    campaignTypeId: ccc.Hex,
+    nftScripts: ccc.ScriptLike[]
+  ): Promise<string> {
+    debug.log("Funding campaign", campaignTypeId, "with NFTs:", nftScripts);
+
+    try {
+      const campaignCell = await fetchCampaignByTypeId(
+        campaignTypeId,
+        this.campaignTypeCodeHash,
+        this.signer.client,
+        this.protocolCell
+      );
+      if (!campaignCell || !campaignCell.cellOutput.type) {
+        throw new Error("Campaign not found");
+      }
+
+      // The campaign lock takes the campaign type hash as args
+      const campaignLock = ccc.Script.from({
+        codeHash: this.campaignLockCodeHash,
+        hashType: "type",
+        args: campaignCell.cellOutput.type.hash(),
+      });
+
+      let tx = ccc.Transaction.from({});
+      tx = await this.addNFTFundingToTransaction(tx, campaignLock, nftScripts);
+
+      await tx.completeInputsByCapacity(this.signer);
+      await tx.completeFeeBy(this.signer);
+
+      const txHash = await sendTransactionWithFeeRetry(this.signer, tx);
+
+      debug.log("Campaign funded with NFTs. Transaction:", txHash);
+      return txHash;
+    } catch (error) {
+      debug.error("Failed to fund campaign with NFTs:", error);
+      throw error;
+    }
+  }
+
   /**
    * Check if a campaign has sufficient funding for its rewards
    */
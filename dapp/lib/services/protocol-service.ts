@@ -164,6 +164,7 @@ export class ProtocolService {
             expiration_duration: defaultTimestamp, // Uint64 as bigint
           },
           endorsers_whitelist: [],
+          attestors: [],
           last_updated: defaultTimestamp, // Uint64 as bigint
           protocol_config: {
             admin_lock_hash_vec: [],
@@ -174,6 +175,7 @@ export class ProtocolService {
               ckb_boost_campaign_lock_code_hash: defaultByte32,
               ckb_boost_user_type_code_hash: defaultByte32,
               ckb_boost_points_udt_type_code_hash: defaultByte32,
+              ckb_boost_quest_type_code_hash: defaultByte32,
               accepted_udt_type_scripts: [],
               accepted_dob_type_scripts: [],
             },
@@ -17,6 +17,10 @@ import { debug } from "../utils/debug";
  * User service that provides high-level user operations
  * Handles user cell creation, quest submissions, and Nostr storage integration
  */
+function pendingRevealKey(userTypeId: ccc.Hex, campaignTypeId: ccc.Hex, questId: number): string {
+  return `ckboost:pending-reveal:${userTypeId}:${campaignTypeId}:${questId}`;
+}
+
 export class UserService {
   private signer: ccc.Signer;
   private userInstance: ckboost.User | null = null;
@@ -137,6 +141,7 @@ export class UserService {
     neventId?: string; // Only present if using Nostr storage
   }> {
     let contentToStore: string;
+    let contentHash: ccc.Hex | undefined;
     let neventId: string | undefined;
 
     // Check if the submissionContent is already a nevent ID
@@ -175,9 +180,10 @@ export class UserService {
         // Continue without Nostr storage
       }
       
-      // Store only the nevent ID on-chain (much smaller)
+      // Store only the nevent URI and the content hash on-chain (much smaller)
       if (neventId) {
-        contentToStore = neventId;
+        contentToStore = "nostr:" + neventId;
+        contentHash = ckboost.User.hashSubmissionContent(submissionContent);
         debug.log("Using Nostr reference for on-chain storage", {
           originalSize: submissionContent.length,
           storedSize: neventId.length,
@@ -219,7 +225,8 @@ export class UserService {
         questId,
         contentToStore,
         existingUserData.typeId,
-        protocolCell
+        protocolCell,
+        contentHash
       );
     } else {
       // Create new user with submission
@@ -236,7 +243,8 @@ export class UserService {
         questId,
         contentToStore,
         protocolCell,
-        userVerificationData
+        userVerificationData,
+        contentHash
       );
     }
 
@@ -285,6 +293,9 @@ export class UserService {
     questId: number;
     timestamp: number;
     submissionContent: string;
+    contentCommitment: ccc.Hex;
+    contentSalt: ccc.Hex;
+    contentHash: ccc.Hex;
     neventId?: string;
     content?: string;
     isFromNostr: boolean;
@@ -362,6 +373,9 @@ export class UserService {
           questId: Number(record.quest_id),
           timestamp: Number(record.submission_timestamp),
           submissionContent: record.submission_content,
+          contentCommitment: ccc.hexFrom(record.content_commitment),
+          contentSalt: ccc.hexFrom(record.content_salt),
+          contentHash: ccc.hexFrom(record.content_hash),
           neventId: undefined as string | undefined,
           content: undefined as string | undefined,
           isFromNostr: false
@@ -392,6 +406,12 @@ export class UserService {
           debug.log("Failed to decode submission content from hex", e);
         }
         
+        // Content-hash mode records reference the Nostr event by URI
+        if (decodedContent.startsWith('nostr:')) {
+          decodedContent = decodedContent.slice('nostr:'.length);
+          submissionData.submissionContent = decodedContent;
+        }
+
         if (decodedContent.startsWith('nevent1')) {
           submissionData.neventId = decodedContent;
           submissionData.content = decodedContent; // Actual content should be fetched via React hook
@@ -416,7 +436,8 @@ export class UserService {
     questId: number,
     submissionContent: string, // Could be nevent ID or actual content
     userTypeId: ccc.Hex,
-    protocolCell: ccc.Cell
+    protocolCell: ccc.Cell,
+    contentHash?: ccc.Hex // Set when the content is kept off-chain behind a URI
   ): Promise<ccc.Hex> {
     // Ensure deployment info is loaded
     await this.ensureDeploymentInfo();
@@ -468,23 +489,22 @@ export class UserService {
       fullLength: submissionContent.length
     });
     
+    // Only a commitment goes on-chain now, the content is revealed in a later transaction
+    const salt = ckboost.User.generateSubmissionSalt();
     const newSubmissionBytes = ckboost.User.createSubmissionRecord(
       campaignTypeId,
       questId,
-      submissionContent // This could be nevent ID or actual content
+      ckboost.User.createSubmissionCommitment(userTypeId, campaignTypeId, questId, salt, submissionContent, contentHash)
     );
+    this.storePendingReveal(userTypeId, campaignTypeId, questId, salt, submissionContent, contentHash);
     
     const newSubmission = ckboost.types.UserSubmissionRecord.decode(newSubmissionBytes);
 
-    // Update or add submission
-    const updatedSubmissions = [...currentUserData.submission_records];
+    // Submission records are append-only, a resubmission adds a new record
+    const updatedSubmissions = [...currentUserData.submission_records, newSubmission];
     if (existingSubmissionIndex >= 0) {
-      // Update existing submission (resubmission case)
-      updatedSubmissions[existingSubmissionIndex] = newSubmission;
-      debug.log("Updating existing submission at index", existingSubmissionIndex);
+      debug.log("Appending resubmission of the record at index", existingSubmissionIndex);
     } else {
-      // Add new submission
-      updatedSubmissions.push(newSubmission);
       debug.log("Adding new submission to user");
     }
 
@@ -493,7 +513,9 @@ export class UserService {
       verification_data: currentUserData.verification_data,
       total_points_earned: currentUserData.total_points_earned,
       last_activity_timestamp: BigInt(Date.now()),
-      submission_records: updatedSubmissions
+      submission_records: updatedSubmissions,
+      archived_submission_count: currentUserData.archived_submission_count,
+      archived_submissions_hash: currentUserData.archived_submissions_hash
     };
 
     // Create executor for SSRI operations
@@ -561,7 +583,8 @@ export class UserService {
       email?: string;
       twitter?: string;
       discord?: string;
-    }
+    },
+    contentHash?: ccc.Hex // Set when the content is kept off-chain behind a URI
   ): Promise<ccc.Hex> {
     // Ensure deployment info is loaded
     await this.ensureDeploymentInfo();
@@ -581,7 +604,9 @@ export class UserService {
     // Create user verification data
     const userVerificationDataStruct = {
       telegram_personal_chat_id: 0n,
-      identity_verification_data: ccc.bytesFrom(identityData, "utf8")
+      identity_verification_data: ccc.bytesFrom(identityData, "utf8"),
+      verified_flags: 0,
+      attestations: []
     };
 
     // Create new submission record
@@ -593,11 +618,23 @@ export class UserService {
       fullLength: submissionContent.length
     });
     
+    // For creation, the contract needs at least one input to calculate type ID
+    // Create a base transaction with at least one input
+    const baseTx = ccc.Transaction.from({});
+    
+    // Add at least one input for capacity (required for type ID calculation)
+    // The contract will use the first input to calculate the type ID
+    await baseTx.completeInputsAtLeastOne(this.signer);
+    
+    // The commitment is bound to the new user's type ID, derived the same way as the contract
+    const newUserTypeId = ccc.hashTypeId(baseTx.inputs[0], baseTx.outputs.length);
+    const salt = ckboost.User.generateSubmissionSalt();
     const newSubmissionBytes = ckboost.User.createSubmissionRecord(
       campaignTypeId,
       questId,
-      submissionContent // This could be nevent ID or actual content
+      ckboost.User.createSubmissionCommitment(newUserTypeId, campaignTypeId, questId, salt, submissionContent, contentHash)
     );
+    this.storePendingReveal(newUserTypeId, campaignTypeId, questId, salt, submissionContent, contentHash);
     const newSubmission = ckboost.types.UserSubmissionRecord.decode(newSubmissionBytes);
 
     // Create initial user data with the submission
@@ -605,7 +642,9 @@ export class UserService {
       verification_data: userVerificationDataStruct,
       total_points_earned: 0,
       last_activity_timestamp: BigInt(Date.now()),
-      submission_records: [newSubmission]
+      submission_records: [newSubmission],
+      archived_submission_count: 0,
+      archived_submissions_hash: "0x" + "00".repeat(32)
     };
 
     // Now that the contract's submit_quest handles both creation and update,
@@ -627,14 +666,6 @@ export class UserService {
       { executor } // Pass the executor in config
     );
 
-    // For creation, the contract needs at least one input to calculate type ID
-    // Create a base transaction with at least one input
-    const baseTx = ccc.Transaction.from({});
-    
-    // Add at least one input for capacity (required for type ID calculation)
-    // The contract will use the first input to calculate the type ID
-    await baseTx.completeInputsAtLeastOne(this.signer);
-    
     // Build transaction using SSRI - the contract will handle creation
     const result = await userInstanceForCreation.submitQuest(
       this.signer,
@@ -704,6 +735,117 @@ export class UserService {
     return txHash;
   }
 
+  /**
+   * Reveal the content of the latest committed submission for a quest
+   * Must run after the commitment transaction is committed
+   */
+  async revealSubmission(campaignTypeId: ccc.Hex, questId: number): Promise<ccc.Hex> {
+    await this.ensureDeploymentInfo();
+
+    const userTypeId = await this.getCurrentUserTypeId();
+    if (!userTypeId) {
+      throw new Error("User cell not found");
+    }
+    const pending = this.loadPendingReveal(userTypeId, campaignTypeId, questId);
+    if (!pending) {
+      throw new Error("No pending submission content stored for this quest");
+    }
+
+    const userCell = await fetchUserByTypeId(
+      userTypeId,
+      this.userTypeCodeHash,
+      this.signer,
+      this.protocolTypeHash
+    );
+    const currentUserData = userCell ? parseUserData(userCell) : null;
+    if (!userCell || !currentUserData) {
+      throw new Error("Failed to load user data");
+    }
+
+    // Reveal the most recent record of the quest, which must still be committed
+    let index = -1;
+    currentUserData.submission_records.forEach((record, i) => {
+      if (ccc.hexFrom(record.campaign_type_id) === campaignTypeId && Number(record.quest_id) === questId) {
+        index = i;
+      }
+    });
+    if (index === -1 || currentUserData.submission_records[index].submission_content !== "") {
+      throw new Error("No committed submission to reveal for this quest");
+    }
+
+    const executorUrl = process.env.NEXT_PUBLIC_SSRI_EXECUTOR_URL || "http://localhost:9090";
+    const executor = new ssri.ExecutorJsonRpc(executorUrl);
+    const userInstanceWithScript = new ckboost.User(
+      this.userTypeCodeCell!,
+      ccc.Script.from({
+        codeHash: this.userTypeCodeHash,
+        hashType: "type",
+        args: userCell.cellOutput.type?.args || ""
+      }),
+      { executor }
+    );
+
+    const result = await userInstanceWithScript.revealSubmission(
+      this.signer,
+      currentUserData,
+      index,
+      pending.salt,
+      pending.content,
+      undefined,
+      pending.contentHash
+    );
+    const revealTx = result.res;
+    await revealTx.completeInputsByCapacity(this.signer);
+    await revealTx.completeFeeBy(this.signer);
+
+    const txHash = await this.signer.sendTransaction(revealTx);
+    this.clearPendingReveal(userTypeId, campaignTypeId, questId);
+    debug.log("Submission revealed", {
+      txHash: txHash.slice(0, 10) + "...",
+      questId
+    });
+
+    return txHash;
+  }
+
+  /**
+   * Keep the salt and content of a committed submission until it is revealed
+   */
+  private storePendingReveal(
+    userTypeId: ccc.Hex,
+    campaignTypeId: ccc.Hex,
+    questId: number,
+    salt: ccc.Hex,
+    content: string,
+    contentHash?: ccc.Hex
+  ): void {
+    if (typeof window === "undefined") {
+      return;
+    }
+    window.localStorage.setItem(
+      pendingRevealKey(userTypeId, campaignTypeId, questId),
+      JSON.stringify({ salt, content, contentHash })
+    );
+  }
+
+  private loadPendingReveal(
+    userTypeId: ccc.Hex,
+    campaignTypeId: ccc.Hex,
+    questId: number
+  ): { salt: ccc.Hex; content: string; contentHash?: ccc.Hex } | null {
+    if (typeof window === "undefined") {
+      return null;
+    }
+    const stored = window.localStorage.getItem(pendingRevealKey(userTypeId, campaignTypeId, questId));
+    return stored ? JSON.parse(stored) : null;
+  }
+
+  private clearPendingReveal(userTypeId: ccc.Hex, campaignTypeId: ccc.Hex, questId: number): void {
+    if (typeof window !== "undefined") {
+      window.localStorage.removeItem(pendingRevealKey(userTypeId, campaignTypeId, questId));
+    }
+  }
+
   /**
    * Helper method to get user by lock hash
    */
@@ -65,6 +65,7 @@ export interface ProtocolChanges {
     ckbBoostCampaignLockCodeHash: FieldChange<string>
     ckbBoostUserTypeCodeHash: FieldChange<string>
     ckbBoostPointsUdtTypeCodeHash: FieldChange<string>
+    ckbBoostQuestTypeCodeHash: FieldChange<string>
     acceptedUdtTypeScripts: FieldChange<ScriptLike[]>
     acceptedDobTypeScripts: FieldChange<ScriptLike[]>
   }
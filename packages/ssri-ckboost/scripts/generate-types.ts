@@ -264,6 +264,7 @@ function getLikeFieldType(type: string): string {
   if (type === 'CellDepVec') return 'ccc.CellDepLike[]';
   if (type === 'CellInputVec') return 'ccc.CellInputLike[]';
   if (type === 'CellOutputVec') return 'ccc.CellOutputLike[]';
+  if (type === 'Uint32Vec') return 'ccc.NumLike[]';
   if (type === 'Uint128Vec') return 'ccc.NumLike[]';
   
   // Option types that wrap Vec types
@@ -305,6 +306,7 @@ function getCodecReference(type: string): string {
   if (type === 'BytesVec') return 'mol.BytesVec';
   if (type === 'Byte32Vec') return 'mol.Byte32Vec';
   if (type === 'Byte32Opt') return 'mol.Byte32Opt';
+  if (type === 'Uint32Vec') return 'mol.Uint32Vec';
   if (type === 'Uint128Vec') return 'mol.Uint128Vec';
   if (type === 'String') return 'mol.String';
   // CKB types from ccc
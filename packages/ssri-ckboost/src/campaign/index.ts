@@ -213,9 +213,24 @@ export class Campaign extends ssri.Trait {
         const connectedTypeIdHex = ccc.hexFrom(connectedTypeIdBytes);
 
         // Update the campaign cell's type script args with the ConnectedTypeID
-        if (resTx.res.outputs[campaignCellOutputIndex].type) {
-          resTx.res.outputs[campaignCellOutputIndex].type.args =
-            connectedTypeIdHex;
+        const campaignCellType = resTx.res.outputs[campaignCellOutputIndex].type;
+        if (campaignCellType) {
+          campaignCellType.args = connectedTypeIdHex;
+
+          // Lock the campaign cell with the campaign lock bound to its final type hash, so
+          // managers unlock it through the campaign type script's role checks
+          const { ProtocolData } = await import("../generated");
+          const protocolData = ProtocolData.decode(
+            this.connectedProtocolCell.outputData
+          );
+          resTx.res.outputs[campaignCellOutputIndex].lock = ccc.Script.from({
+            codeHash: ccc.hexFrom(
+              protocolData.protocol_config.script_code_hashes
+                .ckb_boost_campaign_lock_code_hash
+            ),
+            hashType: "type",
+            args: campaignCellType.hash(),
+          });
         }
 
         // Add the protocol cell as a dependency
@@ -547,7 +562,7 @@ export class Campaign extends ssri.Trait {
           // The iterator returns {done: boolean, value: Cell}
           if (!userCellResult || userCellResult.done || !userCellResult.value) {
             console.warn(
-              `❌ User cell not found for type ID: ${userTypeIdHex}, skipping Points minting`
+              `❌ User cell not found for type ID: ${userTypeIdHex}`
             );
             console.warn(`Search criteria used:`, {
               codeHash: userTypeCodeHash,
@@ -559,7 +574,10 @@ export class Campaign extends ssri.Trait {
               typeId: userTypeIdHex,
               connectedKey: protocolTypeHash,
             });
-            continue;
+            // Points UDT only accepts mints that pay every approved user
+            throw new Error(
+              `User cell not found for type ID ${userTypeIdHex}, cannot mint their points`
+            );
           }
 
           const userCell = userCellResult.value;
@@ -65,6 +65,32 @@ export const QuestMetadata = mol.table({
   difficulty: mol.Uint8,
   time_estimate: mol.Uint32
 });
+export const SubmissionRejection = mol.table({
+  user_type_id: mol.Byte32,
+  reason_code: mol.Uint32,
+  rejection_count: mol.Uint32
+});
+export const SubmissionRejectionVec = mol.vector(SubmissionRejection);
+export const ApprovalRecord = mol.table({
+  user_type_id: mol.Byte32,
+  approved_at: mol.Uint64
+});
+export const ApprovalRecordVec = mol.vector(ApprovalRecord);
+export const SubTaskApproval = mol.table({
+  user_type_id: mol.Byte32,
+  approved_sub_task_ids: mol.Uint8Vec
+});
+export const SubTaskApprovalVec = mol.vector(SubTaskApproval);
+export const SporeData = mol.table({
+  content_type: mol.Bytes,
+  content: mol.Bytes,
+  cluster_id: mol.BytesOpt
+});
+export const NftAward = mol.table({
+  user_type_id: mol.Byte32,
+  nft_script: ccc.Script
+});
+export const NftAwardVec = mol.vector(NftAward);
 export const QuestData = mol.table({
   quest_id: mol.Uint32,
   metadata: QuestMetadata,
@@ -74,7 +100,14 @@ export const QuestData = mol.table({
   status: mol.Uint8,
   sub_tasks: QuestSubTaskDataVec,
   points: mol.Uint128,
-  completion_count: mol.Uint32
+  completion_count: mol.Uint32,
+  rejected_submissions: SubmissionRejectionVec,
+  max_resubmissions: mol.Uint32,
+  pending_approvals: ApprovalRecordVec,
+  prerequisite_quest_ids: mol.Uint32Vec,
+  sub_task_approvals: SubTaskApprovalVec,
+  nft_awards: NftAwardVec,
+  approvals_root: mol.Byte32
 });
 export const QuestDataVec = mol.vector(QuestData);
 export const EndorserInfo = mol.table({
@@ -98,6 +131,11 @@ export const CampaignMetadata = mol.table({
   difficulty: mol.Uint8,
   image_url: mol.String
 });
+export const CampaignManager = mol.table({
+  lock_hash: mol.Byte32,
+  roles: mol.Uint8
+});
+export const CampaignManagerVec = mol.vector(CampaignManager);
 export const CampaignData = mol.table({
   endorser: EndorserInfo,
   created_at: mol.Uint64,
@@ -108,9 +146,21 @@ export const CampaignData = mol.table({
   status: mol.Uint8,
   quests: QuestDataVec,
   participants_count: mol.Uint32,
-  total_completions: mol.Uint32
+  total_completions: mol.Uint32,
+  approval_dispute_window: mol.Uint64,
+  badge_cluster_id: mol.Byte32Opt,
+  managers: CampaignManagerVec,
+  quest_layout: mol.Uint8,
+  approval_tracking: mol.Uint8,
+  next_quest_id: mol.Uint32,
+  last_recorded_at: mol.Uint64
 });
 export const CampaignDataVec = mol.vector(CampaignData);
+export const ApprovalProof = mol.table({
+  quest_id: mol.Uint32,
+  user_type_ids: mol.Byte32Vec,
+  proof: mol.Bytes
+});
 export const TippingProposalMetadata = mol.table({
   contribution_title: mol.String,
   contribution_type_tags: mol.vector(mol.String),
@@ -138,36 +188,63 @@ export const ScriptCodeHashes = mol.table({
   ckb_boost_user_type_code_hash: mol.Byte32,
   ckb_boost_points_udt_type_code_hash: mol.Byte32,
   accepted_udt_type_scripts: mol.vector(ccc.Script),
-  accepted_dob_type_scripts: mol.vector(ccc.Script)
+  accepted_dob_type_scripts: mol.vector(ccc.Script),
+  ckb_boost_quest_type_code_hash: mol.Byte32
 });
 export const ProtocolConfig = mol.table({
   admin_lock_hash_vec: mol.Byte32Vec,
   script_code_hashes: ScriptCodeHashes
 });
+export const AttestorInfo = mol.table({
+  verification_bit: mol.Uint8,
+  pubkey: mol.Bytes,
+  name: mol.String
+});
+export const AttestorInfoVec = mol.vector(AttestorInfo);
 export const ProtocolData = mol.table({
   campaigns_approved: mol.Byte32Vec,
   tipping_proposals: TippingProposalDataVec,
   tipping_config: TippingConfig,
   endorsers_whitelist: EndorserInfoVec,
   last_updated: mol.Uint64,
-  protocol_config: ProtocolConfig
+  protocol_config: ProtocolConfig,
+  attestors: AttestorInfoVec
 });
+export const VerificationAttestation = mol.table({
+  verification_bit: mol.Uint8,
+  expires_at: mol.Uint64,
+  signature: mol.Bytes
+});
+export const VerificationAttestationVec = mol.vector(VerificationAttestation);
 export const UserVerificationData = mol.table({
   telegram_personal_chat_id: mol.Uint128,
-  identity_verification_data: mol.Bytes
+  identity_verification_data: mol.Bytes,
+  verified_flags: mol.Uint8,
+  attestations: VerificationAttestationVec
+});
+export const SubTaskSubmission = mol.table({
+  sub_task_id: mol.Uint8,
+  submission_content: mol.String
 });
+export const SubTaskSubmissionVec = mol.vector(SubTaskSubmission);
 export const UserSubmissionRecord = mol.table({
   campaign_type_id: mol.Byte32,
   quest_id: mol.Uint32,
   submission_timestamp: mol.Uint64,
-  submission_content: mol.String
+  submission_content: mol.String,
+  sub_task_submissions: SubTaskSubmissionVec,
+  content_commitment: mol.Byte32,
+  content_salt: mol.Byte32,
+  content_hash: mol.Byte32
 });
 export const UserSubmissionRecordVec = mol.vector(UserSubmissionRecord);
 export const UserData = mol.table({
   verification_data: UserVerificationData,
   total_points_earned: mol.Uint128,
   last_activity_timestamp: mol.Uint64,
-  submission_records: UserSubmissionRecordVec
+  submission_records: UserSubmissionRecordVec,
+  archived_submission_count: mol.Uint32,
+  archived_submissions_hash: mol.Byte32
 });
 export const ConnectedTypeID = mol.table({
   type_id: mol.Byte32,
@@ -243,6 +320,33 @@ export interface QuestMetadataLike {
   time_estimate: ccc.NumLike;
 }
 
+export interface SubmissionRejectionLike {
+  user_type_id: ccc.HexLike;
+  reason_code: ccc.NumLike;
+  rejection_count: ccc.NumLike;
+}
+
+export interface ApprovalRecordLike {
+  user_type_id: ccc.HexLike;
+  approved_at: ccc.NumLike;
+}
+
+export interface SubTaskApprovalLike {
+  user_type_id: ccc.HexLike;
+  approved_sub_task_ids: ccc.NumLike[];
+}
+
+export interface SporeDataLike {
+  content_type: ccc.BytesLike;
+  content: ccc.BytesLike;
+  cluster_id: ccc.BytesLike | null;
+}
+
+export interface NftAwardLike {
+  user_type_id: ccc.HexLike;
+  nft_script: ccc.ScriptLike;
+}
+
 export interface QuestDataLike {
   quest_id: ccc.NumLike;
   metadata: QuestMetadataLike;
@@ -253,6 +357,13 @@ export interface QuestDataLike {
   sub_tasks: QuestSubTaskDataLike[];
   points: ccc.NumLike;
   completion_count: ccc.NumLike;
+  rejected_submissions: SubmissionRejectionLike[];
+  max_resubmissions: ccc.NumLike;
+  pending_approvals: ApprovalRecordLike[];
+  prerequisite_quest_ids: ccc.NumLike[];
+  sub_task_approvals: SubTaskApprovalLike[];
+  nft_awards: NftAwardLike[];
+  approvals_root: ccc.HexLike;
 }
 
 export interface EndorserInfoLike {
@@ -277,6 +388,11 @@ export interface CampaignMetadataLike {
   image_url: string;
 }
 
+export interface CampaignManagerLike {
+  lock_hash: ccc.HexLike;
+  roles: ccc.NumLike;
+}
+
 export interface CampaignDataLike {
   endorser: EndorserInfoLike;
   created_at: ccc.NumLike;
@@ -288,6 +404,19 @@ export interface CampaignDataLike {
   quests: QuestDataLike[];
   participants_count: ccc.NumLike;
   total_completions: ccc.NumLike;
+  approval_dispute_window: ccc.NumLike;
+  badge_cluster_id: ccc.HexLike | null;
+  managers: CampaignManagerLike[];
+  quest_layout: ccc.NumLike;
+  approval_tracking: ccc.NumLike;
+  next_quest_id: ccc.NumLike;
+  last_recorded_at: ccc.NumLike;
+}
+
+export interface ApprovalProofLike {
+  quest_id: ccc.NumLike;
+  user_type_ids: ccc.HexLike[];
+  proof: ccc.BytesLike;
 }
 
 export interface TippingProposalMetadataLike {
@@ -320,6 +449,7 @@ export interface ScriptCodeHashesLike {
   ckb_boost_points_udt_type_code_hash: ccc.HexLike;
   accepted_udt_type_scripts: ccc.ScriptLike[];
   accepted_dob_type_scripts: ccc.ScriptLike[];
+  ckb_boost_quest_type_code_hash: ccc.HexLike;
 }
 
 export interface ProtocolConfigLike {
@@ -327,6 +457,12 @@ export interface ProtocolConfigLike {
   script_code_hashes: ScriptCodeHashesLike;
 }
 
+export interface AttestorInfoLike {
+  verification_bit: ccc.NumLike;
+  pubkey: ccc.BytesLike;
+  name: string;
+}
+
 export interface ProtocolDataLike {
   campaigns_approved: ccc.HexLike[];
   tipping_proposals: TippingProposalDataLike[];
@@ -334,11 +470,25 @@ export interface ProtocolDataLike {
   endorsers_whitelist: EndorserInfoLike[];
   last_updated: ccc.NumLike;
   protocol_config: ProtocolConfigLike;
+  attestors: AttestorInfoLike[];
+}
+
+export interface VerificationAttestationLike {
+  verification_bit: ccc.NumLike;
+  expires_at: ccc.NumLike;
+  signature: ccc.BytesLike;
 }
 
 export interface UserVerificationDataLike {
   telegram_personal_chat_id: ccc.NumLike;
   identity_verification_data: ccc.BytesLike;
+  verified_flags: ccc.NumLike;
+  attestations: VerificationAttestationLike[];
+}
+
+export interface SubTaskSubmissionLike {
+  sub_task_id: ccc.NumLike;
+  submission_content: string;
 }
 
 export interface UserSubmissionRecordLike {
@@ -346,6 +496,10 @@ export interface UserSubmissionRecordLike {
   quest_id: ccc.NumLike;
   submission_timestamp: ccc.NumLike;
   submission_content: string;
+  sub_task_submissions: SubTaskSubmissionLike[];
+  content_commitment: ccc.HexLike;
+  content_salt: ccc.HexLike;
+  content_hash: ccc.HexLike;
 }
 
 export interface UserDataLike {
@@ -353,6 +507,8 @@ export interface UserDataLike {
   total_points_earned: ccc.NumLike;
   last_activity_timestamp: ccc.NumLike;
   submission_records: UserSubmissionRecordLike[];
+  archived_submission_count: ccc.NumLike;
+  archived_submissions_hash: ccc.HexLike;
 }
 
 export interface ConnectedTypeIDLike {
@@ -204,6 +204,10 @@ export class Protocol extends ssri.Trait {
           ?.ckb_boost_points_udt_type_code_hash
           ? ccc.hexFrom(config.script_code_hashes.ckb_boost_points_udt_type_code_hash)
           : ccc.hexFrom("0x" + "0".repeat(64)),
+        ckb_boost_quest_type_code_hash: config.script_code_hashes
+          ?.ckb_boost_quest_type_code_hash
+          ? ccc.hexFrom(config.script_code_hashes.ckb_boost_quest_type_code_hash)
+          : ccc.hexFrom("0x" + "0".repeat(64)),
         accepted_udt_type_scripts: (
           config.script_code_hashes?.accepted_udt_type_scripts || []
         ).map((script) => ccc.Script.from(script)),
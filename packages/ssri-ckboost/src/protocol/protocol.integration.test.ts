@@ -103,6 +103,7 @@ describe('Protocol Integration Tests', () => {
             verified: 1
           }
         ],
+        attestors: [],
         last_updated: Date.now(),
         protocol_config: {
           admin_lock_hash_vec: [
@@ -119,7 +120,8 @@ describe('Protocol Integration Tests', () => {
             ckb_boost_user_type_code_hash: '0x' + '00'.repeat(32), // Replace with actual
             ckb_boost_points_udt_type_code_hash: '0x' + '00'.repeat(32), // Replace with actual
             accepted_udt_type_scripts: [],
-            accepted_dob_type_scripts: []
+            accepted_dob_type_scripts: [],
+            ckb_boost_quest_type_code_hash: '0x' + '77'.repeat(32)
           }
         }
       };
@@ -183,6 +185,7 @@ describe('Protocol Integration Tests', () => {
           expiration_duration: 3 * 24 * 60 * 60 // 3 days
         },
         endorsers_whitelist: [],
+        attestors: [],
         last_updated: Date.now(),
         protocol_config: {
           admin_lock_hash_vec: [
@@ -198,7 +201,8 @@ describe('Protocol Integration Tests', () => {
             ckb_boost_user_type_code_hash: '0x' + '00'.repeat(32),
             ckb_boost_points_udt_type_code_hash: '0x' + '00'.repeat(32),
             accepted_udt_type_scripts: [],
-            accepted_dob_type_scripts: []
+            accepted_dob_type_scripts: [],
+            ckb_boost_quest_type_code_hash: '0x' + '77'.repeat(32)
           }
         }
       };
@@ -222,6 +226,7 @@ describe('Protocol Integration Tests', () => {
           expiration_duration: 24 * 60 * 60 // 1 day
         },
         endorsers_whitelist: [],
+        attestors: [],
         last_updated: Date.now(),
         protocol_config: {
           admin_lock_hash_vec: [
@@ -237,7 +242,8 @@ describe('Protocol Integration Tests', () => {
             ckb_boost_user_type_code_hash: '0x' + '00'.repeat(32),
             ckb_boost_points_udt_type_code_hash: '0x' + '00'.repeat(32),
             accepted_udt_type_scripts: [],
-            accepted_dob_type_scripts: []
+            accepted_dob_type_scripts: [],
+            ckb_boost_quest_type_code_hash: '0x' + '77'.repeat(32)
           }
         }
       };
@@ -450,6 +456,7 @@ describe('Protocol Integration Tests', () => {
           expiration_duration: 0
         },
         endorsers_whitelist: [],
+        attestors: [],
         last_updated: Date.now(),
         protocol_config: {
           admin_lock_hash_vec: [],
@@ -461,7 +468,8 @@ describe('Protocol Integration Tests', () => {
             ckb_boost_user_type_code_hash: '0x' + '00'.repeat(32),
             ckb_boost_points_udt_type_code_hash: '0x' + '00'.repeat(32),
             accepted_udt_type_scripts: [],
-            accepted_dob_type_scripts: []
+            accepted_dob_type_scripts: [],
+            ckb_boost_quest_type_code_hash: '0x' + '77'.repeat(32)
           }
         }
       };
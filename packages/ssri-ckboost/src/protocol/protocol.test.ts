@@ -190,6 +190,7 @@ describe('Protocol', () => {
           expiration_duration: 3600
         },
         endorsers_whitelist: [],
+        attestors: [],
         last_updated: Date.now(),
         protocol_config: {
           admin_lock_hash_vec: ['0x' + 'aa'.repeat(32)],
@@ -201,7 +202,8 @@ describe('Protocol', () => {
             ckb_boost_user_type_code_hash: '0x' + '55'.repeat(32),
             ckb_boost_points_udt_type_code_hash: '0x' + '66'.repeat(32),
             accepted_udt_type_scripts: [],
-            accepted_dob_type_scripts: []
+            accepted_dob_type_scripts: [],
+            ckb_boost_quest_type_code_hash: '0x' + '77'.repeat(32)
           }
         }
       };
@@ -265,6 +267,7 @@ describe('Protocol', () => {
           expiration_duration: 0
         },
         endorsers_whitelist: [],
+        attestors: [],
         last_updated: Date.now(),
         protocol_config: {
           admin_lock_hash_vec: [],
@@ -276,7 +279,8 @@ describe('Protocol', () => {
             ckb_boost_user_type_code_hash: '0x' + '00'.repeat(32),
             ckb_boost_points_udt_type_code_hash: '0x' + '00'.repeat(32),
             accepted_udt_type_scripts: [],
-            accepted_dob_type_scripts: []
+            accepted_dob_type_scripts: [],
+            ckb_boost_quest_type_code_hash: '0x' + '77'.repeat(32)
           }
         }
       };
@@ -299,6 +303,7 @@ describe('Protocol', () => {
           expiration_duration: 0
         },
         endorsers_whitelist: [],
+        attestors: [],
         last_updated: Date.now(),
         protocol_config: {
           admin_lock_hash_vec: [],
@@ -310,7 +315,8 @@ describe('Protocol', () => {
             ckb_boost_user_type_code_hash: '0x' + '00'.repeat(32),
             ckb_boost_points_udt_type_code_hash: '0x' + '00'.repeat(32),
             accepted_udt_type_scripts: [],
-            accepted_dob_type_scripts: []
+            accepted_dob_type_scripts: [],
+            ckb_boost_quest_type_code_hash: '0x' + '77'.repeat(32)
           }
         }
       };
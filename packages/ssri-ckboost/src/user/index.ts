@@ -6,6 +6,8 @@ import {
   UserSubmissionRecord
 } from "../generated";
 
+const ZERO_HASH = ("0x" + "00".repeat(32)) as ccc.Hex;
+
 /**
  * Represents a CKBoost User contract for managing user operations.
  * 
@@ -107,6 +109,64 @@ export class User extends ssri.Trait {
     return resTx!;
   }
 
+  /**
+   * Reveal the content of a committed submission record
+   * 
+   * Must be sent in a later transaction than the one recording the commitment.
+   * 
+   * @param signer - The signer for the transaction
+   * @param userData - The current user data
+   * @param index - Index of the committed record in `submission_records`
+   * @param salt - The salt used for the commitment
+   * @param submissionContent - The content matching the commitment, or its URI in content-hash mode
+   * @param tx - Optional existing transaction to build upon
+   * @param contentHash - Hash of the off-chain content, omitted for inline content
+   * @returns The updated transaction
+   */
+  async revealSubmission(
+    signer: ccc.Signer,
+    userData: UserDataLike,
+    index: number,
+    salt: ccc.HexLike,
+    submissionContent: string,
+    tx?: ccc.Transaction,
+    contentHash: ccc.HexLike = ZERO_HASH
+  ): Promise<ssri.ExecutorResponse<ccc.Transaction>> {
+    if (!this.executor) {
+      throw new Error("Executor required for SSRI operations");
+    }
+
+    const txReq = ccc.Transaction.from(tx ?? {});
+    if (txReq.inputs.length === 0) {
+      await txReq.completeInputsAtLeastOne(signer);
+      await txReq.completeInputsByCapacity(signer);
+    }
+
+    const res = await this.executor.runScript(
+      this.code,
+      "CKBoostUser.reveal_submission",
+      [
+        ccc.hexFrom(txReq.toBytes()),
+        ccc.hexFrom(UserData.encode(userData)),
+        ccc.hexFrom(ccc.numLeToBytes(index, 4)),
+        ccc.hexFrom(salt),
+        ccc.hexFrom(ccc.bytesFrom(submissionContent, "utf8")),
+        ccc.hexFrom(contentHash),
+      ],
+      { script: this.script }
+    );
+    if (!res) {
+      throw new Error("No result from SSRI executor");
+    }
+
+    const resTx = res.map((res) => ccc.Transaction.fromBytes(res));
+    resTx.res.addCellDeps({
+      outPoint: this.code,
+      depType: "code",
+    });
+    return resTx;
+  }
+
   /**
    * Get user data from a cell
    * 
@@ -118,27 +178,100 @@ export class User extends ssri.Trait {
     return UserData.decode(rawData);
   }
 
+  /**
+   * Generate a random salt for a submission commitment
+   * 
+   * @returns 32 random bytes as hex
+   */
+  static generateSubmissionSalt(): ccc.Hex {
+    return ccc.hexFrom(crypto.getRandomValues(new Uint8Array(32)));
+  }
+
+  /**
+   * Compute the commitment recorded before a submission is revealed
+   * 
+   * Mirrors `submission_commitment` in the shared contract library.
+   * 
+   * @param userTypeId - The type ID of the submitting user cell
+   * @param campaignTypeId - The campaign type ID
+   * @param questId - The quest ID
+   * @param salt - 32-byte salt kept private until the reveal
+   * @param submissionContent - The content to be revealed later, or its URI in content-hash mode
+   * @param contentHash - Hash of the off-chain content, omitted for inline content
+   * @returns The commitment hash
+   */
+  static createSubmissionCommitment(
+    userTypeId: ccc.HexLike,
+    campaignTypeId: ccc.HexLike,
+    questId: number,
+    salt: ccc.HexLike,
+    submissionContent: string,
+    contentHash: ccc.HexLike = ZERO_HASH
+  ): ccc.Hex {
+    return ccc.hashCkb(
+      ccc.bytesFrom("ckboost-submission-commitment", "utf8"),
+      ccc.bytesFrom(userTypeId),
+      ccc.bytesFrom(campaignTypeId),
+      ccc.numLeToBytes(questId, 4),
+      ccc.bytesFrom(salt),
+      ccc.bytesFrom(contentHash),
+      ccc.bytesFrom(submissionContent, "utf8")
+    );
+  }
+
+  /**
+   * Hash off-chain submission content for a content-hash mode record
+   * 
+   * Mirrors `hash_submission_content` in the shared contract library.
+   * 
+   * @param content - The full submission content kept off-chain
+   * @returns The content hash
+   */
+  static hashSubmissionContent(content: string | ccc.BytesLike): ccc.Hex {
+    return ccc.hashCkb(typeof content === "string" ? ccc.bytesFrom(content, "utf8") : content);
+  }
+
+  /**
+   * Verify off-chain content fetched from a record's URI against its on-chain hash
+   * 
+   * @param record - The revealed submission record
+   * @param content - The content fetched from `record.submission_content`
+   * @returns Whether the record is in content-hash mode and the content matches
+   */
+  static verifyOffchainContent(
+    record: ReturnType<typeof UserSubmissionRecord.decode>,
+    content: string | ccc.BytesLike
+  ): boolean {
+    const contentHash = ccc.hexFrom(record.content_hash);
+    return contentHash !== ZERO_HASH && contentHash === User.hashSubmissionContent(content);
+  }
+
   /**
    * Create a new submission record
    * 
+   * The content stays empty until it is revealed with `revealSubmission`.
+   * 
    * @param campaignTypeId - The campaign type ID
    * @param questId - The quest ID
-   * @param submissionContent - The submission content (URL to Neon storage)
+   * @param contentCommitment - Commitment from `createSubmissionCommitment`
    * @returns The submission record
    */
   static createSubmissionRecord(
     campaignTypeId: ccc.HexLike,
     questId: number,
-    submissionContent: string
+    contentCommitment: ccc.HexLike
   ): ReturnType<typeof UserSubmissionRecord.encode> {
     const timestamp = BigInt(Date.now());
     
-    // mol.String expects the string directly, it handles the encoding internally
     return UserSubmissionRecord.encode({
       campaign_type_id: ccc.hexFrom(campaignTypeId),
       quest_id: questId,
       submission_timestamp: timestamp,
-      submission_content: submissionContent
+      submission_content: "",
+      sub_task_submissions: [],
+      content_commitment: ccc.hexFrom(contentCommitment),
+      content_salt: ZERO_HASH,
+      content_hash: ZERO_HASH
     });
   }
 }
\ No newline at end of file
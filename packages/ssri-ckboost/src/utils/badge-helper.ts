@@ -0,0 +1,37 @@
+import { ccc } from '@ckb-ccc/core';
+import { SporeData } from '../generated';
+
+/**
+ * Helper functions for completion badges minted as Spore DOBs
+ */
+
+/**
+ * Content type of completion badges, a DOB/0 digital object
+ */
+export const BADGE_CONTENT_TYPE = 'dob/0';
+
+/**
+ * DOB/0 content of the completion badge of a quest
+ * The DNA is the campaign type ID followed by the little-endian quest ID, hex encoded
+ */
+export function badgeContent(campaignTypeId: ccc.HexLike, questId: ccc.NumLike): string {
+  const dna = ccc.hexFrom(ccc.bytesConcat(ccc.bytesFrom(campaignTypeId), ccc.numLeToBytes(questId, 4)));
+  return JSON.stringify({ dna: dna.slice(2) });
+}
+
+/**
+ * Cell data of the badge spore a campaign with a badge cluster mints for an approved user
+ */
+export function badgeSporeData(
+  campaignTypeId: ccc.HexLike,
+  questId: ccc.NumLike,
+  clusterId: ccc.HexLike
+): ccc.Hex {
+  return ccc.hexFrom(
+    SporeData.encode({
+      content_type: ccc.bytesFrom(BADGE_CONTENT_TYPE, 'utf8'),
+      content: ccc.bytesFrom(badgeContent(campaignTypeId, questId), 'utf8'),
+      cluster_id: clusterId,
+    })
+  );
+}
@@ -1,2 +1,3 @@
 export * from './witness-helper';
-export * from './witness-recipe-helper';
\ No newline at end of file
+export * from './witness-recipe-helper';
+export * from './badge-helper';
\ No newline at end of file